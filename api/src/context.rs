@@ -9,12 +9,15 @@ use crate::response::{
 };
 use anyhow::{ensure, format_err, Context as AnyhowContext, Result};
 use aptos_api_types::{
-    AptosErrorCode, AsConverter, BcsBlock, GasEstimation, LedgerInfo, TransactionOnChainData,
+    AptosErrorCode, AsConverter, BcsBlock, GasEstimation, LedgerInfo, StateValueWithProof,
+    TransactionOnChainData, TransactionsWithProof,
 };
 use aptos_config::config::{NodeConfig, RoleType};
 use aptos_crypto::HashValue;
 use aptos_gas::{AptosGasParameters, FromOnChainGasSchedule};
-use aptos_mempool::{MempoolClientRequest, MempoolClientSender, SubmissionStatus};
+use aptos_mempool::{
+    MempoolClientRequest, MempoolClientSender, PendingTransactionInfo, SubmissionStatus,
+};
 use aptos_state_view::StateView;
 use aptos_types::account_config::NewBlockEvent;
 use aptos_types::account_view::AccountView;
@@ -27,6 +30,7 @@ use aptos_types::{
     contract_event::EventWithVersion,
     event::EventKey,
     ledger_info::LedgerInfoWithSignatures,
+    state_proof::StateProof,
     state_store::{state_key::StateKey, state_key_prefix::StateKeyPrefix, state_value::StateValue},
     transaction::{SignedTransaction, TransactionWithProof, Version},
 };
@@ -91,6 +95,10 @@ impl Context {
         self.node_config.api.max_events_page_size
     }
 
+    pub fn max_gas_view_function(&self) -> u64 {
+        self.node_config.api.max_gas_view_function
+    }
+
     pub fn move_resolver(&self) -> Result<StorageAdapterOwned<DbStateView>> {
         self.db
             .latest_state_checkpoint_view()
@@ -214,6 +222,46 @@ impl Context {
         self.db.get_latest_ledger_info()
     }
 
+    /// Returns the latest ledger info along with the epoch changes since the epoch of
+    /// `known_version`, to prove the ledger info to clients trusting `known_version`
+    pub fn get_state_proof(&self, known_version: Version) -> Result<StateProof> {
+        self.db.get_state_proof(known_version)
+    }
+
+    /// Returns the value of `state_key` in the latest state snapshot at or before `version`,
+    /// along with the proofs of it against the latest ledger info
+    pub fn get_state_value_with_proof(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+    ) -> Result<StateValueWithProof> {
+        // Fetched first, so that it's at least at `version`
+        let ledger_info_with_sigs = self.get_latest_ledger_info_with_signatures()?;
+        let (snapshot_version, _) = self
+            .db
+            .get_state_snapshot_before(version + 1)?
+            .ok_or_else(|| format_err!("No state snapshot at or before version {}", version))?;
+        let (value, sparse_merkle_proof) = self
+            .db
+            .get_state_value_with_proof_by_version(state_key, snapshot_version)?;
+        let transaction_info_with_proof = self
+            .db
+            .get_transaction_by_version(
+                snapshot_version,
+                ledger_info_with_sigs.ledger_info().version(),
+                false,
+            )?
+            .proof;
+
+        Ok(StateValueWithProof {
+            ledger_info_with_sigs,
+            version: snapshot_version,
+            value,
+            sparse_merkle_proof,
+            transaction_info_with_proof,
+        })
+    }
+
     pub fn get_state_value(&self, state_key: &StateKey, version: u64) -> Result<Option<Vec<u8>>> {
         self.db
             .state_view_at_version(Some(version))?
@@ -479,6 +527,27 @@ impl Context {
             .collect()
     }
 
+    /// Returns the transactions along with their events, and the proofs of them against the
+    /// latest ledger info
+    pub fn get_transactions_with_proof(
+        &self,
+        start_version: u64,
+        limit: u16,
+    ) -> Result<TransactionsWithProof> {
+        let ledger_info_with_sigs = self.get_latest_ledger_info_with_signatures()?;
+        let transaction_list_with_proof = self.db.get_transactions(
+            start_version,
+            limit as u64,
+            ledger_info_with_sigs.ledger_info().version(),
+            true,
+        )?;
+
+        Ok(TransactionsWithProof {
+            ledger_info_with_sigs,
+            transaction_list_with_proof,
+        })
+    }
+
     pub fn get_account_transactions<E: NotFoundError + InternalError>(
         &self,
         address: AccountAddress,
@@ -568,6 +637,55 @@ impl Context {
         callback.await.map_err(anyhow::Error::from)
     }
 
+    pub async fn get_pending_transactions_by_sender(
+        &self,
+        sender: AccountAddress,
+    ) -> Result<Vec<PendingTransactionInfo>> {
+        let (req_sender, callback) = oneshot::channel();
+
+        self.mp_sender
+            .clone()
+            .send(MempoolClientRequest::GetPendingTransactionsBySender(
+                sender, req_sender,
+            ))
+            .await
+            .map_err(anyhow::Error::from)?;
+
+        callback.await.map_err(anyhow::Error::from)
+    }
+
+    pub async fn get_parked_transactions(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<PendingTransactionInfo>> {
+        let (req_sender, callback) = oneshot::channel();
+
+        self.mp_sender
+            .clone()
+            .send(MempoolClientRequest::GetParkedTransactions(
+                limit, req_sender,
+            ))
+            .await
+            .map_err(anyhow::Error::from)?;
+
+        callback.await.map_err(anyhow::Error::from)
+    }
+
+    pub async fn evict_pending_transaction(
+        &self,
+        hash: HashValue,
+    ) -> Result<Option<SignedTransaction>> {
+        let (req_sender, callback) = oneshot::channel();
+
+        self.mp_sender
+            .clone()
+            .send(MempoolClientRequest::EvictTransaction(hash, req_sender))
+            .await
+            .map_err(anyhow::Error::from)?;
+
+        callback.await.map_err(anyhow::Error::from)
+    }
+
     pub fn get_transaction_by_version(
         &self,
         version: u64,
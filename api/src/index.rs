@@ -5,10 +5,15 @@ use std::sync::Arc;
 
 use crate::accept_type::AcceptType;
 use crate::context::Context;
-use crate::response::{BasicResponse, BasicResponseStatus, BasicResult};
+use crate::failpoint::fail_point_poem;
+use crate::response::{
+    version_not_found, BadRequestError, BasicErrorWith404, BasicResponse, BasicResponseStatus,
+    BasicResult, BasicResultWith404, InternalError,
+};
 use crate::ApiTags;
-use aptos_api_types::{IndexResponse, IndexResponseBcs};
-use poem_openapi::OpenApi;
+use anyhow::Context as AnyhowContext;
+use aptos_api_types::{AptosErrorCode, HexEncodedBytes, IndexResponse, IndexResponseBcs, U64};
+use poem_openapi::{param::Query, OpenApi};
 
 /// API for the index, to retrieve the ledger information
 pub struct IndexApi {
@@ -53,4 +58,55 @@ impl IndexApi {
             }
         }
     }
+
+    /// Get state proof
+    ///
+    /// Get the latest ledger info signed by the validators, along with the epoch change ledger
+    /// infos since the epoch of the known version. This allows clients trusting the known version
+    /// to verify the latest ledger info, and with it the proofs returned by the other endpoints.
+    ///
+    /// If there are too many epoch changes, only the first of them are returned, and the client
+    /// must request the state proof again from the last of them.
+    ///
+    /// The state proof can only be returned as BCS (StateProof).
+    #[oai(
+        path = "/state_proof",
+        method = "get",
+        operation_id = "get_state_proof",
+        tag = "ApiTags::Proofs"
+    )]
+    async fn get_state_proof(
+        &self,
+        accept_type: AcceptType,
+        /// Ledger version trusted by the client
+        known_version: Query<U64>,
+    ) -> BasicResultWith404<HexEncodedBytes> {
+        fail_point_poem("endpoint_get_state_proof")?;
+        self.context
+            .check_api_output_enabled("Get state proof", &accept_type)?;
+        if accept_type == AcceptType::Json {
+            return Err(BasicErrorWith404::bad_request_with_code_no_info(
+                "State proofs can only be returned as BCS",
+                AptosErrorCode::InvalidInput,
+            ));
+        }
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let known_version = known_version.0 .0;
+        if known_version > ledger_info.version() {
+            return Err(version_not_found(known_version, &ledger_info));
+        }
+
+        let state_proof = self
+            .context
+            .get_state_proof(known_version)
+            .context("Failed to retrieve state proof")
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?;
+        BasicResponse::try_from_bcs((state_proof, &ledger_info, BasicResponseStatus::Ok))
+    }
 }
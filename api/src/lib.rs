@@ -15,6 +15,7 @@ mod events;
 mod failpoint;
 mod index;
 mod log;
+mod mempool;
 pub mod metrics;
 mod page;
 mod response;
@@ -24,6 +25,7 @@ mod state;
 #[cfg(test)]
 pub mod tests;
 mod transactions;
+mod view_function;
 
 /// API categories for the OpenAPI spec
 #[derive(Tags)]
@@ -39,11 +41,20 @@ pub enum ApiTags {
     /// General information
     General,
 
+    /// Access to pending transactions in mempool
+    Mempool,
+
+    /// Access to state and transactions along with proofs of them
+    Proofs,
+
     /// Access to tables
     Tables,
 
     /// Access to transactions
     Transactions,
+
+    /// View functions
+    View,
 }
 
 // Note: Many of these exports are just for the test-context crate, which is
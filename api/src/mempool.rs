@@ -0,0 +1,231 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    accept_type::AcceptType,
+    context::Context,
+    failpoint::fail_point_poem,
+    page::Page,
+    response::{
+        api_disabled, transaction_not_found_by_hash, BasicErrorWith404, BasicResponse,
+        BasicResponseStatus, BasicResultWith404, InternalError,
+    },
+    ApiTags,
+};
+use anyhow::Context as AnyhowContext;
+use aptos_api_types::{
+    Address, AptosErrorCode, AsConverter, HashValue, LedgerInfo, MempoolTransaction,
+    MempoolTransactionStatus, PendingTransaction,
+};
+use aptos_mempool::{PendingTransactionInfo, PendingTransactionStatus};
+use poem_openapi::{
+    param::{Path, Query},
+    OpenApi,
+};
+use std::sync::Arc;
+
+/// API for inspecting and administering the pending transactions in mempool
+pub struct MempoolApi {
+    pub context: Arc<Context>,
+}
+
+#[OpenApi]
+impl MempoolApi {
+    /// Get account pending transactions
+    ///
+    /// Retrieves the transactions of an account that are waiting in the mempool of this node,
+    /// ordered by sequence number. Each transaction comes with its mempool status: a parked
+    /// transaction can't be included in a block until the sender submits the transaction
+    /// with the missing sequence number.
+    #[oai(
+        path = "/accounts/:address/pending_transactions",
+        method = "get",
+        operation_id = "get_account_pending_transactions",
+        tag = "ApiTags::Mempool"
+    )]
+    async fn get_account_pending_transactions(
+        &self,
+        accept_type: AcceptType,
+        /// Address of account with or without a `0x` prefix
+        address: Path<Address>,
+    ) -> BasicResultWith404<Vec<MempoolTransaction>> {
+        fail_point_poem("endpoint_get_account_pending_transactions")?;
+        self.context
+            .check_api_output_enabled("Get account pending transactions", &accept_type)?;
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let txns = self
+            .context
+            .get_pending_transactions_by_sender(address.0.into())
+            .await
+            .context("Failed to get pending transactions from mempool")
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?;
+        self.render_transactions(&accept_type, txns, &ledger_info)
+    }
+
+    /// Get parked transactions
+    ///
+    /// Retrieves transactions in the parking lot of the mempool of this node, i.e.
+    /// transactions that can't be included in a block because a transaction with a
+    /// lower sequence number of the same sender is missing.
+    #[oai(
+        path = "/mempool/parked_transactions",
+        method = "get",
+        operation_id = "get_parked_transactions",
+        tag = "ApiTags::Mempool"
+    )]
+    async fn get_parked_transactions(
+        &self,
+        accept_type: AcceptType,
+        /// Max number of transactions to retrieve.
+        ///
+        /// If not provided, defaults to default page size
+        limit: Query<Option<u16>>,
+    ) -> BasicResultWith404<Vec<MempoolTransaction>> {
+        fail_point_poem("endpoint_get_parked_transactions")?;
+        self.context
+            .check_api_output_enabled("Get parked transactions", &accept_type)?;
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let limit = Page::new(None, limit.0, self.context.max_transactions_page_size())
+            .limit(&ledger_info)?;
+        let txns = self
+            .context
+            .get_parked_transactions(limit as usize)
+            .await
+            .context("Failed to get parked transactions from mempool")
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?;
+        self.render_transactions(&accept_type, txns, &ledger_info)
+    }
+
+    /// Evict pending transaction
+    ///
+    /// Removes a transaction, e.g. a stuck one, from the mempool of this node and returns it.
+    /// The following transactions of the same sender are parked until a transaction with the
+    /// evicted sequence number is submitted again. Note that the transaction may still be
+    /// broadcast back by other nodes that have it in their mempool.
+    ///
+    /// This endpoint is disabled unless `api.mempool_eviction_enabled` is set in the node config.
+    #[oai(
+        path = "/mempool/transactions/:txn_hash",
+        method = "delete",
+        operation_id = "evict_pending_transaction",
+        tag = "ApiTags::Mempool"
+    )]
+    async fn evict_pending_transaction(
+        &self,
+        accept_type: AcceptType,
+        /// Hash of the transaction to evict
+        txn_hash: Path<HashValue>,
+    ) -> BasicResultWith404<PendingTransaction> {
+        fail_point_poem("endpoint_evict_pending_transaction")?;
+        self.context
+            .check_api_output_enabled("Evict pending transaction", &accept_type)?;
+        if !self.context.node_config.api.mempool_eviction_enabled {
+            return Err(api_disabled("Evict pending transaction"));
+        }
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let txn = self
+            .context
+            .evict_pending_transaction(txn_hash.0.into())
+            .await
+            .context("Failed to evict transaction from mempool")
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?
+            .ok_or_else(|| transaction_not_found_by_hash(txn_hash.0, &ledger_info))?;
+
+        match accept_type {
+            AcceptType::Json => {
+                let pending_txn = self
+                    .context
+                    .move_resolver_poem(&ledger_info)?
+                    .as_converter(self.context.db.clone())
+                    .try_into_pending_transaction_poem(txn)
+                    .context("Failed to convert evicted transaction to PendingTransaction")
+                    .map_err(|err| {
+                        BasicErrorWith404::internal_with_code(
+                            err,
+                            AptosErrorCode::InternalError,
+                            &ledger_info,
+                        )
+                    })?;
+                BasicResponse::try_from_json((pending_txn, &ledger_info, BasicResponseStatus::Ok))
+            }
+            AcceptType::Bcs => {
+                BasicResponse::try_from_bcs((txn, &ledger_info, BasicResponseStatus::Ok))
+            }
+        }
+    }
+}
+
+impl MempoolApi {
+    /// Converts the mempool transactions into the outgoing type
+    ///
+    /// JSON: Convert to MempoolTransactions
+    /// BCS: Return the list of PendingTransactionInfo
+    fn render_transactions(
+        &self,
+        accept_type: &AcceptType,
+        txns: Vec<PendingTransactionInfo>,
+        ledger_info: &LedgerInfo,
+    ) -> BasicResultWith404<Vec<MempoolTransaction>> {
+        match accept_type {
+            AcceptType::Json => {
+                let resolver = self.context.move_resolver_poem(ledger_info)?;
+                let converter = resolver.as_converter(self.context.db.clone());
+                let txns = txns
+                    .into_iter()
+                    .map(|info| {
+                        let (status, missing_sequence_number) = match info.status {
+                            PendingTransactionStatus::Ready => {
+                                (MempoolTransactionStatus::Ready, None)
+                            }
+                            PendingTransactionStatus::Parked {
+                                missing_sequence_number,
+                            } => (
+                                MempoolTransactionStatus::Parked,
+                                Some(missing_sequence_number.into()),
+                            ),
+                        };
+                        Ok(MempoolTransaction {
+                            transaction: converter
+                                .try_into_pending_transaction_poem(info.transaction)?,
+                            ranking_score: info.ranking_score.into(),
+                            insertion_timestamp: info.insertion_timestamp_usecs.into(),
+                            account_sequence_number: info.account_sequence_number.into(),
+                            status,
+                            missing_sequence_number,
+                        })
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()
+                    .context("Failed to convert mempool transactions")
+                    .map_err(|err| {
+                        BasicErrorWith404::internal_with_code(
+                            err,
+                            AptosErrorCode::InternalError,
+                            ledger_info,
+                        )
+                    })?;
+                BasicResponse::try_from_json((txns, ledger_info, BasicResponseStatus::Ok))
+            }
+            AcceptType::Bcs => {
+                BasicResponse::try_from_bcs((txns, ledger_info, BasicResponseStatus::Ok))
+            }
+        }
+    }
+}
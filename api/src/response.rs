@@ -32,7 +32,9 @@
 use std::fmt::Display;
 
 use super::accept_type::AcceptType;
-use aptos_api_types::{Address, AptosError, AptosErrorCode, HashValue, LedgerInfo};
+use aptos_api_types::{
+    Address, AptosError, AptosErrorCode, HashValue, HexEncodedBytes, LedgerInfo,
+};
 use move_core_types::identifier::{IdentStr, Identifier};
 use move_core_types::language_storage::StructTag;
 use poem_openapi::{payload::Json, types::ToJSON, ResponseContent};
@@ -675,6 +677,23 @@ pub fn table_item_not_found<E: NotFoundError>(
     )
 }
 
+pub fn raw_table_item_not_found<E: NotFoundError>(
+    table_handle: Address,
+    table_key: &HexEncodedBytes,
+    ledger_version: u64,
+    ledger_info: &LedgerInfo,
+) -> E {
+    build_not_found(
+        "Table Item",
+        format!(
+            "Table handle({}), Table key({}) and Ledger version({})",
+            table_handle, table_key, ledger_version
+        ),
+        AptosErrorCode::TableItemNotFound,
+        ledger_info,
+    )
+}
+
 pub fn block_not_found_by_height<E: NotFoundError>(
     block_height: u64,
     ledger_info: &LedgerInfo,
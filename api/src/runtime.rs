@@ -6,7 +6,8 @@ use std::{net::SocketAddr, sync::Arc};
 use crate::{
     accounts::AccountsApi, basic::BasicApi, blocks::BlocksApi, check_size::PostSizeLimit,
     context::Context, error_converter::convert_error, events::EventsApi, index::IndexApi,
-    log::middleware_log, set_failpoints, state::StateApi, transactions::TransactionsApi,
+    log::middleware_log, mempool::MempoolApi, set_failpoints, state::StateApi,
+    transactions::TransactionsApi, view_function::ViewFunctionApi,
 };
 use anyhow::Context as AnyhowContext;
 use aptos_config::config::NodeConfig;
@@ -68,8 +69,10 @@ pub fn get_api_service(
         BlocksApi,
         EventsApi,
         IndexApi,
+        MempoolApi,
         StateApi,
         TransactionsApi,
+        ViewFunctionApi,
     ),
     (),
 > {
@@ -90,10 +93,16 @@ pub fn get_api_service(
         IndexApi {
             context: context.clone(),
         },
+        MempoolApi {
+            context: context.clone(),
+        },
         StateApi {
             context: context.clone(),
         },
-        TransactionsApi { context },
+        TransactionsApi {
+            context: context.clone(),
+        },
+        ViewFunctionApi { context },
     );
 
     let version = VERSION.to_string();
@@ -1,7 +1,10 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::response::{module_not_found, resource_not_found, table_item_not_found, StdApiError};
+use crate::response::{
+    module_not_found, raw_table_item_not_found, resource_not_found, table_item_not_found,
+    StdApiError,
+};
 use crate::{
     accept_type::AcceptType,
     failpoint::fail_point_poem,
@@ -13,8 +16,9 @@ use crate::{
 };
 use anyhow::Context as AnyhowContext;
 use aptos_api_types::{
-    verify_module_identifier, Address, AptosErrorCode, AsConverter, IdentifierWrapper, LedgerInfo,
-    MoveModuleBytecode, MoveResource, MoveStructTag, MoveValue, TableItemRequest, VerifyInput,
+    verify_module_identifier, Address, AptosErrorCode, AsConverter, HexEncodedBytes,
+    IdentifierWrapper, LedgerInfo, MoveModuleBytecode, MoveResource, MoveStructTag, MoveValue,
+    RawTableItemRequest, StateValueProofRequest, TableItemRequest, VerifyInput,
     VerifyInputWithRecursion, U64,
 };
 use aptos_state_view::StateView;
@@ -165,6 +169,86 @@ impl StateApi {
             ledger_version.0,
         )
     }
+
+    /// Get raw table item
+    ///
+    /// Get a table item at a specific ledger version from the table identified by {table_handle}
+    /// in the path and the BCS serialized "key" (RawTableItemRequest) provided in the request body.
+    ///
+    /// Unlike `get_table_item`, this doesn't require the types of the key and value of the table,
+    /// but the table item can only be returned as BCS.
+    ///
+    /// The Aptos nodes prune account state history, via a configurable time window.
+    /// If the requested ledger version has been pruned, the server responds with a 410.
+    #[oai(
+        path = "/tables/:table_handle/raw_item",
+        method = "post",
+        operation_id = "get_raw_table_item",
+        tag = "ApiTags::Tables"
+    )]
+    async fn get_raw_table_item(
+        &self,
+        accept_type: AcceptType,
+        /// Table handle hex encoded 32-byte string
+        table_handle: Path<Address>,
+        /// Table request with the BCS serialized key
+        table_item_request: Json<RawTableItemRequest>,
+        /// Ledger version to get state of account
+        ///
+        /// If not provided, it will be the latest version
+        ledger_version: Query<Option<U64>>,
+    ) -> BasicResultWith404<MoveValue> {
+        fail_point_poem("endpoint_get_raw_table_item")?;
+        self.context
+            .check_api_output_enabled("Get raw table item", &accept_type)?;
+        if accept_type == AcceptType::Json {
+            return Err(BasicErrorWith404::bad_request_with_code_no_info(
+                "Raw table items can only be returned as BCS",
+                AptosErrorCode::InvalidInput,
+            ));
+        }
+        self.raw_table_item(table_handle.0, table_item_request.0, ledger_version.0)
+    }
+
+    /// Get state value with proof
+    ///
+    /// Get the value of a state key, e.g. of a resource, a module or a table item, along with
+    /// proofs of it against the latest ledger info signed by the validators. The state key is
+    /// provided BCS serialized in the request body (StateValueProofRequest).
+    ///
+    /// The value is read from the latest state snapshot at or before the ledger version, the
+    /// version of the snapshot is returned along with the value. The value and its proofs can
+    /// only be returned as BCS (StateValueWithProof).
+    ///
+    /// The Aptos nodes prune account state history, via a configurable time window.
+    /// If the requested ledger version has been pruned, the server responds with a 410.
+    #[oai(
+        path = "/state_values/proof",
+        method = "post",
+        operation_id = "get_state_value_with_proof",
+        tag = "ApiTags::Proofs"
+    )]
+    async fn get_state_value_with_proof(
+        &self,
+        accept_type: AcceptType,
+        /// Request with the BCS serialized state key
+        state_value_request: Json<StateValueProofRequest>,
+        /// Ledger version to get the state value at
+        ///
+        /// If not provided, it will be the latest version
+        ledger_version: Query<Option<U64>>,
+    ) -> BasicResultWith404<HexEncodedBytes> {
+        fail_point_poem("endpoint_get_state_value_with_proof")?;
+        self.context
+            .check_api_output_enabled("Get state value with proof", &accept_type)?;
+        if accept_type == AcceptType::Json {
+            return Err(BasicErrorWith404::bad_request_with_code_no_info(
+                "State values with proofs can only be returned as BCS",
+                AptosErrorCode::InvalidInput,
+            ));
+        }
+        self.state_value_with_proof(state_value_request.0, ledger_version.0)
+    }
 }
 
 impl StateApi {
@@ -385,4 +469,79 @@ impl StateApi {
             }
         }
     }
+
+    /// Retrieve the BCS encoded table item for a specific ledger version
+    pub fn raw_table_item(
+        &self,
+        table_handle: Address,
+        table_item_request: RawTableItemRequest,
+        ledger_version: Option<U64>,
+    ) -> BasicResultWith404<MoveValue> {
+        let (ledger_info, ledger_version, state_view) =
+            self.preprocess_request(ledger_version.map(|inner| inner.0))?;
+
+        let key = table_item_request.key;
+        let state_key = StateKey::table_item(TableHandle(table_handle.into()), key.0.clone());
+        let bytes = state_view
+            .get_state_value(&state_key)
+            .context(format!(
+                "Failed when trying to retrieve table item from the DB with key: {}",
+                key
+            ))
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?
+            .ok_or_else(|| {
+                raw_table_item_not_found(table_handle, &key, ledger_version, &ledger_info)
+            })?;
+
+        BasicResponse::try_from_encoded((bytes, &ledger_info, BasicResponseStatus::Ok))
+    }
+
+    /// Retrieve the state value with proofs for a specific ledger version
+    pub fn state_value_with_proof(
+        &self,
+        state_value_request: StateValueProofRequest,
+        ledger_version: Option<U64>,
+    ) -> BasicResultWith404<HexEncodedBytes> {
+        let (ledger_info, ledger_version) = self
+            .context
+            .get_latest_ledger_info_and_verify_lookup_version(
+                ledger_version.map(|inner| inner.0),
+            )?;
+
+        let state_key: StateKey = bcs::from_bytes(&state_value_request.state_key.0)
+            .context("'state_key' invalid")
+            .map_err(|err| {
+                BasicErrorWith404::bad_request_with_code(
+                    err,
+                    AptosErrorCode::InvalidInput,
+                    &ledger_info,
+                )
+            })?;
+        let state_value_with_proof = self
+            .context
+            .get_state_value_with_proof(&state_key, ledger_version)
+            .context(format!(
+                "Failed when trying to retrieve state value with proof from the DB at version: {}",
+                ledger_version
+            ))
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?;
+
+        BasicResponse::try_from_bcs((
+            state_value_with_proof,
+            &ledger_info,
+            BasicResponseStatus::Ok,
+        ))
+    }
 }
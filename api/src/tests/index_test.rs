@@ -3,7 +3,10 @@
 
 use super::new_test_context;
 use aptos_api_test_context::current_function_name;
+use aptos_api_types::mime_types::BCS;
+use aptos_types::state_proof::StateProof;
 use serde_json::json;
+use warp::http::header::ACCEPT;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_get_index() {
@@ -33,6 +36,41 @@ async fn test_return_bad_request_if_method_not_allowed() {
     context.check_golden_output(resp);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_state_proof() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account);
+    context.commit_block(&[txn]).await;
+    let ledger_version = context.get_latest_ledger_info().version();
+
+    let resp = context
+        .reply(
+            warp::test::request()
+                .method("GET")
+                .path(&context.prepend_path("/state_proof?known_version=0"))
+                .header(ACCEPT, BCS),
+        )
+        .await;
+    assert_eq!(resp.status(), 200);
+    let state_proof: StateProof = bcs::from_bytes(resp.body()).unwrap();
+    assert_eq!(state_proof.latest_ledger_info().version(), ledger_version);
+
+    // Versions after the latest one aren't known yet
+    let resp = context
+        .reply(
+            warp::test::request()
+                .method("GET")
+                .path(&context.prepend_path(&format!(
+                    "/state_proof?known_version={}",
+                    ledger_version + 1
+                )))
+                .header(ACCEPT, BCS),
+        )
+        .await;
+    assert_eq!(resp.status(), 404);
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_health_check() {
     let context = new_test_context(current_function_name!());
@@ -0,0 +1,191 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use super::new_test_context;
+use aptos_api_test_context::{current_function_name, new_test_context_with_config, TestContext};
+use aptos_config::config::NodeConfig;
+use aptos_sdk::types::LocalAccount;
+use aptos_types::transaction::SignedTransaction;
+use serde_json::Value;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_account_pending_transactions() {
+    let mut context = new_test_context(current_function_name!());
+    let hashes = submit_root_transactions_with_gap(&mut context).await;
+
+    let resp = context
+        .get(&account_pending_transactions_path(&context))
+        .await;
+    let txns = resp.as_array().unwrap();
+    assert_eq!(txns.len(), 2);
+    assert_eq!(txns[0]["transaction"]["hash"], hashes[0]);
+    assert_eq!(txns[0]["status"], "ready");
+    assert_eq!(txns[0]["account_sequence_number"], "0");
+    assert!(txns[0].get("missing_sequence_number").is_none());
+    assert_eq!(txns[1]["transaction"]["hash"], hashes[1]);
+    assert_eq!(txns[1]["status"], "parked");
+    assert_eq!(txns[1]["missing_sequence_number"], "1");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_account_pending_transactions_without_transactions() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+
+    let resp = context
+        .get(&format!(
+            "/accounts/{}/pending_transactions",
+            account.address()
+        ))
+        .await;
+    assert_eq!(resp, Value::Array(vec![]));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_account_pending_transactions_by_invalid_address() {
+    let context = new_test_context(current_function_name!());
+
+    let resp = context
+        .expect_status_code(400)
+        .get("/accounts/0xzzz/pending_transactions")
+        .await;
+    assert_eq!(resp["error_code"], "web_framework_error");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_parked_transactions() {
+    let mut context = new_test_context(current_function_name!());
+    let hashes = submit_root_transactions_with_gap(&mut context).await;
+
+    let resp = context.get("/mempool/parked_transactions").await;
+    let txns = resp.as_array().unwrap();
+    assert_eq!(txns.len(), 1);
+    assert_eq!(txns[0]["transaction"]["hash"], hashes[1]);
+    assert_eq!(txns[0]["status"], "parked");
+    assert_eq!(txns[0]["missing_sequence_number"], "1");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_parked_transactions_with_zero_limit() {
+    let context = new_test_context(current_function_name!());
+
+    let resp = context
+        .expect_status_code(400)
+        .get("/mempool/parked_transactions?limit=0")
+        .await;
+    assert_eq!(resp["error_code"], "invalid_input");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_evict_pending_transaction() {
+    let mut context = new_eviction_test_context(current_function_name!());
+    let mut root_account = context.root_account();
+    let first_txn = create_account_txn(&mut context, &mut root_account);
+    let second_txn = create_account_txn(&mut context, &mut root_account);
+    let first_hash = submit_transaction(&context, &first_txn).await;
+    let second_hash = submit_transaction(&context, &second_txn).await;
+
+    // Evict the first transaction
+    let resp = context
+        .delete(&format!(
+            "/mempool/transactions/{}",
+            first_hash.as_str().unwrap()
+        ))
+        .await;
+    assert_eq!(resp["hash"], first_hash);
+    assert_eq!(resp["sequence_number"], "0");
+
+    // The second transaction is now parked, waiting for the evicted sequence number
+    let resp = context
+        .get(&account_pending_transactions_path(&context))
+        .await;
+    let txns = resp.as_array().unwrap();
+    assert_eq!(txns.len(), 1);
+    assert_eq!(txns[0]["transaction"]["hash"], second_hash);
+    assert_eq!(txns[0]["status"], "parked");
+    assert_eq!(txns[0]["missing_sequence_number"], "0");
+
+    // The evicted transaction can't be evicted again
+    let resp = context
+        .expect_status_code(404)
+        .delete(&format!(
+            "/mempool/transactions/{}",
+            first_hash.as_str().unwrap()
+        ))
+        .await;
+    assert_eq!(resp["error_code"], "transaction_not_found");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_evict_pending_transaction_by_invalid_hash() {
+    let context = new_eviction_test_context(current_function_name!());
+
+    let resp = context
+        .expect_status_code(400)
+        .delete("/mempool/transactions/0xzzz")
+        .await;
+    assert_eq!(resp["error_code"], "web_framework_error");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_evict_pending_transaction_disabled() {
+    let mut context = new_test_context(current_function_name!());
+    let mut root_account = context.root_account();
+    let txn = create_account_txn(&mut context, &mut root_account);
+    let hash = submit_transaction(&context, &txn).await;
+
+    let resp = context
+        .expect_status_code(403)
+        .delete(&format!("/mempool/transactions/{}", hash.as_str().unwrap()))
+        .await;
+    assert_eq!(resp["error_code"], "api_disabled");
+
+    // The transaction is still in mempool
+    let resp = context
+        .get(&account_pending_transactions_path(&context))
+        .await;
+    assert_eq!(resp.as_array().unwrap().len(), 1);
+}
+
+fn new_eviction_test_context(test_name: String) -> TestContext {
+    let mut node_config = NodeConfig::default();
+    node_config.api.mempool_eviction_enabled = true;
+    new_test_context_with_config(test_name, node_config, false)
+}
+
+fn account_pending_transactions_path(context: &TestContext) -> String {
+    format!(
+        "/accounts/{}/pending_transactions",
+        context.root_account().address()
+    )
+}
+
+fn create_account_txn(
+    context: &mut TestContext,
+    root_account: &mut LocalAccount,
+) -> SignedTransaction {
+    let account = context.gen_account();
+    context.create_user_account_by(root_account, &account)
+}
+
+/// Submits the given transaction to mempool and returns its hash
+async fn submit_transaction(context: &TestContext, txn: &SignedTransaction) -> Value {
+    let resp = context
+        .expect_status_code(202)
+        .post_bcs_txn("/transactions", bcs::to_bytes(txn).unwrap())
+        .await;
+    resp["hash"].clone()
+}
+
+/// Submits the root account transactions with sequence numbers 0 and 2, so that
+/// the second one is parked, and returns the hashes of the submitted transactions
+async fn submit_root_transactions_with_gap(context: &mut TestContext) -> Vec<Value> {
+    let mut root_account = context.root_account();
+    let first_txn = create_account_txn(context, &mut root_account);
+    let _missing_txn = create_account_txn(context, &mut root_account);
+    let third_txn = create_account_txn(context, &mut root_account);
+    vec![
+        submit_transaction(context, &first_txn).await,
+        submit_transaction(context, &third_txn).await,
+    ]
+}
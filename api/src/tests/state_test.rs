@@ -3,13 +3,20 @@
 
 use super::new_test_context;
 use aptos_api_test_context::{current_function_name, TestContext};
+use aptos_api_types::{mime_types::BCS, HexEncodedBytes, StateValueWithProof};
 use aptos_sdk::{transaction_builder::aptos_stdlib::aptos_token_stdlib, types::LocalAccount};
-use move_core_types::account_address::AccountAddress;
+use aptos_types::{
+    access_path::AccessPath, account_config::AccountResource, state_store::state_key::StateKey,
+};
+use move_core_types::{
+    account_address::AccountAddress, language_storage::ResourceKey, move_resource::MoveStructType,
+};
 use move_package::BuildConfig;
 use serde::Serialize;
 use serde_json::{json, Value};
 use std::{convert::TryInto, path::PathBuf};
 use storage_interface::DbReader;
+use warp::http::header::ACCEPT;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_get_account_resource() {
@@ -298,6 +305,82 @@ async fn test_get_table_item() {
     assert_table_item(ctx, &nested_table, "u8", "u8", 2, 3).await;
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_raw_table_item_as_json() {
+    let context = new_test_context(current_function_name!());
+    let resp = context
+        .expect_status_code(400)
+        .post(
+            &get_raw_table_item(AccountAddress::ONE),
+            json!({ "key": "0x01" }),
+        )
+        .await;
+    assert_eq!(resp["error_code"], "invalid_input");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_state_value_with_proof() {
+    let context = new_test_context(current_function_name!());
+    let ledger_version = context.get_latest_ledger_info().version();
+
+    let state_keys = [
+        StateKey::AccessPath(AccessPath::resource_access_path(ResourceKey::new(
+            AccountAddress::ONE,
+            AccountResource::struct_tag(),
+        ))),
+        // Doesn't exist.
+        StateKey::AccessPath(AccessPath::resource_access_path(ResourceKey::new(
+            AccountAddress::random(),
+            AccountResource::struct_tag(),
+        ))),
+    ];
+    for state_key in &state_keys {
+        let resp = context
+            .reply(
+                warp::test::request()
+                    .method("POST")
+                    .path(&context.prepend_path("/state_values/proof"))
+                    .header(ACCEPT, BCS)
+                    .json(&json!({
+                        "state_key": HexEncodedBytes(bcs::to_bytes(state_key).unwrap()),
+                    })),
+            )
+            .await;
+        assert_eq!(resp.status(), 200);
+
+        let state_value_with_proof: StateValueWithProof = bcs::from_bytes(resp.body()).unwrap();
+        assert!(state_value_with_proof.version <= ledger_version);
+        let staleness = ledger_version - state_value_with_proof.version;
+        state_value_with_proof
+            .verify(state_key, None, staleness)
+            .unwrap();
+        state_value_with_proof
+            .verify(state_key, Some(ledger_version), staleness)
+            .unwrap();
+        // The snapshot is too old for a later version
+        assert!(state_value_with_proof
+            .verify(state_key, Some(ledger_version + 1), staleness)
+            .is_err());
+        assert_eq!(
+            state_value_with_proof.value,
+            context
+                .db
+                .get_state_value_by_version(state_key, state_value_with_proof.version)
+                .unwrap()
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_state_value_with_proof_as_json() {
+    let context = new_test_context(current_function_name!());
+    let resp = context
+        .expect_status_code(400)
+        .post("/state_values/proof", json!({ "state_key": "0x01" }))
+        .await;
+    assert_eq!(resp["error_code"], "invalid_input");
+}
+
 fn get_account_resource(address: &str, struct_tag: &str) -> String {
     format!("/accounts/{}/resource/{}", address, struct_tag)
 }
@@ -317,6 +400,10 @@ fn get_table_item(handle: AccountAddress) -> String {
     format!("/tables/{}/item", handle)
 }
 
+fn get_raw_table_item(handle: AccountAddress) -> String {
+    format!("/tables/{}/raw_item", handle)
+}
+
 async fn make_test_tables(ctx: &mut TestContext, account: &mut LocalAccount) {
     let module = build_test_module(account.address()).await;
 
@@ -0,0 +1,234 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_api_test_context::{current_function_name, new_test_context_with_config, TestContext};
+use aptos_config::config::NodeConfig;
+use aptos_sdk::types::LocalAccount;
+use aptos_types::transaction::SignedTransaction;
+use serde_json::Value;
+
+/// Transactions committed by `commit_test_transactions`
+struct TestTransactions {
+    start_version: u64,
+    root: LocalAccount,
+    sender: LocalAccount,
+    receiver: LocalAccount,
+    create_sender: String,
+    create_receiver: String,
+    fund_sender: String,
+    transfer: String,
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_transactions_in_pages() {
+    let mut context = new_stream_test_context(current_function_name!());
+    let txns = commit_test_transactions(&mut context).await;
+    let end_version = context.get_latest_ledger_info().version();
+
+    // Every transaction is streamed in order, across several pages
+    let count = (end_version - txns.start_version + 1) as usize;
+    assert!(count > 2 * PAGE_SIZE as usize);
+    let resp = context
+        .stream(
+            &format!("/transactions/stream?start={}", txns.start_version),
+            count,
+        )
+        .await;
+    let versions: Vec<u64> = resp
+        .iter()
+        .map(|txn| txn["version"].as_str().unwrap().parse().unwrap())
+        .collect();
+    assert_eq!(
+        versions,
+        (txns.start_version..=end_version).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        user_transaction_hashes(&resp),
+        vec![
+            txns.create_sender,
+            txns.create_receiver,
+            txns.fund_sender,
+            txns.transfer
+        ]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_transactions_by_sender() {
+    let mut context = new_stream_test_context(current_function_name!());
+    let mut txns = commit_test_transactions(&mut context).await;
+    let marker = commit_transfer(&mut context, &mut txns.sender, &txns.receiver).await;
+
+    let resp = context
+        .stream(
+            &format!(
+                "/transactions/stream?start={}&sender={}",
+                txns.start_version,
+                txns.sender.address().to_hex_literal()
+            ),
+            2,
+        )
+        .await;
+    assert_eq!(hashes(&resp), vec![txns.transfer, marker]);
+    for txn in resp {
+        assert_eq!(txn["sender"], txns.sender.address().to_hex_literal());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_transactions_by_entry_function() {
+    let mut context = new_stream_test_context(current_function_name!());
+    let mut txns = commit_test_transactions(&mut context).await;
+    let marker = commit_transfer(&mut context, &mut txns.root, &txns.receiver).await;
+
+    // Account creations call another function and are skipped
+    let resp = context
+        .stream(
+            &format!(
+                "/transactions/stream?start={}&entry_function=0x1::aptos_account::transfer",
+                txns.start_version
+            ),
+            3,
+        )
+        .await;
+    assert_eq!(hashes(&resp), vec![txns.fund_sender, txns.transfer, marker]);
+
+    let account = context.gen_account();
+    let marker = context.create_user_account_by(&mut txns.root, &account);
+    let marker_hash = transaction_hash(&marker);
+    context.commit_block(&[marker]).await;
+    let resp = context
+        .stream(
+            &format!(
+                "/transactions/stream?start={}&entry_function=0x1::aptos_account::create_account",
+                txns.start_version
+            ),
+            3,
+        )
+        .await;
+    assert_eq!(
+        hashes(&resp),
+        vec![txns.create_sender, txns.create_receiver, marker_hash]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_transactions_by_event_type() {
+    let mut context = new_stream_test_context(current_function_name!());
+    let mut txns = commit_test_transactions(&mut context).await;
+    let marker = commit_transfer(&mut context, &mut txns.root, &txns.receiver).await;
+
+    // Only transfers deposit coins; block metadata and account creations are skipped
+    let resp = context
+        .stream(
+            &format!(
+                "/transactions/stream?start={}&event_type=0x1::coin::DepositEvent",
+                txns.start_version
+            ),
+            3,
+        )
+        .await;
+    assert_eq!(hashes(&resp), vec![txns.fund_sender, txns.transfer, marker]);
+    for txn in resp {
+        assert!(txn["events"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|event| event["type"] == "0x1::coin::DepositEvent"));
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_transactions_with_several_filters() {
+    let mut context = new_stream_test_context(current_function_name!());
+    let mut txns = commit_test_transactions(&mut context).await;
+    let marker = commit_transfer(&mut context, &mut txns.root, &txns.receiver).await;
+
+    // The root account creates accounts and transfers, only its transfers match all the filters
+    let resp = context
+        .stream(
+            &format!(
+                "/transactions/stream?start={}&sender={}&entry_function=0x1::aptos_account::transfer&event_type=0x1::coin::DepositEvent",
+                txns.start_version,
+                txns.root.address().to_hex_literal()
+            ),
+            2,
+        )
+        .await;
+    assert_eq!(hashes(&resp), vec![txns.fund_sender, marker]);
+}
+
+/// Small page size, so that streams read storage in several batches
+const PAGE_SIZE: u16 = 2;
+
+fn new_stream_test_context(test_name: String) -> TestContext {
+    let mut node_config = NodeConfig::default();
+    node_config.api.max_transactions_page_size = PAGE_SIZE;
+    new_test_context_with_config(test_name, node_config, false)
+}
+
+/// Commits the root account creating a sender and a receiver account, then funding the sender,
+/// then the sender transferring coins to the receiver, each in its own block
+async fn commit_test_transactions(context: &mut TestContext) -> TestTransactions {
+    let start_version = context.get_latest_ledger_info().version() + 1;
+    let mut root = context.root_account();
+    let mut sender = context.gen_account();
+    let receiver = context.gen_account();
+
+    let create_sender = context.create_user_account_by(&mut root, &sender);
+    let create_receiver = context.create_user_account_by(&mut root, &receiver);
+    let create_hashes = (
+        transaction_hash(&create_sender),
+        transaction_hash(&create_receiver),
+    );
+    context
+        .commit_block(&[create_sender, create_receiver])
+        .await;
+    let fund_sender = context.account_transfer(&mut root, &sender, 10_000_000);
+    let fund_sender_hash = transaction_hash(&fund_sender);
+    context.commit_block(&[fund_sender]).await;
+    let transfer = commit_transfer(context, &mut sender, &receiver).await;
+
+    TestTransactions {
+        start_version,
+        root,
+        sender,
+        receiver,
+        create_sender: create_hashes.0,
+        create_receiver: create_hashes.1,
+        fund_sender: fund_sender_hash,
+        transfer,
+    }
+}
+
+/// Commits a coin transfer in its own block and returns its hash
+async fn commit_transfer(
+    context: &mut TestContext,
+    sender: &mut LocalAccount,
+    receiver: &LocalAccount,
+) -> String {
+    let txn = context.account_transfer(sender, receiver, 1_000);
+    let hash = transaction_hash(&txn);
+    context.commit_block(&[txn]).await;
+    hash
+}
+
+fn transaction_hash(txn: &SignedTransaction) -> String {
+    txn.clone().committed_hash().to_hex_literal()
+}
+
+fn hashes(txns: &[Value]) -> Vec<String> {
+    txns.iter()
+        .map(|txn| txn["hash"].as_str().unwrap().to_owned())
+        .collect()
+}
+
+fn user_transaction_hashes(txns: &[Value]) -> Vec<String> {
+    hashes(
+        &txns
+            .iter()
+            .filter(|txn| txn["type"] == "user_transaction")
+            .cloned()
+            .collect::<Vec<_>>(),
+    )
+}
@@ -3,6 +3,7 @@
 
 use super::new_test_context;
 use aptos_api_test_context::{assert_json, current_function_name, pretty, TestContext};
+use aptos_api_types::{mime_types::BCS, TransactionsWithProof};
 
 use aptos_crypto::{
     multi_ed25519::{MultiEd25519PrivateKey, MultiEd25519PublicKey},
@@ -13,7 +14,7 @@ use aptos_types::{
     account_address::AccountAddress,
     transaction::{
         authenticator::{AuthenticationKey, TransactionAuthenticator},
-        EntryFunction, Script, SignedTransaction,
+        EntryFunction, Script, SignedTransaction, TransactionListWithProof,
     },
     utility_coin::APTOS_COIN_TYPE,
 };
@@ -26,6 +27,8 @@ use move_core_types::{
 use poem_openapi::types::ParseFromJSON;
 use rand::{distributions::Alphanumeric, thread_rng, Rng};
 use serde_json::json;
+use storage_interface::DbReader;
+use warp::http::header::ACCEPT;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_deserialize_genesis_transaction() {
@@ -45,6 +48,59 @@ async fn test_get_transactions_output_genesis_transaction() {
     context.check_golden_output(resp);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_transactions_with_proof() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account);
+    context.commit_block(&[txn]).await;
+
+    let resp = context
+        .reply(
+            warp::test::request()
+                .method("GET")
+                .path(&context.prepend_path("/transactions/proof?start=0&limit=3"))
+                .header(ACCEPT, BCS),
+        )
+        .await;
+    assert_eq!(resp.status(), 200);
+
+    let transactions_with_proof: TransactionsWithProof = bcs::from_bytes(resp.body()).unwrap();
+    transactions_with_proof.verify(0).unwrap();
+    let transactions = &transactions_with_proof
+        .transaction_list_with_proof
+        .transactions;
+    assert_eq!(transactions.len(), 3);
+    assert_eq!(
+        transactions[0],
+        context
+            .db
+            .get_transaction_by_version(0, 0, false)
+            .unwrap()
+            .transaction
+    );
+
+    // The proofs can't be verified from another version
+    assert!(transactions_with_proof.verify(1).is_err());
+
+    // An empty list doesn't prove anything
+    let empty_transactions_with_proof = TransactionsWithProof {
+        ledger_info_with_sigs: transactions_with_proof.ledger_info_with_sigs.clone(),
+        transaction_list_with_proof: TransactionListWithProof::new_empty(),
+    };
+    assert!(empty_transactions_with_proof.verify(0).is_err());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_transactions_with_proof_as_json() {
+    let context = new_test_context(current_function_name!());
+    let resp = context
+        .expect_status_code(400)
+        .get("/transactions/proof")
+        .await;
+    assert_eq!(resp["error_code"], "invalid_input");
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_get_transactions_returns_last_page_when_start_version_is_not_specified() {
     let mut context = new_test_context(current_function_name!());
@@ -70,6 +126,16 @@ async fn test_get_transactions_with_start_version_is_too_large() {
     context.check_golden_output(resp);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_transactions_with_start_version_is_too_large() {
+    let mut context = new_test_context(current_function_name!());
+    let resp = context
+        .expect_status_code(400)
+        .get("/transactions/stream?start=1000000")
+        .await;
+    context.check_golden_output(resp);
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_get_transactions_with_invalid_start_version_param() {
     let mut context = new_test_context(current_function_name!());
@@ -0,0 +1,101 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use super::new_test_context;
+use aptos_api_test_context::current_function_name;
+use serde_json::json;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_coin_balance() {
+    let context = new_test_context(current_function_name!());
+    let address = context.root_account().address().to_hex_literal();
+
+    let resp = context
+        .post(
+            "/view",
+            json!({
+                "function": "0x1::coin::balance",
+                "type_arguments": ["0x1::aptos_coin::AptosCoin"],
+                "arguments": [address],
+            }),
+        )
+        .await;
+
+    let coin_store = context
+        .get(&format!(
+            "/accounts/{}/resource/0x1::coin::CoinStore%3C0x1::aptos_coin::AptosCoin%3E",
+            address
+        ))
+        .await;
+    assert_eq!(resp, json!([coin_store["data"]["coin"]["value"]]));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_with_ledger_version() {
+    let context = new_test_context(current_function_name!());
+    let ledger_version = context.get_latest_ledger_info().version();
+
+    let resp = context
+        .post(
+            &format!("/view?ledger_version={}", ledger_version),
+            json!({
+                "function": "0x1::coin::is_account_registered",
+                "type_arguments": ["0x1::aptos_coin::AptosCoin"],
+                "arguments": ["0xdeadbeef"],
+            }),
+        )
+        .await;
+    assert_eq!(resp, json!([false]));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_function_without_return_values() {
+    let context = new_test_context(current_function_name!());
+    let address = context.root_account().address().to_hex_literal();
+
+    context
+        .expect_status_code(400)
+        .post(
+            "/view",
+            json!({
+                "function": "0x1::coin::transfer",
+                "type_arguments": ["0x1::aptos_coin::AptosCoin"],
+                "arguments": [address, "1"],
+            }),
+        )
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_function_not_found() {
+    let context = new_test_context(current_function_name!());
+
+    context
+        .expect_status_code(400)
+        .post(
+            "/view",
+            json!({
+                "function": "0x1::coin::not_a_function",
+                "type_arguments": [],
+                "arguments": [],
+            }),
+        )
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_wrong_number_of_type_arguments() {
+    let context = new_test_context(current_function_name!());
+
+    context
+        .expect_status_code(400)
+        .post(
+            "/view",
+            json!({
+                "function": "0x1::coin::balance",
+                "type_arguments": [],
+                "arguments": ["0x1"],
+            }),
+        )
+        .await;
+}
@@ -14,22 +14,26 @@ use crate::{
     page::Page,
     response::{
         api_disabled, transaction_not_found_by_hash, transaction_not_found_by_version,
-        BadRequestError, BasicError, BasicErrorWith404, BasicResponse, BasicResponseStatus,
-        BasicResult, BasicResultWith404, InsufficientStorageError, InternalError,
+        version_pruned, BadRequestError, BasicError, BasicErrorWith404, BasicResponse,
+        BasicResponseStatus, BasicResult, BasicResultWith404, InsufficientStorageError,
+        InternalError,
     },
     ApiTags,
 };
 use anyhow::{anyhow, Context as AnyhowContext};
 use aptos_api_types::{
     verify_function_identifier, verify_module_identifier, Address, AptosError, AptosErrorCode,
-    AsConverter, EncodeSubmissionRequest, GasEstimation, GasEstimationBcs, HashValue,
-    HexEncodedBytes, LedgerInfo, MoveType, PendingTransaction, SubmitTransactionRequest,
-    Transaction, TransactionData, TransactionOnChainData, TransactionsBatchSingleSubmissionFailure,
+    AsConverter, EncodeSubmissionRequest, EntryFunctionId, GasEstimation, GasEstimationBcs,
+    GasProfile, HashValue, HexEncodedBytes, LedgerInfo, MoveStructTag, MoveType,
+    PendingTransaction, SubmitTransactionRequest, Transaction, TransactionData,
+    TransactionOnChainData, TransactionsBatchSingleSubmissionFailure,
     TransactionsBatchSubmissionResult, UserTransaction, VerifyInput, VerifyInputWithRecursion,
     MAX_RECURSIVE_TYPES_ALLOWED, U64,
 };
 use aptos_crypto::{hash::CryptoHash, signing_message};
+use aptos_logger::warn;
 use aptos_types::{
+    account_address::AccountAddress,
     account_config::CoinStoreResource,
     account_view::AccountView,
     mempool_status::MempoolStatusCode,
@@ -40,12 +44,17 @@ use aptos_types::{
     vm_status::StatusCode,
 };
 use aptos_vm::AptosVM;
+use futures::{stream::BoxStream, StreamExt};
+use move_core_types::{
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag, TypeTag},
+};
 use poem_openapi::{
     param::{Path, Query},
-    payload::Json,
+    payload::{EventStream, Json},
     ApiRequest, OpenApi,
 };
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 generate_success_response!(SubmitTransactionResponse, (202, Accepted));
 
@@ -73,6 +82,54 @@ type SubmitTransactionsBatchResult<T> =
 
 type SimulateTransactionResult<T> = poem::Result<BasicResponse<T>, SubmitTransactionError>;
 
+type TransactionStreamResult =
+    poem::Result<EventStream<BoxStream<'static, Transaction>>, BasicErrorWith404>;
+
+/// Filters of a transaction stream. A transaction is streamed if it matches all the filters.
+#[derive(Clone, Debug)]
+struct TransactionFilter {
+    sender: Option<AccountAddress>,
+    entry_function: Option<(ModuleId, Identifier)>,
+    event_type: Option<TypeTag>,
+}
+
+impl TransactionFilter {
+    fn matches(&self, txn: &TransactionOnChainData) -> bool {
+        let user_txn = match &txn.transaction {
+            aptos_types::transaction::Transaction::UserTransaction(user_txn) => Some(user_txn),
+            _ => None,
+        };
+        if let Some(sender) = &self.sender {
+            if user_txn.map_or(true, |user_txn| user_txn.sender() != *sender) {
+                return false;
+            }
+        }
+        if let Some((module, function)) = &self.entry_function {
+            let calls_function = user_txn.map_or(false, |user_txn| {
+                matches!(
+                    user_txn.payload(),
+                    TransactionPayload::EntryFunction(entry_function)
+                        if entry_function.module() == module
+                            && entry_function.function() == function.as_ident_str()
+                )
+            });
+            if !calls_function {
+                return false;
+            }
+        }
+        if let Some(event_type) = &self.event_type {
+            if !txn
+                .events
+                .iter()
+                .any(|event| event.type_tag() == event_type)
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
 // TODO: Consider making both content types accept either
 // SubmitTransactionRequest or SignedTransaction, the way
 // it is now is quite confusing.
@@ -170,6 +227,50 @@ impl TransactionsApi {
         self.list(&accept_type, page)
     }
 
+    /// Get transactions with proof
+    ///
+    /// Retrieve on-chain committed transactions along with their events, and proofs of them
+    /// against the latest ledger info signed by the validators. The page size and start can be
+    /// provided to get a specific sequence of transactions.
+    ///
+    /// The transactions and their proofs can only be returned as BCS (TransactionsWithProof).
+    ///
+    /// If the version has been pruned, then a 410 will be returned
+    #[oai(
+        path = "/transactions/proof",
+        method = "get",
+        operation_id = "get_transactions_with_proof",
+        tag = "ApiTags::Proofs"
+    )]
+    async fn get_transactions_with_proof(
+        &self,
+        accept_type: AcceptType,
+        /// Ledger version to start list of transactions
+        ///
+        /// If not provided, defaults to showing the latest transactions
+        start: Query<Option<U64>>,
+        /// Max number of transactions to retrieve.
+        ///
+        /// If not provided, defaults to default page size
+        limit: Query<Option<u16>>,
+    ) -> BasicResultWith404<HexEncodedBytes> {
+        fail_point_poem("endpoint_get_transactions_with_proof")?;
+        self.context
+            .check_api_output_enabled("Get transactions with proof", &accept_type)?;
+        if accept_type == AcceptType::Json {
+            return Err(BasicErrorWith404::bad_request_with_code_no_info(
+                "Transactions with proofs can only be returned as BCS",
+                AptosErrorCode::InvalidInput,
+            ));
+        }
+        let page = Page::new(
+            start.0.map(|v| v.0),
+            limit.0,
+            self.context.max_transactions_page_size(),
+        );
+        self.list_with_proof(page)
+    }
+
     /// Get transaction by hash
     ///
     /// Look up a transaction by its hash. This is the same hash that is returned
@@ -264,6 +365,71 @@ impl TransactionsApi {
         self.list_by_account(&accept_type, page, address.0)
     }
 
+    /// Stream transactions
+    ///
+    /// Streams committed transactions as Server-Sent Events, starting at the given ledger
+    /// version, with each event holding a transaction in the same JSON format as the other
+    /// transaction endpoints. New transactions are pushed as soon as they are committed, so
+    /// clients don't need to poll `/transactions`. To resume the stream after a disconnect,
+    /// reconnect with `start` set to the version of the last received transaction plus one.
+    ///
+    /// Transactions can be filtered by sender, entry function and emitted event type. When
+    /// several filters are given, only the transactions matching all of them are streamed.
+    ///
+    /// Server-Sent Events are text based, so this endpoint only supports JSON output.
+    #[oai(
+        path = "/transactions/stream",
+        method = "get",
+        operation_id = "stream_transactions",
+        tag = "ApiTags::Transactions"
+    )]
+    async fn stream_transactions(
+        &self,
+        accept_type: AcceptType,
+        /// Ledger version to start streaming transactions from
+        ///
+        /// If not provided, only the transactions committed after the request are streamed
+        start: Query<Option<U64>>,
+        /// Only stream user transactions sent by this account
+        sender: Query<Option<Address>>,
+        /// Only stream user transactions calling this entry function, e.g. `0x1::coin::transfer`
+        entry_function: Query<Option<EntryFunctionId>>,
+        /// Only stream transactions emitting an event of this type, e.g. `0x1::coin::DepositEvent`
+        event_type: Query<Option<MoveStructTag>>,
+    ) -> TransactionStreamResult {
+        fail_point_poem("endpoint_stream_transactions")?;
+        self.context
+            .check_api_output_enabled("Stream transactions", &accept_type)?;
+        if accept_type == AcceptType::Bcs {
+            return Err(BasicErrorWith404::bad_request_with_code_no_info(
+                "Stream transactions only supports JSON output",
+                AptosErrorCode::InvalidInput,
+            ));
+        }
+        let latest_ledger_info = self.context.get_latest_ledger_info()?;
+        let event_type = event_type
+            .0
+            .map(StructTag::try_from)
+            .transpose()
+            .context("'event_type' invalid")
+            .map_err(|err| {
+                BasicErrorWith404::bad_request_with_code(
+                    err,
+                    AptosErrorCode::InvalidInput,
+                    &latest_ledger_info,
+                )
+            })?
+            .map(|tag| TypeTag::Struct(Box::new(tag)));
+        let filter = TransactionFilter {
+            sender: sender.0.map(Into::into),
+            entry_function: entry_function
+                .0
+                .map(|function| (function.module.into(), function.name.into())),
+            event_type,
+        };
+        self.stream(start.0.map(|v| v.0), filter, &latest_ledger_info)
+    }
+
     /// Submit transaction
     ///
     /// This endpoint accepts transaction submissions in two formats.
@@ -391,6 +557,11 @@ impl TransactionsApi {
     ///
     /// To use this endpoint with BCS, you must submit a SignedTransaction
     /// encoded as BCS. See SignedTransaction in types/src/transaction/mod.rs.
+    ///
+    /// If `profile` is set, the returned transaction includes a breakdown of the gas
+    /// charged per function, native function and storage operation, along with the call
+    /// stacks in the folded stacks format used by flamegraph tools. Gas profiling is
+    /// only supported for JSON responses.
     #[oai(
         path = "/transactions/simulate",
         method = "post",
@@ -409,6 +580,9 @@ impl TransactionsApi {
         /// If set to true, the transaction will use a higher price than the original
         /// estimate.
         estimate_prioritized_gas_unit_price: Query<Option<bool>>,
+        /// If set to true, a breakdown of the gas charged will be included in the
+        /// simulated transaction
+        profile: Query<Option<bool>>,
         data: SubmitTransactionPost,
     ) -> SimulateTransactionResult<Vec<UserTransaction>> {
         data.verify()
@@ -425,6 +599,13 @@ impl TransactionsApi {
         if !self.context.node_config.api.transaction_simulation_enabled {
             return Err(api_disabled("Simulate transaction"));
         }
+        let gas_profiling = profile.0.unwrap_or_default();
+        if gas_profiling && accept_type == AcceptType::Bcs {
+            return Err(SubmitTransactionError::bad_request_with_code_no_info(
+                "Gas profiling is not supported with BCS",
+                AptosErrorCode::BcsNotSupported,
+            ));
+        }
         let ledger_info = self.context.get_latest_ledger_info()?;
         let mut signed_transaction = self.get_signed_transaction(&ledger_info, data)?;
 
@@ -518,7 +699,7 @@ impl TransactionsApi {
             );
         }
 
-        self.simulate(&accept_type, ledger_info, signed_transaction)
+        self.simulate(&accept_type, ledger_info, signed_transaction, gas_profiling)
             .await
     }
 
@@ -611,6 +792,91 @@ impl TransactionsApi {
 }
 
 impl TransactionsApi {
+    /// Streams the transactions matching the filter, starting at `start_version`. The stream
+    /// polls storage for new transactions once it has caught up with the latest ledger version,
+    /// and ends if storage can't be read.
+    fn stream(
+        &self,
+        start_version: Option<u64>,
+        filter: TransactionFilter,
+        latest_ledger_info: &LedgerInfo,
+    ) -> TransactionStreamResult {
+        let next_ledger_version = latest_ledger_info.version() + 1;
+        let start_version = start_version.unwrap_or(next_ledger_version);
+        if start_version > next_ledger_version {
+            return Err(BasicErrorWith404::bad_request_with_code(
+                &format!(
+                    "Given start value ({}) is higher than the next ledger version, it must be <= {}",
+                    start_version, next_ledger_version
+                ),
+                AptosErrorCode::InvalidInput,
+                latest_ledger_info,
+            ));
+        }
+        if start_version < latest_ledger_info.oldest_ledger_version.0 {
+            return Err(version_pruned(start_version, latest_ledger_info));
+        }
+
+        let context = self.context.clone();
+        let poll_interval =
+            Duration::from_millis(context.node_config.api.transaction_stream_poll_interval_ms);
+        let stream = futures::stream::unfold(start_version, move |next_version| {
+            let context = context.clone();
+            let filter = filter.clone();
+            async move {
+                loop {
+                    let ledger_info = match context.get_latest_ledger_info_wrapped() {
+                        Ok(ledger_info) => ledger_info,
+                        Err(err) => {
+                            warn!("Transaction stream ended: {:#}", err);
+                            return None;
+                        }
+                    };
+                    if next_version > ledger_info.version() {
+                        tokio::time::sleep(poll_interval).await;
+                        continue;
+                    }
+                    return match Self::read_stream_batch(
+                        &context,
+                        &ledger_info,
+                        next_version,
+                        &filter,
+                    ) {
+                        Ok((txns, num_read)) => {
+                            Some((futures::stream::iter(txns), next_version + num_read))
+                        }
+                        Err(err) => {
+                            warn!("Transaction stream ended: {:#}", err);
+                            None
+                        }
+                    };
+                }
+            }
+        });
+        Ok(EventStream::new(stream.flatten().boxed()))
+    }
+
+    /// Reads the next batch of transactions of a stream, returning the rendered transactions
+    /// matching the filter along with the number of transactions read.
+    fn read_stream_batch(
+        context: &Context,
+        ledger_info: &LedgerInfo,
+        start_version: u64,
+        filter: &TransactionFilter,
+    ) -> anyhow::Result<(Vec<Transaction>, u64)> {
+        let data = context
+            .get_transactions(
+                start_version,
+                context.max_transactions_page_size(),
+                ledger_info.version(),
+            )
+            .context("Failed to read raw transactions from storage")?;
+        let num_read = data.len() as u64;
+        let data = data.into_iter().filter(|txn| filter.matches(txn)).collect();
+        let txns = context.render_transactions_non_sequential::<BasicError>(ledger_info, data)?;
+        Ok((txns, num_read))
+    }
+
     /// List all transactions paging by ledger version
     fn list(&self, accept_type: &AcceptType, page: Page) -> BasicResultWith404<Vec<Transaction>> {
         let latest_ledger_info = self.context.get_latest_ledger_info()?;
@@ -651,6 +917,27 @@ impl TransactionsApi {
         }
     }
 
+    fn list_with_proof(&self, page: Page) -> BasicResultWith404<HexEncodedBytes> {
+        let latest_ledger_info = self.context.get_latest_ledger_info()?;
+        let ledger_version = latest_ledger_info.version();
+
+        let limit = page.limit(&latest_ledger_info)?;
+        let start_version = page.compute_start(limit, ledger_version, &latest_ledger_info)?;
+        let data = self
+            .context
+            .get_transactions_with_proof(start_version, limit)
+            .context("Failed to read transactions with proof from storage")
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &latest_ledger_info,
+                )
+            })?;
+
+        BasicResponse::try_from_bcs((data, &latest_ledger_info, BasicResponseStatus::Ok))
+    }
+
     async fn get_transaction_by_hash_inner(
         &self,
         accept_type: &AcceptType,
@@ -1129,6 +1416,7 @@ impl TransactionsApi {
         accept_type: &AcceptType,
         ledger_info: LedgerInfo,
         txn: SignedTransaction,
+        gas_profiling: bool,
     ) -> SimulateTransactionResult<Vec<UserTransaction>> {
         // Transactions shouldn't have a valid signature or this could be used to attack
         if txn.signature_is_valid() {
@@ -1141,7 +1429,13 @@ impl TransactionsApi {
 
         // Simulate transaction
         let move_resolver = self.context.move_resolver_poem(&ledger_info)?;
-        let (_, output_ext) = AptosVM::simulate_signed_transaction(&txn, &move_resolver);
+        let (_, output_ext, mut gas_profile) = if gas_profiling {
+            AptosVM::simulate_signed_transaction_with_gas_profiling(&txn, &move_resolver)
+        } else {
+            let (vm_status, output_ext) =
+                AptosVM::simulate_signed_transaction(&txn, &move_resolver);
+            (vm_status, output_ext, None)
+        };
         let version = ledger_info.version();
 
         // Apply transaction outputs to build up a transaction
@@ -1189,7 +1483,10 @@ impl TransactionsApi {
                 let mut user_transactions = Vec::new();
                 for transaction in transactions.into_iter() {
                     match transaction {
-                        Transaction::UserTransaction(user_txn) => user_transactions.push(*user_txn),
+                        Transaction::UserTransaction(mut user_txn) => {
+                            user_txn.gas_profile = gas_profile.take().map(GasProfile::from);
+                            user_transactions.push(*user_txn)
+                        }
                         _ => {
                             return Err(SubmitTransactionError::internal_with_code(
                                 "Simulation transaction resulted in a non-UserTransaction",
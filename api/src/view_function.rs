@@ -0,0 +1,141 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    accept_type::AcceptType,
+    failpoint::fail_point_poem,
+    response::{
+        BadRequestError, BasicErrorWith404, BasicResponse, BasicResponseStatus, BasicResultWith404,
+        InternalError,
+    },
+    ApiTags, Context,
+};
+use anyhow::Context as AnyhowContext;
+use aptos_api_types::{AptosErrorCode, AsConverter, MoveValue, VerifyInput, ViewRequest, U64};
+use aptos_vm::{data_cache::AsMoveResolver, AptosVM};
+use poem_openapi::{param::Query, payload::Json, OpenApi};
+use std::sync::Arc;
+
+/// API for executing Move view functions
+pub struct ViewFunctionApi {
+    pub context: Arc<Context>,
+}
+
+#[OpenApi]
+impl ViewFunctionApi {
+    /// Execute view function of a module
+    ///
+    /// Execute the Move function with the given parameters and return its execution result.
+    /// The function must be public and must return at least one value. Nothing the function
+    /// does is committed to storage, and execution is limited to a configurable amount of gas.
+    ///
+    /// The Aptos nodes prune account state history, via a configurable time window.
+    /// If the requested ledger version has been pruned, the server responds with a 410.
+    #[oai(
+        path = "/view",
+        method = "post",
+        operation_id = "view",
+        tag = "ApiTags::View"
+    )]
+    async fn view_function(
+        &self,
+        accept_type: AcceptType,
+        /// View function request with type and position arguments
+        request: Json<ViewRequest>,
+        /// Ledger version to get state of account
+        ///
+        /// If not provided, it will be the latest version
+        ledger_version: Query<Option<U64>>,
+    ) -> BasicResultWith404<Vec<MoveValue>> {
+        request
+            .0
+            .verify()
+            .context("'request' invalid")
+            .map_err(|err| {
+                BasicErrorWith404::bad_request_with_code_no_info(err, AptosErrorCode::InvalidInput)
+            })?;
+        fail_point_poem("endpoint_view_function")?;
+        self.context
+            .check_api_output_enabled("View function", &accept_type)?;
+        self.view(&accept_type, request.0, ledger_version.0)
+    }
+}
+
+impl ViewFunctionApi {
+    /// Execute the view function at the requested ledger version
+    ///
+    /// JSON: Convert return values to MoveValues
+    /// BCS: Return the list of BCS encoded return values
+    fn view(
+        &self,
+        accept_type: &AcceptType,
+        request: ViewRequest,
+        ledger_version: Option<U64>,
+    ) -> BasicResultWith404<Vec<MoveValue>> {
+        let (ledger_info, requested_version) = self
+            .context
+            .get_latest_ledger_info_and_verify_lookup_version(
+                ledger_version.map(|inner| inner.0),
+            )?;
+        let state_view = self
+            .context
+            .state_view_at_version(requested_version)
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?;
+
+        let resolver = state_view.as_move_resolver();
+        let converter = resolver.as_converter(self.context.db.clone());
+        let view_function = converter.convert_view_function(request).map_err(|err| {
+            BasicErrorWith404::bad_request_with_code(
+                err,
+                AptosErrorCode::InvalidInput,
+                &ledger_info,
+            )
+        })?;
+
+        let return_vals = AptosVM::execute_view_function(
+            &state_view,
+            view_function.module.clone(),
+            view_function.function.clone(),
+            view_function.ty_args.clone(),
+            view_function.args.clone(),
+            self.context.max_gas_view_function(),
+        )
+        .map_err(|status| {
+            BasicErrorWith404::bad_request_with_code(
+                format!("Failed to execute view function: {:?}", status),
+                AptosErrorCode::InvalidInput,
+                &ledger_info,
+            )
+        })?;
+
+        match accept_type {
+            AcceptType::Json => {
+                let move_values = view_function
+                    .return_types
+                    .iter()
+                    .zip(return_vals.iter())
+                    .map(|(typ, bytes)| converter.try_into_move_value(typ, bytes))
+                    .collect::<anyhow::Result<Vec<_>>>()
+                    .context("Failed to convert view function return values")
+                    .map_err(|err| {
+                        BasicErrorWith404::internal_with_code(
+                            err,
+                            AptosErrorCode::InternalError,
+                            &ledger_info,
+                        )
+                    })?;
+
+                BasicResponse::try_from_json((move_values, &ledger_info, BasicResponseStatus::Ok))
+            }
+            AcceptType::Bcs => {
+                BasicResponse::try_from_bcs((return_vals, &ledger_info, BasicResponseStatus::Ok))
+            }
+        }
+    }
+}
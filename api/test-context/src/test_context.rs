@@ -85,6 +85,14 @@ impl ApiSpecificConfig {
 }
 
 pub fn new_test_context(test_name: String, use_db_with_indexer: bool) -> TestContext {
+    new_test_context_with_config(test_name, NodeConfig::default(), use_db_with_indexer)
+}
+
+pub fn new_test_context_with_config(
+    test_name: String,
+    node_config: NodeConfig,
+    use_db_with_indexer: bool,
+) -> TestContext {
     let tmp_dir = TempPath::new();
     tmp_dir.create_as_dir().unwrap();
 
@@ -125,8 +133,6 @@ pub fn new_test_context(test_name: String, use_db_with_indexer: bool) -> TestCon
 
     let mempool = MockSharedMempool::new_in_runtime(&db_rw, VMValidator::new(db.clone()));
 
-    let node_config = NodeConfig::default();
-
     let context = Context::new(
         ChainId::test(),
         db.clone(),
@@ -550,6 +556,52 @@ impl TestContext {
         .await
     }
 
+    pub async fn delete(&self, path: &str) -> Value {
+        self.execute(
+            warp::test::request()
+                .method("DELETE")
+                .path(&self.prepend_path(path)),
+        )
+        .await
+    }
+
+    /// Reads the first `count` Server-Sent Events of a streaming endpoint as JSON values.
+    /// Streams never end on their own, so this connects to the API server directly instead of
+    /// going through warp, which waits for the full response body.
+    pub async fn stream(&self, path: &str, count: usize) -> Vec<Value> {
+        let ApiSpecificConfig::V1(address) = self.api_specific_config;
+        let url = format!("http://{}{}", address, self.prepend_path(path));
+        let read_events = async {
+            let mut resp = reqwest::Client::new().get(url).send().await.unwrap();
+            assert_eq!(self.expect_status_code, resp.status().as_u16());
+
+            let mut buffer = String::new();
+            let mut events = vec![];
+            while events.len() < count {
+                let chunk = resp
+                    .chunk()
+                    .await
+                    .unwrap()
+                    .expect("stream ended before all events were received");
+                buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+                while let Some(end) = buffer.find("\n\n") {
+                    let event: String = buffer.drain(..end + 2).collect();
+                    events.extend(
+                        event
+                            .lines()
+                            .filter_map(|line| line.strip_prefix("data:"))
+                            .map(|data| serde_json::from_str::<Value>(data.trim()).unwrap()),
+                    );
+                }
+            }
+            events.truncate(count);
+            events
+        };
+        tokio::time::timeout(Duration::from_secs(30), read_events)
+            .await
+            .expect("timed out waiting for stream events")
+    }
+
     pub async fn post_bcs_txn(&self, path: &str, body: impl AsRef<[u8]>) -> Value {
         self.execute(
             warp::test::request()
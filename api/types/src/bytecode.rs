@@ -9,7 +9,7 @@ use move_binary_format::{
         AddressIdentifierIndex, CompiledModule, CompiledScript, FieldDefinition,
         FunctionDefinition, FunctionHandle, FunctionHandleIndex, IdentifierIndex, ModuleHandle,
         ModuleHandleIndex, Signature, SignatureIndex, SignatureToken, StructDefinition,
-        StructFieldInformation, StructHandle, StructHandleIndex,
+        StructFieldInformation, StructHandle, StructHandleIndex, Visibility,
     },
 };
 use move_core_types::{account_address::AccountAddress, identifier::IdentStr};
@@ -37,6 +37,8 @@ pub trait Bytecode {
 
     fn find_entry_function(&self, name: &IdentStr) -> Option<MoveFunction>;
 
+    fn find_function(&self, name: &IdentStr) -> Option<MoveFunction>;
+
     fn new_move_struct_field(&self, def: &FieldDefinition) -> MoveStructField {
         MoveStructField {
             name: self.identifier_at(def.name).to_owned().into(),
@@ -181,6 +183,17 @@ impl Bytecode for CompiledModule {
             })
             .map(|def| self.new_move_function(def))
     }
+
+    fn find_function(&self, name: &IdentStr) -> Option<MoveFunction> {
+        self.function_defs
+            .iter()
+            .filter(|def| def.visibility == Visibility::Public)
+            .find(|def| {
+                let fhandle = ModuleAccess::function_handle_at(self, def.function);
+                ModuleAccess::identifier_at(self, fhandle.name) == name
+            })
+            .map(|def| self.new_move_function(def))
+    }
 }
 
 impl Bytecode for CompiledScript {
@@ -215,4 +228,8 @@ impl Bytecode for CompiledScript {
             None
         }
     }
+
+    fn find_function(&self, name: &IdentStr) -> Option<MoveFunction> {
+        self.find_entry_function(name)
+    }
 }
@@ -8,10 +8,10 @@ use crate::{
         WriteResource, WriteTableItem,
     },
     Bytecode, DirectWriteSet, EntryFunctionId, EntryFunctionPayload, Event, HexEncodedBytes,
-    MoveFunction, MoveModuleBytecode, MoveResource, MoveScriptBytecode, MoveValue,
+    MoveFunction, MoveModuleBytecode, MoveResource, MoveScriptBytecode, MoveType, MoveValue,
     PendingTransaction, ScriptPayload, ScriptWriteSet, SubmitTransactionRequest, Transaction,
     TransactionInfo, TransactionOnChainData, TransactionPayload, UserTransactionRequest,
-    VersionedEvent, WriteSet, WriteSetChange, WriteSetPayload,
+    VersionedEvent, ViewFunction, ViewRequest, WriteSet, WriteSetChange, WriteSetPayload,
 };
 use anyhow::{bail, ensure, format_err, Context as AnyhowContext, Result};
 use aptos_crypto::{hash::CryptoHash, HashValue};
@@ -680,6 +680,70 @@ impl<'a, R: MoveResolverExt + ?Sized> MoveConverter<'a, R> {
     pub fn try_into_move_value(&self, typ: &TypeTag, bytes: &[u8]) -> Result<MoveValue> {
         self.inner.view_value(typ, bytes)?.try_into()
     }
+
+    pub fn convert_view_function(&self, view_request: ViewRequest) -> Result<ViewFunction> {
+        let ViewRequest {
+            function,
+            type_arguments,
+            arguments,
+        } = view_request;
+
+        let module = function.module.clone();
+        let code = self.inner.get_module(&module.clone().into())? as Rc<dyn Bytecode>;
+        let func = code
+            .find_function(function.name.0.as_ident_str())
+            .ok_or_else(|| format_err!("could not find public function by {}", function))?;
+        ensure!(
+            func.generic_type_params.len() == type_arguments.len(),
+            "expect {} type arguments for view function {}, but got {}",
+            func.generic_type_params.len(),
+            function,
+            type_arguments.len()
+        );
+        ensure!(
+            func.params.len() == arguments.len(),
+            "expect {} arguments for view function {}, but got {}",
+            func.params.len(),
+            function,
+            arguments.len()
+        );
+        let ty_args = type_arguments
+            .into_iter()
+            .map(|v| v.try_into())
+            .collect::<Result<Vec<TypeTag>>>()?;
+
+        let args = func
+            .params
+            .iter()
+            .zip(arguments.into_iter())
+            .enumerate()
+            .map(|(i, (arg_type, arg))| {
+                let type_tag = instantiate_type_tag(arg_type, &ty_args)?;
+                let value = self.try_into_vm_value(&type_tag, arg).map_err(|e| {
+                    format_err!(
+                        "parse arguments[{}] failed, expect {}, caused by error: {}",
+                        i,
+                        arg_type.json_type_name(),
+                        e,
+                    )
+                })?;
+                Ok(bcs::to_bytes(&value)?)
+            })
+            .collect::<Result<_>>()?;
+        let return_types = func
+            .return_
+            .iter()
+            .map(|typ| instantiate_type_tag(typ, &ty_args))
+            .collect::<Result<_>>()?;
+
+        Ok(ViewFunction {
+            module: module.into(),
+            function: function.name.into(),
+            ty_args,
+            args,
+            return_types,
+        })
+    }
 }
 
 impl<'a, R: MoveResolverExt + ?Sized> ExplainVMStatus for MoveConverter<'a, R> {
@@ -713,6 +777,31 @@ pub fn new_vm_utf8_string(string: &str) -> move_core_types::value::MoveValue {
     MoveValue::Struct(move_string)
 }
 
+/// Converts a type from a function signature into a `TypeTag`, replacing generic type params with
+/// the given type arguments.
+fn instantiate_type_tag(typ: &MoveType, type_args: &[TypeTag]) -> Result<TypeTag> {
+    Ok(match typ {
+        MoveType::GenericTypeParam { index } => type_args
+            .get(*index as usize)
+            .cloned()
+            .ok_or_else(|| format_err!("missing type argument for type param {}", index))?,
+        MoveType::Vector { items } => {
+            TypeTag::Vector(Box::new(instantiate_type_tag(items, type_args)?))
+        }
+        MoveType::Struct(struct_tag) => TypeTag::Struct(Box::new(StructTag {
+            address: struct_tag.address.into(),
+            module: struct_tag.module.clone().into(),
+            name: struct_tag.name.clone().into(),
+            type_params: struct_tag
+                .generic_type_params
+                .iter()
+                .map(|typ| instantiate_type_tag(typ, type_args))
+                .collect::<Result<_>>()?,
+        })),
+        _ => typ.clone().try_into()?,
+    })
+}
+
 fn abort_location_to_str(loc: &AbortLocation) -> String {
     match loc {
         AbortLocation::Module(mid) => {
@@ -263,6 +263,7 @@ impl_poem_type!(
 
 impl_poem_parameter!(
     Address,
+    EntryFunctionId,
     HashValue,
     IdentifierWrapper,
     HexEncodedBytes,
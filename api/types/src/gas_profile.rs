@@ -0,0 +1,66 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::U64;
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+/// The gas charged for a function, a native function or a storage operation, along with the
+/// number of times it was charged
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct GasProfileEntry {
+    pub name: String,
+    pub count: U64,
+    /// Amount of gas, in internal gas units
+    pub gas: U64,
+}
+
+impl From<aptos_gas::GasProfileEntry> for GasProfileEntry {
+    fn from(entry: aptos_gas::GasProfileEntry) -> Self {
+        Self {
+            name: entry.name,
+            count: entry.count.into(),
+            gas: entry.gas.into(),
+        }
+    }
+}
+
+/// A breakdown of the gas charged for a simulated transaction
+///
+/// All amounts are in internal gas units, which can be converted into gas units by dividing
+/// them by `gas_unit_scaling_factor`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct GasProfile {
+    pub gas_unit_scaling_factor: U64,
+    /// Gas charged for the size of the transaction
+    pub intrinsic: U64,
+    /// Gas charged for executing the instructions of each function, excluding the functions
+    /// it calls, sorted by gas
+    pub functions: Vec<GasProfileEntry>,
+    /// Gas charged by each native function, sorted by gas
+    pub natives: Vec<GasProfileEntry>,
+    /// Gas charged for loading resources from storage
+    pub storage_reads: GasProfileEntry,
+    /// Gas charged for each item written to storage, sorted by gas
+    pub storage_writes: Vec<GasProfileEntry>,
+    /// The gas charged to each call stack, in the folded stacks format used by flamegraph tools
+    pub folded_stacks: Vec<String>,
+}
+
+impl From<aptos_gas::GasProfile> for GasProfile {
+    fn from(profile: aptos_gas::GasProfile) -> Self {
+        let into_entries = |entries: Vec<aptos_gas::GasProfileEntry>| {
+            entries.into_iter().map(GasProfileEntry::from).collect()
+        };
+
+        Self {
+            gas_unit_scaling_factor: profile.gas_unit_scaling_factor.into(),
+            intrinsic: profile.intrinsic.into(),
+            functions: into_entries(profile.functions),
+            natives: into_entries(profile.natives),
+            storage_reads: profile.storage_reads.into(),
+            storage_writes: into_entries(profile.storage_writes),
+            folded_stacks: profile.folded_stacks,
+        }
+    }
+}
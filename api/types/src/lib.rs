@@ -8,14 +8,18 @@ mod bytecode;
 mod convert;
 mod derives;
 mod error;
+mod gas_profile;
 mod hash;
 mod headers;
 mod index;
 mod ledger_info;
+mod mempool;
 pub mod mime_types;
 mod move_types;
+mod proof;
 mod table;
 mod transaction;
+mod view;
 mod wrappers;
 
 pub use account::AccountData;
@@ -24,10 +28,12 @@ pub use block::{BcsBlock, Block};
 pub use bytecode::Bytecode;
 pub use convert::{new_vm_utf8_string, AsConverter, ExplainVMStatus, MoveConverter};
 pub use error::{AptosError, AptosErrorCode};
+pub use gas_profile::{GasProfile, GasProfileEntry};
 pub use hash::HashValue;
 pub use headers::*;
 pub use index::{IndexResponse, IndexResponseBcs};
 pub use ledger_info::LedgerInfo;
+pub use mempool::{MempoolTransaction, MempoolTransactionStatus};
 pub use move_types::{
     verify_field_identifier, verify_function_identifier, verify_module_identifier, EntryFunctionId,
     HexEncodedBytes, MoveAbility, MoveFunction, MoveFunctionGenericTypeParam,
@@ -35,9 +41,10 @@ pub use move_types::{
     MoveScriptBytecode, MoveStruct, MoveStructField, MoveStructTag, MoveType, MoveValue,
     MAX_RECURSIVE_TYPES_ALLOWED, U128, U64,
 };
+pub use proof::{StateValueProofRequest, StateValueWithProof, TransactionsWithProof};
 use serde::{Deserialize, Deserializer};
 use std::str::FromStr;
-pub use table::TableItemRequest;
+pub use table::{RawTableItemRequest, TableItemRequest};
 pub use transaction::{
     AccountSignature, BlockMetadataTransaction, DeleteModule, DeleteResource, DeleteTableItem,
     DirectWriteSet, Ed25519Signature, EncodeSubmissionRequest, EntryFunctionPayload, Event,
@@ -49,6 +56,7 @@ pub use transaction::{
     UserCreateSigningMessageRequest, UserTransaction, UserTransactionRequest, VersionedEvent,
     WriteModule, WriteResource, WriteSet, WriteSetChange, WriteSetPayload, WriteTableItem,
 };
+pub use view::{ViewFunction, ViewRequest};
 pub use wrappers::{EventGuid, IdentifierWrapper};
 
 pub fn deserialize_from_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
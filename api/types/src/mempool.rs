@@ -0,0 +1,34 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{PendingTransaction, U64};
+use poem_openapi::{Enum, Object};
+use serde::{Deserialize, Serialize};
+
+/// Whether a transaction in mempool can be included in a block
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[oai(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum MempoolTransactionStatus {
+    /// All previous transactions of the sender are committed or ready
+    Ready,
+    /// The transaction is in the parking lot, waiting for a missing sequence number
+    Parked,
+}
+
+/// A transaction waiting in mempool, along with its mempool metadata
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct MempoolTransaction {
+    pub transaction: PendingTransaction,
+    /// Priority of the transaction in mempool, derived from its gas unit price
+    pub ranking_score: U64,
+    /// Time at which the transaction entered mempool, in microseconds
+    pub insertion_timestamp: U64,
+    /// Sequence number of the sender as known by mempool
+    pub account_sequence_number: U64,
+    pub status: MempoolTransactionStatus,
+    /// For a parked transaction, the sequence number of the sender's transaction that must be
+    /// submitted before this transaction can be included in a block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_sequence_number: Option<U64>,
+}
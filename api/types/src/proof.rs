@@ -0,0 +1,108 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::HexEncodedBytes;
+use anyhow::{ensure, format_err, Result};
+use aptos_crypto::hash::CryptoHash;
+use aptos_types::{
+    ledger_info::LedgerInfoWithSignatures,
+    proof::{SparseMerkleProof, TransactionInfoWithProof},
+    state_store::{state_key::StateKey, state_value::StateValue},
+    transaction::{TransactionListWithProof, Version},
+};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+/// State value request for the GetStateValueWithProof API
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct StateValueProofRequest {
+    /// The BCS serialized state key, e.g. of a resource, a module or a table item
+    pub state_key: HexEncodedBytes,
+}
+
+/// The value of a state key along with the proofs of it against a ledger info signed by the
+/// validators.  Only for responding in BCS
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct StateValueWithProof {
+    pub ledger_info_with_sigs: LedgerInfoWithSignatures,
+    /// Version of the state snapshot the value is read from
+    pub version: Version,
+    pub value: Option<StateValue>,
+    /// Proof of the value against the root hash of the state snapshot
+    pub sparse_merkle_proof: SparseMerkleProof,
+    /// Proof of the transaction info at `version`, which holds the root hash of the state
+    /// snapshot, against the ledger info
+    pub transaction_info_with_proof: TransactionInfoWithProof,
+}
+
+impl StateValueWithProof {
+    /// Verifies that `value` is the value of `state_key` in the state snapshot at `version`, and
+    /// that the snapshot is committed by the ledger info.  The snapshot must be at or before
+    /// `requested_version` (the version of the ledger info if None), and at most `max_staleness`
+    /// versions older than it.  The signatures of the ledger info aren't verified.
+    pub fn verify(
+        &self,
+        state_key: &StateKey,
+        requested_version: Option<Version>,
+        max_staleness: u64,
+    ) -> Result<()> {
+        let requested_version =
+            requested_version.unwrap_or_else(|| self.ledger_info_with_sigs.ledger_info().version());
+        ensure!(
+            self.version <= requested_version,
+            "State snapshot at version {} is after the requested version {}",
+            self.version,
+            requested_version
+        );
+        ensure!(
+            requested_version - self.version <= max_staleness,
+            "State snapshot at version {} is more than {} versions older than the requested version {}",
+            self.version,
+            max_staleness,
+            requested_version
+        );
+        self.transaction_info_with_proof
+            .verify(self.ledger_info_with_sigs.ledger_info(), self.version)?;
+        let state_root_hash = self
+            .transaction_info_with_proof
+            .transaction_info()
+            .state_checkpoint_hash()
+            .ok_or_else(|| {
+                format_err!(
+                    "Transaction at version {} isn't a state checkpoint",
+                    self.version
+                )
+            })?;
+        self.sparse_merkle_proof
+            .verify(state_root_hash, state_key.hash(), self.value.as_ref())
+    }
+}
+
+/// A list of consecutive transactions, along with their events and the proofs of them against a
+/// ledger info signed by the validators.  Only for responding in BCS
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TransactionsWithProof {
+    pub ledger_info_with_sigs: LedgerInfoWithSignatures,
+    pub transaction_list_with_proof: TransactionListWithProof,
+}
+
+impl TransactionsWithProof {
+    /// Verifies that the transactions start at `start_version`, and that they and their events
+    /// are committed by the ledger info.  An empty list is rejected, as it doesn't prove anything
+    /// about `start_version`.  The signatures of the ledger info aren't verified.
+    pub fn verify(&self, start_version: Version) -> Result<()> {
+        let ledger_info = self.ledger_info_with_sigs.ledger_info();
+        ensure!(
+            !self.transaction_list_with_proof.transactions.is_empty(),
+            "No transactions returned from version {} (ledger info at version {})",
+            start_version,
+            ledger_info.version()
+        );
+        self.transaction_list_with_proof
+            .verify(ledger_info, Some(start_version))?;
+        if self.transaction_list_with_proof.events.is_none() {
+            return Err(format_err!("The events of the transactions are missing"));
+        }
+        Ok(())
+    }
+}
@@ -2,16 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    Address, AptosError, EntryFunctionId, EventGuid, HashValue, HexEncodedBytes,
+    Address, AptosError, EntryFunctionId, EventGuid, GasProfile, HashValue, HexEncodedBytes,
     MoveModuleBytecode, MoveModuleId, MoveResource, MoveScriptBytecode, MoveStructTag, MoveType,
     MoveValue, VerifyInput, VerifyInputWithRecursion, U64,
 };
 use anyhow::{bail, Context as AnyhowContext};
 use aptos_crypto::ed25519::{ED25519_PUBLIC_KEY_LENGTH, ED25519_SIGNATURE_LENGTH};
 use aptos_crypto::multi_ed25519::{BITMAP_NUM_OF_BYTES, MAX_NUM_OF_KEYS};
+use aptos_crypto::secp256k1_ecdsa::{
+    SECP256K1_ECDSA_PUBLIC_KEY_LENGTH, SECP256K1_ECDSA_SIGNATURE_LENGTH,
+};
+use aptos_crypto::secp256r1_ecdsa::{
+    SECP256R1_ECDSA_PUBLIC_KEY_LENGTH, SECP256R1_ECDSA_SIGNATURE_LENGTH,
+};
 use aptos_crypto::{
     ed25519::{self, Ed25519PublicKey},
     multi_ed25519::{self, MultiEd25519PublicKey},
+    secp256k1_ecdsa::{self, Secp256k1EcdsaPublicKey},
+    secp256r1_ecdsa::{self, Secp256r1EcdsaPublicKey},
 };
 use aptos_types::transaction::authenticator::MAX_NUM_OF_SIGS;
 use aptos_types::{
@@ -20,7 +28,7 @@ use aptos_types::{
     contract_event::{ContractEvent, EventWithVersion},
     transaction::{
         authenticator::{AccountAuthenticator, TransactionAuthenticator},
-        Script, SignedTransaction, TransactionOutput, TransactionWithProof,
+        webauthn, Script, SignedTransaction, TransactionOutput, TransactionWithProof,
     },
 };
 use poem_openapi::{Object, Union};
@@ -266,6 +274,7 @@ impl
             request: (txn, payload).into(),
             events,
             timestamp: timestamp.into(),
+            gas_profile: None,
         }))
     }
 }
@@ -366,6 +375,10 @@ pub struct UserTransaction {
     /// Events generated by the transaction
     pub events: Vec<Event>,
     pub timestamp: U64,
+    /// Breakdown of the gas charged, only present when simulating a transaction with gas
+    /// profiling enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_profile: Option<GasProfile>,
 }
 
 /// A state checkpoint transaction
@@ -812,6 +825,9 @@ pub enum TransactionSignature {
     Ed25519Signature(Ed25519Signature),
     MultiEd25519Signature(MultiEd25519Signature),
     MultiAgentSignature(MultiAgentSignature),
+    Secp256r1EcdsaSignature(Secp256r1EcdsaSignature),
+    WebAuthnSignature(WebAuthnSignature),
+    Secp256k1EcdsaSignature(Secp256k1EcdsaSignature),
 }
 
 impl VerifyInput for TransactionSignature {
@@ -820,6 +836,9 @@ impl VerifyInput for TransactionSignature {
             TransactionSignature::Ed25519Signature(inner) => inner.verify(),
             TransactionSignature::MultiEd25519Signature(inner) => inner.verify(),
             TransactionSignature::MultiAgentSignature(inner) => inner.verify(),
+            TransactionSignature::Secp256r1EcdsaSignature(inner) => inner.verify(),
+            TransactionSignature::WebAuthnSignature(inner) => inner.verify(),
+            TransactionSignature::Secp256k1EcdsaSignature(inner) => inner.verify(),
         }
     }
 }
@@ -831,6 +850,9 @@ impl TryFrom<TransactionSignature> for TransactionAuthenticator {
             TransactionSignature::Ed25519Signature(sig) => sig.try_into()?,
             TransactionSignature::MultiEd25519Signature(sig) => sig.try_into()?,
             TransactionSignature::MultiAgentSignature(sig) => sig.try_into()?,
+            TransactionSignature::Secp256r1EcdsaSignature(sig) => sig.try_into()?,
+            TransactionSignature::WebAuthnSignature(sig) => sig.try_into()?,
+            TransactionSignature::Secp256k1EcdsaSignature(sig) => sig.try_into()?,
         })
     }
 }
@@ -1025,18 +1047,235 @@ impl TryFrom<MultiEd25519Signature> for AccountAuthenticator {
     }
 }
 
+/// A single Secp256r1 ECDSA signature
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct Secp256r1EcdsaSignature {
+    pub public_key: HexEncodedBytes,
+    pub signature: HexEncodedBytes,
+}
+
+impl VerifyInput for Secp256r1EcdsaSignature {
+    fn verify(&self) -> anyhow::Result<()> {
+        verify_secp256r1_ecdsa_input(&self.public_key, &self.signature, "Secp256r1Ecdsa")
+    }
+}
+
+impl TryFrom<Secp256r1EcdsaSignature> for TransactionAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Secp256r1EcdsaSignature) -> Result<Self, Self::Error> {
+        let (public_key, signature) = value.parse()?;
+        Ok(TransactionAuthenticator::secp256r1_ecdsa(
+            public_key, signature,
+        ))
+    }
+}
+
+impl TryFrom<Secp256r1EcdsaSignature> for AccountAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Secp256r1EcdsaSignature) -> Result<Self, Self::Error> {
+        let (public_key, signature) = value.parse()?;
+        Ok(AccountAuthenticator::secp256r1_ecdsa(public_key, signature))
+    }
+}
+
+impl Secp256r1EcdsaSignature {
+    fn parse(
+        self,
+    ) -> anyhow::Result<(
+        Secp256r1EcdsaPublicKey,
+        secp256r1_ecdsa::Secp256r1EcdsaSignature,
+    )> {
+        let Secp256r1EcdsaSignature {
+            public_key,
+            signature,
+        } = self;
+        Ok((
+            public_key
+                .inner()
+                .try_into()
+                .context("Failed to parse given public_key bytes as a Secp256r1EcdsaPublicKey")?,
+            signature
+                .inner()
+                .try_into()
+                .context("Failed to parse given signature as a Secp256r1EcdsaSignature")?,
+        ))
+    }
+}
+
+/// A WebAuthn assertion signed by a Secp256r1 ECDSA key, e.g. a passkey
+///
+/// The authenticator signs `authenticator_data || sha256(client_data_json)`, where the challenge
+/// in `client_data_json` is the base64url-encoded SHA3-256 hash of the transaction's signing message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct WebAuthnSignature {
+    pub public_key: HexEncodedBytes,
+    pub signature: HexEncodedBytes,
+    pub authenticator_data: HexEncodedBytes,
+    pub client_data_json: HexEncodedBytes,
+}
+
+impl VerifyInput for WebAuthnSignature {
+    fn verify(&self) -> anyhow::Result<()> {
+        verify_secp256r1_ecdsa_input(&self.public_key, &self.signature, "WebAuthn")?;
+        if self.authenticator_data.inner().is_empty() {
+            bail!("WebAuthn signature has no authenticator data")
+        } else if self.client_data_json.inner().is_empty() {
+            bail!("WebAuthn signature has no client data")
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<WebAuthnSignature> for TransactionAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: WebAuthnSignature) -> Result<Self, Self::Error> {
+        let (public_key, signature) = value.parse()?;
+        Ok(TransactionAuthenticator::webauthn(public_key, signature))
+    }
+}
+
+impl TryFrom<WebAuthnSignature> for AccountAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: WebAuthnSignature) -> Result<Self, Self::Error> {
+        let (public_key, signature) = value.parse()?;
+        Ok(AccountAuthenticator::webauthn(public_key, signature))
+    }
+}
+
+impl WebAuthnSignature {
+    fn parse(self) -> anyhow::Result<(Secp256r1EcdsaPublicKey, webauthn::WebAuthnSignature)> {
+        let WebAuthnSignature {
+            public_key,
+            signature,
+            authenticator_data,
+            client_data_json,
+        } = self;
+        let (public_key, signature) = Secp256r1EcdsaSignature {
+            public_key,
+            signature,
+        }
+        .parse()?;
+        Ok((
+            public_key,
+            webauthn::WebAuthnSignature::new(
+                signature,
+                authenticator_data.into(),
+                client_data_json.into(),
+            ),
+        ))
+    }
+}
+
+fn verify_secp256r1_ecdsa_input(
+    public_key: &HexEncodedBytes,
+    signature: &HexEncodedBytes,
+    name: &str,
+) -> anyhow::Result<()> {
+    if public_key.inner().len() != SECP256R1_ECDSA_PUBLIC_KEY_LENGTH {
+        bail!(
+            "{} signature's public key is an invalid number of bytes, should be {} bytes",
+            name,
+            SECP256R1_ECDSA_PUBLIC_KEY_LENGTH
+        )
+    } else if signature.inner().len() != SECP256R1_ECDSA_SIGNATURE_LENGTH {
+        bail!(
+            "{} signature length is an invalid number of bytes, should be {} bytes",
+            name,
+            SECP256R1_ECDSA_SIGNATURE_LENGTH
+        )
+    }
+    Ok(())
+}
+
+/// A single Secp256k1 ECDSA signature
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct Secp256k1EcdsaSignature {
+    pub public_key: HexEncodedBytes,
+    pub signature: HexEncodedBytes,
+}
+
+impl VerifyInput for Secp256k1EcdsaSignature {
+    fn verify(&self) -> anyhow::Result<()> {
+        if self.public_key.inner().len() != SECP256K1_ECDSA_PUBLIC_KEY_LENGTH {
+            bail!(
+                "Secp256k1Ecdsa signature's public key is an invalid number of bytes, should be {} bytes",
+                SECP256K1_ECDSA_PUBLIC_KEY_LENGTH
+            )
+        } else if self.signature.inner().len() != SECP256K1_ECDSA_SIGNATURE_LENGTH {
+            bail!(
+                "Secp256k1Ecdsa signature length is an invalid number of bytes, should be {} bytes",
+                SECP256K1_ECDSA_SIGNATURE_LENGTH
+            )
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<Secp256k1EcdsaSignature> for TransactionAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Secp256k1EcdsaSignature) -> Result<Self, Self::Error> {
+        let (public_key, signature) = value.parse()?;
+        Ok(TransactionAuthenticator::secp256k1_ecdsa(
+            public_key, signature,
+        ))
+    }
+}
+
+impl TryFrom<Secp256k1EcdsaSignature> for AccountAuthenticator {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Secp256k1EcdsaSignature) -> Result<Self, Self::Error> {
+        let (public_key, signature) = value.parse()?;
+        Ok(AccountAuthenticator::secp256k1_ecdsa(public_key, signature))
+    }
+}
+
+impl Secp256k1EcdsaSignature {
+    fn parse(
+        self,
+    ) -> anyhow::Result<(
+        Secp256k1EcdsaPublicKey,
+        secp256k1_ecdsa::Secp256k1EcdsaSignature,
+    )> {
+        let Secp256k1EcdsaSignature {
+            public_key,
+            signature,
+        } = self;
+        Ok((
+            public_key
+                .inner()
+                .try_into()
+                .context("Failed to parse given public_key bytes as a Secp256k1EcdsaPublicKey")?,
+            signature
+                .inner()
+                .try_into()
+                .context("Failed to parse given signature as a Secp256k1EcdsaSignature")?,
+        ))
+    }
+}
+
 /// Account signature scheme
 ///
-/// The account signature scheme allows you to have two types of accounts:
+/// The account signature scheme allows you to have these types of accounts:
 ///
 ///   1. A single Ed25519 key account, one private key
 ///   2. A k-of-n multi-Ed25519 key account, multiple private keys, such that k-of-n must sign a transaction.
+///   3. A single Secp256r1 ECDSA key account, which signs either directly or with WebAuthn
+///   4. A single Secp256k1 ECDSA key account, one private key
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Union)]
 #[serde(tag = "type", rename_all = "snake_case")]
 #[oai(one_of, discriminator_name = "type", rename_all = "snake_case")]
 pub enum AccountSignature {
     Ed25519Signature(Ed25519Signature),
     MultiEd25519Signature(MultiEd25519Signature),
+    Secp256r1EcdsaSignature(Secp256r1EcdsaSignature),
+    WebAuthnSignature(WebAuthnSignature),
+    Secp256k1EcdsaSignature(Secp256k1EcdsaSignature),
 }
 
 impl VerifyInput for AccountSignature {
@@ -1044,6 +1283,9 @@ impl VerifyInput for AccountSignature {
         match self {
             AccountSignature::Ed25519Signature(inner) => inner.verify(),
             AccountSignature::MultiEd25519Signature(inner) => inner.verify(),
+            AccountSignature::Secp256r1EcdsaSignature(inner) => inner.verify(),
+            AccountSignature::WebAuthnSignature(inner) => inner.verify(),
+            AccountSignature::Secp256k1EcdsaSignature(inner) => inner.verify(),
         }
     }
 }
@@ -1055,6 +1297,9 @@ impl TryFrom<AccountSignature> for AccountAuthenticator {
         Ok(match sig {
             AccountSignature::Ed25519Signature(s) => s.try_into()?,
             AccountSignature::MultiEd25519Signature(s) => s.try_into()?,
+            AccountSignature::Secp256r1EcdsaSignature(s) => s.try_into()?,
+            AccountSignature::WebAuthnSignature(s) => s.try_into()?,
+            AccountSignature::Secp256k1EcdsaSignature(s) => s.try_into()?,
         })
     }
 }
@@ -1151,6 +1396,55 @@ impl
     }
 }
 
+impl
+    From<(
+        &Secp256r1EcdsaPublicKey,
+        &secp256r1_ecdsa::Secp256r1EcdsaSignature,
+    )> for Secp256r1EcdsaSignature
+{
+    fn from(
+        (pk, sig): (
+            &Secp256r1EcdsaPublicKey,
+            &secp256r1_ecdsa::Secp256r1EcdsaSignature,
+        ),
+    ) -> Self {
+        Self {
+            public_key: pk.to_bytes().to_vec().into(),
+            signature: sig.to_bytes().to_vec().into(),
+        }
+    }
+}
+
+impl
+    From<(
+        &Secp256k1EcdsaPublicKey,
+        &secp256k1_ecdsa::Secp256k1EcdsaSignature,
+    )> for Secp256k1EcdsaSignature
+{
+    fn from(
+        (pk, sig): (
+            &Secp256k1EcdsaPublicKey,
+            &secp256k1_ecdsa::Secp256k1EcdsaSignature,
+        ),
+    ) -> Self {
+        Self {
+            public_key: pk.to_bytes().to_vec().into(),
+            signature: sig.to_bytes().to_vec().into(),
+        }
+    }
+}
+
+impl From<(&Secp256r1EcdsaPublicKey, &webauthn::WebAuthnSignature)> for WebAuthnSignature {
+    fn from((pk, sig): (&Secp256r1EcdsaPublicKey, &webauthn::WebAuthnSignature)) -> Self {
+        Self {
+            public_key: pk.to_bytes().to_vec().into(),
+            signature: sig.signature().to_bytes().to_vec().into(),
+            authenticator_data: sig.authenticator_data().to_vec().into(),
+            client_data_json: sig.client_data_json().to_vec().into(),
+        }
+    }
+}
+
 impl From<&AccountAuthenticator> for AccountSignature {
     fn from(auth: &AccountAuthenticator) -> Self {
         use AccountAuthenticator::*;
@@ -1163,6 +1457,18 @@ impl From<&AccountAuthenticator> for AccountSignature {
                 public_key,
                 signature,
             } => Self::MultiEd25519Signature((public_key, signature).into()),
+            Secp256r1Ecdsa {
+                public_key,
+                signature,
+            } => Self::Secp256r1EcdsaSignature((public_key, signature).into()),
+            WebAuthn {
+                public_key,
+                signature,
+            } => Self::WebAuthnSignature((public_key, signature).into()),
+            Secp256k1Ecdsa {
+                public_key,
+                signature,
+            } => Self::Secp256k1EcdsaSignature((public_key, signature).into()),
         }
     }
 }
@@ -1208,6 +1514,18 @@ impl From<TransactionAuthenticator> for TransactionSignature {
             } => Self::MultiAgentSignature(
                 (sender, secondary_signer_addresses, secondary_signers).into(),
             ),
+            Secp256r1Ecdsa {
+                public_key,
+                signature,
+            } => Self::Secp256r1EcdsaSignature((public_key, signature).into()),
+            WebAuthn {
+                public_key,
+                signature,
+            } => Self::WebAuthnSignature((public_key, signature).into()),
+            Secp256k1Ecdsa {
+                public_key,
+                signature,
+            } => Self::Secp256k1EcdsaSignature((public_key, signature).into()),
         }
     }
 }
@@ -0,0 +1,42 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{EntryFunctionId, MoveType, VerifyInput, VerifyInputWithRecursion};
+use move_core_types::{
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+/// View request for the Move view function API
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ViewRequest {
+    pub function: EntryFunctionId,
+    /// Type arguments of the function
+    pub type_arguments: Vec<MoveType>,
+    /// Arguments of the function
+    pub arguments: Vec<serde_json::Value>,
+}
+
+impl VerifyInput for ViewRequest {
+    fn verify(&self) -> anyhow::Result<()> {
+        self.function.verify()?;
+        for type_arg in self.type_arguments.iter() {
+            type_arg.verify(0)?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`ViewRequest`] resolved against the on-chain module ABI, ready to be executed by the VM
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViewFunction {
+    pub module: ModuleId,
+    pub function: Identifier,
+    pub ty_args: Vec<TypeTag>,
+    /// BCS encoded arguments
+    pub args: Vec<Vec<u8>>,
+    /// Return types of the function, instantiated with `ty_args`
+    pub return_types: Vec<TypeTag>,
+}
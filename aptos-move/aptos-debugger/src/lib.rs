@@ -0,0 +1,314 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Replays committed transactions with the current VM, and optionally with locally built Move
+//! packages in place of the on-chain ones, and compares the outputs with the ones that were
+//! committed. This allows to validate VM and framework upgrades against the history of a network
+//! before proposing them.
+
+mod output_diff;
+
+pub use crate::output_diff::{OutputDiff, TransactionMismatch};
+
+use anyhow::{ensure, Result};
+use aptos_rest_client::Client;
+use aptos_state_view::StateView;
+use aptos_types::{
+    access_path::AccessPath,
+    state_store::{state_key::StateKey, state_storage_usage::StateStorageUsage},
+    transaction::{Transaction, TransactionOutput, TransactionStatus, Version},
+};
+use aptos_validator_interface::{
+    AptosValidatorInterface, DBDebuggerInterface, DebuggerStateView, RestDebuggerInterface,
+};
+use aptos_vm::{AptosVM, VMExecutor};
+use framework::{BuildOptions, BuiltPackage};
+use rayon::prelude::*;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+pub struct AptosDebugger {
+    debugger: Box<dyn AptosValidatorInterface>,
+    /// Code which replaces the on-chain code of the same modules during execution.
+    module_overrides: HashMap<StateKey, Vec<u8>>,
+}
+
+impl AptosDebugger {
+    pub fn new(debugger: Box<dyn AptosValidatorInterface>) -> Self {
+        Self {
+            debugger,
+            module_overrides: HashMap::new(),
+        }
+    }
+
+    pub fn db<P: AsRef<Path> + Clone>(db_root_path: P) -> Result<Self> {
+        Ok(Self::new(Box::new(DBDebuggerInterface::open(
+            db_root_path,
+        )?)))
+    }
+
+    /// Fetches the transactions and state from a node's REST API, caching the fetched state
+    /// values in `cache_dir` if provided.
+    pub fn rest_client(rest_client: Client, cache_dir: Option<PathBuf>) -> Result<Self> {
+        let mut debugger = RestDebuggerInterface::new(rest_client)?;
+        if let Some(cache_dir) = cache_dir {
+            debugger = debugger.with_cache_dir(cache_dir)?;
+        }
+        Ok(Self::new(Box::new(debugger)))
+    }
+
+    /// Builds the Move package at `package_path` (e.g. a locally modified framework package),
+    /// and executes its modules instead of the on-chain ones from now on.
+    pub fn override_package(&mut self, package_path: PathBuf) -> Result<()> {
+        let package = BuiltPackage::build(package_path, BuildOptions::default())?;
+        for module in package.modules() {
+            let mut code = vec![];
+            module.serialize(&mut code)?;
+            self.module_overrides.insert(
+                StateKey::AccessPath(AccessPath::code_access_path(module.self_id())),
+                code,
+            );
+        }
+        Ok(())
+    }
+
+    /// Executes `txns` on top of the state right before `version`, i.e. the state that the
+    /// committed transaction at `version` was executed on.
+    pub fn execute_transactions_at_version(
+        &self,
+        version: Version,
+        txns: Vec<Transaction>,
+    ) -> Result<Vec<TransactionOutput>> {
+        let state_view = OverrideStateView {
+            base: DebuggerStateView::new(&*self.debugger, version.checked_sub(1)),
+            overrides: &self.module_overrides,
+        };
+        Ok(AptosVM::execute_block(txns, &state_view)?)
+    }
+
+    /// Replays the committed transactions in `[begin, begin + limit)` and returns the ones whose
+    /// outputs differ from the committed outputs. The range is split into batches of
+    /// `batch_size` transactions which are replayed in parallel, each on top of the committed
+    /// state right before its first transaction.
+    pub fn replay_transactions(
+        &self,
+        begin: Version,
+        limit: u64,
+        batch_size: u64,
+    ) -> Result<Vec<TransactionMismatch>> {
+        ensure!(batch_size > 0, "Batch size must be positive");
+
+        let end = begin + limit;
+        let batches: Vec<_> = (begin..end)
+            .step_by(batch_size as usize)
+            .map(|batch_begin| (batch_begin, std::cmp::min(batch_size, end - batch_begin)))
+            .collect();
+
+        let mismatches = batches
+            .into_par_iter()
+            .map(|(batch_begin, batch_limit)| self.replay_batch(batch_begin, batch_limit))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(mismatches.into_iter().flatten().collect())
+    }
+
+    fn replay_batch(&self, begin: Version, limit: u64) -> Result<Vec<TransactionMismatch>> {
+        let (txns, expected_outputs): (Vec<_>, Vec<_>) = self
+            .debugger
+            .get_committed_transaction_outputs(begin, limit)?
+            .into_iter()
+            .unzip();
+        ensure!(
+            txns.len() as u64 == limit,
+            "Expected {} committed transactions from version {}, got {}",
+            limit,
+            begin,
+            txns.len()
+        );
+
+        let mut mismatches = vec![];
+        let mut version = begin;
+        let mut txns = txns.into_iter();
+        let mut expected_outputs = expected_outputs.into_iter();
+        while !txns.as_slice().is_empty() {
+            let outputs =
+                self.execute_transactions_at_version(version, txns.as_slice().to_vec())?;
+            ensure!(
+                outputs.first().map(|output| output.status()) != Some(&TransactionStatus::Retry),
+                "Transaction at version {} can't be replayed",
+                version
+            );
+            for output in outputs {
+                // The transactions following a reconfiguration are retried, they'll be executed
+                // again on top of the committed state after the reconfiguration.
+                if output.status() == &TransactionStatus::Retry {
+                    break;
+                }
+
+                let expected_output = expected_outputs.next().expect("Output must exist");
+                txns.next();
+                mismatches.extend(TransactionMismatch::compare(
+                    version,
+                    &expected_output,
+                    &output,
+                ));
+                version += 1;
+            }
+        }
+        Ok(mismatches)
+    }
+}
+
+/// A state view which returns the overridden values of some state keys.
+struct OverrideStateView<'a, S> {
+    base: S,
+    overrides: &'a HashMap<StateKey, Vec<u8>>,
+}
+
+impl<'a, S: StateView> StateView for OverrideStateView<'a, S> {
+    fn get_state_value(&self, state_key: &StateKey) -> Result<Option<Vec<u8>>> {
+        match self.overrides.get(state_key) {
+            Some(value) => Ok(Some(value.clone())),
+            None => self.base.get_state_value(state_key),
+        }
+    }
+
+    fn is_genesis(&self) -> bool {
+        self.base.is_genesis()
+    }
+
+    fn get_usage(&self) -> Result<StateStorageUsage> {
+        self.base.get_usage()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_api_test_context::new_test_context;
+    use aptos_types::{
+        account_address::AccountAddress, account_state::AccountState,
+        contract_event::EventWithVersion, event::EventKey, state_store::state_value::StateValue,
+    };
+
+    /// Serves the committed data of a DB, except for the gas used by the transaction at
+    /// `tampered_version`, which is off by one.
+    struct TamperedInterface {
+        db: DBDebuggerInterface,
+        tampered_version: Version,
+    }
+
+    impl AptosValidatorInterface for TamperedInterface {
+        fn get_account_state_by_version(
+            &self,
+            account: AccountAddress,
+            version: Version,
+        ) -> Result<Option<AccountState>> {
+            self.db.get_account_state_by_version(account, version)
+        }
+
+        fn get_state_value_by_version(
+            &self,
+            state_key: &StateKey,
+            version: Version,
+        ) -> Result<Option<StateValue>> {
+            self.db.get_state_value_by_version(state_key, version)
+        }
+
+        fn get_events(
+            &self,
+            key: &EventKey,
+            start_seq: u64,
+            limit: u64,
+            ledger_version: Version,
+        ) -> Result<Vec<EventWithVersion>> {
+            self.db.get_events(key, start_seq, limit, ledger_version)
+        }
+
+        fn get_committed_transactions(
+            &self,
+            start: Version,
+            limit: u64,
+        ) -> Result<Vec<Transaction>> {
+            self.db.get_committed_transactions(start, limit)
+        }
+
+        fn get_committed_transaction_outputs(
+            &self,
+            start: Version,
+            limit: u64,
+        ) -> Result<Vec<(Transaction, TransactionOutput)>> {
+            let mut txns_and_outputs = self.db.get_committed_transaction_outputs(start, limit)?;
+            for (version, (_, output)) in (start..).zip(txns_and_outputs.iter_mut()) {
+                if version == self.tampered_version {
+                    *output = TransactionOutput::new(
+                        output.write_set().clone(),
+                        output.events().to_vec(),
+                        output.gas_used() + 1,
+                        output.status().clone(),
+                    );
+                }
+            }
+            Ok(txns_and_outputs)
+        }
+
+        fn get_latest_version(&self) -> Result<Version> {
+            self.db.get_latest_version()
+        }
+
+        fn get_state_storage_usage(&self, version: Version) -> Result<StateStorageUsage> {
+            self.db.get_state_storage_usage(version)
+        }
+
+        fn get_version_by_account_sequence(
+            &self,
+            account: AccountAddress,
+            seq: u64,
+        ) -> Result<Option<Version>> {
+            self.db.get_version_by_account_sequence(account, seq)
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_replay_transactions() {
+        let mut context = new_test_context("test_replay_transactions".to_string(), false);
+        let mut root = context.root_account();
+        let accounts = [context.gen_account(), context.gen_account()];
+        let txns: Vec<_> = accounts
+            .iter()
+            .map(|account| context.create_user_account_by(&mut root, account))
+            .collect();
+        context.commit_block(&txns).await;
+        let transfer = context.account_transfer(&mut root, &accounts[0], 1_000);
+        context.commit_block(&[transfer]).await;
+
+        // The two blocks: block metadata, user transactions and state checkpoint
+        let end = context.get_latest_ledger_info().version() + 1;
+        let begin = end - 7;
+        let db = context.db.clone();
+
+        // Replayed outputs match the committed ones, whatever the batches
+        let debugger = AptosDebugger::new(Box::new(DBDebuggerInterface::new(db.clone())));
+        for batch_size in [1, 3, 7] {
+            assert!(debugger
+                .replay_transactions(begin, end - begin, batch_size)
+                .unwrap()
+                .is_empty());
+        }
+
+        // The transfer's output was tampered with
+        let tampered_version = end - 2;
+        let debugger = AptosDebugger::new(Box::new(TamperedInterface {
+            db: DBDebuggerInterface::new(db),
+            tampered_version,
+        }));
+        let mismatches = debugger.replay_transactions(begin, end - begin, 3).unwrap();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].version, tampered_version);
+        assert!(matches!(
+            &mismatches[0].diffs[..],
+            [OutputDiff::GasUsed { expected, actual }] if *expected == *actual + 1
+        ));
+    }
+}
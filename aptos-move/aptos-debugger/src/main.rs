@@ -0,0 +1,76 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use aptos_debugger::AptosDebugger;
+use aptos_rest_client::Client;
+use aptos_types::transaction::Version;
+use clap::Parser;
+use std::path::PathBuf;
+use url::Url;
+
+/// Replays committed transactions from a local DB, or from a node's REST API, and compares their
+/// outputs with the committed ones.
+#[derive(Parser)]
+#[clap(name = "aptos-debugger")]
+struct Args {
+    /// Path to the root of the DB to replay the transactions from.
+    #[clap(long, parse(from_os_str))]
+    db: Option<PathBuf>,
+
+    /// URL of the REST API of a node to replay the transactions from, instead of a local DB.
+    #[clap(long)]
+    rest_endpoint: Option<Url>,
+
+    /// Directory caching the state fetched from the REST API across runs.
+    #[clap(long, parse(from_os_str))]
+    cache_dir: Option<PathBuf>,
+
+    /// Version of the first transaction to replay.
+    #[clap(long)]
+    begin_version: Version,
+
+    /// Number of transactions to replay.
+    #[clap(long)]
+    limit: u64,
+
+    /// Number of transactions replayed at once. Batches are replayed in parallel.
+    #[clap(long, default_value = "1000")]
+    batch_size: u64,
+
+    /// Path to a Move package (e.g. a locally built framework package) whose modules are
+    /// executed instead of the on-chain ones. Can be repeated.
+    #[clap(long, parse(from_os_str))]
+    override_package: Vec<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let mut debugger = match (args.db, args.rest_endpoint) {
+        (Some(db), None) => AptosDebugger::db(db)?,
+        (None, Some(rest_endpoint)) => {
+            AptosDebugger::rest_client(Client::new(rest_endpoint), args.cache_dir)?
+        }
+        _ => bail!("Exactly one of --db and --rest-endpoint must be provided"),
+    };
+    for package_path in args.override_package {
+        debugger.override_package(package_path)?;
+    }
+
+    let mismatches =
+        debugger.replay_transactions(args.begin_version, args.limit, args.batch_size)?;
+    for mismatch in &mismatches {
+        println!("{}", mismatch);
+    }
+
+    if !mismatches.is_empty() {
+        bail!(
+            "{} of {} replayed transactions have mismatching outputs",
+            mismatches.len(),
+            args.limit
+        );
+    }
+    println!("All {} replayed transactions match", args.limit);
+    Ok(())
+}
@@ -0,0 +1,276 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_types::{
+    contract_event::ContractEvent,
+    state_store::state_key::StateKey,
+    transaction::{TransactionOutput, TransactionStatus, Version},
+    write_set::WriteOp,
+};
+use std::{collections::HashMap, fmt};
+
+/// A difference between the output of a replayed transaction and the one it produced when it was
+/// committed.
+#[derive(Debug)]
+pub enum OutputDiff {
+    Status {
+        expected: TransactionStatus,
+        actual: TransactionStatus,
+    },
+    GasUsed {
+        expected: u64,
+        actual: u64,
+    },
+    /// A key that was written differently, or only by one of the outputs.
+    WriteOp {
+        key: StateKey,
+        expected: Option<WriteOp>,
+        actual: Option<WriteOp>,
+    },
+    Events {
+        expected: Vec<ContractEvent>,
+        actual: Vec<ContractEvent>,
+    },
+}
+
+impl fmt::Display for OutputDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputDiff::Status { expected, actual } => {
+                write!(f, "status: expected {:?}, got {:?}", expected, actual)
+            }
+            OutputDiff::GasUsed { expected, actual } => {
+                write!(f, "gas used: expected {}, got {}", expected, actual)
+            }
+            OutputDiff::WriteOp {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "write to {:?}: expected {:?}, got {:?}",
+                key, expected, actual
+            ),
+            OutputDiff::Events { expected, actual } => {
+                write!(f, "events: expected {:?}, got {:?}", expected, actual)
+            }
+        }
+    }
+}
+
+/// The differences between the replayed and committed outputs of the transaction at `version`.
+#[derive(Debug)]
+pub struct TransactionMismatch {
+    pub version: Version,
+    pub diffs: Vec<OutputDiff>,
+}
+
+impl TransactionMismatch {
+    /// Compares the outputs, and returns the mismatch if they differ.
+    pub fn compare(
+        version: Version,
+        expected: &TransactionOutput,
+        actual: &TransactionOutput,
+    ) -> Option<Self> {
+        let mut diffs = vec![];
+
+        if expected.status() != actual.status() {
+            diffs.push(OutputDiff::Status {
+                expected: expected.status().clone(),
+                actual: actual.status().clone(),
+            });
+        }
+        if expected.gas_used() != actual.gas_used() {
+            diffs.push(OutputDiff::GasUsed {
+                expected: expected.gas_used(),
+                actual: actual.gas_used(),
+            });
+        }
+
+        let mut actual_writes: HashMap<&StateKey, &WriteOp> = actual.write_set().iter().collect();
+        for (key, expected_op) in expected.write_set().iter() {
+            match actual_writes.remove(key) {
+                Some(actual_op) if actual_op == expected_op => (),
+                actual_op => diffs.push(OutputDiff::WriteOp {
+                    key: key.clone(),
+                    expected: Some(expected_op.clone()),
+                    actual: actual_op.cloned(),
+                }),
+            }
+        }
+        // Keep the order of the write set for the keys that were only written by the replay.
+        for (key, actual_op) in actual.write_set().iter() {
+            if actual_writes.contains_key(key) {
+                diffs.push(OutputDiff::WriteOp {
+                    key: key.clone(),
+                    expected: None,
+                    actual: Some(actual_op.clone()),
+                });
+            }
+        }
+
+        if expected.events() != actual.events() {
+            diffs.push(OutputDiff::Events {
+                expected: expected.events().to_vec(),
+                actual: actual.events().to_vec(),
+            });
+        }
+
+        if diffs.is_empty() {
+            None
+        } else {
+            Some(Self { version, diffs })
+        }
+    }
+}
+
+impl fmt::Display for TransactionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Mismatch at version {}:", self.version)?;
+        for diff in &self.diffs {
+            writeln!(f, "  {}", diff)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_types::{
+        account_address::AccountAddress,
+        event::EventKey,
+        transaction::ExecutionStatus,
+        vm_status::StatusCode,
+        write_set::{WriteSet, WriteSetMut},
+    };
+    use move_core_types::language_storage::TypeTag;
+
+    fn key(byte: u8) -> StateKey {
+        StateKey::Raw(vec![byte])
+    }
+
+    fn write_set(ops: Vec<(StateKey, WriteOp)>) -> WriteSet {
+        WriteSetMut::new(ops).freeze().unwrap()
+    }
+
+    fn event(data: u8) -> ContractEvent {
+        ContractEvent::new(
+            EventKey::new(0, AccountAddress::ONE),
+            0,
+            TypeTag::U64,
+            vec![data],
+        )
+    }
+
+    fn output() -> TransactionOutput {
+        TransactionOutput::new(
+            write_set(vec![
+                (key(1), WriteOp::Modification(vec![1])),
+                (key(2), WriteOp::Deletion),
+            ]),
+            vec![event(1)],
+            10,
+            TransactionStatus::Keep(ExecutionStatus::Success),
+        )
+    }
+
+    fn with_write_set(write_set: WriteSet) -> TransactionOutput {
+        let output = output();
+        TransactionOutput::new(
+            write_set,
+            output.events().to_vec(),
+            output.gas_used(),
+            output.status().clone(),
+        )
+    }
+
+    #[test]
+    fn test_same_outputs() {
+        assert!(TransactionMismatch::compare(0, &output(), &output()).is_none());
+    }
+
+    #[test]
+    fn test_different_write_sets() {
+        // A different value, a missing key and an additional key
+        let actual = with_write_set(write_set(vec![
+            (key(3), WriteOp::Creation(vec![3])),
+            (key(1), WriteOp::Modification(vec![2])),
+        ]));
+        let mismatch = TransactionMismatch::compare(5, &output(), &actual).unwrap();
+        assert_eq!(mismatch.version, 5);
+        assert!(matches!(
+            &mismatch.diffs[..],
+            [
+                OutputDiff::WriteOp {
+                    key: key1,
+                    expected: Some(WriteOp::Modification(expected1)),
+                    actual: Some(WriteOp::Modification(actual1)),
+                },
+                OutputDiff::WriteOp {
+                    key: key2,
+                    expected: Some(WriteOp::Deletion),
+                    actual: None,
+                },
+                OutputDiff::WriteOp {
+                    key: key3,
+                    expected: None,
+                    actual: Some(WriteOp::Creation(actual3)),
+                },
+            ] if key1 == &key(1) && expected1 == &vec![1] && actual1 == &vec![2]
+                && key2 == &key(2)
+                && key3 == &key(3) && actual3 == &vec![3]
+        ));
+    }
+
+    #[test]
+    fn test_same_write_sets_in_different_order() {
+        let actual = with_write_set(write_set(vec![
+            (key(2), WriteOp::Deletion),
+            (key(1), WriteOp::Modification(vec![1])),
+        ]));
+        assert!(TransactionMismatch::compare(0, &output(), &actual).is_none());
+    }
+
+    #[test]
+    fn test_different_events() {
+        let expected = output();
+        let actual = TransactionOutput::new(
+            expected.write_set().clone(),
+            vec![event(1), event(2)],
+            expected.gas_used(),
+            expected.status().clone(),
+        );
+        let mismatch = TransactionMismatch::compare(0, &expected, &actual).unwrap();
+        assert!(matches!(
+            &mismatch.diffs[..],
+            [OutputDiff::Events { expected, actual }]
+                if expected == &vec![event(1)] && actual == &vec![event(1), event(2)]
+        ));
+    }
+
+    #[test]
+    fn test_different_gas_used_and_status() {
+        let expected = output();
+        let actual = TransactionOutput::new(
+            expected.write_set().clone(),
+            expected.events().to_vec(),
+            20,
+            TransactionStatus::Discard(StatusCode::OUT_OF_GAS),
+        );
+        let mismatch = TransactionMismatch::compare(0, &expected, &actual).unwrap();
+        assert!(matches!(
+            &mismatch.diffs[..],
+            [
+                OutputDiff::Status {
+                    expected: TransactionStatus::Keep(ExecutionStatus::Success),
+                    actual: TransactionStatus::Discard(StatusCode::OUT_OF_GAS),
+                },
+                OutputDiff::GasUsed {
+                    expected: 10,
+                    actual: 20,
+                },
+            ]
+        ));
+    }
+}
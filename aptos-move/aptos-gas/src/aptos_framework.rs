@@ -75,6 +75,16 @@ crate::natives::define_gas_parameters_for_natives!(GasParameters, "aptos_framewo
     [.hash.keccak256.base, optional "hash.keccak256.base", 4000 * MUL],
     [.hash.keccak256.per_byte, optional "hash.keccak256.per_byte", 45 * MUL],
 
+    // Note(Gas): these initial values are guesswork, based on the costs of the other hash functions.
+    [.hash.sha2_512.base, optional "hash.sha2_512.base", 3_240 * MUL],
+    [.hash.sha2_512.per_byte, optional "hash.sha2_512.per_byte", 60 * MUL],
+    [.hash.sha3_512.base, optional "hash.sha3_512.base", 4_500 * MUL],
+    [.hash.sha3_512.per_byte, optional "hash.sha3_512.per_byte", 50 * MUL],
+    [.hash.blake2b_256.base, optional "hash.blake2b_256.base", 1_750 * MUL],
+    [.hash.blake2b_256.per_byte, optional "hash.blake2b_256.per_byte", 15 * MUL],
+    [.hash.ripemd160.base, optional "hash.ripemd160.base", 3_000 * MUL],
+    [.hash.ripemd160.per_byte, optional "hash.ripemd160.per_byte", 50 * MUL],
+
     [.type_info.type_of.base, "type_info.type_of.base", 300 * MUL],
     // TODO(Gas): the on-chain name is wrong...
     [.type_info.type_of.per_byte_in_str, "type_info.type_of.per_abstract_memory_unit", 5 * MUL],
@@ -6,6 +6,7 @@
 
 use crate::{
     algebra::{AbstractValueSize, Gas},
+    gas_profiler::{GasProfile, GasProfiler},
     instr::InstructionGasParameters,
     misc::MiscGasParameters,
     transaction::StorageGasParameters,
@@ -176,6 +177,8 @@ pub struct AptosGasMeter {
     memory_quota: AbstractValueSize,
 
     is_call_table: bool,
+
+    profiler: Option<GasProfiler>,
 }
 
 impl AptosGasMeter {
@@ -201,16 +204,40 @@ impl AptosGasMeter {
             balance,
             memory_quota,
             is_call_table: false,
+            profiler: None,
         }
     }
 
+    /// Starts attributing all subsequent charges to the call frames, native functions and storage
+    /// operations they are made for. `entry_point` names the outermost frame, usually the entry
+    /// function or script being executed.
+    pub fn enable_profiling(&mut self, entry_point: String) {
+        self.profiler = Some(GasProfiler::new(entry_point));
+    }
+
+    /// Returns the breakdown of the gas charged so far, if profiling is enabled.
+    pub fn gas_profile(&self) -> Option<GasProfile> {
+        self.profiler
+            .as_ref()
+            .map(|profiler| profiler.to_profile(self.gas_params.txn.scaling_factor().into()))
+    }
+
     pub fn balance(&self) -> Gas {
         self.balance
             .to_unit_round_down_with_params(&self.gas_params.txn)
     }
 
+    /// Charges gas for executing instructions in the current frame.
     #[inline]
     fn charge(&mut self, amount: InternalGas) -> PartialVMResult<()> {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.record_execution(amount);
+        }
+        self.deduct(amount)
+    }
+
+    #[inline]
+    fn deduct(&mut self, amount: InternalGas) -> PartialVMResult<()> {
         match self.balance.checked_sub(amount) {
             Some(new_balance) => {
                 self.balance = new_balance;
@@ -266,6 +293,10 @@ impl GasMeter for AptosGasMeter {
         _ty_args: impl ExactSizeIterator<Item = impl TypeView>,
         args: impl ExactSizeIterator<Item = impl ValueView>,
     ) -> PartialVMResult<()> {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.enter_native_function();
+        }
+
         // TODO(Gas): The table extension maintains its own memory space and currently it's hard
         //            for us to track when values are created or dropped there.
         //            Therefore as a temporary hack, we do not consider the memory released when
@@ -303,7 +334,10 @@ impl GasMeter for AptosGasMeter {
             }))?;
         }
 
-        self.charge(amount)
+        if let Some(profiler) = &mut self.profiler {
+            profiler.record_native(amount);
+        }
+        self.deduct(amount)
     }
 
     #[inline]
@@ -342,7 +376,10 @@ impl GasMeter for AptosGasMeter {
             }
         };
 
-        self.charge(cost)
+        if let Some(profiler) = &mut self.profiler {
+            profiler.record_storage_read(cost);
+        }
+        self.deduct(cost)
     }
 
     #[inline]
@@ -360,8 +397,8 @@ impl GasMeter for AptosGasMeter {
     #[inline]
     fn charge_call(
         &mut self,
-        _module_id: &ModuleId,
-        _func_name: &str,
+        module_id: &ModuleId,
+        func_name: &str,
         args: impl ExactSizeIterator<Item = impl ValueView>,
         num_locals: NumArgs,
     ) -> PartialVMResult<()> {
@@ -372,14 +409,18 @@ impl GasMeter for AptosGasMeter {
             cost += params.call_per_local * num_locals;
         }
 
-        self.charge(cost)
+        self.charge(cost)?;
+        if let Some(profiler) = &mut self.profiler {
+            profiler.enter_function(module_id, func_name);
+        }
+        Ok(())
     }
 
     #[inline]
     fn charge_call_generic(
         &mut self,
         module_id: &ModuleId,
-        _func_name: &str,
+        func_name: &str,
         ty_args: impl ExactSizeIterator<Item = impl TypeView>,
         args: impl ExactSizeIterator<Item = impl ValueView>,
         num_locals: NumArgs,
@@ -397,7 +438,11 @@ impl GasMeter for AptosGasMeter {
             cost += params.call_generic_per_local * num_locals;
         }
 
-        self.charge(cost)
+        self.charge(cost)?;
+        if let Some(profiler) = &mut self.profiler {
+            profiler.enter_function(module_id, func_name);
+        }
+        Ok(())
     }
 
     #[inline]
@@ -743,6 +788,10 @@ impl GasMeter for AptosGasMeter {
                 .abstract_heap_size(val, self.feature_version)
         }));
 
+        if let Some(profiler) = &mut self.profiler {
+            profiler.exit_function();
+        }
+
         Ok(())
     }
 }
@@ -750,21 +799,54 @@ impl GasMeter for AptosGasMeter {
 impl AptosGasMeter {
     pub fn charge_intrinsic_gas_for_transaction(&mut self, txn_size: NumBytes) -> VMResult<()> {
         let cost = self.gas_params.txn.calculate_intrinsic_gas(txn_size);
-        self.charge(cost).map_err(|e| e.finish(Location::Undefined))
+
+        if let Some(profiler) = &mut self.profiler {
+            profiler.record_intrinsic(cost);
+        }
+        self.deduct(cost).map_err(|e| e.finish(Location::Undefined))
     }
 
     pub fn charge_write_set_gas<'a>(
         &mut self,
         ops: impl IntoIterator<Item = (&'a StateKey, &'a WriteOp)>,
     ) -> VMResult<()> {
-        let cost = match self.feature_version {
+        let cost = match self.profiler.is_some() {
+            false => self.calculate_write_set_gas(ops),
+            true => {
+                // Both formulas are linear in the write ops, so the cost of the write set can be
+                // broken down op by op without changing the total.
+                let op_costs: Vec<_> = ops
+                    .into_iter()
+                    .map(|(key, op)| {
+                        let cost = self.calculate_write_set_gas(std::iter::once((key, op)));
+                        (key, op, cost)
+                    })
+                    .collect();
+
+                let mut cost = InternalGas::zero();
+                for (key, op, op_cost) in op_costs {
+                    if let Some(profiler) = &mut self.profiler {
+                        profiler.record_storage_write(key, op, op_cost);
+                    }
+                    cost += op_cost;
+                }
+                cost
+            }
+        };
+        self.deduct(cost).map_err(|e| e.finish(Location::Undefined))
+    }
+
+    fn calculate_write_set_gas<'a>(
+        &self,
+        ops: impl IntoIterator<Item = (&'a StateKey, &'a WriteOp)>,
+    ) -> InternalGas {
+        match self.feature_version {
             0 => self.gas_params.txn.calculate_write_set_gas(ops),
             _ => self
                 .storage_gas_params
                 .as_ref()
                 .unwrap()
                 .calculate_write_set_gas(ops, self.feature_version),
-        };
-        self.charge(cost).map_err(|e| e.finish(Location::Undefined))
+        }
     }
 }
@@ -0,0 +1,386 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements an optional gas profiler for the [`AptosGasMeter`](crate::AptosGasMeter).
+//!
+//! When enabled, the profiler follows the call frames of the Move VM and attributes every charge
+//! to the function, native function or storage operation it was made for. The result is a
+//! [`GasProfile`], which can be rendered either as a JSON breakdown or in the folded stacks format
+//! (one `frame_1;frame_2;...;frame_n gas` line per call stack) understood by flamegraph tools such
+//! as `flamegraph.pl` or `inferno-flamegraph`.
+
+use aptos_types::{access_path::Path, state_store::state_key::StateKey, write_set::WriteOp};
+use move_core_types::{gas_algebra::InternalGas, language_storage::ModuleId};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Name of the frame the intrinsic gas is attributed to in the folded stacks.
+const INTRINSIC_FRAME: &str = "intrinsic";
+/// Name of the frame the storage writes are attributed to in the folded stacks.
+const STORAGE_WRITES_FRAME: &str = "storage_writes";
+/// Name of the frame resource loads are attributed to in the folded stacks, under the function
+/// that triggered them.
+const LOAD_RESOURCE_FRAME: &str = "load_resource";
+
+/// The gas charged for one item (a function, a native function or a storage operation), along
+/// with the number of times it was charged.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasProfileEntry {
+    pub name: String,
+    pub count: u64,
+    /// Amount of gas, in internal gas units.
+    pub gas: u64,
+}
+
+/// A breakdown of the gas charged for a transaction.
+///
+/// All amounts are in internal gas units, which can be converted into gas units by dividing them
+/// by `gas_unit_scaling_factor`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasProfile {
+    pub gas_unit_scaling_factor: u64,
+    /// Gas charged for the size of the transaction.
+    pub intrinsic: u64,
+    /// Gas charged for executing the instructions of each function, excluding the functions it
+    /// calls. Sorted by gas, highest first.
+    pub functions: Vec<GasProfileEntry>,
+    /// Gas charged by each native function. Sorted by gas, highest first.
+    pub natives: Vec<GasProfileEntry>,
+    /// Gas charged for loading resources from storage.
+    pub storage_reads: GasProfileEntry,
+    /// Gas charged for each item written to storage. Sorted by gas, highest first.
+    pub storage_writes: Vec<GasProfileEntry>,
+    /// The gas charged to each call stack, in the folded stacks format.
+    pub folded_stacks: Vec<String>,
+}
+
+impl GasProfile {
+    /// Returns the total amount of gas charged, in internal gas units.
+    pub fn total(&self) -> u64 {
+        self.intrinsic
+            + self.storage_reads.gas
+            + self
+                .functions
+                .iter()
+                .chain(&self.natives)
+                .chain(&self.storage_writes)
+                .map(|entry| entry.gas)
+                .sum::<u64>()
+    }
+
+    /// Renders the call stacks in the folded stacks format, ready to be fed to a flamegraph tool.
+    pub fn to_folded_stacks(&self) -> String {
+        let mut folded = self.folded_stacks.join("\n");
+        folded.push('\n');
+        folded
+    }
+}
+
+#[derive(Default)]
+struct Usage {
+    count: u64,
+    gas: u64,
+}
+
+impl Usage {
+    fn add(&mut self, count: u64, gas: InternalGas) {
+        self.count += count;
+        self.gas += u64::from(gas);
+    }
+}
+
+struct Frame {
+    name: String,
+    /// The names of this frame and all of its callers, separated by `;`.
+    stack: String,
+    is_native: bool,
+    /// Whether an execution of this frame has already been counted.
+    is_counted: bool,
+}
+
+/// Records the gas charged by an [`AptosGasMeter`](crate::AptosGasMeter), following the call
+/// frames of the Move VM.
+///
+/// Frames are pushed when a call is charged and popped when they are dropped, or right after the
+/// gas of a native function has been charged. The frame of the entry point is never popped.
+pub(crate) struct GasProfiler {
+    frames: Vec<Frame>,
+    intrinsic: InternalGas,
+    functions: BTreeMap<String, Usage>,
+    natives: BTreeMap<String, Usage>,
+    storage_reads: Usage,
+    storage_writes: BTreeMap<String, Usage>,
+    stacks: BTreeMap<String, u64>,
+}
+
+impl GasProfiler {
+    pub(crate) fn new(entry_point: String) -> Self {
+        Self {
+            frames: vec![Frame {
+                stack: entry_point.clone(),
+                name: entry_point,
+                is_native: false,
+                is_counted: false,
+            }],
+            intrinsic: 0.into(),
+            functions: BTreeMap::new(),
+            natives: BTreeMap::new(),
+            storage_reads: Usage::default(),
+            storage_writes: BTreeMap::new(),
+            stacks: BTreeMap::new(),
+        }
+    }
+
+    fn current_frame(&self) -> &Frame {
+        self.frames
+            .last()
+            .expect("The frame of the entry point should never be popped")
+    }
+
+    fn add_to_stack(&mut self, stack: String, amount: InternalGas) {
+        *self.stacks.entry(stack).or_default() += u64::from(amount);
+    }
+
+    pub(crate) fn enter_function(&mut self, module_id: &ModuleId, func_name: &str) {
+        let name = format!(
+            "{}::{}::{}",
+            module_id.address().to_hex_literal(),
+            module_id.name(),
+            func_name
+        );
+        let stack = format!("{};{}", self.current_frame().stack, name);
+        self.frames.push(Frame {
+            name,
+            stack,
+            is_native: false,
+            is_counted: false,
+        });
+    }
+
+    /// Marks the function that has just been entered as native, so that its gas gets attributed
+    /// to the natives once charged.
+    pub(crate) fn enter_native_function(&mut self) {
+        if self.frames.len() > 1 {
+            if let Some(frame) = self.frames.last_mut() {
+                frame.is_native = true;
+            }
+        }
+    }
+
+    pub(crate) fn exit_function(&mut self) {
+        if self.frames.len() > 1 {
+            self.frames.pop();
+        }
+    }
+
+    pub(crate) fn record_execution(&mut self, amount: InternalGas) {
+        let frame = self
+            .frames
+            .last_mut()
+            .expect("The frame of the entry point should never be popped");
+        let count = if frame.is_counted { 0 } else { 1 };
+        frame.is_counted = true;
+
+        let (name, stack) = (frame.name.clone(), frame.stack.clone());
+        self.functions.entry(name).or_default().add(count, amount);
+        self.add_to_stack(stack, amount);
+    }
+
+    pub(crate) fn record_native(&mut self, amount: InternalGas) {
+        if !self.current_frame().is_native {
+            return self.record_execution(amount);
+        }
+
+        let frame = self
+            .frames
+            .pop()
+            .expect("The frame of the entry point should never be popped");
+        self.natives.entry(frame.name).or_default().add(1, amount);
+        self.add_to_stack(frame.stack, amount);
+    }
+
+    pub(crate) fn record_storage_read(&mut self, amount: InternalGas) {
+        self.storage_reads.add(1, amount);
+
+        let stack = format!("{};{}", self.current_frame().stack, LOAD_RESOURCE_FRAME);
+        self.add_to_stack(stack, amount);
+    }
+
+    pub(crate) fn record_intrinsic(&mut self, amount: InternalGas) {
+        self.intrinsic += amount;
+    }
+
+    pub(crate) fn record_storage_write(
+        &mut self,
+        key: &StateKey,
+        op: &WriteOp,
+        amount: InternalGas,
+    ) {
+        let name = describe_write_op(key, op);
+        self.add_to_stack(format!("{};{}", STORAGE_WRITES_FRAME, name), amount);
+        self.storage_writes.entry(name).or_default().add(1, amount);
+    }
+
+    pub(crate) fn to_profile(&self, gas_unit_scaling_factor: u64) -> GasProfile {
+        let intrinsic = u64::from(self.intrinsic);
+
+        let mut folded_stacks = vec![format!("{} {}", INTRINSIC_FRAME, intrinsic)];
+        folded_stacks.extend(
+            self.stacks
+                .iter()
+                .map(|(stack, gas)| format!("{} {}", stack, gas)),
+        );
+
+        GasProfile {
+            gas_unit_scaling_factor,
+            intrinsic,
+            functions: to_entries(&self.functions),
+            natives: to_entries(&self.natives),
+            storage_reads: GasProfileEntry {
+                name: LOAD_RESOURCE_FRAME.to_string(),
+                count: self.storage_reads.count,
+                gas: self.storage_reads.gas,
+            },
+            storage_writes: to_entries(&self.storage_writes),
+            folded_stacks,
+        }
+    }
+}
+
+fn to_entries(usages: &BTreeMap<String, Usage>) -> Vec<GasProfileEntry> {
+    let mut entries: Vec<_> = usages
+        .iter()
+        .map(|(name, usage)| GasProfileEntry {
+            name: name.clone(),
+            count: usage.count,
+            gas: usage.gas,
+        })
+        .collect();
+    entries.sort_by(|a, b| b.gas.cmp(&a.gas).then_with(|| a.name.cmp(&b.name)));
+    entries
+}
+
+fn describe_write_op(key: &StateKey, op: &WriteOp) -> String {
+    let op = match op {
+        WriteOp::Creation(_) => "create",
+        WriteOp::Modification(_) => "modify",
+        WriteOp::Deletion => "delete",
+    };
+    let key = match key {
+        StateKey::AccessPath(access_path) => match access_path.get_path() {
+            Path::Resource(struct_tag) => format!(
+                "resource {} at {}",
+                struct_tag,
+                access_path.address.to_hex_literal()
+            ),
+            Path::Code(module_id) => format!(
+                "module {}::{}",
+                module_id.address().to_hex_literal(),
+                module_id.name()
+            ),
+        },
+        StateKey::TableItem { handle, .. } => {
+            format!("table item in {}", handle.0.to_hex_literal())
+        }
+        StateKey::Raw(_) => "raw state key".to_string(),
+    };
+    format!("{} {}", op, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+
+    fn module_id(name: &str) -> ModuleId {
+        ModuleId::new(AccountAddress::ONE, Identifier::new(name).unwrap())
+    }
+
+    #[test]
+    fn attributes_gas_to_call_frames() {
+        let mut profiler = GasProfiler::new("0x1::coin::transfer".to_string());
+        profiler.record_intrinsic(100.into());
+        profiler.record_execution(10.into());
+
+        // A Move function, which loads a resource.
+        profiler.enter_function(&module_id("coin"), "withdraw");
+        profiler.record_execution(20.into());
+        profiler.record_storage_read(7.into());
+
+        // A native function called from within it.
+        profiler.enter_function(&module_id("signer"), "borrow_address");
+        profiler.enter_native_function();
+        profiler.record_native(3.into());
+        profiler.record_execution(5.into());
+        profiler.exit_function();
+
+        profiler.record_execution(1.into());
+        // The entry point frame is never popped.
+        profiler.exit_function();
+        profiler.record_execution(2.into());
+
+        let profile = profiler.to_profile(10_000);
+        assert_eq!(profile.intrinsic, 100);
+        assert_eq!(
+            profile.functions,
+            vec![
+                GasProfileEntry {
+                    name: "0x1::coin::withdraw".to_string(),
+                    count: 1,
+                    gas: 25,
+                },
+                GasProfileEntry {
+                    name: "0x1::coin::transfer".to_string(),
+                    count: 1,
+                    gas: 13,
+                },
+            ]
+        );
+        assert_eq!(
+            profile.natives,
+            vec![GasProfileEntry {
+                name: "0x1::signer::borrow_address".to_string(),
+                count: 1,
+                gas: 3,
+            }]
+        );
+        assert_eq!(profile.storage_reads.count, 1);
+        assert_eq!(profile.storage_reads.gas, 7);
+        assert_eq!(profile.total(), 148);
+        assert_eq!(
+            profile.to_folded_stacks(),
+            "intrinsic 100\n\
+             0x1::coin::transfer 13\n\
+             0x1::coin::transfer;0x1::coin::withdraw 25\n\
+             0x1::coin::transfer;0x1::coin::withdraw;0x1::signer::borrow_address 3\n\
+             0x1::coin::transfer;0x1::coin::withdraw;load_resource 7\n"
+        );
+    }
+
+    #[test]
+    fn attributes_gas_to_storage_writes() {
+        let mut profiler = GasProfiler::new("script".to_string());
+        let key = StateKey::Raw(vec![1, 2, 3]);
+        profiler.record_storage_write(&key, &WriteOp::Modification(vec![]), 4.into());
+        profiler.record_storage_write(&key, &WriteOp::Modification(vec![]), 6.into());
+        profiler.record_storage_write(&key, &WriteOp::Deletion, 0.into());
+
+        let profile = profiler.to_profile(1);
+        assert_eq!(
+            profile.storage_writes,
+            vec![
+                GasProfileEntry {
+                    name: "modify raw state key".to_string(),
+                    count: 2,
+                    gas: 10,
+                },
+                GasProfileEntry {
+                    name: "delete raw state key".to_string(),
+                    count: 1,
+                    gas: 0,
+                },
+            ]
+        );
+        assert_eq!(profile.total(), 10);
+    }
+}
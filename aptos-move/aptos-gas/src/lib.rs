@@ -5,6 +5,7 @@
 //!
 //! More specifically, it
 //!   - Is home to the gas meter implementation
+//!   - Implements an optional gas profiler, attributing the gas charged to call frames
 //!   - Defines the gas parameters and formulae for instructions
 //!   - Defines the gas parameters for transactions
 //!   - Sets the initial values for all gas parameters, including the instruction, transaction
@@ -27,6 +28,7 @@ mod params;
 mod algebra;
 mod aptos_framework;
 mod gas_meter;
+mod gas_profiler;
 pub mod gen;
 mod instr;
 mod misc;
@@ -39,6 +41,7 @@ pub use gas_meter::{
     AptosGasMeter, AptosGasParameters, FromOnChainGasSchedule, InitialGasSchedule,
     NativeGasParameters, ToOnChainGasSchedule, LATEST_GAS_FEATURE_VERSION,
 };
+pub use gas_profiler::{GasProfile, GasProfileEntry};
 pub use instr::InstructionGasParameters;
 pub use misc::{AbstractValueSizeGasParameters, MiscGasParameters};
 pub use move_core_types::gas_algebra::{
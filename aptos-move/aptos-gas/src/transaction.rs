@@ -194,7 +194,7 @@ crate::params::define_gas_parameters!(
 impl TransactionGasParameters {
     // TODO(Gas): Right now we are relying on this to avoid div by zero errors when using the all-zero
     //            gas parameters. See if there's a better way we can handle this.
-    fn scaling_factor(&self) -> GasScalingFactor {
+    pub(crate) fn scaling_factor(&self) -> GasScalingFactor {
         match u64::from(self.gas_unit_scaling_factor) {
             0 => 1.into(),
             x => x.into(),
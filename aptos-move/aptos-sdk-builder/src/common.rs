@@ -103,13 +103,7 @@ pub(crate) fn mangle_type(type_tag: &TypeTag) -> String {
         Address => "address".into(),
         Vector(type_tag) => match type_tag.as_ref() {
             U8 => "u8vector".into(),
-            Vector(type_tag) => {
-                if type_tag.as_ref() == &U8 {
-                    "vecbytes".into()
-                } else {
-                    type_not_allowed(type_tag)
-                }
-            }
+            Vector(inner) if inner.as_ref() == &U8 => "vecbytes".into(),
             _ => format!("vec{}", mangle_type(type_tag)),
         },
         Struct(tag) => match tag {
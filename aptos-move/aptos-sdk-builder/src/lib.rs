@@ -5,7 +5,9 @@ use aptos_types::transaction::EntryABI;
 use std::{ffi::OsStr, fs, io::Read, path::Path};
 
 pub mod golang;
+pub mod python;
 pub mod rust;
+pub mod typescript;
 
 /// Internals shared between languages.
 mod common;
@@ -17,6 +17,8 @@ arg_enum! {
 enum Language {
     Rust,
     Go,
+    Python,
+    TypeScript,
 }
 }
 
@@ -43,6 +45,8 @@ struct Options {
     /// * In Java, this is expected to be a package name, e.g. "com.test" to create Java files in `com/test`.
     /// * In Go, this is expected to be of the format "go_module/path/go_package_name",
     /// and `aptos_types` is assumed to be in "go_module/path/aptos_types".
+    /// * In Python and TypeScript, this is the name of the directory of the generated module, e.g.
+    /// "aptos_framework", next to which `aptos_types` (resp. `aptosTypes`) is expected.
     #[structopt(long)]
     module_name: Option<String>,
 
@@ -85,6 +89,18 @@ fn main() {
                     )
                     .unwrap();
                 }
+                Language::Python => {
+                    aptos_sdk_builder::python::output(
+                        &mut out,
+                        options.serde_package_name.clone(),
+                        options.package_name.clone(),
+                        &abis,
+                    )
+                    .unwrap();
+                }
+                Language::TypeScript => {
+                    aptos_sdk_builder::typescript::output(&mut out, &abis).unwrap()
+                }
             }
             return;
         }
@@ -100,6 +116,13 @@ fn main() {
                     install_dir.clone(),
                     options.serde_package_name.clone(),
                 )),
+                Language::Python => Box::new(serdegen::python3::Installer::new(
+                    install_dir.clone(),
+                    options.serde_package_name.clone(),
+                )),
+                Language::TypeScript => {
+                    Box::new(serdegen::typescript::Installer::new(install_dir.clone()))
+                }
             };
 
         let content =
@@ -120,12 +143,20 @@ fn main() {
                 vec!["aptos-types"],
             ),
             Language::Go => ("aptostypes".to_string(), vec!["aptostypes"]),
+            Language::Python => ("aptos_types".to_string(), vec!["aptos_types"]),
+            Language::TypeScript => ("aptosTypes".to_string(), vec!["aptosTypes"]),
         };
 
         let config = serdegen::CodeGeneratorConfig::new(package_name)
             .with_encodings(vec![serdegen::Encoding::Bcs]);
 
         installer.install_module(&config, &registry).unwrap();
+
+        // The Serde and BCS runtimes are only published as packages for Rust and Go
+        if let Language::Python | Language::TypeScript = options.language {
+            installer.install_serde_runtime().unwrap();
+            installer.install_bcs_runtime().unwrap();
+        }
     }
 
     // Transaction builders
@@ -140,6 +171,14 @@ fn main() {
                 options.serde_package_name,
                 options.package_name,
             )),
+            Language::Python => Box::new(aptos_sdk_builder::python::Installer::new(
+                install_dir,
+                options.serde_package_name,
+                options.package_name,
+            )),
+            Language::TypeScript => {
+                Box::new(aptos_sdk_builder::typescript::Installer::new(install_dir))
+            }
         };
 
     if let Some(name) = options.module_name {
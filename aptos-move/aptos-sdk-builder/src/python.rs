@@ -0,0 +1,299 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common;
+use aptos_types::transaction::{ArgumentABI, EntryABI, EntryFunctionABI, TypeArgumentABI};
+use move_core_types::{
+    account_address::AccountAddress,
+    language_storage::{ModuleId, TypeTag},
+};
+use serde_generate::indent::{IndentConfig, IndentedWriter};
+
+use heck::SnakeCase;
+use move_core_types::language_storage::StructTag;
+use once_cell::sync::Lazy;
+use std::str::FromStr;
+use std::{
+    io::{Result, Write},
+    path::PathBuf,
+};
+
+/// Output transaction builders in Python for the given ABIs.
+///
+/// Only entry functions are supported, transaction scripts are skipped.
+pub fn output(
+    out: &mut dyn Write,
+    serde_package_name: Option<String>,
+    aptos_package_name: Option<String>,
+    abis: &[EntryABI],
+) -> Result<()> {
+    let mut emitter = PythonEmitter {
+        out: IndentedWriter::new(out, IndentConfig::Space(4)),
+        serde_package_name,
+        aptos_package_name,
+    };
+    let abis = abis
+        .iter()
+        .filter(|abi| abi.is_entry_fun_abi())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    emitter.output_preamble()?;
+    for abi in common::entry_function_abis(&abis) {
+        emitter.output_entry_function_encoder_function(&abi)?;
+    }
+    emitter.output_encoding_helpers(&abis)?;
+    Ok(())
+}
+
+/// Shared state for the Python code generator.
+struct PythonEmitter<T> {
+    /// Writer.
+    out: IndentedWriter<T>,
+    /// Package containing the Serde and BCS runtimes.
+    /// `None` to import them as top-level modules.
+    serde_package_name: Option<String>,
+    /// Package containing the `aptos_types` module.
+    /// `None` to import it as a top-level module.
+    aptos_package_name: Option<String>,
+}
+
+impl<T> PythonEmitter<T>
+where
+    T: Write,
+{
+    fn output_preamble(&mut self) -> Result<()> {
+        let (serde_types, bcs) = match &self.serde_package_name {
+            Some(package) => (
+                format!("from {} import serde_types as st", package),
+                format!("from {} import bcs", package),
+            ),
+            None => ("import serde_types as st".into(), "import bcs".into()),
+        };
+        let aptos_types = match &self.aptos_package_name {
+            Some(package) => format!("{}.aptos_types", package),
+            None => "aptos_types".into(),
+        };
+        writeln!(
+            self.out,
+            r#"# pyre-strict
+import typing
+{}
+{}
+from {} import (
+    AccountAddress,
+    EntryFunction,
+    Identifier,
+    ModuleId,
+    TransactionPayload,
+    TransactionPayload__EntryFunction,
+    TypeTag,
+)"#,
+            serde_types, bcs, aptos_types,
+        )
+    }
+
+    fn output_entry_function_encoder_function(&mut self, abi: &EntryFunctionABI) -> Result<()> {
+        writeln!(
+            self.out,
+            "\n\ndef encode_{}_{}({}) -> TransactionPayload:",
+            abi.module_name().name(),
+            abi.name(),
+            [
+                Self::quote_type_parameters(abi.ty_args()),
+                Self::quote_parameters(abi.args()),
+            ]
+            .concat()
+            .join(", ")
+        )?;
+        self.out.indent();
+        self.output_doc(abi.doc())?;
+        writeln!(
+            self.out,
+            r#"return TransactionPayload__EntryFunction(
+    value=EntryFunction(
+        module={},
+        function=Identifier("{}"),
+        ty_args=[{}],
+        args=[{}],
+    )
+)"#,
+            Self::quote_module_id(abi.module_name()),
+            abi.name(),
+            Self::quote_type_arguments(abi.ty_args()),
+            Self::quote_arguments(abi.args()),
+        )?;
+        self.out.unindent();
+        Ok(())
+    }
+
+    fn output_encoding_helpers(&mut self, abis: &[EntryABI]) -> Result<()> {
+        let required_types = common::get_required_helper_types(abis);
+        for required_type in required_types {
+            self.output_encoding_helper(required_type)?;
+        }
+        Ok(())
+    }
+
+    fn output_encoding_helper(&mut self, type_tag: &TypeTag) -> Result<()> {
+        writeln!(
+            self.out,
+            r#"
+
+def encode_{0}_argument(arg: {1}) -> bytes:
+    return bcs.serialize(arg, {1})"#,
+            common::mangle_type(type_tag),
+            Self::quote_type(type_tag),
+        )
+    }
+
+    fn output_doc(&mut self, doc: &str) -> Result<()> {
+        let doc = common::prepare_doc_string(doc)
+            .replace('\\', "\\\\")
+            .replace("\"\"\"", "\\\"\"\"");
+        if doc.is_empty() {
+            return Ok(());
+        }
+        if doc.contains('\n') {
+            writeln!(self.out, "\"\"\"{}\n\"\"\"", doc)
+        } else {
+            writeln!(self.out, "\"\"\"{}\"\"\"", doc)
+        }
+    }
+
+    fn quote_address(address: &AccountAddress) -> String {
+        format!(
+            "AccountAddress.bcs_deserialize(bytes.fromhex(\"{:x}\"))",
+            address
+        )
+    }
+
+    fn quote_module_id(module_id: &ModuleId) -> String {
+        format!(
+            "ModuleId(address={}, name=Identifier(\"{}\"))",
+            Self::quote_address(module_id.address()),
+            module_id.name(),
+        )
+    }
+
+    fn quote_type_parameters(ty_args: &[TypeArgumentABI]) -> Vec<String> {
+        ty_args
+            .iter()
+            .map(|ty_arg| format!("{}: TypeTag", Self::quote_name(ty_arg.name())))
+            .collect()
+    }
+
+    fn quote_parameters(args: &[ArgumentABI]) -> Vec<String> {
+        args.iter()
+            .map(|arg| {
+                format!(
+                    "{}: {}",
+                    Self::quote_name(arg.name()),
+                    Self::quote_type(arg.type_tag())
+                )
+            })
+            .collect()
+    }
+
+    fn quote_type_arguments(ty_args: &[TypeArgumentABI]) -> String {
+        ty_args
+            .iter()
+            .map(|ty_arg| Self::quote_name(ty_arg.name()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn quote_arguments(args: &[ArgumentABI]) -> String {
+        args.iter()
+            .map(|arg| {
+                format!(
+                    "encode_{}_argument({})",
+                    common::mangle_type(arg.type_tag()),
+                    Self::quote_name(arg.name())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn quote_type(type_tag: &TypeTag) -> String {
+        use TypeTag::*;
+        let str_tag: Lazy<StructTag> =
+            Lazy::new(|| StructTag::from_str("0x1::string::String").unwrap());
+        match type_tag {
+            Bool => "bool".into(),
+            U8 => "st.uint8".into(),
+            U64 => "st.uint64".into(),
+            U128 => "st.uint128".into(),
+            Address => "AccountAddress".into(),
+            Vector(type_tag) => match type_tag.as_ref() {
+                U8 => "bytes".into(),
+                type_tag => format!("typing.Sequence[{}]", Self::quote_type(type_tag)),
+            },
+            Struct(struct_tag) => match struct_tag {
+                tag if &**tag == Lazy::force(&str_tag) => "str".into(),
+                _ => common::type_not_allowed(type_tag),
+            },
+            Signer => common::type_not_allowed(type_tag),
+        }
+    }
+
+    /// Names of parameters in snake case, avoiding the keywords of Python and the names used by
+    /// the generated code.
+    fn quote_name(name: &str) -> String {
+        let name = name.to_snake_case();
+        if RESERVED_WORDS.contains(&name.as_str()) {
+            format!("{}_", name)
+        } else {
+            name
+        }
+    }
+}
+
+const RESERVED_WORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "bcs", "break", "bytes",
+    "class", "continue", "def", "del", "elif", "else", "except", "finally", "for", "from",
+    "global", "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise",
+    "return", "st", "try", "typing", "while", "with", "yield",
+];
+
+pub struct Installer {
+    install_dir: PathBuf,
+    serde_package_name: Option<String>,
+    aptos_package_name: Option<String>,
+}
+
+impl Installer {
+    pub fn new(
+        install_dir: PathBuf,
+        serde_package_name: Option<String>,
+        aptos_package_name: Option<String>,
+    ) -> Self {
+        Installer {
+            install_dir,
+            serde_package_name,
+            aptos_package_name,
+        }
+    }
+}
+
+impl crate::SourceInstaller for Installer {
+    type Error = Box<dyn std::error::Error>;
+
+    fn install_transaction_builders(
+        &self,
+        name: &str,
+        abis: &[EntryABI],
+    ) -> std::result::Result<(), Self::Error> {
+        let dir_path = self.install_dir.join(name);
+        std::fs::create_dir_all(&dir_path)?;
+        let mut file = std::fs::File::create(dir_path.join("__init__.py"))?;
+        output(
+            &mut file,
+            self.serde_package_name.clone(),
+            self.aptos_package_name.clone(),
+            abis,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common;
+use aptos_types::transaction::{ArgumentABI, EntryABI, EntryFunctionABI, TypeArgumentABI};
+use move_core_types::{
+    account_address::AccountAddress,
+    language_storage::{ModuleId, TypeTag},
+};
+use serde_generate::indent::{IndentConfig, IndentedWriter};
+
+use heck::{CamelCase, MixedCase};
+use move_core_types::language_storage::StructTag;
+use once_cell::sync::Lazy;
+use std::str::FromStr;
+use std::{
+    io::{Result, Write},
+    path::PathBuf,
+};
+
+/// Output transaction builders in TypeScript for the given ABIs.
+///
+/// Only entry functions are supported, transaction scripts are skipped. The generated module
+/// expects the Aptos types and the Serde and BCS runtimes to be installed next to it, in
+/// `aptosTypes`, `serde` and `bcs` respectively.
+pub fn output(out: &mut dyn Write, abis: &[EntryABI]) -> Result<()> {
+    let mut emitter = TypeScriptEmitter {
+        out: IndentedWriter::new(out, IndentConfig::Space(2)),
+    };
+    let abis = abis
+        .iter()
+        .filter(|abi| abi.is_entry_fun_abi())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    emitter.output_preamble()?;
+    for abi in common::entry_function_abis(&abis) {
+        emitter.output_entry_function_encoder_function(&abi)?;
+    }
+    emitter.output_encoding_helpers(&abis)?;
+    Ok(())
+}
+
+/// Shared state for the TypeScript code generator.
+struct TypeScriptEmitter<T> {
+    /// Writer.
+    out: IndentedWriter<T>,
+}
+
+impl<T> TypeScriptEmitter<T>
+where
+    T: Write,
+{
+    fn output_preamble(&mut self) -> Result<()> {
+        writeln!(
+            self.out,
+            r#"import {{ BcsSerializer }} from "../bcs/mod";
+import {{ Seq, bool, bytes, str, uint8, uint64, uint128 }} from "../serde/mod";
+import * as AptosTypes from "../aptosTypes/mod";"#
+        )
+    }
+
+    fn output_entry_function_encoder_function(&mut self, abi: &EntryFunctionABI) -> Result<()> {
+        writeln!(self.out)?;
+        self.output_doc(abi.doc())?;
+        writeln!(
+            self.out,
+            "export function encode{}{}({}): AptosTypes.TransactionPayload {{",
+            abi.module_name().name().to_string().to_camel_case(),
+            abi.name().to_camel_case(),
+            [
+                Self::quote_type_parameters(abi.ty_args()),
+                Self::quote_parameters(abi.args()),
+            ]
+            .concat()
+            .join(", ")
+        )?;
+        self.out.indent();
+        writeln!(
+            self.out,
+            r#"return new AptosTypes.TransactionPayloadVariantEntryFunction(
+  new AptosTypes.EntryFunction(
+    {},
+    new AptosTypes.Identifier({}),
+    [{}],
+    [{}]
+  )
+);"#,
+            Self::quote_module_id(abi.module_name()),
+            Self::quote_identifier(abi.name()),
+            Self::quote_type_arguments(abi.ty_args()),
+            Self::quote_arguments(abi.args()),
+        )?;
+        self.out.unindent();
+        writeln!(self.out, "}}")
+    }
+
+    fn output_encoding_helpers(&mut self, abis: &[EntryABI]) -> Result<()> {
+        let required_types = common::get_required_helper_types(abis);
+        for required_type in required_types {
+            self.output_encoding_helper(required_type)?;
+        }
+        Ok(())
+    }
+
+    fn output_encoding_helper(&mut self, type_tag: &TypeTag) -> Result<()> {
+        writeln!(
+            self.out,
+            "\nfunction encode{}Argument(arg: {}): bytes {{",
+            common::mangle_type(type_tag).to_camel_case(),
+            Self::quote_type(type_tag),
+        )?;
+        self.out.indent();
+        writeln!(self.out, "const serializer = new BcsSerializer();")?;
+        self.output_serialization(type_tag, "arg", 0)?;
+        writeln!(self.out, "return serializer.getBytes();")?;
+        self.out.unindent();
+        writeln!(self.out, "}}")
+    }
+
+    /// Serializes `value`, naming the items of nested vectors after their `depth` so that inner
+    /// loops don't shadow the outer ones.
+    fn output_serialization(
+        &mut self,
+        type_tag: &TypeTag,
+        value: &str,
+        depth: usize,
+    ) -> Result<()> {
+        use TypeTag::*;
+        match type_tag {
+            Bool => writeln!(self.out, "serializer.serializeBool({});", value),
+            U8 => writeln!(self.out, "serializer.serializeU8({});", value),
+            U64 => writeln!(self.out, "serializer.serializeU64({});", value),
+            U128 => writeln!(self.out, "serializer.serializeU128({});", value),
+            Address => writeln!(self.out, "{}.serialize(serializer);", value),
+            Vector(type_tag) => match type_tag.as_ref() {
+                U8 => writeln!(self.out, "serializer.serializeBytes({});", value),
+                _ => {
+                    writeln!(self.out, "serializer.serializeLen({}.length);", value)?;
+                    let item = format!("item{}", depth);
+                    writeln!(self.out, "for (const {} of {}) {{", item, value)?;
+                    self.out.indent();
+                    self.output_serialization(type_tag, &item, depth + 1)?;
+                    self.out.unindent();
+                    writeln!(self.out, "}}")
+                }
+            },
+            // Only strings are allowed, as checked by `quote_type`
+            Struct(_) => writeln!(self.out, "serializer.serializeStr({});", value),
+            Signer => common::type_not_allowed(type_tag),
+        }
+    }
+
+    fn output_doc(&mut self, doc: &str) -> Result<()> {
+        let doc = common::prepare_doc_string(doc);
+        if doc.is_empty() {
+            return Ok(());
+        }
+        writeln!(self.out, "/**")?;
+        for line in doc.replace("*/", "* /").lines() {
+            if line.is_empty() {
+                writeln!(self.out, " *")?;
+            } else {
+                writeln!(self.out, " * {}", line)?;
+            }
+        }
+        writeln!(self.out, " */")
+    }
+
+    fn quote_identifier(ident: &str) -> String {
+        format!("\"{}\"", ident)
+    }
+
+    fn quote_address(address: &AccountAddress) -> String {
+        format!(
+            "new AptosTypes.AccountAddress([{}])",
+            address
+                .to_vec()
+                .iter()
+                .map(|x| format!("[{}]", x))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn quote_module_id(module_id: &ModuleId) -> String {
+        format!(
+            "new AptosTypes.ModuleId({}, new AptosTypes.Identifier({}))",
+            Self::quote_address(module_id.address()),
+            Self::quote_identifier(module_id.name().as_str()),
+        )
+    }
+
+    fn quote_type_parameters(ty_args: &[TypeArgumentABI]) -> Vec<String> {
+        ty_args
+            .iter()
+            .map(|ty_arg| format!("{}: AptosTypes.TypeTag", Self::quote_name(ty_arg.name())))
+            .collect()
+    }
+
+    fn quote_parameters(args: &[ArgumentABI]) -> Vec<String> {
+        args.iter()
+            .map(|arg| {
+                format!(
+                    "{}: {}",
+                    Self::quote_name(arg.name()),
+                    Self::quote_type(arg.type_tag())
+                )
+            })
+            .collect()
+    }
+
+    fn quote_type_arguments(ty_args: &[TypeArgumentABI]) -> String {
+        ty_args
+            .iter()
+            .map(|ty_arg| Self::quote_name(ty_arg.name()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn quote_arguments(args: &[ArgumentABI]) -> String {
+        args.iter()
+            .map(|arg| {
+                format!(
+                    "encode{}Argument({})",
+                    common::mangle_type(arg.type_tag()).to_camel_case(),
+                    Self::quote_name(arg.name())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn quote_type(type_tag: &TypeTag) -> String {
+        use TypeTag::*;
+        let str_tag: Lazy<StructTag> =
+            Lazy::new(|| StructTag::from_str("0x1::string::String").unwrap());
+        match type_tag {
+            Bool => "bool".into(),
+            U8 => "uint8".into(),
+            U64 => "uint64".into(),
+            U128 => "uint128".into(),
+            Address => "AptosTypes.AccountAddress".into(),
+            Vector(type_tag) => match type_tag.as_ref() {
+                U8 => "bytes".into(),
+                type_tag => format!("Seq<{}>", Self::quote_type(type_tag)),
+            },
+            Struct(struct_tag) => match struct_tag {
+                tag if &**tag == Lazy::force(&str_tag) => "str".into(),
+                _ => common::type_not_allowed(type_tag),
+            },
+            Signer => common::type_not_allowed(type_tag),
+        }
+    }
+
+    /// Names of parameters in camel case, avoiding the reserved words of TypeScript.
+    fn quote_name(name: &str) -> String {
+        let name = name.to_mixed_case();
+        if RESERVED_WORDS.contains(&name.as_str()) {
+            format!("{}_", name)
+        } else {
+            name
+        }
+    }
+}
+
+const RESERVED_WORDS: &[&str] = &[
+    "arguments",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+pub struct Installer {
+    install_dir: PathBuf,
+}
+
+impl Installer {
+    pub fn new(install_dir: PathBuf) -> Self {
+        Installer { install_dir }
+    }
+}
+
+impl crate::SourceInstaller for Installer {
+    type Error = Box<dyn std::error::Error>;
+
+    fn install_transaction_builders(
+        &self,
+        name: &str,
+        abis: &[EntryABI],
+    ) -> std::result::Result<(), Self::Error> {
+        let dir_path = self.install_dir.join(name);
+        std::fs::create_dir_all(&dir_path)?;
+        let mut file = std::fs::File::create(dir_path.join("mod.ts"))?;
+        output(&mut file, abis)?;
+        Ok(())
+    }
+}
@@ -2,11 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use aptos_sdk_builder as buildgen;
-use aptos_types::transaction::EntryABI;
+use aptos_types::transaction::{
+    ArgumentABI, EntryABI, EntryFunction, EntryFunctionABI, TransactionPayload, TypeArgumentABI,
+};
+use goldenfile::Mint;
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag, TypeTag},
+};
 use serde_generate as serdegen;
 use serde_generate::SourceInstaller as _;
 use serde_reflection::Registry;
-use std::{io::Write, process::Command};
+use std::{io::Write, process::Command, str::FromStr};
 use tempfile::tempdir;
 
 fn get_aptos_registry() -> Registry {
@@ -92,3 +100,252 @@ fn test_that_rust_entry_fun_code_compiles() {
         EXPECTED_SCRIPT_FUN_OUTPUT,
     );
 }
+
+fn get_test_abis() -> Vec<EntryABI> {
+    let string = TypeTag::Struct(Box::new(
+        StructTag::from_str("0x1::string::String").unwrap(),
+    ));
+    vec![
+        EntryABI::EntryFunction(EntryFunctionABI::new(
+            "transfer".to_string(),
+            ModuleId::from_str("0x1::coin").unwrap(),
+            " Transfers `amount` of coins `CoinType` from `from` to `to`.".to_string(),
+            vec![TypeArgumentABI::new("CoinType".to_string())],
+            vec![
+                ArgumentABI::new("to".to_string(), TypeTag::Address),
+                ArgumentABI::new("amount".to_string(), TypeTag::U64),
+            ],
+        )),
+        EntryABI::EntryFunction(EntryFunctionABI::new(
+            "publish_package_txn".to_string(),
+            ModuleId::from_str("0x1::code").unwrap(),
+            " Same as `publish_package` but as an entry function.\n\n The metadata needs to be passed in serialized form.".to_string(),
+            vec![],
+            vec![
+                ArgumentABI::new(
+                    "metadata_serialized".to_string(),
+                    TypeTag::Vector(Box::new(TypeTag::U8)),
+                ),
+                ArgumentABI::new(
+                    "code".to_string(),
+                    TypeTag::Vector(Box::new(TypeTag::Vector(Box::new(TypeTag::U8)))),
+                ),
+            ],
+        )),
+        EntryABI::EntryFunction(EntryFunctionABI::new(
+            "call".to_string(),
+            ModuleId::from_str("0xcafe::all_types").unwrap(),
+            "".to_string(),
+            vec![],
+            vec![
+                ArgumentABI::new("flag".to_string(), TypeTag::Bool),
+                ArgumentABI::new("small".to_string(), TypeTag::U8),
+                ArgumentABI::new("large".to_string(), TypeTag::U128),
+                ArgumentABI::new("name".to_string(), string.clone()),
+                ArgumentABI::new(
+                    "names".to_string(),
+                    TypeTag::Vector(Box::new(string)),
+                ),
+                ArgumentABI::new(
+                    "addresses".to_string(),
+                    TypeTag::Vector(Box::new(TypeTag::Address)),
+                ),
+                ArgumentABI::new(
+                    "matrix".to_string(),
+                    TypeTag::Vector(Box::new(TypeTag::Vector(Box::new(TypeTag::U64)))),
+                ),
+                ArgumentABI::new("from".to_string(), TypeTag::U64),
+                ArgumentABI::new("function".to_string(), TypeTag::U64),
+            ],
+        )),
+    ]
+}
+
+#[test]
+fn test_python_golden_output() {
+    let mut mint = Mint::new("tests/goldens");
+    let mut file = mint.new_goldenfile("entry_functions.py").unwrap();
+    buildgen::python::output(&mut file, None, None, &get_test_abis()).unwrap();
+}
+
+#[test]
+fn test_typescript_golden_output() {
+    let mut mint = Mint::new("tests/goldens");
+    let mut file = mint.new_goldenfile("entry_functions.ts").unwrap();
+    buildgen::typescript::output(&mut file, &get_test_abis()).unwrap();
+}
+
+/// Installs the Aptos types, the Serde and BCS runtimes, and the transaction builders of the test
+/// ABIs in `aptos_framework` (resp. `aptosFramework`), as the CLI does.
+fn install_test_builders(
+    registry_installer: &dyn serdegen::SourceInstaller<Error = Box<dyn std::error::Error>>,
+    builders_installer: &dyn buildgen::SourceInstaller<Error = Box<dyn std::error::Error>>,
+    aptos_types: &str,
+    name: &str,
+) {
+    let config = serdegen::CodeGeneratorConfig::new(aptos_types.to_string())
+        .with_encodings(vec![serdegen::Encoding::Bcs]);
+    registry_installer
+        .install_module(&config, &get_aptos_registry())
+        .unwrap();
+    registry_installer.install_serde_runtime().unwrap();
+    registry_installer.install_bcs_runtime().unwrap();
+    builders_installer
+        .install_transaction_builders(name, &get_test_abis())
+        .unwrap();
+}
+
+/// BCS of the payload built by the Python and TypeScript demos, in hex.
+fn expected_all_types_call_payload() -> String {
+    let matrix: &[&[u64]] = &[&[1], &[2, 3]];
+    let args = vec![
+        bcs::to_bytes(&true).unwrap(),
+        bcs::to_bytes(&1u8).unwrap(),
+        bcs::to_bytes(&2u128).unwrap(),
+        bcs::to_bytes("aptos").unwrap(),
+        bcs::to_bytes(&["a", "b"][..]).unwrap(),
+        bcs::to_bytes(&[AccountAddress::ONE][..]).unwrap(),
+        bcs::to_bytes(&matrix).unwrap(),
+        bcs::to_bytes(&4u64).unwrap(),
+        bcs::to_bytes(&5u64).unwrap(),
+    ];
+    let payload = TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::from_str("0xcafe::all_types").unwrap(),
+        Identifier::new("call").unwrap(),
+        vec![],
+        args,
+    ));
+    bcs::to_bytes(&payload)
+        .unwrap()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+const PYTHON_DEMO: &str = r#"
+import aptos_framework
+from aptos_types import AccountAddress
+
+payload = aptos_framework.encode_all_types_call(
+    flag=True,
+    small=1,
+    large=2,
+    name="aptos",
+    names=["a", "b"],
+    addresses=[AccountAddress.bcs_deserialize(bytes(31) + bytes([1]))],
+    matrix=[[1], [2, 3]],
+    from_=4,
+    function=5,
+)
+print(payload.bcs_serialize().hex())
+"#;
+
+#[test]
+// Ignored because the Serde runtime of Python requires `python3` with `numpy` installed.
+#[ignore]
+fn test_that_python_code_runs() {
+    let dir = tempdir().unwrap();
+    install_test_builders(
+        &serdegen::python3::Installer::new(dir.path().to_path_buf(), None),
+        &buildgen::python::Installer::new(dir.path().to_path_buf(), None, None),
+        "aptos_types",
+        "aptos_framework",
+    );
+    std::fs::write(dir.path().join("demo.py"), PYTHON_DEMO).unwrap();
+
+    let output = Command::new("python3")
+        .current_dir(dir.path())
+        .arg("demo.py")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        std::str::from_utf8(&output.stdout).unwrap().trim(),
+        expected_all_types_call_payload()
+    );
+}
+
+const TYPESCRIPT_DEMO: &str = r#"
+import { BcsDeserializer, BcsSerializer } from "./bcs/mod";
+import * as AptosTypes from "./aptosTypes/mod";
+import { encodeAllTypesCall } from "./aptosFramework/mod";
+
+const address = AptosTypes.AccountAddress.deserialize(
+  new BcsDeserializer(new Uint8Array([...new Array(31).fill(0), 1]))
+);
+const payload = encodeAllTypesCall(
+  true,
+  1,
+  BigInt(2),
+  "aptos",
+  ["a", "b"],
+  [address],
+  [[BigInt(1)], [BigInt(2), BigInt(3)]],
+  BigInt(4),
+  BigInt(5)
+);
+const serializer = new BcsSerializer();
+payload.serialize(serializer);
+console.log(
+  Array.from(serializer.getBytes())
+    .map((byte) => byte.toString(16).padStart(2, "0"))
+    .join("")
+);
+"#;
+
+#[test]
+// Ignored because it requires `tsc` (5.7 or later, to rewrite the `.ts` imports of the Serde
+// runtime) and `node`.
+#[ignore]
+fn test_that_typescript_code_runs() {
+    let dir = tempdir().unwrap();
+    install_test_builders(
+        &serdegen::typescript::Installer::new(dir.path().to_path_buf()),
+        &buildgen::typescript::Installer::new(dir.path().to_path_buf()),
+        "aptosTypes",
+        "aptosFramework",
+    );
+    std::fs::write(dir.path().join("demo.ts"), TYPESCRIPT_DEMO).unwrap();
+
+    // The Serde runtime and the Aptos types import their modules with a `.ts` extension.
+    let output = Command::new("tsc")
+        .current_dir(dir.path())
+        .args([
+            "--target",
+            "es2020",
+            "--module",
+            "commonjs",
+            "--moduleResolution",
+            "node",
+            "--rewriteRelativeImportExtensions",
+            "--outDir",
+            "out",
+            "demo.ts",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+
+    let output = Command::new("node")
+        .current_dir(dir.path())
+        .arg("out/demo.js")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        std::str::from_utf8(&output.stdout).unwrap().trim(),
+        expected_all_types_call_payload()
+    );
+}
@@ -0,0 +1,95 @@
+# pyre-strict
+import typing
+import serde_types as st
+import bcs
+from aptos_types import (
+    AccountAddress,
+    EntryFunction,
+    Identifier,
+    ModuleId,
+    TransactionPayload,
+    TransactionPayload__EntryFunction,
+    TypeTag,
+)
+
+
+def encode_coin_transfer(coin_type: TypeTag, to: AccountAddress, amount: st.uint64) -> TransactionPayload:
+    """Transfers `amount` of coins `CoinType` from `from` to `to`."""
+    return TransactionPayload__EntryFunction(
+        value=EntryFunction(
+            module=ModuleId(address=AccountAddress.bcs_deserialize(bytes.fromhex("0000000000000000000000000000000000000000000000000000000000000001")), name=Identifier("coin")),
+            function=Identifier("transfer"),
+            ty_args=[coin_type],
+            args=[encode_address_argument(to), encode_u64_argument(amount)],
+        )
+    )
+
+
+def encode_code_publish_package_txn(metadata_serialized: bytes, code: typing.Sequence[bytes]) -> TransactionPayload:
+    """Same as `publish_package` but as an entry function.
+
+    The metadata needs to be passed in serialized form.
+    """
+    return TransactionPayload__EntryFunction(
+        value=EntryFunction(
+            module=ModuleId(address=AccountAddress.bcs_deserialize(bytes.fromhex("0000000000000000000000000000000000000000000000000000000000000001")), name=Identifier("code")),
+            function=Identifier("publish_package_txn"),
+            ty_args=[],
+            args=[encode_u8vector_argument(metadata_serialized), encode_vecbytes_argument(code)],
+        )
+    )
+
+
+def encode_all_types_call(flag: bool, small: st.uint8, large: st.uint128, name: str, names: typing.Sequence[str], addresses: typing.Sequence[AccountAddress], matrix: typing.Sequence[typing.Sequence[st.uint64]], from_: st.uint64, function: st.uint64) -> TransactionPayload:
+    return TransactionPayload__EntryFunction(
+        value=EntryFunction(
+            module=ModuleId(address=AccountAddress.bcs_deserialize(bytes.fromhex("000000000000000000000000000000000000000000000000000000000000cafe")), name=Identifier("all_types")),
+            function=Identifier("call"),
+            ty_args=[],
+            args=[encode_bool_argument(flag), encode_u8_argument(small), encode_u128_argument(large), encode_string_argument(name), encode_vecstring_argument(names), encode_vecaddress_argument(addresses), encode_vecvecu64_argument(matrix), encode_u64_argument(from_), encode_u64_argument(function)],
+        )
+    )
+
+
+def encode_bool_argument(arg: bool) -> bytes:
+    return bcs.serialize(arg, bool)
+
+
+def encode_u8_argument(arg: st.uint8) -> bytes:
+    return bcs.serialize(arg, st.uint8)
+
+
+def encode_u64_argument(arg: st.uint64) -> bytes:
+    return bcs.serialize(arg, st.uint64)
+
+
+def encode_u128_argument(arg: st.uint128) -> bytes:
+    return bcs.serialize(arg, st.uint128)
+
+
+def encode_address_argument(arg: AccountAddress) -> bytes:
+    return bcs.serialize(arg, AccountAddress)
+
+
+def encode_u8vector_argument(arg: bytes) -> bytes:
+    return bcs.serialize(arg, bytes)
+
+
+def encode_vecaddress_argument(arg: typing.Sequence[AccountAddress]) -> bytes:
+    return bcs.serialize(arg, typing.Sequence[AccountAddress])
+
+
+def encode_vecbytes_argument(arg: typing.Sequence[bytes]) -> bytes:
+    return bcs.serialize(arg, typing.Sequence[bytes])
+
+
+def encode_vecvecu64_argument(arg: typing.Sequence[typing.Sequence[st.uint64]]) -> bytes:
+    return bcs.serialize(arg, typing.Sequence[typing.Sequence[st.uint64]])
+
+
+def encode_vecstring_argument(arg: typing.Sequence[str]) -> bytes:
+    return bcs.serialize(arg, typing.Sequence[str])
+
+
+def encode_string_argument(arg: str) -> bytes:
+    return bcs.serialize(arg, str)
@@ -0,0 +1,125 @@
+import { BcsSerializer } from "../bcs/mod";
+import { Seq, bool, bytes, str, uint8, uint64, uint128 } from "../serde/mod";
+import * as AptosTypes from "../aptosTypes/mod";
+
+/**
+ * Transfers `amount` of coins `CoinType` from `from` to `to`.
+ */
+export function encodeCoinTransfer(coinType: AptosTypes.TypeTag, to: AptosTypes.AccountAddress, amount: uint64): AptosTypes.TransactionPayload {
+  return new AptosTypes.TransactionPayloadVariantEntryFunction(
+    new AptosTypes.EntryFunction(
+      new AptosTypes.ModuleId(new AptosTypes.AccountAddress([[0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [1]]), new AptosTypes.Identifier("coin")),
+      new AptosTypes.Identifier("transfer"),
+      [coinType],
+      [encodeAddressArgument(to), encodeU64Argument(amount)]
+    )
+  );
+}
+
+/**
+ * Same as `publish_package` but as an entry function.
+ *
+ * The metadata needs to be passed in serialized form.
+ */
+export function encodeCodePublishPackageTxn(metadataSerialized: bytes, code: Seq<bytes>): AptosTypes.TransactionPayload {
+  return new AptosTypes.TransactionPayloadVariantEntryFunction(
+    new AptosTypes.EntryFunction(
+      new AptosTypes.ModuleId(new AptosTypes.AccountAddress([[0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [1]]), new AptosTypes.Identifier("code")),
+      new AptosTypes.Identifier("publish_package_txn"),
+      [],
+      [encodeU8vectorArgument(metadataSerialized), encodeVecbytesArgument(code)]
+    )
+  );
+}
+
+export function encodeAllTypesCall(flag: bool, small: uint8, large: uint128, name: str, names: Seq<str>, addresses: Seq<AptosTypes.AccountAddress>, matrix: Seq<Seq<uint64>>, from: uint64, function_: uint64): AptosTypes.TransactionPayload {
+  return new AptosTypes.TransactionPayloadVariantEntryFunction(
+    new AptosTypes.EntryFunction(
+      new AptosTypes.ModuleId(new AptosTypes.AccountAddress([[0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [0], [202], [254]]), new AptosTypes.Identifier("all_types")),
+      new AptosTypes.Identifier("call"),
+      [],
+      [encodeBoolArgument(flag), encodeU8Argument(small), encodeU128Argument(large), encodeStringArgument(name), encodeVecstringArgument(names), encodeVecaddressArgument(addresses), encodeVecvecu64Argument(matrix), encodeU64Argument(from), encodeU64Argument(function_)]
+    )
+  );
+}
+
+function encodeBoolArgument(arg: bool): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeBool(arg);
+  return serializer.getBytes();
+}
+
+function encodeU8Argument(arg: uint8): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeU8(arg);
+  return serializer.getBytes();
+}
+
+function encodeU64Argument(arg: uint64): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeU64(arg);
+  return serializer.getBytes();
+}
+
+function encodeU128Argument(arg: uint128): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeU128(arg);
+  return serializer.getBytes();
+}
+
+function encodeAddressArgument(arg: AptosTypes.AccountAddress): bytes {
+  const serializer = new BcsSerializer();
+  arg.serialize(serializer);
+  return serializer.getBytes();
+}
+
+function encodeU8vectorArgument(arg: bytes): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeBytes(arg);
+  return serializer.getBytes();
+}
+
+function encodeVecaddressArgument(arg: Seq<AptosTypes.AccountAddress>): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeLen(arg.length);
+  for (const item0 of arg) {
+    item0.serialize(serializer);
+  }
+  return serializer.getBytes();
+}
+
+function encodeVecbytesArgument(arg: Seq<bytes>): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeLen(arg.length);
+  for (const item0 of arg) {
+    serializer.serializeBytes(item0);
+  }
+  return serializer.getBytes();
+}
+
+function encodeVecvecu64Argument(arg: Seq<Seq<uint64>>): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeLen(arg.length);
+  for (const item0 of arg) {
+    serializer.serializeLen(item0.length);
+    for (const item1 of item0) {
+      serializer.serializeU64(item1);
+    }
+  }
+  return serializer.getBytes();
+}
+
+function encodeVecstringArgument(arg: Seq<str>): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeLen(arg.length);
+  for (const item0 of arg) {
+    serializer.serializeStr(item0);
+  }
+  return serializer.getBytes();
+}
+
+function encodeStringArgument(arg: str): bytes {
+  const serializer = new BcsSerializer();
+  serializer.serializeStr(arg);
+  return serializer.getBytes();
+}
@@ -24,10 +24,32 @@ fn peer_to_peer<M: Measurement + 'static>(c: &mut Criterion<M>) {
     });
 }
 
+/// Number of accounts of the hot-account workloads, where most transfers conflict.
+const NUM_HOT_ACCOUNTS: usize = 5;
+
+fn peer_to_peer_hot_accounts<M: Measurement + 'static>(c: &mut Criterion<M>) {
+    let mut bencher = TransactionBencher::new(any_with::<P2PTransferGen>((1_000, 1_000_000)));
+    bencher.num_accounts(NUM_HOT_ACCOUNTS);
+
+    let (aborts, aborts_with_preordering) = bencher.speculative_aborts();
+    println!(
+        "peer_to_peer_hot_accounts speculative aborts: {} without pre-ordering, {} with pre-ordering",
+        aborts, aborts_with_preordering
+    );
+
+    c.bench_function("peer_to_peer_hot_accounts_parallel", |b| {
+        bencher.bench_parallel(b)
+    });
+
+    c.bench_function("peer_to_peer_hot_accounts_parallel_with_preordering", |b| {
+        bencher.bench_parallel_with_preordering(b)
+    });
+}
+
 criterion_group!(
     name = txn_benches;
     config = wall_time_measurement().sample_size(10);
-    targets = peer_to_peer
+    targets = peer_to_peer, peer_to_peer_hot_accounts
 );
 
 criterion_main!(txn_benches);
@@ -3,13 +3,15 @@
 
 use aptos_bitvec::BitVec;
 use aptos_crypto::HashValue;
+use aptos_parallel_executor::counters::SPECULATIVE_ABORT_COUNT;
 use aptos_types::{
     block_metadata::BlockMetadata,
     on_chain_config::{OnChainConfig, ValidatorSet},
     transaction::Transaction,
 };
 use aptos_vm::{
-    data_cache::AsMoveResolver, parallel_executor::ParallelAptosVM, AptosVM, VMExecutor,
+    data_cache::AsMoveResolver, parallel_executor::ParallelAptosVM,
+    read_write_set_analysis::add_on_functions_list, AptosVM, VMExecutor,
 };
 use criterion::{measurement::Measurement, BatchSize, Bencher};
 use language_e2e_tests::{
@@ -17,11 +19,25 @@ use language_e2e_tests::{
     executor::FakeExecutor,
     gas_costs::TXN_RESERVED,
 };
+use once_cell::sync::Lazy;
 use proptest::{
     collection::vec,
     strategy::{Strategy, ValueTree},
     test_runner::TestRunner,
 };
+use read_write_set_dynamic::NormalizedReadWriteSetAnalysis;
+
+/// Read/write set analysis of the head framework, used to pre-order the transactions by their
+/// inferred conflicts in parallel execution.
+static READ_WRITE_SET_ANALYSIS: Lazy<NormalizedReadWriteSetAnalysis> = Lazy::new(|| {
+    read_write_set::analyze(
+        cached_packages::head_release_bundle()
+            .compiled_modules()
+            .iter(),
+    )
+    .expect("Analysis of the framework should succeed")
+    .normalize_all_scripts(add_on_functions_list())
+});
 
 /// Benchmarking support for transactions.
 #[derive(Clone, Debug)]
@@ -94,6 +110,48 @@ where
             BatchSize::LargeInput,
         )
     }
+
+    /// Runs the bencher, pre-ordering the transactions by their inferred conflicts.
+    pub fn bench_parallel_with_preordering<M: Measurement>(&self, b: &mut Bencher<M>) {
+        // Run the analysis outside of the measured routine.
+        Lazy::force(&READ_WRITE_SET_ANALYSIS);
+        b.iter_batched(
+            || {
+                TransactionBenchState::with_size(
+                    &self.strategy,
+                    self.num_accounts,
+                    self.num_transactions,
+                )
+            },
+            |state| state.execute_parallel_with_preordering(),
+            // The input here is the entire list of signed transactions, so it's pretty large.
+            BatchSize::LargeInput,
+        )
+    }
+
+    /// Executes a block via parallel execution, without and with pre-ordering, and returns the
+    /// number of speculative aborts of each run.
+    pub fn speculative_aborts(&self) -> (u64, u64) {
+        let state = TransactionBenchState::with_size(
+            &self.strategy,
+            self.num_accounts,
+            self.num_transactions,
+        );
+        let aborts_before = SPECULATIVE_ABORT_COUNT.get();
+        state.execute_parallel();
+        let aborts = SPECULATIVE_ABORT_COUNT.get() - aborts_before;
+
+        let state = TransactionBenchState::with_size(
+            &self.strategy,
+            self.num_accounts,
+            self.num_transactions,
+        );
+        let aborts_before = SPECULATIVE_ABORT_COUNT.get();
+        state.execute_parallel_with_preordering();
+        let aborts_with_preordering = SPECULATIVE_ABORT_COUNT.get() - aborts_before;
+
+        (aborts, aborts_with_preordering)
+    }
 }
 
 struct TransactionBenchState {
@@ -203,6 +261,20 @@ impl TransactionBenchState {
         )
         .expect("VM should not fail to start");
     }
+
+    /// Executes this state in a single block via parallel execution, pre-ordering the
+    /// transactions by their inferred conflicts.
+    fn execute_parallel_with_preordering(self) {
+        // The output is ignored here since we're just testing transaction performance, not trying
+        // to assert correctness.
+        ParallelAptosVM::execute_block_with_read_write_set_analysis(
+            self.transactions,
+            self.executor.get_state_view(),
+            num_cpus::get(),
+            &READ_WRITE_SET_ANALYSIS,
+        )
+        .expect("VM should not fail to start");
+    }
 }
 
 /// Returns a strategy for the account universe customized for benchmarks.
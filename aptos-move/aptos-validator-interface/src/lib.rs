@@ -1,9 +1,10 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
+mod rest_interface;
 mod storage_interface;
 
-pub use crate::storage_interface::DBDebuggerInterface;
+pub use crate::{rest_interface::RestDebuggerInterface, storage_interface::DBDebuggerInterface};
 
 use anyhow::{anyhow, Result};
 use aptos_state_view::StateView;
@@ -17,7 +18,7 @@ use aptos_types::{
     event::EventKey,
     on_chain_config::ValidatorSet,
     state_store::{state_key::StateKey, state_value::StateValue},
-    transaction::{Transaction, Version},
+    transaction::{Transaction, TransactionOutput, Version},
 };
 use move_binary_format::file_format::CompiledModule;
 
@@ -46,8 +47,18 @@ pub trait AptosValidatorInterface: Sync {
 
     fn get_committed_transactions(&self, start: Version, limit: u64) -> Result<Vec<Transaction>>;
 
+    /// Returns the committed transactions starting at version `start`, together with the outputs
+    /// (write set, events, gas used and status) they produced when they were committed.
+    fn get_committed_transaction_outputs(
+        &self,
+        start: Version,
+        limit: u64,
+    ) -> Result<Vec<(Transaction, TransactionOutput)>>;
+
     fn get_latest_version(&self) -> Result<Version>;
 
+    fn get_state_storage_usage(&self, version: Version) -> Result<StateStorageUsage>;
+
     fn get_version_by_account_sequence(
         &self,
         account: AccountAddress,
@@ -138,6 +149,9 @@ impl<'a> StateView for DebuggerStateView<'a> {
     }
 
     fn get_usage(&self) -> Result<StateStorageUsage> {
-        unimplemented!()
+        match self.version {
+            None => Ok(StateStorageUsage::zero()),
+            Some(version) => self.db.get_state_storage_usage(version),
+        }
     }
 }
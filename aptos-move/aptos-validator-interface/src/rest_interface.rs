@@ -0,0 +1,451 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::AptosValidatorInterface;
+use anyhow::{anyhow, bail, ensure, Result};
+use aptos_crypto::HashValue;
+use aptos_rest_client::{error::RestError, Client, Response};
+use aptos_types::{
+    access_path::{AccessPath, Path},
+    account_address::AccountAddress,
+    account_config::CORE_CODE_ADDRESS,
+    account_state::AccountState,
+    contract_event::EventWithVersion,
+    event::EventKey,
+    state_store::{
+        state_key::StateKey, state_storage_usage::StateStorageUsage, state_value::StateValue,
+    },
+    transaction::{Transaction, TransactionOutput, Version},
+};
+use move_core_types::language_storage::{ModuleId, ResourceKey};
+use std::{collections::HashMap, future::Future, io::Write, path::PathBuf};
+use tokio::runtime::Runtime;
+
+/// Maximum number of transactions or events requested at once, the page size of the REST API.
+const MAX_PAGE_SIZE: u64 = 100;
+
+/// Debugger interface backed by the REST API of a (e.g. public) fullnode, so that transactions can
+/// be replayed without syncing a DB. Fetched state values can be cached on disk, to avoid fetching
+/// them again when replaying the same transactions.
+pub struct RestDebuggerInterface {
+    client: Client,
+    runtime: Runtime,
+    cache_dir: Option<PathBuf>,
+}
+
+impl RestDebuggerInterface {
+    pub fn new(client: Client) -> Result<Self> {
+        Ok(Self {
+            client,
+            runtime: tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()?,
+            cache_dir: None,
+        })
+    }
+
+    /// Caches the fetched state values in `cache_dir`, which is created if it doesn't exist.
+    pub fn with_cache_dir(mut self, cache_dir: PathBuf) -> Result<Self> {
+        std::fs::create_dir_all(&cache_dir)?;
+        self.cache_dir = Some(cache_dir);
+        Ok(self)
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn fetch_state_value(&self, state_key: &StateKey, version: Version) -> Result<Option<Vec<u8>>> {
+        match state_key {
+            StateKey::AccessPath(access_path) => match access_path.get_path() {
+                Path::Resource(struct_tag) => not_found_as_none(self.block_on(
+                    self.client.get_account_resource_at_version_bytes(
+                        access_path.address,
+                        &struct_tag.to_string(),
+                        version,
+                    ),
+                )),
+                Path::Code(module_id) => Ok(not_found_as_none(self.block_on(
+                    self.client.get_account_module_at_version_bcs(
+                        access_path.address,
+                        module_id.name().as_str(),
+                        version,
+                    ),
+                ))?
+                .map(|bytes| bytes.to_vec())),
+            },
+            StateKey::TableItem { handle, key } => not_found_as_none(
+                self.block_on(
+                    self.client
+                        .get_raw_table_item_at_version(handle.0, key, version),
+                ),
+            ),
+            StateKey::Raw(_) => bail!("Raw state keys can't be fetched from the REST API"),
+        }
+    }
+
+    /// Fetches the `0x1::state_storage::StateStorageUsage` resource at `version`, i.e. the epoch
+    /// and the state storage usage (items and bytes) recorded at the beginning of that epoch.
+    fn fetch_recorded_storage_usage(&self, version: Version) -> Result<(u64, (u64, u64))> {
+        Ok(self
+            .block_on(self.client.get_account_resource_at_version_bcs(
+                CORE_CODE_ADDRESS,
+                "0x1::state_storage::StateStorageUsage",
+                version,
+            ))?
+            .into_inner())
+    }
+
+    /// Path of the file caching the value of `state_key` at `version`.
+    fn cache_path(&self, state_key: &StateKey, version: Version) -> Result<Option<PathBuf>> {
+        Ok(match &self.cache_dir {
+            Some(cache_dir) => {
+                let hash = HashValue::sha3_256_of(&bcs::to_bytes(&(state_key, version))?);
+                Some(cache_dir.join(hash.to_hex()))
+            }
+            None => None,
+        })
+    }
+}
+
+impl AptosValidatorInterface for RestDebuggerInterface {
+    fn get_account_state_by_version(
+        &self,
+        account: AccountAddress,
+        version: Version,
+    ) -> Result<Option<AccountState>> {
+        let resources = match not_found_as_none(
+            self.block_on(
+                self.client
+                    .get_account_resources_at_version_bcs(account, version),
+            ),
+        )? {
+            Some(resources) => resources,
+            None => return Ok(None),
+        };
+        let modules = not_found_as_none(
+            self.block_on(
+                self.client
+                    .get_account_modules_at_version_bcs(account, version),
+            ),
+        )?
+        .unwrap_or_default();
+
+        let mut key_value_map = HashMap::new();
+        for (struct_tag, bytes) in resources {
+            key_value_map.insert(
+                StateKey::AccessPath(AccessPath::resource_access_path(ResourceKey::new(
+                    account, struct_tag,
+                ))),
+                StateValue::from(bytes),
+            );
+        }
+        for (module_id, bytes) in modules {
+            key_value_map.insert(
+                StateKey::AccessPath(AccessPath::code_access_path(ModuleId::from(module_id))),
+                StateValue::from(bytes),
+            );
+        }
+        AccountState::from_access_paths_and_values(account, &key_value_map)
+    }
+
+    fn get_state_value_by_version(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+    ) -> Result<Option<StateValue>> {
+        let cache_path = self.cache_path(state_key, version)?;
+        if let Some(cache_path) = &cache_path {
+            if cache_path.exists() {
+                let value: Option<Vec<u8>> = bcs::from_bytes(&std::fs::read(cache_path)?)?;
+                return Ok(value.map(StateValue::from));
+            }
+        }
+
+        let value = self.fetch_state_value(state_key, version)?;
+
+        if let (Some(cache_path), Some(cache_dir)) = (&cache_path, &self.cache_dir) {
+            // Write to a temporary file first, so that concurrent readers never see a partially
+            // written value.
+            let mut file = tempfile::NamedTempFile::new_in(cache_dir)?;
+            file.write_all(&bcs::to_bytes(&value)?)?;
+            file.persist(cache_path)?;
+        }
+        Ok(value.map(StateValue::from))
+    }
+
+    fn get_events(
+        &self,
+        key: &EventKey,
+        start_seq: u64,
+        limit: u64,
+        ledger_version: Version,
+    ) -> Result<Vec<EventWithVersion>> {
+        let mut events = vec![];
+        while (events.len() as u64) < limit {
+            let page_size = std::cmp::min(limit - events.len() as u64, MAX_PAGE_SIZE);
+            let page = self
+                .block_on(self.client.get_account_events_by_creation_number_bcs(
+                    key.get_creator_address(),
+                    key.get_creation_number(),
+                    Some(start_seq + events.len() as u64),
+                    Some(page_size as u16),
+                ))?
+                .into_inner();
+            let is_last_page = (page.len() as u64) < page_size;
+            events.extend(
+                page.into_iter()
+                    .take_while(|event| event.transaction_version <= ledger_version),
+            );
+            if is_last_page {
+                break;
+            }
+        }
+        Ok(events)
+    }
+
+    fn get_committed_transactions(&self, start: Version, limit: u64) -> Result<Vec<Transaction>> {
+        Ok(self
+            .get_committed_transaction_outputs(start, limit)?
+            .into_iter()
+            .map(|(txn, _)| txn)
+            .collect())
+    }
+
+    fn get_committed_transaction_outputs(
+        &self,
+        start: Version,
+        limit: u64,
+    ) -> Result<Vec<(Transaction, TransactionOutput)>> {
+        let mut txns_and_outputs = vec![];
+        while (txns_and_outputs.len() as u64) < limit {
+            let page_size = std::cmp::min(limit - txns_and_outputs.len() as u64, MAX_PAGE_SIZE);
+            let page = self
+                .block_on(self.client.get_transactions_bcs(
+                    Some(start + txns_and_outputs.len() as u64),
+                    Some(page_size as u16),
+                ))?
+                .into_inner();
+            if page.is_empty() {
+                break;
+            }
+            txns_and_outputs.extend(page.into_iter().map(|txn| {
+                let output = TransactionOutput::new(
+                    txn.changes,
+                    txn.events,
+                    txn.info.gas_used(),
+                    txn.info.status().clone().into(),
+                );
+                (txn.transaction, output)
+            }));
+        }
+        Ok(txns_and_outputs)
+    }
+
+    fn get_latest_version(&self) -> Result<Version> {
+        Ok(self
+            .block_on(self.client.get_ledger_information())?
+            .into_inner()
+            .version)
+    }
+
+    /// The REST API doesn't serve the state storage usage, but the VM only reads it in the first
+    /// block of an epoch, which records it in the `0x1::state_storage::StateStorageUsage`
+    /// resource. So the usage is read from that resource when `version` is right before the first
+    /// block of an epoch, and isn't available at other versions.
+    fn get_state_storage_usage(&self, version: Version) -> Result<StateStorageUsage> {
+        let (epoch, _) = self.fetch_recorded_storage_usage(version)?;
+        let (next_epoch, (items, bytes)) = self.fetch_recorded_storage_usage(version + 1)?;
+        ensure!(
+            next_epoch != epoch,
+            "The state storage usage at version {} isn't available from the REST API, it's only \
+             available right before the first block of an epoch",
+            version
+        );
+        Ok(StateStorageUsage::new(items as usize, bytes as usize))
+    }
+
+    fn get_version_by_account_sequence(
+        &self,
+        account: AccountAddress,
+        seq: u64,
+    ) -> Result<Option<Version>> {
+        Ok(
+            not_found_as_none(self.block_on(self.client.get_account_transactions_bcs(
+                account,
+                Some(seq),
+                Some(1),
+            )))?
+            .and_then(|txns| txns.into_iter().next())
+            .map(|txn| txn.version),
+        )
+    }
+}
+
+/// Converts the responses for missing resources, modules, table items or accounts into None.
+fn not_found_as_none<T>(result: Result<Response<T>, RestError>) -> Result<Option<T>> {
+    match result {
+        Ok(response) => Ok(Some(response.into_inner())),
+        Err(RestError::Api(err)) if err.status_code.as_u16() == 404 => Ok(None),
+        Err(err) => Err(anyhow!(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_api_test_context::{new_test_context, ApiSpecificConfig, TestContext};
+    use aptos_temppath::TempPath;
+    use aptos_types::{account_config::AccountResource, state_store::table::TableHandle};
+    use move_core_types::{identifier::Identifier, move_resource::MoveStructType};
+    use storage_interface::DbReader;
+    use url::Url;
+
+    fn new_client(context: &TestContext) -> Client {
+        let ApiSpecificConfig::V1(address) = context.api_specific_config;
+        Client::new(Url::parse(&format!("http://{}", address)).unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_rest_interface_matches_db() {
+        let mut context = new_test_context("test_rest_interface_matches_db".to_string(), false);
+        let account = context.gen_account();
+        let txn = context.create_user_account(&account);
+        context.commit_block(&[txn]).await;
+        let version = context.get_latest_ledger_info().version();
+
+        let client = new_client(&context);
+        let db = context.db.clone();
+        let cache_dir = TempPath::new();
+
+        // The interface blocks on its own runtime, so it can't be used from the test's runtime.
+        tokio::task::spawn_blocking(move || {
+            let interface = RestDebuggerInterface::new(client)
+                .unwrap()
+                .with_cache_dir(cache_dir.path().to_path_buf())
+                .unwrap();
+
+            let state_keys = [
+                StateKey::AccessPath(AccessPath::resource_access_path(ResourceKey::new(
+                    account.address(),
+                    AccountResource::struct_tag(),
+                ))),
+                StateKey::AccessPath(AccessPath::code_access_path(ModuleId::new(
+                    CORE_CODE_ADDRESS,
+                    Identifier::new("coin").unwrap(),
+                ))),
+                // Doesn't exist.
+                StateKey::AccessPath(AccessPath::resource_access_path(ResourceKey::new(
+                    AccountAddress::random(),
+                    AccountResource::struct_tag(),
+                ))),
+            ];
+            for state_key in &state_keys {
+                let expected = db.get_state_value_by_version(state_key, version).unwrap();
+                assert_eq!(
+                    interface
+                        .get_state_value_by_version(state_key, version)
+                        .unwrap(),
+                    expected
+                );
+                // Served from the cache.
+                assert!(interface
+                    .cache_path(state_key, version)
+                    .unwrap()
+                    .unwrap()
+                    .exists());
+                assert_eq!(
+                    interface
+                        .get_state_value_by_version(state_key, version)
+                        .unwrap(),
+                    expected
+                );
+            }
+
+            let expected_txns = db
+                .get_transactions(0, version + 1, version, false)
+                .unwrap()
+                .transactions;
+            assert_eq!(
+                interface
+                    .get_committed_transactions(0, version + 1)
+                    .unwrap(),
+                expected_txns
+            );
+            assert_eq!(interface.get_latest_version().unwrap(), version);
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_rest_interface_table_items() {
+        let context = new_test_context("test_rest_interface_table_items".to_string(), false);
+        let version = context.get_latest_ledger_info().version();
+
+        // The supply of the Aptos coin is an aggregator, which is stored as a table item.
+        let coin_info = context
+            .get("/accounts/0x1/resource/0x1::coin::CoinInfo%3C0x1::aptos_coin::AptosCoin%3E")
+            .await;
+        let aggregator = &coin_info["data"]["supply"]["vec"][0]["aggregator"]["vec"][0];
+        let handle =
+            AccountAddress::from_hex_literal(aggregator["handle"].as_str().unwrap()).unwrap();
+        let key = AccountAddress::from_hex_literal(aggregator["key"].as_str().unwrap()).unwrap();
+
+        let client = new_client(&context);
+        let db = context.db.clone();
+        tokio::task::spawn_blocking(move || {
+            let interface = RestDebuggerInterface::new(client).unwrap();
+            let state_keys = [
+                StateKey::table_item(TableHandle(handle), bcs::to_bytes(&key).unwrap()),
+                // Doesn't exist.
+                StateKey::table_item(
+                    TableHandle(handle),
+                    bcs::to_bytes(&AccountAddress::random()).unwrap(),
+                ),
+            ];
+            for state_key in &state_keys {
+                assert_eq!(
+                    interface
+                        .get_state_value_by_version(state_key, version)
+                        .unwrap(),
+                    db.get_state_value_by_version(state_key, version).unwrap()
+                );
+            }
+            assert!(interface
+                .get_state_value_by_version(&state_keys[0], version)
+                .unwrap()
+                .is_some());
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_rest_interface_state_storage_usage() {
+        let mut context =
+            new_test_context("test_rest_interface_state_storage_usage".to_string(), false);
+        for _ in 0..2 {
+            let account = context.gen_account();
+            let txn = context.create_user_account(&account);
+            context.commit_block(&[txn]).await;
+        }
+
+        let client = new_client(&context);
+        let db = context.db.clone();
+        tokio::task::spawn_blocking(move || {
+            let interface = RestDebuggerInterface::new(client).unwrap();
+
+            // The genesis is followed by the first block of an epoch, which records the usage.
+            assert_eq!(
+                interface.get_state_storage_usage(0).unwrap(),
+                db.get_state_storage_usage(Some(0)).unwrap()
+            );
+            // The usage isn't recorded in the middle of an epoch.
+            let err = interface.get_state_storage_usage(3).unwrap_err();
+            assert!(err.to_string().contains("isn't available"), "{:#}", err);
+        })
+        .await
+        .unwrap();
+    }
+}
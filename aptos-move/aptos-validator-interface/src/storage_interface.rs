@@ -12,8 +12,11 @@ use aptos_types::{
     account_state::AccountState,
     contract_event::EventWithVersion,
     event::EventKey,
-    state_store::{state_key::StateKey, state_key_prefix::StateKeyPrefix, state_value::StateValue},
-    transaction::{Transaction, Version},
+    state_store::{
+        state_key::StateKey, state_key_prefix::StateKeyPrefix,
+        state_storage_usage::StateStorageUsage, state_value::StateValue,
+    },
+    transaction::{Transaction, TransactionOutput, Version},
 };
 use aptosdb::AptosDB;
 use std::{path::Path, sync::Arc};
@@ -22,6 +25,10 @@ use storage_interface::{DbReader, Order};
 pub struct DBDebuggerInterface(Arc<dyn DbReader>);
 
 impl DBDebuggerInterface {
+    pub fn new(db: Arc<dyn DbReader>) -> Self {
+        Self(db)
+    }
+
     pub fn open<P: AsRef<Path> + Clone>(db_root_path: P) -> Result<Self> {
         Ok(Self(Arc::new(AptosDB::open(
             db_root_path,
@@ -78,6 +85,17 @@ impl AptosValidatorInterface for DBDebuggerInterface {
             .transactions)
     }
 
+    fn get_committed_transaction_outputs(
+        &self,
+        start: Version,
+        limit: u64,
+    ) -> Result<Vec<(Transaction, TransactionOutput)>> {
+        Ok(self
+            .0
+            .get_transaction_outputs(start, limit, self.get_latest_version()?)?
+            .transactions_and_outputs)
+    }
+
     fn get_latest_version(&self) -> Result<Version> {
         let (version, _) = self
             .0
@@ -86,6 +104,10 @@ impl AptosValidatorInterface for DBDebuggerInterface {
         Ok(version)
     }
 
+    fn get_state_storage_usage(&self, version: Version) -> Result<StateStorageUsage> {
+        self.0.get_state_storage_usage(Some(version))
+    }
+
     fn get_version_by_account_sequence(
         &self,
         account: AccountAddress,
@@ -25,7 +25,7 @@ use aptos_aggregator::{
     transaction::{ChangeSetExt, TransactionOutputExt},
 };
 use aptos_crypto::HashValue;
-use aptos_gas::AptosGasMeter;
+use aptos_gas::{AptosGasMeter, GasProfile};
 use aptos_logger::prelude::*;
 use aptos_module_verifier::module_init::verify_module_init_function;
 use aptos_state_view::StateView;
@@ -33,11 +33,11 @@ use aptos_types::{
     account_config,
     account_config::new_block_event_key,
     block_metadata::BlockMetadata,
-    on_chain_config::new_epoch_event_key,
+    on_chain_config::{new_epoch_event_key, FeatureFlag},
     transaction::{
-        ChangeSet, ExecutionStatus, ModuleBundle, SignatureCheckedTransaction, SignedTransaction,
-        Transaction, TransactionOutput, TransactionPayload, TransactionStatus, VMValidatorResult,
-        WriteSetPayload,
+        authenticator::Scheme, ChangeSet, ExecutionStatus, ModuleBundle,
+        SignatureCheckedTransaction, SignedTransaction, Transaction, TransactionOutput,
+        TransactionPayload, TransactionStatus, VMValidatorResult, WriteSetPayload,
     },
     vm_status::{AbortLocation, DiscardedVMStatus, StatusCode, VMStatus},
     write_set::WriteSet,
@@ -47,12 +47,14 @@ use framework::natives::code::PublishRequest;
 use move_binary_format::{
     access::ModuleAccess,
     errors::{verification_error, Location, PartialVMError, VMError, VMResult},
+    file_format::Visibility,
     CompiledModule, IndexKind,
 };
 use move_core_types::{
     account_address::AccountAddress,
     ident_str,
-    language_storage::ModuleId,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
     transaction_argument::convert_txn_args,
     value::{serialize_values, MoveValue},
 };
@@ -934,7 +936,91 @@ impl AptosVM {
         let vm = AptosVM::new(state_view);
         let simulation_vm = AptosSimulationVM(vm);
         let log_context = AdapterLogSchema::new(state_view.id(), 0);
-        simulation_vm.simulate_signed_transaction(&state_view.as_move_resolver(), txn, &log_context)
+        let (vm_status, output, _) = simulation_vm.simulate_signed_transaction(
+            &state_view.as_move_resolver(),
+            txn,
+            &log_context,
+            false,
+        );
+        (vm_status, output)
+    }
+
+    /// Same as `simulate_signed_transaction`, but also returns a breakdown of the gas charged,
+    /// attributed to call frames, native functions and storage operations. No profile is returned
+    /// if the transaction is discarded before execution.
+    pub fn simulate_signed_transaction_with_gas_profiling(
+        txn: &SignedTransaction,
+        state_view: &impl StateView,
+    ) -> (VMStatus, TransactionOutputExt, Option<GasProfile>) {
+        let vm = AptosVM::new(state_view);
+        let simulation_vm = AptosSimulationVM(vm);
+        let log_context = AdapterLogSchema::new(state_view.id(), 0);
+        simulation_vm.simulate_signed_transaction(
+            &state_view.as_move_resolver(),
+            txn,
+            &log_context,
+            true,
+        )
+    }
+
+    /// Executes a public Move function against `state_view` without a transaction and returns
+    /// the BCS-serialized return values. The resulting writes are discarded, and execution is
+    /// bounded by `gas_budget` so that a single call can't run forever.
+    pub fn execute_view_function(
+        state_view: &impl StateView,
+        module_id: ModuleId,
+        func_name: Identifier,
+        type_args: Vec<TypeTag>,
+        arguments: Vec<Vec<u8>>,
+        gas_budget: u64,
+    ) -> Result<Vec<Vec<u8>>, VMStatus> {
+        let vm = AptosVM::new(state_view);
+        let log_context = AdapterLogSchema::new(state_view.id(), 0);
+        let gas_params = vm.0.get_gas_parameters(&log_context)?;
+        let storage_gas_params = vm.0.get_storage_gas_parameters(&log_context)?;
+        let mut gas_meter = AptosGasMeter::new(
+            vm.0.get_gas_feature_version(),
+            gas_params.clone(),
+            storage_gas_params.cloned(),
+            gas_budget,
+        );
+
+        let resolver = state_view.as_move_resolver();
+        let module =
+            vm.0.load_module(&module_id, &resolver)
+                .map_err(|e| e.into_vm_status())?;
+        // Only public functions can be viewed, private and friend functions may rely on
+        // invariants established by their callers.
+        let is_public = module.function_defs().iter().any(|def| {
+            let handle = module.function_handle_at(def.function);
+            module.identifier_at(handle.name) == func_name.as_ident_str()
+                && def.visibility == Visibility::Public
+        });
+        if !is_public {
+            return Err(VMStatus::Error(StatusCode::VISIBILITY_MISMATCH));
+        }
+
+        let mut session = vm.new_session(&resolver, SessionId::void());
+        let function = session
+            .load_function(&module_id, &func_name, &type_args)
+            .map_err(|e| e.into_vm_status())?;
+        let args = transaction_arg_validation::validate_view_function_args(
+            &session, arguments, &function,
+        )?;
+
+        Ok(session
+            .execute_function_bypass_visibility(
+                &module_id,
+                &func_name,
+                type_args,
+                args,
+                &mut gas_meter,
+            )
+            .map_err(|e| e.into_vm_status())?
+            .return_values
+            .into_iter()
+            .map(|(bytes, _layout)| bytes)
+            .collect())
     }
 
     fn run_prologue_with_payload<S: MoveResolverExt>(
@@ -1042,6 +1128,19 @@ impl VMAdapter for AptosVM {
             return Err(VMStatus::Error(StatusCode::SIGNERS_CONTAIN_DUPLICATES));
         }
 
+        // Authentication schemes which are not enabled yet are rejected for any of the signers.
+        let features = self.0.get_features();
+        for auth in txn.authenticator().all_signers() {
+            let required_feature = match auth.scheme() {
+                Scheme::Secp256r1Ecdsa => Some(FeatureFlag::SECP256R1_ECDSA_AUTHENTICATOR),
+                Scheme::Secp256k1Ecdsa => Some(FeatureFlag::SECP256K1_ECDSA_AUTHENTICATOR),
+                _ => None,
+            };
+            if matches!(required_feature, Some(feature) if !features.is_enabled(feature)) {
+                return Err(VMStatus::Error(StatusCode::FEATURE_UNDER_GATING));
+            }
+        }
+
         Ok(())
     }
 
@@ -1181,11 +1280,12 @@ impl AptosSimulationVM {
         storage: &S,
         txn: &SignedTransaction,
         log_context: &AdapterLogSchema,
-    ) -> (VMStatus, TransactionOutputExt) {
+        gas_profiling: bool,
+    ) -> (VMStatus, TransactionOutputExt, Option<GasProfile>) {
         // simulation transactions should not carry valid signatures, otherwise malicious fullnodes
         // may execute them without user's explicit permission.
         if txn.signature_is_valid() {
-            return discard_error_vm_status(VMStatus::Error(StatusCode::INVALID_SIGNATURE));
+            return discard_simulated_transaction(VMStatus::Error(StatusCode::INVALID_SIGNATURE));
         }
 
         // Revalidate the transaction.
@@ -1198,15 +1298,15 @@ impl AptosSimulationVM {
             &txn_data,
             log_context,
         ) {
-            return discard_error_vm_status(err);
+            return discard_simulated_transaction(err);
         };
 
         let gas_params = match self.0 .0.get_gas_parameters(log_context) {
-            Err(err) => return discard_error_vm_status(err),
+            Err(err) => return discard_simulated_transaction(err),
             Ok(s) => s,
         };
         let storage_gas_params = match self.0 .0.get_storage_gas_parameters(log_context) {
-            Err(err) => return discard_error_vm_status(err),
+            Err(err) => return discard_simulated_transaction(err),
             Ok(s) => s,
         };
 
@@ -1216,6 +1316,9 @@ impl AptosSimulationVM {
             storage_gas_params.cloned(),
             txn_data.max_gas_amount(),
         );
+        if gas_profiling {
+            gas_meter.enable_profiling(entry_point_name(txn.payload()));
+        }
 
         let result = match txn.payload() {
             payload @ TransactionPayload::Script(_)
@@ -1235,23 +1338,44 @@ impl AptosSimulationVM {
             }
         };
 
-        match result {
+        let (vm_status, output) = match result {
             Ok(output) => output,
             Err(err) => {
                 let txn_status = TransactionStatus::from(err.clone());
                 if txn_status.is_discarded() {
-                    discard_error_vm_status(err)
+                    return discard_simulated_transaction(err);
                 } else {
-                    let (vm_status, output) = self.0.failed_transaction_cleanup_and_keep_vm_status(
+                    self.0.failed_transaction_cleanup_and_keep_vm_status(
                         err,
                         &mut gas_meter,
                         &txn_data,
                         storage,
                         log_context,
-                    );
-                    (vm_status, output)
+                    )
                 }
             }
-        }
+        };
+        (vm_status, output, gas_meter.gas_profile())
+    }
+}
+
+fn discard_simulated_transaction(
+    err: VMStatus,
+) -> (VMStatus, TransactionOutputExt, Option<GasProfile>) {
+    let (vm_status, output) = discard_error_vm_status(err);
+    (vm_status, output, None)
+}
+
+/// Name of the outermost frame in gas profiles.
+fn entry_point_name(payload: &TransactionPayload) -> String {
+    match payload {
+        TransactionPayload::Script(_) => "script".to_string(),
+        TransactionPayload::ModuleBundle(_) => "module_bundle".to_string(),
+        TransactionPayload::EntryFunction(entry_function) => format!(
+            "{}::{}::{}",
+            entry_function.module().address().to_hex_literal(),
+            entry_function.module().name(),
+            entry_function.function()
+        ),
     }
 }
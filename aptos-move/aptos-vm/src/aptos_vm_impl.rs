@@ -53,6 +53,7 @@ pub struct AptosVMImpl {
     storage_gas_params: Option<StorageGasParameters>,
     version: Option<Version>,
     transaction_validation: Option<TransactionValidation>,
+    features: Features,
     metadata_cache: DashMap<ModuleId, Option<RuntimeModuleMetadata>>,
 }
 
@@ -127,6 +128,7 @@ impl AptosVMImpl {
             storage_gas_params,
             version: None,
             transaction_validation: None,
+            features,
             metadata_cache: Default::default(),
         };
         vm.version = Version::fetch_config(&storage);
@@ -194,6 +196,10 @@ impl AptosVMImpl {
         self.gas_feature_version
     }
 
+    pub fn get_features(&self) -> &Features {
+        &self.features
+    }
+
     pub fn get_version(&self) -> Result<Version, VMStatus> {
         self.version.clone().ok_or_else(|| {
             CRITICAL_ERRORS.inc();
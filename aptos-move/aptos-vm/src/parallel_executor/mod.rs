@@ -1,13 +1,18 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
+mod read_write_set_inferencer;
 mod storage_wrapper;
 mod vm_wrapper;
 
 use crate::{
     adapter_common::{preprocess_transaction, PreprocessedTransaction},
     aptos_vm::AptosVM,
-    parallel_executor::vm_wrapper::AptosVMWrapper,
+    data_cache::AsMoveResolver,
+    parallel_executor::{
+        read_write_set_inferencer::AptosReadWriteSetInferencer, vm_wrapper::AptosVMWrapper,
+    },
+    read_write_set_analysis::ReadWriteSetAnalysis,
 };
 use aptos_aggregator::{delta_change_set::DeltaOp, transaction::TransactionOutputExt};
 use aptos_parallel_executor::{
@@ -24,6 +29,7 @@ use aptos_types::{
 };
 use move_core_types::vm_status::{StatusCode, VMStatus};
 use rayon::prelude::*;
+use read_write_set_dynamic::NormalizedReadWriteSetAnalysis;
 use std::collections::HashMap;
 
 impl PTransaction for PreprocessedTransaction {
@@ -86,6 +92,28 @@ impl ParallelAptosVM {
         transactions: Vec<Transaction>,
         state_view: &S,
         concurrency_level: usize,
+    ) -> Result<(Vec<TransactionOutput>, Option<Error<VMStatus>>), VMStatus> {
+        Self::execute_block_impl(transactions, state_view, concurrency_level, None)
+    }
+
+    /// Same as 'execute_block', but uses the read/write set analysis to infer the conflicts
+    /// between the transactions of the block before executing it, and dispatches the
+    /// independent transactions first. Transactions that can't be analyzed are scheduled as
+    /// in 'execute_block'.
+    pub fn execute_block_with_read_write_set_analysis<S: StateView>(
+        transactions: Vec<Transaction>,
+        state_view: &S,
+        concurrency_level: usize,
+        analysis: &NormalizedReadWriteSetAnalysis,
+    ) -> Result<(Vec<TransactionOutput>, Option<Error<VMStatus>>), VMStatus> {
+        Self::execute_block_impl(transactions, state_view, concurrency_level, Some(analysis))
+    }
+
+    fn execute_block_impl<S: StateView>(
+        transactions: Vec<Transaction>,
+        state_view: &S,
+        concurrency_level: usize,
+        analysis: Option<&NormalizedReadWriteSetAnalysis>,
     ) -> Result<(Vec<TransactionOutput>, Option<Error<VMStatus>>), VMStatus> {
         // Verify the signatures of all the transactions in parallel.
         // This is time consuming so don't wait and do the checking
@@ -95,11 +123,26 @@ impl ParallelAptosVM {
             .map(|txn| preprocess_transaction::<AptosVM>(txn.clone()))
             .collect();
 
-        match ParallelTransactionExecutor::<PreprocessedTransaction, AptosVMWrapper<S>>::new(
-            concurrency_level,
-        )
-        .execute_transactions_parallel(state_view, signature_verified_block)
-        {
+        let executor =
+            ParallelTransactionExecutor::<PreprocessedTransaction, AptosVMWrapper<S>>::new(
+                concurrency_level,
+            );
+        let result = match analysis {
+            Some(analysis) => {
+                let resolver = state_view.as_move_resolver();
+                let inferencer = AptosReadWriteSetInferencer::new(ReadWriteSetAnalysis::new(
+                    analysis, &resolver,
+                ));
+                executor.execute_transactions_parallel_with_preordering(
+                    state_view,
+                    signature_verified_block,
+                    &inferencer,
+                )
+            }
+            None => executor.execute_transactions_parallel(state_view, signature_verified_block),
+        };
+
+        match result {
             Ok((results, delta_resolver)) => {
                 // TODO: with more deltas, collect keys in parallel (in parallel executor).
                 let mut aggregator_keys: HashMap<StateKey, anyhow::Result<ResolvedData>> =
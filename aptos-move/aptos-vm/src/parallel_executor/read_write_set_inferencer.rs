@@ -0,0 +1,55 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    adapter_common::PreprocessedTransaction, move_vm_ext::MoveResolverExt,
+    read_write_set_analysis::ReadWriteSetAnalysis,
+};
+use aptos_parallel_executor::task::{Accesses, ReadWriteSetInferencer};
+use aptos_types::{
+    access_path::AccessPath, account_config::CoinInfoResource, state_store::state_key::StateKey,
+};
+use move_core_types::{language_storage::ResourceKey, move_resource::MoveStructType};
+use rayon::prelude::*;
+
+/// Infers the accesses of the transactions of a block from the static read/write set analysis
+/// of the Move code they call, concretized against the state the block is executed on.
+pub(crate) struct AptosReadWriteSetInferencer<'a, R: MoveResolverExt> {
+    analysis: ReadWriteSetAnalysis<'a, R>,
+}
+
+impl<'a, R: MoveResolverExt> AptosReadWriteSetInferencer<'a, R> {
+    pub fn new(analysis: ReadWriteSetAnalysis<'a, R>) -> Self {
+        Self { analysis }
+    }
+
+    fn infer_accesses(&self, txn: &PreprocessedTransaction) -> Option<Accesses<StateKey>> {
+        let (keys_read, keys_written) = self.analysis.get_keys_transaction(txn, true).ok()?;
+
+        // The supply of AptosCoin is tracked by an aggregator, so burning the gas fees updates
+        // it with a delta and doesn't make transactions conflict with each other.
+        let coin_info_tag = CoinInfoResource::struct_tag();
+        Some(Accesses {
+            keys_read: keys_read.into_iter().map(Self::state_key).collect(),
+            keys_written: keys_written
+                .into_iter()
+                .filter(|key| key.type_() != &coin_info_tag)
+                .map(Self::state_key)
+                .collect(),
+        })
+    }
+
+    fn state_key(key: ResourceKey) -> StateKey {
+        StateKey::AccessPath(AccessPath::resource_access_path(key))
+    }
+}
+
+impl<'a, R: MoveResolverExt + Sync> ReadWriteSetInferencer for AptosReadWriteSetInferencer<'a, R> {
+    type T = PreprocessedTransaction;
+
+    fn infer_reads_writes(&self, txns: &[Self::T]) -> Vec<Option<Accesses<StateKey>>> {
+        txns.par_iter()
+            .map(|txn| self.infer_accesses(txn))
+            .collect()
+    }
+}
@@ -100,6 +100,43 @@ pub(crate) fn validate_combine_signer_and_txn_args<S: MoveResolverExt>(
     Ok(combined_args)
 }
 
+/// Validate args for a view function
+/// validation includes:
+/// 1. return signature is not empty
+/// 2. no signer params
+/// 3. check arg types are allowed
+///
+/// view functions are executed without a sender, so args are passed through as is
+pub(crate) fn validate_view_function_args<S: MoveResolverExt>(
+    session: &SessionExt<S>,
+    args: Vec<Vec<u8>>,
+    func: &LoadedFunctionInstantiation,
+) -> Result<Vec<Vec<u8>>, VMStatus> {
+    // a view function without return values has nothing to view
+    if func.return_.is_empty() {
+        return Err(VMStatus::Error(StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE));
+    }
+    // validate all params, signers are rejected by `is_valid_txn_arg`
+    let mut needs_validation = vec![];
+    for (idx, ty) in func.parameters.iter().enumerate() {
+        let (valid, validation) = is_valid_txn_arg(session, ty);
+        if !valid {
+            return Err(VMStatus::Error(StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE));
+        }
+        if validation {
+            needs_validation.push(idx);
+        }
+    }
+
+    if args.len() != func.parameters.len() {
+        return Err(VMStatus::Error(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH));
+    }
+    if !needs_validation.is_empty() {
+        validate_args(session, &needs_validation, &args, func)?;
+    }
+    Ok(args)
+}
+
 // Return whether the argument is valid/allowed and whether it needs validation.
 // Validation is only needed for String arguments at the moment and vectors of them.
 fn is_valid_txn_arg<S: MoveResolverExt>(session: &SessionExt<S>, typ: &Type) -> (bool, bool) {
@@ -1,6 +1,15 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::{assert_success, MoveHarness};
+use aptos_types::{
+    account_address::AccountAddress,
+    on_chain_config::FeatureFlag,
+    transaction::{RawTransaction, SignedTransaction, TransactionStatus},
+    vm_status::StatusCode,
+};
+use cached_packages::aptos_stdlib;
+use language_e2e_tests::account::Account;
 use std::path::PathBuf;
 
 pub fn test_dir_path(s: &str) -> PathBuf {
@@ -16,3 +25,38 @@ pub fn framework_dir_path(s: &str) -> PathBuf {
         .join("framework")
         .join(s)
 }
+
+/// Funds the account at `address`, controlled by the authentication scheme gated by `feature`,
+/// and checks that a transaction it signed with `sign` is discarded until the feature is enabled.
+pub fn check_authenticator_is_feature_gated(
+    feature: FeatureFlag,
+    address: AccountAddress,
+    sign: impl FnOnce(RawTransaction) -> SignedTransaction,
+) {
+    let mut h = MoveHarness::new_with_features(vec![], vec![feature]);
+    let funder = h.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+    assert_success!(h.run_transaction_payload(
+        &funder,
+        aptos_stdlib::aptos_account_transfer(address, 1_000_000_000)
+    ));
+
+    let txn = sign(
+        Account::new_genesis_account(address)
+            .transaction()
+            .sequence_number(0)
+            .max_gas_amount(2_000_000)
+            .gas_unit_price(1)
+            .payload(aptos_stdlib::aptos_coin_transfer(*funder.address(), 100))
+            .raw(),
+    );
+
+    // The transaction is discarded as long as the feature is disabled ...
+    assert_eq!(
+        h.run(txn.clone()),
+        TransactionStatus::Discard(StatusCode::FEATURE_UNDER_GATING)
+    );
+
+    // ... and executes successfully once it is enabled.
+    h.enable_features(vec![feature], vec![]);
+    assert_success!(h.run(txn));
+}
@@ -18,6 +18,8 @@ mod mint_nft;
 mod offer_signer_capability;
 mod rotate_auth_key;
 mod scripts;
+mod secp256k1_ecdsa;
+mod secp256r1_ecdsa;
 mod simple_defi;
 mod stake;
 mod string_args;
@@ -0,0 +1,25 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::tests::common::check_authenticator_is_feature_gated;
+use aptos_crypto::{secp256k1_ecdsa::Secp256k1EcdsaPrivateKey, PrivateKey, Uniform};
+use aptos_types::{on_chain_config::FeatureFlag, transaction::authenticator::AuthenticationKey};
+
+#[test]
+fn secp256k1_ecdsa_authenticator_is_feature_gated() {
+    // Create an account controlled by a Secp256k1 ECDSA key.
+    let private_key = Secp256k1EcdsaPrivateKey::generate_for_testing();
+    let public_key = private_key.public_key();
+    let address = AuthenticationKey::secp256k1_ecdsa(&public_key).derived_address();
+
+    check_authenticator_is_feature_gated(
+        FeatureFlag::SECP256K1_ECDSA_AUTHENTICATOR,
+        address,
+        |raw_txn| {
+            raw_txn
+                .sign_secp256k1_ecdsa(&private_key, public_key)
+                .unwrap()
+                .into_inner()
+        },
+    );
+}
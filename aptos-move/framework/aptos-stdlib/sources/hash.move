@@ -1,5 +1,9 @@
 /// Cryptographic hashes:
 /// - Keccak-256: see https://keccak.team/keccak.html
+/// - SHA2-512: see https://en.wikipedia.org/wiki/SHA-2
+/// - SHA3-512: see https://en.wikipedia.org/wiki/SHA-3
+/// - BLAKE2b-256: see https://www.blake2.net
+/// - RIPEMD-160: see https://homes.esat.kuleuven.be/~bosselae/ripemd160.html
 ///
 /// In addition, SHA2-256 and SHA3-256 are available in `std::hash`. Note that SHA3-256 is a variant of Keccak: it is
 /// NOT the same as Keccak-256.
@@ -23,6 +27,17 @@ module aptos_std::aptos_hash {
 
     native public fun keccak256(bytes: vector<u8>): vector<u8>;
 
+    native public fun sha2_512(bytes: vector<u8>): vector<u8>;
+
+    native public fun sha3_512(bytes: vector<u8>): vector<u8>;
+
+    /// BLAKE2b with a 32 byte output, which is NOT the 32 byte prefix of the BLAKE2b-512 hash.
+    native public fun blake2b_256(bytes: vector<u8>): vector<u8>;
+
+    /// Returns the 20 byte RIPEMD-160 hash, e.g. to compute Bitcoin addresses as
+    /// `ripemd160(std::hash::sha2_256(public_key))`.
+    native public fun ripemd160(bytes: vector<u8>): vector<u8>;
+
     //
     // Testing
     //
@@ -50,4 +65,100 @@ module aptos_std::aptos_hash {
             i = i + 1;
         };
     }
+
+    #[test]
+    fun sha2_512_test() {
+        let inputs = vector[
+            b"testing",
+            b"",
+        ];
+
+        let outputs = vector[
+            x"521b9ccefbcd14d179e7a1bb877752870a6d620938b28a66a107eac6e6805b9d0989f45b5730508041aa5e710847d439ea74cd312c9355f1f2dae08d40e41d50",
+            x"cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+        ];
+
+        let i = 0;
+        while (i < std::vector::length(&inputs)) {
+            let input = *std::vector::borrow(&inputs, i);
+            let hash_expected = *std::vector::borrow(&outputs, i);
+            let hash = sha2_512(input);
+
+            assert!(hash_expected == hash, 1);
+
+            i = i + 1;
+        };
+    }
+
+    #[test]
+    fun sha3_512_test() {
+        let inputs = vector[
+            b"testing",
+            b"",
+        ];
+
+        let outputs = vector[
+            x"881c7d6ba98678bcd96e253086c4048c3ea15306d0d13ff48341c6285ee71102a47b6f16e20e4d65c0c3d677be689dfda6d326695609cbadfafa1800e9eb7fc1",
+            x"a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26",
+        ];
+
+        let i = 0;
+        while (i < std::vector::length(&inputs)) {
+            let input = *std::vector::borrow(&inputs, i);
+            let hash_expected = *std::vector::borrow(&outputs, i);
+            let hash = sha3_512(input);
+
+            assert!(hash_expected == hash, 1);
+
+            i = i + 1;
+        };
+    }
+
+    #[test]
+    fun blake2b_256_test() {
+        let inputs = vector[
+            b"testing",
+            b"",
+        ];
+
+        let outputs = vector[
+            x"99397ff32ae348b8b6536d5c213f343d7e9fdeaa10e8a23a9f90ab21a1658565",
+            x"0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8",
+        ];
+
+        let i = 0;
+        while (i < std::vector::length(&inputs)) {
+            let input = *std::vector::borrow(&inputs, i);
+            let hash_expected = *std::vector::borrow(&outputs, i);
+            let hash = blake2b_256(input);
+
+            assert!(hash_expected == hash, 1);
+
+            i = i + 1;
+        };
+    }
+
+    #[test]
+    fun ripemd160_test() {
+        let inputs = vector[
+            b"testing",
+            b"",
+        ];
+
+        let outputs = vector[
+            x"b89ba156b40bed29a5965684b7d244c49a3a769b",
+            x"9c1185a5c5e9fc54612808977ee8f548b2258d31",
+        ];
+
+        let i = 0;
+        while (i < std::vector::length(&inputs)) {
+            let input = *std::vector::borrow(&inputs, i);
+            let hash_expected = *std::vector::borrow(&outputs, i);
+            let hash = ripemd160(input);
+
+            assert!(hash_expected == hash, 1);
+
+            i = i + 1;
+        };
+    }
 }
@@ -47,6 +47,25 @@ module std::features {
         is_enabled(TREAT_FRIEND_AS_PRIVATE)
     }
 
+    // --------------------------------------------------------------------------------------------
+    // Transaction Authentication
+
+    /// Whether transactions signed with a Secp256r1 ECDSA key, either directly or via a WebAuthn
+    /// assertion, are accepted. This is checked by the VM before running the prologue.
+    /// Lifetime: ephemeral
+    const SECP256R1_ECDSA_AUTHENTICATOR: u64 = 3;
+    public fun secp256r1_ecdsa_authenticator_enabled(): bool acquires Features {
+        is_enabled(SECP256R1_ECDSA_AUTHENTICATOR)
+    }
+
+    /// Whether transactions signed with a Secp256k1 ECDSA key are accepted. This is checked by the
+    /// VM before running the prologue.
+    /// Lifetime: ephemeral
+    const SECP256K1_ECDSA_AUTHENTICATOR: u64 = 4;
+    public fun secp256k1_ecdsa_authenticator_enabled(): bool acquires Features {
+        is_enabled(SECP256K1_ECDSA_AUTHENTICATOR)
+    }
+
     // ============================================================================================
     // Feature Flag Implementation
 
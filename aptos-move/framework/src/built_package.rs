@@ -221,6 +221,23 @@ impl BuiltPackage {
             })
     }
 
+    /// Returns an iterator for all compiled proper (non-script) modules, including the ones of the
+    /// dependencies.
+    pub fn all_modules(&self) -> impl Iterator<Item = &CompiledModule> {
+        self.package
+            .root_modules()
+            .chain(
+                self.package
+                    .deps_compiled_units
+                    .iter()
+                    .map(|(_, unit)| unit),
+            )
+            .filter_map(|unit| match &unit.unit {
+                CompiledUnit::Module(NamedCompiledModule { module, .. }) => Some(module),
+                CompiledUnit::Script(_) => None,
+            })
+    }
+
     /// Returns the number of scripts in the package.
     pub fn script_count(&self) -> usize {
         self.package.scripts().count()
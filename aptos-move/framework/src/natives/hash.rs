@@ -10,6 +10,13 @@ use move_vm_types::{
     loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
 };
 
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+use ripemd160::Ripemd160;
+use sha2::{Digest, Sha512};
+use sha3::Sha3_512;
 use smallvec::smallvec;
 use std::{collections::VecDeque, hash::Hasher};
 use tiny_keccak::{Hasher as KeccakHasher, Keccak};
@@ -75,6 +82,106 @@ fn native_keccak256(
     Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
 }
 
+#[derive(Debug, Clone)]
+pub struct Sha2_512HashGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_sha2_512(
+    gas_params: &Sha2_512HashGasParameters,
+    _context: &mut NativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+
+    let output = Sha512::digest(&bytes).to_vec();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
+}
+
+#[derive(Debug, Clone)]
+pub struct Sha3_512HashGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_sha3_512(
+    gas_params: &Sha3_512HashGasParameters,
+    _context: &mut NativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+
+    let output = Sha3_512::digest(&bytes).to_vec();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
+}
+
+#[derive(Debug, Clone)]
+pub struct Blake2b256HashGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_blake2b_256(
+    gas_params: &Blake2b256HashGasParameters,
+    _context: &mut NativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+
+    // BLAKE2b with a 32 byte output, which is NOT the same as BLAKE2b-512 truncated to 32 bytes.
+    let mut hasher = VarBlake2b::new(32).expect("32 is a valid BLAKE2b output size");
+    hasher.update(&bytes);
+    let mut output = vec![];
+    hasher.finalize_variable(|hash| output.extend_from_slice(hash));
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
+}
+
+#[derive(Debug, Clone)]
+pub struct Ripemd160HashGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_ripemd160(
+    gas_params: &Ripemd160HashGasParameters,
+    _context: &mut NativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes.len() as u64);
+
+    let output = Ripemd160::digest(&bytes).to_vec();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
+}
+
 /***************************************************************************************************
  * module
  *
@@ -83,6 +190,10 @@ fn native_keccak256(
 pub struct GasParameters {
     pub sip_hash: SipHashGasParameters,
     pub keccak256: Keccak256HashGasParameters,
+    pub sha2_512: Sha2_512HashGasParameters,
+    pub sha3_512: Sha3_512HashGasParameters,
+    pub blake2b_256: Blake2b256HashGasParameters,
+    pub ripemd160: Ripemd160HashGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
@@ -95,6 +206,22 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
             "keccak256",
             make_native_from_func(gas_params.keccak256, native_keccak256),
         ),
+        (
+            "sha2_512",
+            make_native_from_func(gas_params.sha2_512, native_sha2_512),
+        ),
+        (
+            "sha3_512",
+            make_native_from_func(gas_params.sha3_512, native_sha3_512),
+        ),
+        (
+            "blake2b_256",
+            make_native_from_func(gas_params.blake2b_256, native_blake2b_256),
+        ),
+        (
+            "ripemd160",
+            make_native_from_func(gas_params.ripemd160, native_ripemd160),
+        ),
     ];
 
     crate::natives::helpers::make_module_natives(natives)
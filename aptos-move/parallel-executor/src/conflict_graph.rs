@@ -0,0 +1,38 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{scheduler::TxnIndex, task::Accesses};
+use std::{collections::HashMap, hash::Hash};
+
+/// Builds the conflict graph of a block from the inferred accesses of its transactions, and
+/// returns, for each transaction, the closest preceding transaction it conflicts with, if any.
+///
+/// A transaction conflicts with a preceding one if it accesses (reads or writes) a key that the
+/// preceding transaction is inferred to write. Executing the former before the latter would
+/// likely lead to an abort. Transactions without inferred accesses conflict with none, and
+/// nothing is assumed about the keys they write.
+pub(crate) fn preceding_conflicts<K: Hash + Eq + Clone>(
+    accesses: &[Option<Accesses<K>>],
+) -> Vec<Option<TxnIndex>> {
+    let mut last_writers: HashMap<K, TxnIndex> = HashMap::new();
+
+    accesses
+        .iter()
+        .enumerate()
+        .map(|(txn_idx, txn_accesses)| {
+            let txn_accesses = txn_accesses.as_ref()?;
+
+            let conflict = txn_accesses
+                .keys_read
+                .iter()
+                .chain(&txn_accesses.keys_written)
+                .filter_map(|key| last_writers.get(key).copied())
+                .max();
+            for key in &txn_accesses.keys_written {
+                last_writers.insert(key.clone(), txn_idx);
+            }
+
+            conflict
+        })
+        .collect()
+}
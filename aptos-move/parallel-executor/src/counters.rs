@@ -21,3 +21,12 @@ pub static SPECULATIVE_ABORT_COUNT: Lazy<IntCounter> = Lazy::new(|| {
     )
     .unwrap()
 });
+
+/// Count of transactions whose first execution was deferred by the pre-ordering pass.
+pub static PREORDERING_DEFERRED_TXN_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_execution_preordering_deferred_txn_count",
+        "Number of transactions deferred until a preceding conflicting transaction was executed"
+    )
+    .unwrap()
+});
@@ -2,11 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    conflict_graph::preceding_conflicts,
     counters,
     errors::*,
     output_delta_resolver::OutputDeltaResolver,
     scheduler::{Scheduler, SchedulerTask, TaskGuard, TxnIndex, Version},
-    task::{ExecutionStatus, ExecutorTask, ModulePath, Transaction, TransactionOutput},
+    task::{
+        ExecutionStatus, ExecutorTask, ModulePath, ReadWriteSetInferencer, Transaction,
+        TransactionOutput,
+    },
     txn_last_input_output::{ReadDescriptor, TxnLastInputOutput},
 };
 use aptos_aggregator::delta_change_set::DeltaOp;
@@ -367,15 +371,77 @@ where
         ),
         E::Error,
     > {
-        let versioned_data_cache = MVHashMap::new();
+        if signature_verified_block.is_empty() {
+            return Ok((vec![], OutputDeltaResolver::new(MVHashMap::new())));
+        }
 
+        let scheduler = Scheduler::new(signature_verified_block.len());
+        self.execute_transactions_parallel_with_scheduler(
+            executor_initial_arguments,
+            signature_verified_block,
+            scheduler,
+        )
+    }
+
+    /// Same as 'execute_transactions_parallel', but first uses the inferencer to build the
+    /// conflict graph of the block. The first incarnation of a transaction that is inferred to
+    /// conflict with a preceding transaction is deferred until the latter is executed, so that
+    /// independent transactions get dispatched first. The outputs are the same as without
+    /// pre-ordering: the transactions for which the inference is inconclusive (or all of them,
+    /// if the inferred accesses don't match the block) are scheduled by Block-STM as usual.
+    pub fn execute_transactions_parallel_with_preordering<I>(
+        &self,
+        executor_initial_arguments: E::Argument,
+        signature_verified_block: Vec<T>,
+        inferencer: &I,
+    ) -> Result<
+        (
+            Vec<E::Output>,
+            OutputDeltaResolver<<T as Transaction>::Key, <T as Transaction>::Value>,
+        ),
+        E::Error,
+    >
+    where
+        I: ReadWriteSetInferencer<T = T>,
+    {
         if signature_verified_block.is_empty() {
-            return Ok((vec![], OutputDeltaResolver::new(versioned_data_cache)));
+            return Ok((vec![], OutputDeltaResolver::new(MVHashMap::new())));
         }
 
+        let num_txns = signature_verified_block.len();
+        let accesses = inferencer.infer_reads_writes(&signature_verified_block);
+        let scheduler = if accesses.len() == num_txns {
+            let dependencies = preceding_conflicts(&accesses);
+            counters::PREORDERING_DEFERRED_TXN_COUNT
+                .inc_by(dependencies.iter().flatten().count() as u64);
+            Scheduler::new_with_dependencies(dependencies)
+        } else {
+            Scheduler::new(num_txns)
+        };
+
+        self.execute_transactions_parallel_with_scheduler(
+            executor_initial_arguments,
+            signature_verified_block,
+            scheduler,
+        )
+    }
+
+    fn execute_transactions_parallel_with_scheduler(
+        &self,
+        executor_initial_arguments: E::Argument,
+        signature_verified_block: Vec<T>,
+        scheduler: Scheduler,
+    ) -> Result<
+        (
+            Vec<E::Output>,
+            OutputDeltaResolver<<T as Transaction>::Key, <T as Transaction>::Value>,
+        ),
+        E::Error,
+    > {
+        let versioned_data_cache = MVHashMap::new();
+
         let num_txns = signature_verified_block.len();
         let last_input_output = TxnLastInputOutput::new(num_txns);
-        let scheduler = Scheduler::new(num_txns);
 
         RAYON_EXEC_POOL.scope(|s| {
             for _ in 0..self.concurrency_level {
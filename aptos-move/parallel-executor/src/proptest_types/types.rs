@@ -5,8 +5,8 @@ use crate::{
     errors::{Error, Result},
     executor::{MVHashMapView, ReadResult},
     task::{
-        ExecutionStatus, ExecutorTask, ModulePath, Transaction as TransactionType,
-        TransactionOutput,
+        Accesses, ExecutionStatus, ExecutorTask, ModulePath, ReadWriteSetInferencer,
+        Transaction as TransactionType, TransactionOutput,
     },
 };
 use aptos_aggregator::{
@@ -389,6 +389,51 @@ where
     }
 }
 
+///////////////////////////////////////////////////////////////////////////
+// Naive read/write-set inferencer implementation.
+///////////////////////////////////////////////////////////////////////////
+
+/// Infers the accesses of a transaction from the read- and write-sets of its first incarnation.
+/// The inference is inconclusive for transactions that don't write.
+pub struct Inferencer<K, V>(PhantomData<(K, V)>);
+
+impl<K, V> Inferencer<K, V> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<K, V> ReadWriteSetInferencer for Inferencer<K, V>
+where
+    K: PartialOrd + Send + Sync + Clone + Hash + Eq + ModulePath + 'static,
+    V: Send + Sync + Debug + Clone + TransactionWrite + 'static,
+{
+    type T = Transaction<K, V>;
+
+    fn infer_reads_writes(&self, txns: &[Self::T]) -> Vec<Option<Accesses<K>>> {
+        txns.iter()
+            .map(|txn| match txn {
+                Transaction::Write {
+                    reads,
+                    writes_and_deltas,
+                    ..
+                } => {
+                    let (writes, deltas) = &writes_and_deltas[0];
+                    Some(Accesses {
+                        keys_read: reads[0].clone(),
+                        keys_written: writes
+                            .iter()
+                            .map(|(k, _)| k.clone())
+                            .chain(deltas.iter().map(|(k, _)| k.clone()))
+                            .collect(),
+                    })
+                }
+                Transaction::SkipRest | Transaction::Abort => None,
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug)]
 pub struct Output<K, V>(Vec<(K, V)>, Vec<(K, DeltaOp)>, Vec<ReadResult<V>>);
 
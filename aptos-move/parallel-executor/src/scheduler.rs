@@ -66,7 +66,15 @@ pub enum SchedulerTask<'a> {
 /// to 'ReadyToExecute(incarnation + 1)', allowing the scheduler to create an execution
 /// task for the next incarnation of the transaction.
 ///
+/// 'Deferred' is the initial status of a transaction whose first incarnation should not be
+/// executed before a preceding transaction, which it is inferred to conflict with, finishes its
+/// execution (see 'Scheduler::new_with_dependencies'). It becomes 'ReadyToExecute(0)' once that
+/// transaction is executed, the same way a suspended transaction is resumed.
+///
 /// Status transition diagram:
+/// Deferred
+///    |  resume (preceding conflicting transaction executed)
+///    ↓
 /// Ready(i)
 ///    |  try_incarnate (incarnate successfully)
 ///    |
@@ -83,6 +91,7 @@ pub enum SchedulerTask<'a> {
 ///
 #[derive(Debug)]
 enum TransactionStatus {
+    Deferred,
     ReadyToExecute(Incarnation, Option<DependencyCondvar>),
     Executing(Incarnation),
     Suspended(Incarnation, DependencyCondvar),
@@ -99,6 +108,7 @@ impl PartialEq for TransactionStatus {
             | (&Suspended(ref a, _), &Suspended(ref b, _))
             | (&Executed(ref a), &Executed(ref b))
             | (&Aborting(ref a), &Aborting(ref b)) => a == b,
+            (&Deferred, &Deferred) => true,
             _ => false,
         }
     }
@@ -156,6 +166,29 @@ impl Scheduler {
         }
     }
 
+    /// Creates a scheduler where the first incarnation of each transaction with a dependency is
+    /// deferred until the execution of the dependency finishes, which must be a preceding
+    /// transaction. Meanwhile, the scheduler dispatches the following transactions instead.
+    pub fn new_with_dependencies(dependencies: Vec<Option<TxnIndex>>) -> Self {
+        let scheduler = Self::new(dependencies.len());
+
+        for (txn_idx, dependency) in dependencies.into_iter().enumerate() {
+            if let Some(dep_txn_idx) = dependency {
+                assert!(
+                    dep_txn_idx < txn_idx,
+                    "Transaction {} can't be deferred until transaction {} is executed",
+                    txn_idx,
+                    dep_txn_idx
+                );
+
+                *scheduler.txn_status[txn_idx].lock() = TransactionStatus::Deferred;
+                scheduler.txn_dependency[dep_txn_idx].lock().push(txn_idx);
+            }
+        }
+
+        scheduler
+    }
+
     /// Return the number of transactions to be executed from the block.
     pub fn num_txn_to_execute(&self) -> usize {
         self.num_txns
@@ -266,8 +299,8 @@ impl Scheduler {
         let min_dep = txn_deps
             .into_iter()
             .map(|dep| {
-                // Mark the status of dependencies as 'ReadyToExecute' since dependency on
-                // transaction txn_idx is now resolved.
+                // Mark the status of dependencies (either suspended or deferred) as
+                // 'ReadyToExecute' since dependency on transaction txn_idx is now resolved.
                 self.resume(dep);
 
                 dep
@@ -458,15 +491,17 @@ impl Scheduler {
     }
 
     /// When a dependency is resolved, mark the transaction as ReadyToExecute with an
-    /// incremented incarnation number.
-    /// The caller must ensure that the transaction is in the Suspended state.
+    /// incremented incarnation number, or with the first incarnation if it was deferred.
+    /// The caller must ensure that the transaction is in the Suspended or Deferred state.
     fn resume(&self, txn_idx: TxnIndex) {
         let mut status = self.txn_status[txn_idx].lock();
-        if let TransactionStatus::Suspended(incarnation, dep_condvar) = &*status {
-            *status = TransactionStatus::ReadyToExecute(*incarnation, Some(dep_condvar.clone()));
-        } else {
-            unreachable!();
-        }
+        *status = match &*status {
+            TransactionStatus::Suspended(incarnation, dep_condvar) => {
+                TransactionStatus::ReadyToExecute(*incarnation, Some(dep_condvar.clone()))
+            }
+            TransactionStatus::Deferred => TransactionStatus::ReadyToExecute(0, None),
+            _ => unreachable!(),
+        };
     }
 
     /// Set status of the transaction to Executed(incarnation).
@@ -49,6 +49,21 @@ pub struct Accesses<K> {
     pub keys_written: Vec<K>,
 }
 
+/// Trait for inferring the keys that the transactions of a block may access, before executing
+/// them. The inferred accesses are only used as scheduling hints, so they don't need to be sound:
+/// imprecise accesses may cost some parallelism or lead to aborts, but never change the outputs.
+pub trait ReadWriteSetInferencer: Sync {
+    /// Type of transaction and its associated key and value.
+    type T: Transaction;
+
+    /// Returns the inferred accesses of each transaction in the block, in order, or None for the
+    /// transactions for which the inference is inconclusive.
+    fn infer_reads_writes(
+        &self,
+        txns: &[Self::T],
+    ) -> Vec<Option<Accesses<<Self::T as Transaction>::Key>>>;
+}
+
 /// Trait for single threaded transaction executor.
 // TODO: Sync should not be required. Sync is only introduced because this trait occurs as a phantom type of executor struct.
 pub trait ExecutorTask: Sync {
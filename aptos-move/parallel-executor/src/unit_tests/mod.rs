@@ -3,7 +3,7 @@
 
 use crate::{
     executor::ParallelTransactionExecutor,
-    proptest_types::types::{ExpectedOutput, KeyType, Task, Transaction, ValueType},
+    proptest_types::types::{ExpectedOutput, Inferencer, KeyType, Task, Transaction, ValueType},
     scheduler::{Scheduler, SchedulerTask, TaskGuard},
     task::ModulePath,
 };
@@ -30,6 +30,24 @@ where
     baseline.assert_output(&output, None);
 }
 
+fn run_and_assert_with_preordering<K, V>(transactions: Vec<Transaction<K, V>>)
+where
+    K: PartialOrd + Send + Sync + Clone + Hash + Eq + ModulePath + 'static,
+    V: Send + Sync + Debug + Clone + Eq + TransactionWrite + 'static,
+{
+    let output = ParallelTransactionExecutor::<Transaction<K, V>, Task<K, V>>::new(num_cpus::get())
+        .execute_transactions_parallel_with_preordering(
+            (),
+            transactions.clone(),
+            &Inferencer::new(),
+        )
+        .map(|(res, _)| res);
+
+    let baseline = ExpectedOutput::generate_baseline(&transactions, None);
+
+    baseline.assert_output(&output, None);
+}
+
 fn random_value(delete_value: bool) -> ValueType<Vec<u8>> {
     ValueType((0..4).map(|_| (random::<u8>())).collect(), !delete_value)
 }
@@ -155,8 +173,7 @@ fn delta_chains() {
 const TOTAL_KEY_NUM: u64 = 50;
 const WRITES_PER_KEY: u64 = 100;
 
-#[test]
-fn cycle_transactions() {
+fn cycle_transactions_block() -> Vec<Transaction<KeyType<[u8; 32]>, ValueType<Vec<u8>>>> {
     let mut transactions = vec![];
     // For every key in `TOTAL_KEY_NUM`, generate a series of transactions that will assign a
     // value to this key.
@@ -170,7 +187,17 @@ fn cycle_transactions() {
             })
         }
     }
-    run_and_assert(transactions)
+    transactions
+}
+
+#[test]
+fn cycle_transactions() {
+    run_and_assert(cycle_transactions_block())
+}
+
+#[test]
+fn cycle_transactions_with_preordering() {
+    run_and_assert_with_preordering(cycle_transactions_block())
 }
 
 const NUM_BLOCKS: u64 = 10;
@@ -200,8 +227,7 @@ fn one_reads_all_barrier() {
     run_and_assert(transactions)
 }
 
-#[test]
-fn one_writes_all_barrier() {
+fn one_writes_all_barrier_block() -> Vec<Transaction<KeyType<[u8; 32]>, ValueType<Vec<u8>>>> {
     let mut transactions = vec![];
     let keys: Vec<KeyType<_>> = (0..TXN_PER_BLOCK)
         .map(|_| KeyType(random::<[u8; 32]>(), false))
@@ -226,7 +252,17 @@ fn one_writes_all_barrier() {
             )],
         })
     }
-    run_and_assert(transactions)
+    transactions
+}
+
+#[test]
+fn one_writes_all_barrier() {
+    run_and_assert(one_writes_all_barrier_block())
+}
+
+#[test]
+fn one_writes_all_barrier_with_preordering() {
+    run_and_assert_with_preordering(one_writes_all_barrier_block())
 }
 
 #[test]
@@ -598,3 +634,44 @@ fn scheduler_drain_idx() {
 
     assert!(matches!(s.next_task(), SchedulerTask::Done));
 }
+
+#[test]
+fn scheduler_deferred() {
+    // txn 1 is deferred until txn 0 is executed, and txn 3 until txn 1 is executed.
+    let s = Scheduler::new_with_dependencies(vec![None, Some(0), None, Some(1)]);
+    let fake_counter = AtomicUsize::new(0);
+
+    assert!(matches!(
+        s.next_task(),
+        SchedulerTask::ExecutionTask((0, 0), None, _)
+    ));
+    // Deferred txn 1 is skipped.
+    assert!(matches!(
+        s.next_task(),
+        SchedulerTask::ExecutionTask((2, 0), None, _)
+    ));
+
+    // txn 1 is resumed with its first incarnation, and execution index is decreased.
+    assert!(matches!(
+        s.finish_execution(0, 0, false, TaskGuard::new(&fake_counter)),
+        SchedulerTask::ValidationTask((0, 0), _)
+    ));
+    assert!(matches!(
+        s.next_task(),
+        SchedulerTask::ExecutionTask((1, 0), None, _)
+    ));
+
+    // txn 3 is resumed, execution index is already lower.
+    assert!(matches!(
+        s.finish_execution(1, 0, true, TaskGuard::new(&fake_counter)),
+        SchedulerTask::NoTask
+    ));
+    assert!(matches!(
+        s.next_task(),
+        SchedulerTask::ValidationTask((1, 0), _)
+    ));
+    assert!(matches!(
+        s.next_task(),
+        SchedulerTask::ExecutionTask((3, 0), None, _)
+    ));
+}
@@ -11,7 +11,7 @@ use aptos_build_info::build_information;
 use aptos_config::{
     config::{
         AptosDataClientConfig, BaseConfig, DataStreamingServiceConfig, NetworkConfig, NodeConfig,
-        PersistableConfig, StorageServiceConfig,
+        PeerMonitoringServiceConfig, PersistableConfig, StorageServiceConfig,
     },
     network_id::NetworkId,
     utils::get_genesis_txn,
@@ -45,6 +45,12 @@ use log_build_information::log_build_information;
 use mempool_notifications::MempoolNotificationSender;
 use network::application::storage::PeerMetadataStorage;
 use network_builder::builder::NetworkBuilder;
+use peer_monitoring_service_client::{
+    PeerMonitor, PeerMonitorState, PeerMonitoringServiceClient, PeerMonitoringServiceMultiSender,
+};
+use peer_monitoring_service_server::{
+    network::PeerMonitoringServiceNetworkEvents, PeerMonitoringServiceServer,
+};
 use rand::{rngs::StdRng, SeedableRng};
 use state_sync_driver::{
     driver_factory::{DriverFactory, StateSyncRuntimes},
@@ -169,6 +175,7 @@ pub struct AptosHandle {
     _consensus_runtime: Option<Runtime>,
     _mempool: Runtime,
     _network_runtimes: Vec<Runtime>,
+    _peer_monitoring_service_runtime: Runtime,
     _fh_stream: Option<Runtime>,
     _index_runtime: Option<Runtime>,
     _state_sync_runtimes: StateSyncRuntimes,
@@ -390,6 +397,7 @@ fn create_state_sync_runtimes<M: MempoolNotificationSender + 'static>(
         storage_service_client::StorageServiceNetworkSender,
     >,
     peer_metadata_storage: Arc<PeerMetadataStorage>,
+    peer_monitor_state: PeerMonitorState,
     mempool_notifier: M,
     consensus_listener: ConsensusNotificationListener,
     waypoint: Waypoint,
@@ -410,6 +418,7 @@ fn create_state_sync_runtimes<M: MempoolNotificationSender + 'static>(
         node_config.base.clone(),
         storage_service_client_network_handles,
         peer_metadata_storage,
+        peer_monitor_state,
     )?;
 
     // Start the data streaming service
@@ -478,6 +487,7 @@ fn setup_aptos_data_client(
     base_config: BaseConfig,
     network_handles: HashMap<NetworkId, storage_service_client::StorageServiceNetworkSender>,
     peer_metadata_storage: Arc<PeerMetadataStorage>,
+    peer_monitor_state: PeerMonitorState,
 ) -> anyhow::Result<(AptosNetDataClient, Runtime)> {
     // Combine all storage service client handles
     let network_client = StorageServiceClient::new(
@@ -504,6 +514,7 @@ fn setup_aptos_data_client(
         storage_service_config,
         TimeService::real(),
         network_client,
+        peer_monitor_state,
         Some(aptos_data_client_runtime.handle().clone()),
     );
     aptos_data_client_runtime.spawn(data_summary_poller.start_poller());
@@ -544,6 +555,57 @@ fn setup_state_sync_storage_service(
     Ok(storage_service_runtime)
 }
 
+fn setup_peer_monitoring_service(
+    config: PeerMonitoringServiceConfig,
+    server_network_handles: Vec<PeerMonitoringServiceNetworkEvents>,
+    client_network_handles: HashMap<
+        NetworkId,
+        peer_monitoring_service_client::PeerMonitoringServiceNetworkSender,
+    >,
+    peer_metadata_storage: Arc<PeerMetadataStorage>,
+    peer_monitor_state: PeerMonitorState,
+) -> anyhow::Result<Runtime> {
+    // Create a new peer monitoring service runtime
+    let peer_monitoring_service_runtime = Builder::new_multi_thread()
+        .thread_name_fn(|| {
+            static ATOMIC_ID: AtomicUsize = AtomicUsize::new(0);
+            let id = ATOMIC_ID.fetch_add(1, Ordering::SeqCst);
+            format!("peer-mon-{}", id)
+        })
+        .disable_lifo_slot()
+        .enable_all()
+        .build()
+        .map_err(|err| anyhow!("Failed to start the peer monitoring service {}", err))?;
+
+    // Spawn all peer monitoring service servers on the same runtime
+    for events in server_network_handles {
+        let service = PeerMonitoringServiceServer::new(
+            config.clone(),
+            peer_monitoring_service_runtime.handle().clone(),
+            events,
+            peer_metadata_storage.clone(),
+            TimeService::real(),
+        );
+        peer_monitoring_service_runtime.spawn(service.start());
+    }
+
+    // Spawn the peer monitor (which populates the shared peer monitor state)
+    let network_client = PeerMonitoringServiceClient::new(
+        PeerMonitoringServiceMultiSender::new(client_network_handles),
+        peer_metadata_storage,
+    );
+    let peer_monitor = PeerMonitor::new(
+        config,
+        network_client,
+        peer_monitor_state,
+        Some(peer_monitoring_service_runtime.handle().clone()),
+        TimeService::real(),
+    );
+    peer_monitoring_service_runtime.spawn(peer_monitor.start());
+
+    Ok(peer_monitoring_service_runtime)
+}
+
 #[cfg(feature = "indexer")]
 fn bootstrap_indexer(
     node_config: &NodeConfig,
@@ -632,6 +694,8 @@ pub fn setup_environment(
     let mut consensus_network_handles = None;
     let mut storage_service_server_network_handles = vec![];
     let mut storage_service_client_network_handles = HashMap::new();
+    let mut peer_monitoring_service_server_network_handles = vec![];
+    let mut peer_monitoring_service_client_network_handles = HashMap::new();
 
     // Create an event subscription service so that components can be notified of events and reconfigs
     let mut event_subscription_service = EventSubscriptionService::new(
@@ -743,6 +807,18 @@ pub fn setup_environment(
             network_builder.add_client(&storage_service_client::network_endpoint_config());
         storage_service_client_network_handles.insert(network_id, storage_service_sender);
 
+        // Register the peer monitoring service (both client and server) with Network
+        let peer_monitoring_service_events = network_builder.add_service(
+            &peer_monitoring_service_server::network::network_endpoint_config(
+                node_config.peer_monitoring_service.clone(),
+            ),
+        );
+        peer_monitoring_service_server_network_handles.push(peer_monitoring_service_events);
+        let peer_monitoring_service_sender =
+            network_builder.add_client(&peer_monitoring_service_client::network_endpoint_config());
+        peer_monitoring_service_client_network_handles
+            .insert(network_id, peer_monitoring_service_sender);
+
         // Create the endpoints to connect the Network to mempool.
         let (mempool_sender, mempool_events) = network_builder.add_p2p_service(
             &aptos_mempool::network::network_endpoint_config(MEMPOOL_NETWORK_CHANNEL_BUFFER_SIZE),
@@ -773,6 +849,17 @@ pub fn setup_environment(
     // TODO set up on-chain discovery network based on UpstreamConfig.fallback_network
     // and pass network handles to mempool/state sync
 
+    // Start the peer monitoring service. The peer monitor state is shared
+    // with state sync and mempool to help with peer selection.
+    let peer_monitor_state = PeerMonitorState::new();
+    let peer_monitoring_service_runtime = setup_peer_monitoring_service(
+        node_config.peer_monitoring_service.clone(),
+        peer_monitoring_service_server_network_handles,
+        peer_monitoring_service_client_network_handles,
+        peer_metadata_storage.clone(),
+        peer_monitor_state.clone(),
+    )?;
+
     // For state sync to send notifications to mempool and receive notifications from consensus.
     let (mempool_notifier, mempool_listener) =
         mempool_notifications::new_mempool_notifier_listener_pair();
@@ -790,6 +877,7 @@ pub fn setup_environment(
         storage_service_server_network_handles,
         storage_service_client_network_handles,
         peer_metadata_storage.clone(),
+        peer_monitor_state.clone(),
         mempool_notifier,
         consensus_listener,
         genesis_waypoint,
@@ -835,13 +923,10 @@ pub fn setup_environment(
         mempool_listener,
         mempool_reconfig_subscription,
         peer_metadata_storage.clone(),
+        peer_monitor_state,
     );
     debug!("Mempool started in {} ms", instant.elapsed().as_millis());
 
-    assert!(
-        !node_config.consensus.use_quorum_store,
-        "QuorumStore is not yet implemented"
-    );
     assert_ne!(
         node_config.consensus.use_quorum_store,
         node_config.mempool.shared_mempool_validator_broadcast,
@@ -882,6 +967,7 @@ pub fn setup_environment(
         _consensus_runtime: consensus_runtime,
         _mempool: mempool,
         _network_runtimes: network_runtimes,
+        _peer_monitoring_service_runtime: peer_monitoring_service_runtime,
         _index_runtime: index_runtime,
         _fh_stream: sf_runtime,
         _state_sync_runtimes: state_sync_runtimes,
@@ -30,12 +30,19 @@ pub struct ApiConfig {
     pub transaction_submission_enabled: bool,
     #[serde(default = "default_enabled")]
     pub transaction_simulation_enabled: bool,
+    /// Allows evicting transactions from mempool, e.g. stuck ones, through the API
+    #[serde(default = "default_disabled")]
+    pub mempool_eviction_enabled: bool,
 
     pub max_submit_transaction_batch_size: usize,
 
     /// Maximum page size for paginated APIs
     pub max_transactions_page_size: u16,
     pub max_events_page_size: u16,
+    /// Maximum gas units a single view function call may consume
+    pub max_gas_view_function: u64,
+    /// Interval at which transaction streams check for newly committed transactions
+    pub transaction_stream_poll_interval_ms: u64,
 }
 
 pub const DEFAULT_ADDRESS: &str = "127.0.0.1";
@@ -43,6 +50,8 @@ pub const DEFAULT_PORT: u16 = 8080;
 pub const DEFAULT_REQUEST_CONTENT_LENGTH_LIMIT: u64 = 8 * 1024 * 1024; // 8 MB
 pub const DEFAULT_MAX_SUBMIT_TRANSACTION_BATCH_SIZE: usize = 10;
 pub const DEFAULT_MAX_PAGE_SIZE: u16 = 100;
+pub const DEFAULT_MAX_VIEW_GAS: u64 = 2_000_000;
+pub const DEFAULT_TRANSACTION_STREAM_POLL_INTERVAL_MS: u64 = 100;
 
 fn default_enabled() -> bool {
     true
@@ -68,9 +77,12 @@ impl Default for ApiConfig {
             encode_submission_enabled: default_enabled(),
             transaction_submission_enabled: default_enabled(),
             transaction_simulation_enabled: default_enabled(),
+            mempool_eviction_enabled: default_disabled(),
             max_submit_transaction_batch_size: DEFAULT_MAX_SUBMIT_TRANSACTION_BATCH_SIZE,
             max_transactions_page_size: DEFAULT_MAX_PAGE_SIZE,
             max_events_page_size: DEFAULT_MAX_PAGE_SIZE,
+            max_gas_view_function: DEFAULT_MAX_VIEW_GAS,
+            transaction_stream_poll_interval_ms: DEFAULT_TRANSACTION_STREAM_POLL_INTERVAL_MS,
         }
     }
 }
@@ -32,6 +32,7 @@ pub struct ConsensusConfig {
     // the period = (poll_count - 1) * 30ms
     pub quorum_store_poll_count: u64,
     pub intra_consensus_channel_buffer_size: usize,
+    pub quorum_store: QuorumStoreConfig,
 }
 
 impl Default for ConsensusConfig {
@@ -59,6 +60,44 @@ impl Default for ConsensusConfig {
             quorum_store_pull_timeout_ms: 1000,
             quorum_store_poll_count: 10,
             intra_consensus_channel_buffer_size: 10,
+            quorum_store: QuorumStoreConfig::default(),
+        }
+    }
+}
+
+/// Parameters of the batch-dissemination Quorum Store, only used when `use_quorum_store` is set.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QuorumStoreConfig {
+    // How often a new batch is pulled from mempool and broadcast (in milliseconds)
+    pub batch_generation_interval_ms: u64,
+    pub max_batch_txns: u64,
+    pub max_batch_bytes: u64,
+    // Number of rounds after the latest committed round that a locally created batch stays valid
+    pub batch_expiry_round_gap: u64,
+    // Batches from other validators that expire further than this gap in the future are rejected
+    pub max_batch_expiry_round_gap: u64,
+    // How long a locally created batch waits for a proof of store before it is dropped (in milliseconds)
+    pub proof_timeout_ms: u64,
+    // Timeout of a single batch request RPC to a remote signer (in milliseconds)
+    pub batch_request_timeout_ms: u64,
+    // Maximum number of batches a validator may have in flight without a proof
+    pub max_pending_batches: usize,
+    pub channel_size: usize,
+}
+
+impl Default for QuorumStoreConfig {
+    fn default() -> QuorumStoreConfig {
+        QuorumStoreConfig {
+            batch_generation_interval_ms: 50,
+            max_batch_txns: 250,
+            max_batch_bytes: 500 * 1024, // 500 KB
+            batch_expiry_round_gap: 100,
+            max_batch_expiry_round_gap: 200,
+            proof_timeout_ms: 10000,
+            batch_request_timeout_ms: 5000,
+            max_pending_batches: 20,
+            channel_size: 1000,
         }
     }
 }
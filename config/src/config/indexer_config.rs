@@ -26,6 +26,13 @@ pub struct IndexerConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub processor: Option<String>,
 
+    /// The processors that it will run over the same stream of transactions, ex:
+    /// ["default_processor", "coin_processor"]. Processors registered by other crates can be run
+    /// alongside the built-in ones. Takes precedence over `processor`.
+    /// Alternatively can set the `PROCESSOR_NAMES` env var, comma separated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processors: Option<Vec<String>>,
+
     /// If set, will ignore database contents and start processing from the specified version.
     /// This will not delete any database contents, just transactions as it reprocesses them.
     /// Alternatively can set the `STARTING_VERSION` env var
@@ -22,11 +22,16 @@ pub struct MempoolConfig {
     pub shared_mempool_batch_size: usize,
     pub shared_mempool_max_batch_bytes: u64,
     pub shared_mempool_max_concurrent_inbound_syncs: usize,
+    // interval at which the upstream peers are re-prioritized (e.g., using the peer latencies)
+    pub shared_mempool_peer_update_interval_ms: u64,
     pub shared_mempool_tick_interval_ms: u64,
     pub system_transaction_timeout_secs: u64,
     pub system_transaction_gc_interval_ms: u64,
     pub shared_mempool_validator_broadcast: bool,
     pub broadcast_buckets: Vec<u64>,
+    // minimum gas unit price increase, in percent, for a transaction to replace a pending
+    // transaction with the same sequence number
+    pub replace_by_fee_min_bump_percentage: u64,
 }
 
 impl Default for MempoolConfig {
@@ -38,6 +43,7 @@ impl Default for MempoolConfig {
             shared_mempool_max_batch_bytes: MAX_APPLICATION_MESSAGE_SIZE as u64,
             shared_mempool_ack_timeout_ms: 2_000,
             shared_mempool_max_concurrent_inbound_syncs: 4,
+            shared_mempool_peer_update_interval_ms: 1_000,
             max_broadcasts_per_peer: 1,
             mempool_snapshot_interval_secs: 180,
             capacity: 2_000_000,
@@ -48,6 +54,7 @@ impl Default for MempoolConfig {
             system_transaction_gc_interval_ms: 60_000,
             shared_mempool_validator_broadcast: true,
             broadcast_buckets: DEFAULT_BROADCAST_BUCKETS.to_vec(),
+            replace_by_fee_min_bump_percentage: 10,
         }
     }
 }
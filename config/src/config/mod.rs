@@ -334,6 +334,19 @@ impl NodeConfig {
             None,
         );
 
+        self.indexer.processors = match std::env::var("PROCESSOR_NAMES").ok() {
+            Some(names) => Some(
+                names
+                    .split(',')
+                    .map(|name| name.trim().to_string())
+                    .collect(),
+            ),
+            None => self
+                .indexer
+                .processors
+                .or_else(|| self.indexer.processor.clone().map(|name| vec![name])),
+        };
+
         self.indexer.starting_version = match std::env::var("STARTING_VERSION").ok() {
             None => self.indexer.starting_version,
             Some(s) => match s.parse::<u64>() {
@@ -331,15 +331,25 @@ impl NetworkConfig {
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct PeerMonitoringServiceConfig {
-    pub max_concurrent_requests: u64, // Max num of concurrent server tasks
-    pub max_network_channel_size: u64, // Max num of pending network messages
+    pub depth_from_validators_interval_ms: u64, // Interval (ms) between depth requests to each peer
+    pub latency_ping_interval_ms: u64,          // Interval (ms) between latency pings to each peer
+    pub max_concurrent_requests: u64,           // Max num of concurrent server tasks
+    pub max_network_channel_size: u64,          // Max num of pending network messages
+    pub max_num_latency_pings_to_retain: u64,   // Max num of latency pings to retain per peer
+    pub peer_monitor_interval_ms: u64, // Interval (ms) at which the client checks which peers to poll
+    pub request_timeout_ms: u64,       // Timeout (ms) for each request sent by the client
 }
 
 impl Default for PeerMonitoringServiceConfig {
     fn default() -> Self {
         Self {
+            depth_from_validators_interval_ms: 60_000, // 1 minute
+            latency_ping_interval_ms: 30_000,          // 30 seconds
             max_concurrent_requests: 1000,
             max_network_channel_size: 1000,
+            max_num_latency_pings_to_retain: 10,
+            peer_monitor_interval_ms: 1000,
+            request_timeout_ms: 10_000,
         }
     }
 }
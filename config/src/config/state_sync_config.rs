@@ -165,11 +165,20 @@ impl Default for DataStreamingServiceConfig {
     }
 }
 
+/// The peer selection policy determines how the data client chooses
+/// between the peers that are able to service a request.
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum PeerSelectionPolicy {
+    Random,   // Selects a serviceable peer uniformly at random
+    Weighted, // Weights serviceable peers by latency, throughput, errors and distance to validators
+}
+
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct AptosDataClientConfig {
     pub max_num_in_flight_priority_polls: u64, // Max num of in-flight polls for priority peers
     pub max_num_in_flight_regular_polls: u64,  // Max num of in-flight polls for regular peers
+    pub peer_selection_policy: PeerSelectionPolicy, // The policy used to select peers for requests
     pub response_timeout_ms: u64, // Timeout (in milliseconds) when waiting for a response
     pub summary_poll_interval_ms: u64, // Interval (in milliseconds) between data summary polls
     pub use_compression: bool,    // Whether or not to request compression for incoming data
@@ -180,6 +189,7 @@ impl Default for AptosDataClientConfig {
         Self {
             max_num_in_flight_priority_polls: 10,
             max_num_in_flight_regular_polls: 10,
+            peer_selection_policy: PeerSelectionPolicy::Weighted,
             response_timeout_ms: 20000, // 20 seconds
             summary_poll_interval_ms: 200,
             use_compression: true,
@@ -1,11 +1,16 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::proof_of_store::ProofOfStore;
+use anyhow::{bail, ensure};
 use aptos_crypto::HashValue;
-use aptos_types::{account_address::AccountAddress, transaction::SignedTransaction};
+use aptos_types::{
+    account_address::AccountAddress, transaction::SignedTransaction,
+    validator_verifier::ValidatorVerifier,
+};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::{fmt, fmt::Write};
+use std::{collections::HashSet, fmt, fmt::Write};
 
 /// The round of a block is a consensus-internal counter, which starts with 0 and increases
 /// monotonically. It is used for the protocol safety and liveness (please see the detailed
@@ -37,6 +42,9 @@ pub struct RejectedTransactionSummary {
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub enum Payload {
     DirectMempool(Vec<SignedTransaction>),
+    /// Proofs of store of the batches to execute, the transactions are fetched from the
+    /// quorum store before execution.
+    InQuorumStore(Vec<ProofOfStore>),
 }
 
 impl Payload {
@@ -47,12 +55,17 @@ impl Payload {
     pub fn len(&self) -> usize {
         match self {
             Payload::DirectMempool(txns) => txns.len(),
+            Payload::InQuorumStore(proofs) => proofs
+                .iter()
+                .map(|proof| proof.info().num_txns as usize)
+                .sum(),
         }
     }
 
     pub fn is_empty(&self) -> bool {
         match self {
             Payload::DirectMempool(txns) => txns.is_empty(),
+            Payload::InQuorumStore(proofs) => proofs.is_empty(),
         }
     }
 
@@ -64,18 +77,40 @@ impl Payload {
                 .with_min_len(100)
                 .map(|txn| txn.raw_txn_bytes_len())
                 .sum(),
+            Payload::InQuorumStore(proofs) => proofs
+                .iter()
+                .map(|proof| proof.info().num_bytes as usize)
+                .sum(),
         }
     }
-}
-
-// TODO: What I really want is an iterator that isn't necessarily a vector (e.g., read lazily from RocksDB). This doesn't seem like the way.
-impl IntoIterator for Payload {
-    type Item = SignedTransaction;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        match self {
-            Payload::DirectMempool(txns) => txns.into_iter(),
+    /// Verifies that the payload matches the quorum store mode and that every proof of store
+    /// is certified by a quorum. An empty payload is accepted in either mode.
+    pub fn verify(
+        &self,
+        validator: &ValidatorVerifier,
+        quorum_store_enabled: bool,
+    ) -> anyhow::Result<()> {
+        match (quorum_store_enabled, self) {
+            (false, Payload::DirectMempool(_)) => Ok(()),
+            (true, Payload::DirectMempool(txns)) if txns.is_empty() => Ok(()),
+            (true, Payload::InQuorumStore(proofs)) => {
+                let mut digests = HashSet::new();
+                for proof in proofs {
+                    ensure!(
+                        digests.insert(*proof.digest()),
+                        "Duplicate batch {} in payload",
+                        proof.digest()
+                    );
+                    proof.verify(validator)?;
+                }
+                Ok(())
+            }
+            (_, _) => bail!(
+                "Wrong payload type (quorum store enabled: {}): {}",
+                quorum_store_enabled,
+                self
+            ),
         }
     }
 }
@@ -86,6 +121,9 @@ impl fmt::Display for Payload {
             Payload::DirectMempool(txns) => {
                 write!(f, "InMemory txns: {}", txns.len())
             }
+            Payload::InQuorumStore(proofs) => {
+                write!(f, "InQuorumStore proofs: {}", proofs.len())
+            }
         }
     }
 }
@@ -94,27 +132,40 @@ impl fmt::Display for Payload {
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub enum PayloadFilter {
     DirectMempool(Vec<TransactionSummary>),
+    InQuorumStore(HashSet<HashValue>),
 }
 
 impl From<&Vec<&Payload>> for PayloadFilter {
     fn from(exclude_payloads: &Vec<&Payload>) -> Self {
-        if exclude_payloads.is_empty() {
-            return PayloadFilter::DirectMempool(vec![]);
-        }
-        match exclude_payloads.first().unwrap() {
-            Payload::DirectMempool(_) => {
+        // Empty direct mempool payloads can show up in either mode, so they are skipped when
+        // deciding on the filter type.
+        let first_non_empty = exclude_payloads.iter().find(|payload| !payload.is_empty());
+        match first_non_empty {
+            None | Some(Payload::DirectMempool(_)) => {
                 let mut exclude_txns = vec![];
                 for payload in exclude_payloads {
-                    let Payload::DirectMempool(txns) = payload;
-                    for txn in txns {
-                        exclude_txns.push(TransactionSummary {
-                            sender: txn.sender(),
-                            sequence_number: txn.sequence_number(),
-                        });
+                    if let Payload::DirectMempool(txns) = payload {
+                        for txn in txns {
+                            exclude_txns.push(TransactionSummary {
+                                sender: txn.sender(),
+                                sequence_number: txn.sequence_number(),
+                            });
+                        }
                     }
                 }
                 PayloadFilter::DirectMempool(exclude_txns)
             }
+            Some(Payload::InQuorumStore(_)) => {
+                let mut exclude_proofs = HashSet::new();
+                for payload in exclude_payloads {
+                    if let Payload::InQuorumStore(proofs) = payload {
+                        for proof in proofs {
+                            exclude_proofs.insert(*proof.digest());
+                        }
+                    }
+                }
+                PayloadFilter::InQuorumStore(exclude_proofs)
+            }
         }
     }
 }
@@ -129,6 +180,13 @@ impl fmt::Display for PayloadFilter {
                 }
                 write!(f, "{}", txns_str)
             }
+            PayloadFilter::InQuorumStore(excluded_proofs) => {
+                let mut proofs_str = "".to_string();
+                for proof in excluded_proofs.iter() {
+                    write!(proofs_str, "{} ", proof)?;
+                }
+                write!(f, "{}", proofs_str)
+            }
         }
     }
 }
@@ -0,0 +1,218 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{Author, Round};
+use anyhow::{ensure, Context};
+use aptos_crypto::{bls12381, CryptoMaterialError, HashValue};
+use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
+use aptos_types::{
+    aggregate_signature::AggregateSignature, validator_signer::ValidatorSigner,
+    validator_verifier::ValidatorVerifier,
+};
+use serde::{Deserialize, Serialize};
+use short_hex_str::AsShortHexStr;
+use std::fmt::{Display, Formatter};
+
+/// Logical time used by the quorum store to expire batches, ordered by (epoch, round).
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct LogicalTime {
+    epoch: u64,
+    round: Round,
+}
+
+impl LogicalTime {
+    pub fn new(epoch: u64, round: Round) -> Self {
+        Self { epoch, round }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    pub fn round(&self) -> Round {
+        self.round
+    }
+}
+
+impl Display for LogicalTime {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "({}, {})", self.epoch, self.round)
+    }
+}
+
+/// The information a validator signs to attest that it stores a batch until its expiration.
+#[derive(
+    Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, CryptoHasher, BCSCryptoHash,
+)]
+pub struct SignedDigestInfo {
+    pub batch_author: Author,
+    pub digest: HashValue,
+    pub expiration: LogicalTime,
+    pub num_txns: u64,
+    pub num_bytes: u64,
+}
+
+impl SignedDigestInfo {
+    pub fn new(
+        batch_author: Author,
+        digest: HashValue,
+        expiration: LogicalTime,
+        num_txns: u64,
+        num_bytes: u64,
+    ) -> Self {
+        Self {
+            batch_author,
+            digest,
+            expiration,
+            num_txns,
+            num_bytes,
+        }
+    }
+}
+
+impl Display for SignedDigestInfo {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "[author: {}, digest: {}, expiration: {}, txns: {}, bytes: {}]",
+            self.batch_author.short_str(),
+            self.digest,
+            self.expiration,
+            self.num_txns,
+            self.num_bytes
+        )
+    }
+}
+
+/// A single validator's signature over a `SignedDigestInfo`, sent back to the batch author.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SignedDigest {
+    epoch: u64,
+    signer: Author,
+    info: SignedDigestInfo,
+    signature: bls12381::Signature,
+}
+
+impl SignedDigest {
+    pub fn new(
+        epoch: u64,
+        info: SignedDigestInfo,
+        validator_signer: &ValidatorSigner,
+    ) -> Result<Self, CryptoMaterialError> {
+        let signature = validator_signer.sign(&info)?;
+        Ok(Self {
+            epoch,
+            signer: validator_signer.author(),
+            info,
+            signature,
+        })
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    pub fn signer(&self) -> Author {
+        self.signer
+    }
+
+    pub fn info(&self) -> &SignedDigestInfo {
+        &self.info
+    }
+
+    pub fn digest(&self) -> HashValue {
+        self.info.digest
+    }
+
+    pub fn signature(&self) -> &bls12381::Signature {
+        &self.signature
+    }
+
+    /// Verifies that the signature was produced by the signer over the digest info.
+    pub fn verify(&self, validator: &ValidatorVerifier) -> anyhow::Result<()> {
+        ensure!(
+            self.epoch == self.info.expiration.epoch(),
+            "SignedDigest epoch {} does not match the batch expiration {}",
+            self.epoch,
+            self.info.expiration
+        );
+        validator
+            .verify(self.signer, &self.info, &self.signature)
+            .context("Failed to verify SignedDigest")
+    }
+}
+
+impl Display for SignedDigest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "SignedDigest: [signer: {}, {}]",
+            self.signer.short_str(),
+            self.info
+        )
+    }
+}
+
+/// Certifies that a quorum of validators store the batch identified by the digest, so that
+/// proposals can carry the digest instead of the transactions themselves.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ProofOfStore {
+    info: SignedDigestInfo,
+    multi_signature: AggregateSignature,
+}
+
+impl ProofOfStore {
+    pub fn new(info: SignedDigestInfo, multi_signature: AggregateSignature) -> Self {
+        Self {
+            info,
+            multi_signature,
+        }
+    }
+
+    pub fn info(&self) -> &SignedDigestInfo {
+        &self.info
+    }
+
+    pub fn digest(&self) -> &HashValue {
+        &self.info.digest
+    }
+
+    pub fn expiration(&self) -> LogicalTime {
+        self.info.expiration
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.info.expiration.epoch()
+    }
+
+    pub fn multi_signature(&self) -> &AggregateSignature {
+        &self.multi_signature
+    }
+
+    /// Verifies that the proof is signed by a quorum of the validators.
+    pub fn verify(&self, validator: &ValidatorVerifier) -> anyhow::Result<()> {
+        validator
+            .verify_multi_signatures(&self.info, &self.multi_signature)
+            .context("Failed to verify ProofOfStore")
+    }
+
+    /// Returns the validators that signed the proof, i.e. the ones that can serve the batch.
+    pub fn signers(&self, validator: &ValidatorVerifier) -> Vec<Author> {
+        let ordered_validators: Vec<_> = validator.get_ordered_account_addresses_iter().collect();
+        self.multi_signature
+            .get_voter_addresses(&ordered_validators)
+    }
+}
+
+impl Display for ProofOfStore {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ProofOfStore: [{}, signers: {}]",
+            self.info,
+            self.multi_signature.get_num_voters()
+        )
+    }
+}
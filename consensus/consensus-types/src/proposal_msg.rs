@@ -79,7 +79,12 @@ impl ProposalMsg {
         Ok(())
     }
 
-    pub fn verify(&self, validator: &ValidatorVerifier) -> Result<()> {
+    pub fn verify(&self, validator: &ValidatorVerifier, quorum_store_enabled: bool) -> Result<()> {
+        if let Some(payload) = self.proposal.payload() {
+            payload
+                .verify(validator, quorum_store_enabled)
+                .context("Payload verification failed")?;
+        }
         self.proposal
             .validate_signature(validator)
             .map_err(|e| format_err!("{:?}", e))?;
@@ -3,6 +3,7 @@
 
 use crate::common::{Payload, PayloadFilter, Round};
 use anyhow::Result;
+use aptos_crypto::HashValue;
 use futures::channel::oneshot;
 use std::{fmt, fmt::Formatter};
 
@@ -25,6 +26,8 @@ pub enum ConsensusRequest {
         u64,
         // round
         Round,
+        // digests of the quorum store batches committed up to this round
+        Vec<HashValue>,
         // callback to respond to
         oneshot::Sender<Result<ConsensusResponse>>,
     ),
@@ -40,8 +43,14 @@ impl fmt::Display for ConsensusRequest {
                     max_txns, max_bytes, excluded
                 )
             }
-            ConsensusRequest::CleanRequest(epoch, round, _) => {
-                write!(f, "CleanRequest [epoch: {}, round: {}]", epoch, round)
+            ConsensusRequest::CleanRequest(epoch, round, digests, _) => {
+                write!(
+                    f,
+                    "CleanRequest [epoch: {}, round: {}, committed batches: {}]",
+                    epoch,
+                    round,
+                    digests.len()
+                )
             }
         }
     }
@@ -29,14 +29,22 @@ use crate::{
     logging::{LogEvent, LogSchema},
     metrics_safety_rules::MetricsSafetyRules,
     monitor,
-    network::{IncomingBlockRetrievalRequest, NetworkReceivers, NetworkSender},
+    network::{
+        IncomingBatchRetrievalRequest, IncomingBlockRetrievalRequest, NetworkReceivers,
+        NetworkSender,
+    },
     network_interface::{ConsensusMsg, ConsensusNetworkSender},
     payload_manager::QuorumStoreClient,
     persistent_liveness_storage::{LedgerRecoveryData, PersistentLivenessStorage, RecoveryData},
-    quorum_store::direct_mempool_quorum_store::DirectMempoolQuorumStore,
+    quorum_store::{
+        batch_generator::BatchGenerator,
+        batch_store::{BatchReader, BatchStore},
+        direct_mempool_quorum_store::DirectMempoolQuorumStore,
+        quorum_store_coordinator::QuorumStoreCoordinator,
+    },
     recovery_manager::RecoveryManager,
     round_manager::{RoundManager, UnverifiedEvent, VerifiedEvent},
-    state_replication::StateComputer,
+    state_replication::{PayloadManager, StateComputer},
     util::time_service::TimeService,
 };
 use anyhow::{bail, ensure, Context};
@@ -52,6 +60,7 @@ use aptos_types::{
         LeaderReputationType, OnChainConfigPayload, OnChainConsensusConfig, ProposerElectionType,
         ValidatorSet,
     },
+    validator_signer::ValidatorSigner,
     validator_verifier::ValidatorVerifier,
 };
 use channel::{aptos_channel, message_queues::QueueStyle};
@@ -72,7 +81,7 @@ use futures::{
 };
 use itertools::Itertools;
 use network::protocols::network::{ApplicationNetworkSender, Event};
-use safety_rules::SafetyRulesManager;
+use safety_rules::{PersistentSafetyStorage, SafetyRulesManager};
 use std::{
     cmp::Ordering,
     collections::HashMap,
@@ -107,6 +116,8 @@ pub struct EpochManager {
     commit_state_computer: Arc<dyn StateComputer>,
     storage: Arc<dyn PersistentLivenessStorage>,
     safety_rules_manager: SafetyRulesManager,
+    // safety rules storage to load the consensus key signing the quorum store batches
+    quorum_store_storage: Option<PersistentSafetyStorage>,
     reconfig_events: ReconfigNotificationListener,
     commit_notifier: Arc<dyn CommitNotifier>,
     // channels to buffer manager
@@ -120,6 +131,10 @@ pub struct EpochManager {
     epoch_state: Option<EpochState>,
     block_retrieval_tx:
         Option<aptos_channel::Sender<AccountAddress, IncomingBlockRetrievalRequest>>,
+    // channels to quorum store
+    quorum_store_msg_tx: Option<aptos_channel::Sender<AccountAddress, (Author, VerifiedEvent)>>,
+    batch_retrieval_tx:
+        Option<aptos_channel::Sender<AccountAddress, IncomingBatchRetrievalRequest>>,
 }
 
 impl EpochManager {
@@ -139,6 +154,11 @@ impl EpochManager {
         let config = node_config.consensus.clone();
         let sr_config = &node_config.consensus.safety_rules;
         let safety_rules_manager = SafetyRulesManager::new(sr_config);
+        let quorum_store_storage = if config.use_quorum_store {
+            Some(safety_rules::storage(sr_config))
+        } else {
+            None
+        };
         Self {
             author,
             config,
@@ -150,6 +170,7 @@ impl EpochManager {
             commit_state_computer,
             storage,
             safety_rules_manager,
+            quorum_store_storage,
             reconfig_events,
             commit_notifier,
             buffer_manager_msg_tx: None,
@@ -158,6 +179,8 @@ impl EpochManager {
             round_manager_close_tx: None,
             epoch_state: None,
             block_retrieval_tx: None,
+            quorum_store_msg_tx: None,
+            batch_retrieval_tx: None,
         }
     }
 
@@ -421,8 +444,89 @@ impl EpochManager {
         Ok(())
     }
 
+    /// Spawns the quorum store serving the payloads to consensus, returns the batch reader
+    /// resolving the proofs of store if the batch-dissemination quorum store is enabled.
     fn spawn_quorum_store(
         &mut self,
+        epoch_state: &EpochState,
+        last_committed_round: Round,
+        consensus_to_quorum_store_receiver: Receiver<ConsensusRequest>,
+    ) -> Option<Arc<BatchReader>> {
+        if !self.config.use_quorum_store {
+            self.spawn_direct_mempool_quorum_store(consensus_to_quorum_store_receiver);
+            return None;
+        }
+
+        let epoch = epoch_state.epoch;
+        let config = self.config.quorum_store.clone();
+        let network_sender = NetworkSender::new(
+            self.author,
+            self.network_sender.clone(),
+            self.self_sender.clone(),
+            epoch_state.verifier.clone(),
+        );
+        let batch_store = Arc::new(BatchStore::new(
+            epoch,
+            last_committed_round,
+            config.max_batch_expiry_round_gap,
+        ));
+        let batch_reader = Arc::new(BatchReader::new(
+            self.author,
+            batch_store.clone(),
+            network_sender.clone(),
+            epoch_state.verifier.clone(),
+            config.batch_request_timeout_ms,
+        ));
+
+        // Only the validators of the epoch disseminate batches, the other nodes only fetch the
+        // batches of the proposals they execute.
+        let validator_signer = match self.quorum_store_signer(epoch_state) {
+            Some(validator_signer) => Arc::new(validator_signer),
+            None => {
+                info!(
+                    epoch = epoch,
+                    "Not a validator of the epoch, only reading the batches of the quorum store"
+                );
+                self.spawn_direct_mempool_quorum_store(consensus_to_quorum_store_receiver);
+                return Some(batch_reader);
+            }
+        };
+        let batch_generator = BatchGenerator::new(
+            epoch,
+            self.quorum_store_to_mempool_sender.clone(),
+            self.config.mempool_txn_pull_timeout_ms,
+            config.max_batch_txns,
+            config.max_batch_bytes,
+            config.batch_expiry_round_gap,
+        );
+
+        let (quorum_store_msg_tx, quorum_store_msg_rx) = aptos_channel::new(
+            QueueStyle::FIFO,
+            config.channel_size,
+            Some(&counters::QUORUM_STORE_MSGS),
+        );
+        self.quorum_store_msg_tx = Some(quorum_store_msg_tx);
+
+        let quorum_store = QuorumStoreCoordinator::new(
+            epoch,
+            config,
+            network_sender,
+            validator_signer,
+            epoch_state.verifier.clone(),
+            batch_store.clone(),
+            batch_generator,
+        );
+        spawn_named!(
+            "Quorum Store",
+            quorum_store.start(consensus_to_quorum_store_receiver, quorum_store_msg_rx)
+        );
+        self.spawn_batch_retrieval_task(epoch, batch_store);
+        Some(batch_reader)
+    }
+
+    /// Spawns the quorum store pulling the payloads of consensus directly from mempool.
+    fn spawn_direct_mempool_quorum_store(
+        &self,
         consensus_to_quorum_store_receiver: Receiver<ConsensusRequest>,
     ) {
         let quorum_store = DirectMempoolQuorumStore::new(
@@ -433,6 +537,46 @@ impl EpochManager {
         spawn_named!("Quorum Store", quorum_store.start());
     }
 
+    /// Loads the consensus key of the epoch from the safety rules storage, returns None if the
+    /// node isn't in the validator set of the epoch or if its key can't be retrieved.
+    fn quorum_store_signer(&self, epoch_state: &EpochState) -> Option<ValidatorSigner> {
+        let storage = self.quorum_store_storage.as_ref()?;
+        let public_key = epoch_state.verifier.get_public_key(&self.author)?;
+        match storage.consensus_key_for_version(public_key) {
+            Ok(private_key) => Some(ValidatorSigner::new(self.author, private_key)),
+            Err(error) => {
+                error!(
+                    epoch = epoch_state.epoch,
+                    error = ?error,
+                    "Unable to retrieve the consensus key of the epoch"
+                );
+                None
+            }
+        }
+    }
+
+    fn spawn_batch_retrieval_task(&mut self, epoch: u64, batch_store: Arc<BatchStore>) {
+        let (request_tx, mut request_rx) = aptos_channel::new(
+            QueueStyle::FIFO,
+            self.config.quorum_store.channel_size,
+            Some(&counters::BATCH_RETRIEVAL_TASK_MSGS),
+        );
+        let task = async move {
+            info!(epoch = epoch, "Batch retrieval task starts");
+            while let Some(request) = request_rx.next().await {
+                if let Err(e) = monitor!(
+                    "process_batch_retrieval",
+                    batch_store.process_batch_retrieval(request)
+                ) {
+                    warn!(epoch = epoch, error = ?e, kind = error_kind(&e));
+                }
+            }
+            info!(epoch = epoch, "Batch retrieval task stops");
+        };
+        self.batch_retrieval_tx = Some(request_tx);
+        tokio::spawn(task);
+    }
+
     fn spawn_block_retrieval_task(&mut self, epoch: u64, block_store: Arc<BlockStore>) {
         let (request_tx, mut request_rx) = aptos_channel::new(
             QueueStyle::LIFO,
@@ -532,6 +676,10 @@ impl EpochManager {
 
         // Shutdown the block retrieval task by dropping the sender
         self.block_retrieval_tx = None;
+
+        // Shutdown the quorum store and the batch retrieval task by dropping the senders
+        self.quorum_store_msg_tx = None;
+        self.batch_retrieval_tx = None;
     }
 
     async fn start_recovery_manager(
@@ -609,16 +757,22 @@ impl EpochManager {
 
         let (consensus_to_quorum_store_sender, consensus_to_quorum_store_receiver) =
             mpsc::channel(self.config.intra_consensus_channel_buffer_size);
-        self.spawn_quorum_store(consensus_to_quorum_store_receiver);
-        let payload_manager = QuorumStoreClient::new(
+        let batch_reader = self.spawn_quorum_store(
+            &epoch_state,
+            recovery_data.root_block().round(),
+            consensus_to_quorum_store_receiver,
+        );
+        let payload_manager: Arc<dyn PayloadManager> = Arc::new(QuorumStoreClient::new(
             consensus_to_quorum_store_sender.clone(),
             self.config.quorum_store_poll_count,
             self.config.quorum_store_pull_timeout_ms,
-        );
+            batch_reader,
+        ));
         self.commit_notifier
             .new_epoch(consensus_to_quorum_store_sender);
 
-        self.commit_state_computer.new_epoch(&epoch_state);
+        self.commit_state_computer
+            .new_epoch(&epoch_state, payload_manager.clone());
         let state_computer = if onchain_config.decoupled_execution() {
             Arc::new(self.spawn_decoupled_execution(
                 safety_rules_container.clone(),
@@ -644,7 +798,7 @@ impl EpochManager {
         let proposal_generator = ProposalGenerator::new(
             self.author,
             block_store.clone(),
-            Arc::new(payload_manager),
+            payload_manager,
             self.time_service.clone(),
             self.config.max_sending_block_txns,
             self.config.max_sending_block_bytes,
@@ -745,7 +899,7 @@ impl EpochManager {
                 "verify_message",
                 unverified_event
                     .clone()
-                    .verify(&self.epoch_state().verifier)
+                    .verify(&self.epoch_state().verifier, self.config.use_quorum_store)
             )
             .context("[EpochManager] Verify event")
             .map_err(|err| {
@@ -774,7 +928,10 @@ impl EpochManager {
             | ConsensusMsg::SyncInfo(_)
             | ConsensusMsg::VoteMsg(_)
             | ConsensusMsg::CommitVoteMsg(_)
-            | ConsensusMsg::CommitDecisionMsg(_) => {
+            | ConsensusMsg::CommitDecisionMsg(_)
+            | ConsensusMsg::BatchMsg(_)
+            | ConsensusMsg::SignedDigestMsg(_)
+            | ConsensusMsg::ProofOfStoreMsg(_) => {
                 let event: UnverifiedEvent = msg.into();
                 if event.epoch() == self.epoch() {
                     return Ok(Some(event));
@@ -840,6 +997,15 @@ impl EpochManager {
                     bail!("Commit Phase not started but received Commit Message (CommitVote/CommitDecision)");
                 }
             }
+            quorum_store_event @ (VerifiedEvent::BatchMsg(_)
+            | VerifiedEvent::SignedDigestMsg(_)
+            | VerifiedEvent::ProofOfStoreMsg(_)) => {
+                if let Some(sender) = &mut self.quorum_store_msg_tx {
+                    sender.push(peer_id, (peer_id, quorum_store_event))?;
+                } else {
+                    bail!("Quorum store not started but received quorum store message");
+                }
+            }
             round_manager_event => {
                 self.forward_to_round_manager(peer_id, round_manager_event);
             }
@@ -872,6 +1038,18 @@ impl EpochManager {
         }
     }
 
+    fn process_batch_retrieval(
+        &self,
+        peer_id: Author,
+        request: IncomingBatchRetrievalRequest,
+    ) -> anyhow::Result<()> {
+        if let Some(tx) = &self.batch_retrieval_tx {
+            tx.push(peer_id, request)
+        } else {
+            Err(anyhow::anyhow!("Quorum store not started"))
+        }
+    }
+
     fn process_local_timeout(&mut self, round: u64) {
         self.forward_to_round_manager(self.author, VerifiedEvent::LocalTimeout(round));
     }
@@ -900,11 +1078,21 @@ impl EpochManager {
                         error!(epoch = self.epoch(), error = ?e, kind = error_kind(&e));
                     }
                 },
+                (peer, msg) = network_receivers.quorum_store_messages.select_next_some() => {
+                    if let Err(e) = self.process_message(peer, msg).await {
+                        error!(epoch = self.epoch(), error = ?e, kind = error_kind(&e));
+                    }
+                },
                 (peer, request) = network_receivers.block_retrieval.select_next_some() => {
                     if let Err(e) = self.process_block_retrieval(peer, request) {
                         error!(epoch = self.epoch(), error = ?e, kind = error_kind(&e));
                     }
                 },
+                (peer, request) = network_receivers.batch_retrieval.select_next_some() => {
+                    if let Err(e) = self.process_batch_retrieval(peer, request) {
+                        error!(epoch = self.epoch(), error = ?e, kind = error_kind(&e));
+                    }
+                },
                 round = round_timeout_sender_rx.select_next_some() => {
                     self.process_local_timeout(round);
                 },
@@ -3,7 +3,10 @@
 
 //! Interface between Consensus and Network layers.
 
-use crate::counters;
+use crate::{
+    counters,
+    quorum_store::types::{Batch, BatchRequest},
+};
 use anyhow::anyhow;
 use aptos_config::network_id::{NetworkId, PeerNetworkId};
 use aptos_logger::prelude::*;
@@ -14,6 +17,7 @@ use consensus_types::{
     block_retrieval::{BlockRetrievalRequest, BlockRetrievalResponse},
     epoch_retrieval::EpochRetrievalRequest,
     experimental::{commit_decision::CommitDecision, commit_vote::CommitVote},
+    proof_of_store::{ProofOfStore, SignedDigest},
     proposal_msg::ProposalMsg,
     sync_info::SyncInfo,
     vote_msg::VoteMsg,
@@ -62,6 +66,16 @@ pub enum ConsensusMsg {
     /// than 2f + 1 signatures on the commit proposal. This part is not on the critical path, but
     /// it can save slow machines to quickly confirm the execution result.
     CommitDecisionMsg(Box<CommitDecision>),
+    /// Quorum store batch broadcast by its author to all validators.
+    BatchMsg(Box<Batch>),
+    /// Signature of a validator that stored a batch, sent back to the batch author.
+    SignedDigestMsg(Box<SignedDigest>),
+    /// Proof that a quorum of validators store a batch, broadcast by the batch author.
+    ProofOfStoreMsg(Box<ProofOfStore>),
+    /// RPC to fetch a quorum store batch that is not available locally.
+    BatchRequestMsg(Box<BatchRequest>),
+    /// Carries the requested batch.
+    BatchResponse(Box<Batch>),
 }
 
 /// Network type for consensus
@@ -79,6 +93,11 @@ impl ConsensusMsg {
             ConsensusMsg::VoteMsg(_) => "VoteMsg",
             ConsensusMsg::CommitVoteMsg(_) => "CommitVoteMsg",
             ConsensusMsg::CommitDecisionMsg(_) => "CommitDecisionMsg",
+            ConsensusMsg::BatchMsg(_) => "BatchMsg",
+            ConsensusMsg::SignedDigestMsg(_) => "SignedDigestMsg",
+            ConsensusMsg::ProofOfStoreMsg(_) => "ProofOfStoreMsg",
+            ConsensusMsg::BatchRequestMsg(_) => "BatchRequestMsg",
+            ConsensusMsg::BatchResponse(_) => "BatchResponse",
         }
     }
 }
@@ -0,0 +1,146 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{monitor, quorum_store::counters};
+use anyhow::Result;
+use aptos_crypto::HashValue;
+use aptos_mempool::{QuorumStoreRequest, QuorumStoreResponse};
+use aptos_types::transaction::SignedTransaction;
+use consensus_types::{
+    common::{Round, TransactionSummary},
+    proof_of_store::LogicalTime,
+};
+use futures::channel::{mpsc::Sender, oneshot};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    time::Duration,
+};
+use tokio::time::timeout;
+
+/// Pulls transactions from the local mempool into batches. The transactions of batches that
+/// are neither expired nor dropped are excluded from the following pulls.
+pub struct BatchGenerator {
+    epoch: u64,
+    mempool_sender: Sender<QuorumStoreRequest>,
+    mempool_txn_pull_timeout_ms: u64,
+    max_batch_txns: u64,
+    max_batch_bytes: u64,
+    batch_expiry_round_gap: Round,
+    batches_in_progress: HashMap<HashValue, Vec<TransactionSummary>>,
+    expirations: BTreeMap<Round, HashSet<HashValue>>,
+}
+
+impl BatchGenerator {
+    pub fn new(
+        epoch: u64,
+        mempool_sender: Sender<QuorumStoreRequest>,
+        mempool_txn_pull_timeout_ms: u64,
+        max_batch_txns: u64,
+        max_batch_bytes: u64,
+        batch_expiry_round_gap: Round,
+    ) -> Self {
+        Self {
+            epoch,
+            mempool_sender,
+            mempool_txn_pull_timeout_ms,
+            max_batch_txns,
+            max_batch_bytes,
+            batch_expiry_round_gap,
+            batches_in_progress: HashMap::new(),
+            expirations: BTreeMap::new(),
+        }
+    }
+
+    async fn pull_internal(&self) -> Result<Vec<SignedTransaction>, anyhow::Error> {
+        let exclude_txns: Vec<_> = self
+            .batches_in_progress
+            .values()
+            .flatten()
+            .cloned()
+            .collect();
+        let (callback, callback_rcv) = oneshot::channel();
+        let msg = QuorumStoreRequest::GetBatchRequest(
+            self.max_batch_txns,
+            self.max_batch_bytes,
+            exclude_txns,
+            callback,
+        );
+        self.mempool_sender
+            .clone()
+            .try_send(msg)
+            .map_err(anyhow::Error::from)?;
+        // wait for response
+        match monitor!(
+            "pull_txn",
+            timeout(
+                Duration::from_millis(self.mempool_txn_pull_timeout_ms),
+                callback_rcv
+            )
+            .await
+        ) {
+            Err(_) => Err(anyhow::anyhow!(
+                "[quorum_store] did not receive GetBatchResponse on time"
+            )),
+            Ok(resp) => match resp.map_err(anyhow::Error::from)?? {
+                QuorumStoreResponse::GetBatchResponse(txns) => Ok(txns),
+                _ => Err(anyhow::anyhow!(
+                    "[quorum_store] did not receive expected GetBatchResponse"
+                )),
+            },
+        }
+    }
+
+    /// Pulls the next batch of transactions from mempool, returns the transactions with the
+    /// expiration of the batch, or None if mempool has no new transactions.
+    pub async fn generate_batch(
+        &mut self,
+        last_committed_round: Round,
+    ) -> Result<Option<(Vec<SignedTransaction>, LogicalTime)>> {
+        let txns = self.pull_internal().await?;
+        if txns.is_empty() {
+            return Ok(None);
+        }
+        counters::BATCH_SIZE.observe(txns.len() as f64);
+        let expiration = LogicalTime::new(
+            self.epoch,
+            last_committed_round + self.batch_expiry_round_gap,
+        );
+        Ok(Some((txns, expiration)))
+    }
+
+    /// Tracks the transactions of a created batch so they are not pulled again.
+    pub fn add_batch(
+        &mut self,
+        digest: HashValue,
+        txns: &[SignedTransaction],
+        expiration: LogicalTime,
+    ) {
+        let summaries = txns
+            .iter()
+            .map(|txn| TransactionSummary {
+                sender: txn.sender(),
+                sequence_number: txn.sequence_number(),
+            })
+            .collect();
+        self.batches_in_progress.insert(digest, summaries);
+        self.expirations
+            .entry(expiration.round())
+            .or_default()
+            .insert(digest);
+    }
+
+    /// Stops excluding the transactions of a batch, e.g. when it failed to get a proof of store.
+    pub fn remove_batch(&mut self, digest: &HashValue) {
+        self.batches_in_progress.remove(digest);
+    }
+
+    /// Stops excluding the transactions of the batches expired at the committed round. Committed
+    /// transactions are removed from mempool by state sync, the others become available again.
+    pub fn update_committed_round(&mut self, committed_round: Round) {
+        let not_expired = self.expirations.split_off(&(committed_round + 1));
+        let expired = std::mem::replace(&mut self.expirations, not_expired);
+        for digest in expired.into_values().flatten() {
+            self.batches_in_progress.remove(&digest);
+        }
+    }
+}
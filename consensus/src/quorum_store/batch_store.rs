@@ -0,0 +1,210 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    network::{IncomingBatchRetrievalRequest, NetworkSender},
+    network_interface::ConsensusMsg,
+    quorum_store::{
+        counters,
+        types::{Batch, BatchRequest},
+    },
+};
+use anyhow::{bail, ensure};
+use aptos_crypto::HashValue;
+use aptos_infallible::Mutex;
+use aptos_logger::prelude::*;
+use aptos_types::{transaction::SignedTransaction, validator_verifier::ValidatorVerifier, PeerId};
+use consensus_types::{
+    common::Round,
+    proof_of_store::{LogicalTime, ProofOfStore},
+};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+/// In-memory storage of the batches this validator has signed (or created), kept until they
+/// expire with respect to the latest committed round.
+pub struct BatchStore {
+    epoch: u64,
+    max_expiry_round_gap: Round,
+    inner: Mutex<BatchStoreInner>,
+}
+
+#[derive(Default)]
+struct BatchStoreInner {
+    last_committed_round: Round,
+    batches: HashMap<HashValue, Batch>,
+    expirations: BTreeMap<Round, HashSet<HashValue>>,
+}
+
+impl BatchStore {
+    pub fn new(epoch: u64, last_committed_round: Round, max_expiry_round_gap: Round) -> Self {
+        Self {
+            epoch,
+            max_expiry_round_gap,
+            inner: Mutex::new(BatchStoreInner {
+                last_committed_round,
+                ..Default::default()
+            }),
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    pub fn last_committed_round(&self) -> Round {
+        self.inner.lock().last_committed_round
+    }
+
+    /// Stores the batch if its expiration is within the accepted window. Returns false if the
+    /// batch was already stored.
+    pub fn persist(&self, batch: Batch) -> anyhow::Result<bool> {
+        let expiration = batch.expiration();
+        ensure!(
+            expiration.epoch() == self.epoch,
+            "Batch {} from a different epoch",
+            batch.digest()
+        );
+        let mut inner = self.inner.lock();
+        ensure!(
+            expiration.round() > inner.last_committed_round,
+            "Batch {} already expired at {}",
+            batch.digest(),
+            expiration
+        );
+        ensure!(
+            expiration.round() <= inner.last_committed_round + self.max_expiry_round_gap,
+            "Batch {} expires too far in the future at {}",
+            batch.digest(),
+            expiration
+        );
+        let digest = batch.digest();
+        if inner.batches.contains_key(&digest) {
+            return Ok(false);
+        }
+        inner
+            .expirations
+            .entry(expiration.round())
+            .or_default()
+            .insert(digest);
+        inner.batches.insert(digest, batch);
+        counters::NUM_BATCHES_IN_STORE.set(inner.batches.len() as i64);
+        Ok(true)
+    }
+
+    pub fn get(&self, digest: &HashValue) -> Option<Batch> {
+        self.inner.lock().batches.get(digest).cloned()
+    }
+
+    /// Removes the batches that expired at the committed time and returns their digests.
+    pub fn update_committed_time(&self, committed: LogicalTime) -> Vec<HashValue> {
+        if committed.epoch() != self.epoch {
+            return vec![];
+        }
+        let mut inner = self.inner.lock();
+        if committed.round() <= inner.last_committed_round {
+            return vec![];
+        }
+        inner.last_committed_round = committed.round();
+        let not_expired = inner.expirations.split_off(&(committed.round() + 1));
+        let expired = std::mem::replace(&mut inner.expirations, not_expired);
+        let expired_digests: Vec<_> = expired.into_values().flatten().collect();
+        for digest in &expired_digests {
+            inner.batches.remove(digest);
+        }
+        counters::NUM_BATCHES_IN_STORE.set(inner.batches.len() as i64);
+        expired_digests
+    }
+
+    /// Serves a batch retrieval RPC from the local store.
+    pub fn process_batch_retrieval(
+        &self,
+        request: IncomingBatchRetrievalRequest,
+    ) -> anyhow::Result<()> {
+        let req = &request.req;
+        ensure!(
+            req.epoch() == self.epoch,
+            "Batch request for epoch {} in epoch {}",
+            req.epoch(),
+            self.epoch
+        );
+        let batch = match self.get(&req.digest()) {
+            Some(batch) => batch,
+            None => bail!("Batch {} not found for {}", req.digest(), req.source()),
+        };
+        let response_bytes = request
+            .protocol
+            .to_bytes(&ConsensusMsg::BatchResponse(Box::new(batch)))?;
+        request
+            .response_sender
+            .send(Ok(response_bytes.into()))
+            .map_err(|_| anyhow::anyhow!("Failed to send batch retrieval response"))
+    }
+}
+
+/// Resolves the transactions referenced by proofs of store, from the local store or from the
+/// validators that signed the proofs.
+pub struct BatchReader {
+    my_peer_id: PeerId,
+    batch_store: Arc<BatchStore>,
+    network_sender: NetworkSender,
+    validator_verifier: ValidatorVerifier,
+    request_timeout: Duration,
+}
+
+impl BatchReader {
+    pub fn new(
+        my_peer_id: PeerId,
+        batch_store: Arc<BatchStore>,
+        network_sender: NetworkSender,
+        validator_verifier: ValidatorVerifier,
+        request_timeout_ms: u64,
+    ) -> Self {
+        Self {
+            my_peer_id,
+            batch_store,
+            network_sender,
+            validator_verifier,
+            request_timeout: Duration::from_millis(request_timeout_ms),
+        }
+    }
+
+    pub async fn get_batch(&self, proof: &ProofOfStore) -> anyhow::Result<Vec<SignedTransaction>> {
+        let digest = *proof.digest();
+        if let Some(batch) = self.batch_store.get(&digest) {
+            return Ok(batch.into_txns());
+        }
+        counters::BATCH_REQUESTS_COUNT.inc();
+        for signer in proof.signers(&self.validator_verifier) {
+            if signer == self.my_peer_id {
+                continue;
+            }
+            let request = BatchRequest::new(self.batch_store.epoch(), self.my_peer_id, digest);
+            match self
+                .network_sender
+                .request_batch(request, signer, self.request_timeout)
+                .await
+            {
+                Ok(batch) => {
+                    if let Err(e) = self.batch_store.persist(batch.clone()) {
+                        // The batch is still valid for the block being executed.
+                        debug!(error = ?e, "Fetched batch not persisted");
+                    }
+                    return Ok(batch.into_txns());
+                }
+                Err(e) => {
+                    counters::BATCH_REQUEST_FAILURES_COUNT.inc();
+                    warn!(
+                        remote_peer = signer,
+                        error = ?e,
+                        "Failed to fetch batch {}", digest
+                    );
+                }
+            }
+        }
+        bail!("Unable to fetch batch {} from any signer", digest)
+    }
+}
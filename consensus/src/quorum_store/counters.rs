@@ -1,7 +1,8 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 use aptos_metrics_core::{
-    op_counters::DurationHistogram, register_histogram, register_histogram_vec, HistogramVec,
+    op_counters::DurationHistogram, register_histogram, register_histogram_vec,
+    register_int_counter, register_int_gauge, Histogram, HistogramVec, IntCounter, IntGauge,
 };
 use once_cell::sync::Lazy;
 use std::time::Duration;
@@ -42,3 +43,84 @@ pub static MAIN_LOOP: Lazy<DurationHistogram> = Lazy::new(|| {
         .unwrap(),
     )
 });
+
+/// Number of transactions in the batches created by this validator.
+pub static BATCH_SIZE: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "quorum_store_batch_size",
+        "Number of transactions in the batches created by this validator"
+    )
+    .unwrap()
+});
+
+/// Number of batches currently held in the batch store.
+pub static NUM_BATCHES_IN_STORE: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "quorum_store_num_batches_in_store",
+        "Number of batches currently held in the batch store"
+    )
+    .unwrap()
+});
+
+/// Number of batches removed from the batch store after expiring.
+pub static EXPIRED_BATCHES_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "quorum_store_expired_batches_count",
+        "Number of batches removed from the batch store after expiring"
+    )
+    .unwrap()
+});
+
+/// Number of batches fetched from remote peers because they were missing locally.
+pub static BATCH_REQUESTS_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "quorum_store_batch_requests_count",
+        "Number of batches fetched from remote peers because they were missing locally"
+    )
+    .unwrap()
+});
+
+/// Number of failed batch requests to remote peers.
+pub static BATCH_REQUEST_FAILURES_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "quorum_store_batch_request_failures_count",
+        "Number of failed batch requests to remote peers"
+    )
+    .unwrap()
+});
+
+/// Number of proofs of store aggregated for the batches of this validator.
+pub static PROOFS_CREATED_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "quorum_store_proofs_created_count",
+        "Number of proofs of store aggregated for the batches of this validator"
+    )
+    .unwrap()
+});
+
+/// Number of batches of this validator that did not get a proof of store in time.
+pub static PROOF_TIMEOUTS_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "quorum_store_proof_timeouts_count",
+        "Number of batches of this validator that did not get a proof of store in time"
+    )
+    .unwrap()
+});
+
+/// Number of proofs of store available for proposals.
+pub static NUM_PROOFS_IN_POOL: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "quorum_store_num_proofs_in_pool",
+        "Number of proofs of store available for proposals"
+    )
+    .unwrap()
+});
+
+/// Number of proofs of store pulled into each proposal.
+pub static NUM_PROOFS_IN_PROPOSAL: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "quorum_store_num_proofs_in_proposal",
+        "Number of proofs of store pulled into each proposal"
+    )
+    .unwrap()
+});
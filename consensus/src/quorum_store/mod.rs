@@ -1,8 +1,20 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
+/// Pulls transactions from mempool into batches.
+pub mod batch_generator;
+/// Stores the batches and fetches the missing ones from remote peers.
+pub mod batch_store;
 /// Equivalent to directly fetching blocks from mempool without a quorum store.
 pub mod direct_mempool_quorum_store;
+/// Aggregates the signed digests of own batches into proofs of store.
+pub mod proof_coordinator;
+/// Pool of proofs of store to be proposed.
+pub mod proof_manager;
+/// Batch-dissemination quorum store, enabled with `use_quorum_store`.
+pub mod quorum_store_coordinator;
+/// Batch and batch request types.
+pub mod types;
 
 mod counters;
 #[cfg(test)]
@@ -0,0 +1,106 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, ensure};
+use aptos_crypto::HashValue;
+use aptos_types::{
+    aggregate_signature::PartialSignatures, validator_verifier::ValidatorVerifier, PeerId,
+};
+use consensus_types::proof_of_store::{ProofOfStore, SignedDigest, SignedDigestInfo};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+struct IncrementalProofState {
+    info: SignedDigestInfo,
+    signatures: PartialSignatures,
+}
+
+/// Aggregates the signatures on the batches created by this validator into proofs of store.
+pub struct ProofCoordinator {
+    my_peer_id: PeerId,
+    proof_timeout: Duration,
+    digest_to_proof: HashMap<HashValue, IncrementalProofState>,
+    timeouts: VecDeque<(Instant, HashValue)>,
+}
+
+impl ProofCoordinator {
+    pub fn new(my_peer_id: PeerId, proof_timeout_ms: u64) -> Self {
+        Self {
+            my_peer_id,
+            proof_timeout: Duration::from_millis(proof_timeout_ms),
+            digest_to_proof: HashMap::new(),
+            timeouts: VecDeque::new(),
+        }
+    }
+
+    /// Starts collecting signatures for a batch created by this validator.
+    pub fn init_proof(&mut self, info: SignedDigestInfo, now: Instant) {
+        let digest = info.digest;
+        self.digest_to_proof.insert(
+            digest,
+            IncrementalProofState {
+                info,
+                signatures: PartialSignatures::empty(),
+            },
+        );
+        self.timeouts.push_back((now + self.proof_timeout, digest));
+    }
+
+    /// Adds a verified signature, returns the proof of store once a quorum signed the batch.
+    pub fn add_signature(
+        &mut self,
+        signed_digest: SignedDigest,
+        validator_verifier: &ValidatorVerifier,
+    ) -> anyhow::Result<Option<ProofOfStore>> {
+        ensure!(
+            signed_digest.info().batch_author == self.my_peer_id,
+            "Signed digest for a batch authored by {}",
+            signed_digest.info().batch_author
+        );
+        let digest = signed_digest.digest();
+        let state = match self.digest_to_proof.get_mut(&digest) {
+            Some(state) => state,
+            // The proof was already completed or timed out.
+            None => return Ok(None),
+        };
+        if state.info != *signed_digest.info() {
+            bail!("Signed digest info mismatch for batch {}", digest);
+        }
+        state
+            .signatures
+            .add_signature(signed_digest.signer(), signed_digest.signature().clone());
+        if validator_verifier
+            .check_voting_power(state.signatures.signatures().keys())
+            .is_err()
+        {
+            return Ok(None);
+        }
+        let state = self
+            .digest_to_proof
+            .remove(&digest)
+            .expect("Proof state must exist");
+        let multi_signature = validator_verifier.aggregate_signatures(&state.signatures)?;
+        Ok(Some(ProofOfStore::new(state.info, multi_signature)))
+    }
+
+    /// Drops the batches that did not get a quorum in time and returns their digests.
+    pub fn expire(&mut self, now: Instant) -> Vec<HashValue> {
+        let mut expired = vec![];
+        while let Some((deadline, digest)) = self.timeouts.front() {
+            if *deadline > now {
+                break;
+            }
+            if self.digest_to_proof.remove(digest).is_some() {
+                expired.push(*digest);
+            }
+            self.timeouts.pop_front();
+        }
+        expired
+    }
+
+    pub fn num_pending_proofs(&self) -> usize {
+        self.digest_to_proof.len()
+    }
+}
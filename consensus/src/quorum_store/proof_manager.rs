@@ -0,0 +1,113 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_crypto::HashValue;
+use consensus_types::{
+    common::Round,
+    proof_of_store::{LogicalTime, ProofOfStore},
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Proofs expiring within this many rounds after the latest committed round are not proposed,
+/// so that the batches are still stored by the signers when the block gets executed.
+pub const PROOF_EXPIRATION_MARGIN_ROUNDS: Round = 20;
+
+/// Pool of proofs of store, from all validators, that can be pulled into proposals.
+pub struct ProofManager {
+    epoch: u64,
+    last_committed_round: Round,
+    proofs: HashMap<HashValue, ProofOfStore>,
+    // Insertion order of the proofs, so that older batches are proposed first.
+    proof_queue: VecDeque<HashValue>,
+    // Committed batches, kept until expiration to ignore proofs that arrive late.
+    committed: HashMap<HashValue, Round>,
+}
+
+impl ProofManager {
+    pub fn new(epoch: u64, last_committed_round: Round) -> Self {
+        Self {
+            epoch,
+            last_committed_round,
+            proofs: HashMap::new(),
+            proof_queue: VecDeque::new(),
+            committed: HashMap::new(),
+        }
+    }
+
+    fn is_proposable(&self, proof: &ProofOfStore) -> bool {
+        proof.expiration().round() > self.last_committed_round + PROOF_EXPIRATION_MARGIN_ROUNDS
+    }
+
+    pub fn add_proof(&mut self, proof: ProofOfStore) {
+        let digest = *proof.digest();
+        if proof.epoch() != self.epoch
+            || proof.expiration().round() <= self.last_committed_round
+            || self.committed.contains_key(&digest)
+            || self.proofs.contains_key(&digest)
+        {
+            return;
+        }
+        self.proofs.insert(digest, proof);
+        self.proof_queue.push_back(digest);
+    }
+
+    /// Returns the oldest proofs not in the excluded set, up to the given limits.
+    pub fn pull_proofs(
+        &self,
+        max_txns: u64,
+        max_bytes: u64,
+        excluded: &HashSet<HashValue>,
+    ) -> Vec<ProofOfStore> {
+        let mut result = vec![];
+        let mut num_txns = 0;
+        let mut num_bytes = 0;
+        for digest in &self.proof_queue {
+            if excluded.contains(digest) {
+                continue;
+            }
+            let proof = match self.proofs.get(digest) {
+                Some(proof) => proof,
+                None => continue,
+            };
+            if !self.is_proposable(proof) {
+                continue;
+            }
+            let info = proof.info();
+            if num_txns + info.num_txns > max_txns || num_bytes + info.num_bytes > max_bytes {
+                break;
+            }
+            num_txns += info.num_txns;
+            num_bytes += info.num_bytes;
+            result.push(proof.clone());
+        }
+        result
+    }
+
+    /// Removes the committed and the expired proofs.
+    pub fn handle_commit(&mut self, committed: LogicalTime, committed_digests: Vec<HashValue>) {
+        if committed.epoch() != self.epoch {
+            return;
+        }
+        for digest in committed_digests {
+            let expiration = match self.proofs.remove(&digest) {
+                Some(proof) => proof.expiration().round(),
+                // Keep rejecting the proof until the batch expires at the latest.
+                None => committed.round() + PROOF_EXPIRATION_MARGIN_ROUNDS,
+            };
+            self.committed.insert(digest, expiration);
+        }
+        self.last_committed_round = self.last_committed_round.max(committed.round());
+        let last_committed_round = self.last_committed_round;
+        self.proofs
+            .retain(|_, proof| proof.expiration().round() > last_committed_round);
+        self.committed
+            .retain(|_, expiration| *expiration > last_committed_round);
+        let proofs = &self.proofs;
+        self.proof_queue
+            .retain(|digest| proofs.contains_key(digest));
+    }
+
+    pub fn num_proofs(&self) -> usize {
+        self.proofs.len()
+    }
+}
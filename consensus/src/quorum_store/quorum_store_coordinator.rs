@@ -0,0 +1,267 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    network::NetworkSender,
+    quorum_store::{
+        batch_generator::BatchGenerator, batch_store::BatchStore, counters,
+        proof_coordinator::ProofCoordinator, proof_manager::ProofManager, types::Batch,
+    },
+    round_manager::VerifiedEvent,
+};
+use anyhow::Result;
+use aptos_config::config::QuorumStoreConfig;
+use aptos_crypto::HashValue;
+use aptos_logger::prelude::*;
+use aptos_types::{
+    account_address::AccountAddress, validator_signer::ValidatorSigner,
+    validator_verifier::ValidatorVerifier, PeerId,
+};
+use channel::aptos_channel;
+use consensus_types::{
+    common::{Payload, PayloadFilter},
+    proof_of_store::{LogicalTime, ProofOfStore, SignedDigest},
+    request_response::{ConsensusRequest, ConsensusResponse},
+};
+use futures::{
+    channel::{mpsc::Receiver, oneshot},
+    FutureExt, StreamExt,
+};
+use std::{
+    collections::HashSet,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Batch-dissemination quorum store: validators broadcast batches of their mempool transactions,
+/// sign the digests of the batches they store and aggregate the signatures into proofs of store.
+/// Proposals carry the proofs instead of the transactions.
+pub struct QuorumStoreCoordinator {
+    epoch: u64,
+    my_peer_id: PeerId,
+    config: QuorumStoreConfig,
+    network_sender: NetworkSender,
+    validator_signer: Arc<ValidatorSigner>,
+    validator_verifier: ValidatorVerifier,
+    batch_store: Arc<BatchStore>,
+    batch_generator: BatchGenerator,
+    proof_coordinator: ProofCoordinator,
+    proof_manager: ProofManager,
+}
+
+impl QuorumStoreCoordinator {
+    pub fn new(
+        epoch: u64,
+        config: QuorumStoreConfig,
+        network_sender: NetworkSender,
+        validator_signer: Arc<ValidatorSigner>,
+        validator_verifier: ValidatorVerifier,
+        batch_store: Arc<BatchStore>,
+        batch_generator: BatchGenerator,
+    ) -> Self {
+        let my_peer_id = validator_signer.author();
+        let proof_coordinator = ProofCoordinator::new(my_peer_id, config.proof_timeout_ms);
+        let proof_manager = ProofManager::new(epoch, batch_store.last_committed_round());
+        Self {
+            epoch,
+            my_peer_id,
+            config,
+            network_sender,
+            validator_signer,
+            validator_verifier,
+            batch_store,
+            batch_generator,
+            proof_coordinator,
+            proof_manager,
+        }
+    }
+
+    fn handle_block_request(
+        &self,
+        max_txns: u64,
+        max_bytes: u64,
+        payload_filter: PayloadFilter,
+        callback: oneshot::Sender<Result<ConsensusResponse>>,
+    ) {
+        let get_block_start_time = Instant::now();
+        let excluded = match payload_filter {
+            PayloadFilter::InQuorumStore(excluded) => excluded,
+            // Only empty payloads are pending, the filter defaults to the direct mempool one.
+            PayloadFilter::DirectMempool(_) => HashSet::new(),
+        };
+        let proofs = self
+            .proof_manager
+            .pull_proofs(max_txns, max_bytes, &excluded);
+        counters::NUM_PROOFS_IN_PROPOSAL.observe(proofs.len() as f64);
+        let payload = Payload::InQuorumStore(proofs);
+        let result = match callback.send(Ok(ConsensusResponse::GetBlockResponse(payload))) {
+            Err(_) => {
+                error!("Callback failed");
+                counters::CALLBACK_FAIL_LABEL
+            }
+            Ok(_) => counters::CALLBACK_SUCCESS_LABEL,
+        };
+        counters::quorum_store_service_latency(
+            counters::GET_BLOCK_RESPONSE_LABEL,
+            result,
+            get_block_start_time.elapsed(),
+        );
+    }
+
+    fn handle_clean_request(
+        &mut self,
+        epoch: u64,
+        round: u64,
+        batch_digests: Vec<HashValue>,
+        callback: oneshot::Sender<Result<ConsensusResponse>>,
+    ) {
+        let committed = LogicalTime::new(epoch, round);
+        let expired = self.batch_store.update_committed_time(committed);
+        counters::EXPIRED_BATCHES_COUNT.inc_by(expired.len() as u64);
+        if epoch == self.epoch {
+            self.batch_generator.update_committed_round(round);
+        }
+        self.proof_manager.handle_commit(committed, batch_digests);
+        counters::NUM_PROOFS_IN_POOL.set(self.proof_manager.num_proofs() as i64);
+        if callback
+            .send(Ok(ConsensusResponse::CleanResponse()))
+            .is_err()
+        {
+            error!("Callback failed");
+        }
+    }
+
+    fn handle_consensus_request(&mut self, req: ConsensusRequest) {
+        match req {
+            ConsensusRequest::GetBlockRequest(max_txns, max_bytes, payload_filter, callback) => {
+                self.handle_block_request(max_txns, max_bytes, payload_filter, callback);
+            }
+            ConsensusRequest::CleanRequest(epoch, round, batch_digests, callback) => {
+                self.handle_clean_request(epoch, round, batch_digests, callback);
+            }
+        }
+    }
+
+    async fn handle_batch(&mut self, peer_id: AccountAddress, batch: Batch) {
+        if batch.author() != peer_id {
+            warn!(
+                remote_peer = peer_id,
+                "Batch {} sent by a different peer than its author",
+                batch.digest()
+            );
+            return;
+        }
+        let info = batch.info();
+        if let Err(e) = self.batch_store.persist(batch) {
+            warn!(remote_peer = peer_id, error = ?e, "Batch not stored");
+            return;
+        }
+        match SignedDigest::new(self.epoch, info, &self.validator_signer) {
+            Ok(signed_digest) => {
+                self.network_sender
+                    .send_signed_digest(signed_digest, peer_id)
+                    .await
+            }
+            Err(e) => error!(error = ?e, "Failed to sign batch digest"),
+        }
+    }
+
+    async fn handle_signed_digest(&mut self, signed_digest: SignedDigest) {
+        match self
+            .proof_coordinator
+            .add_signature(signed_digest, &self.validator_verifier)
+        {
+            Ok(Some(proof)) => {
+                counters::PROOFS_CREATED_COUNT.inc();
+                self.network_sender.broadcast_proof_of_store(proof).await;
+            }
+            Ok(None) => (),
+            Err(e) => warn!(error = ?e, "Failed to add signed digest"),
+        }
+    }
+
+    fn handle_proof_of_store(&mut self, proof: ProofOfStore) {
+        self.proof_manager.add_proof(proof);
+        counters::NUM_PROOFS_IN_POOL.set(self.proof_manager.num_proofs() as i64);
+    }
+
+    async fn handle_network_event(&mut self, peer_id: AccountAddress, event: VerifiedEvent) {
+        match event {
+            VerifiedEvent::BatchMsg(batch) => self.handle_batch(peer_id, *batch).await,
+            VerifiedEvent::SignedDigestMsg(signed_digest) => {
+                self.handle_signed_digest(*signed_digest).await
+            }
+            VerifiedEvent::ProofOfStoreMsg(proof) => self.handle_proof_of_store(*proof),
+            _ => warn!(remote_peer = peer_id, "Unexpected quorum store event"),
+        }
+    }
+
+    async fn handle_tick(&mut self) {
+        let now = Instant::now();
+        for digest in self.proof_coordinator.expire(now) {
+            counters::PROOF_TIMEOUTS_COUNT.inc();
+            self.batch_generator.remove_batch(&digest);
+        }
+        if self.proof_coordinator.num_pending_proofs() >= self.config.max_pending_batches {
+            return;
+        }
+        let get_batch_start_time = Instant::now();
+        let result = match self
+            .batch_generator
+            .generate_batch(self.batch_store.last_committed_round())
+            .await
+        {
+            Ok(Some((txns, expiration))) => {
+                let batch = Batch::new(self.epoch, self.my_peer_id, expiration, txns);
+                self.batch_generator
+                    .add_batch(batch.digest(), batch.txns(), expiration);
+                self.proof_coordinator.init_proof(batch.info(), now);
+                // The batch is broadcast to self as well, so it is stored and signed locally
+                // through the same path as the batches of the other validators.
+                self.network_sender.broadcast_batch(batch).await;
+                counters::REQUEST_SUCCESS_LABEL
+            }
+            Ok(None) => counters::REQUEST_SUCCESS_LABEL,
+            Err(e) => {
+                error!(error = ?e, "GetBatch failed");
+                counters::REQUEST_FAIL_LABEL
+            }
+        };
+        counters::quorum_store_service_latency(
+            counters::GET_BATCH_LABEL,
+            result,
+            get_batch_start_time.elapsed(),
+        );
+    }
+
+    pub async fn start(
+        mut self,
+        mut consensus_receiver: Receiver<ConsensusRequest>,
+        mut network_msg_rx: aptos_channel::Receiver<
+            AccountAddress,
+            (AccountAddress, VerifiedEvent),
+        >,
+    ) {
+        info!(epoch = self.epoch, "QuorumStoreCoordinator starts");
+        let mut interval = tokio::time::interval(Duration::from_millis(
+            self.config.batch_generation_interval_ms,
+        ));
+        loop {
+            let _timer = counters::MAIN_LOOP.start_timer();
+            ::futures::select! {
+                msg = consensus_receiver.select_next_some() => {
+                    self.handle_consensus_request(msg);
+                },
+                event = network_msg_rx.next() => match event {
+                    Some((peer_id, event)) => self.handle_network_event(peer_id, event).await,
+                    // The epoch manager drops the sender when the epoch ends.
+                    None => break,
+                },
+                _ = interval.tick().fuse() => {
+                    self.handle_tick().await;
+                },
+            }
+        }
+        info!(epoch = self.epoch, "QuorumStoreCoordinator stops");
+    }
+}
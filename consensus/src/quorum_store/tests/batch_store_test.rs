@@ -0,0 +1,80 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::quorum_store::{batch_store::BatchStore, types::Batch};
+use aptos_types::{account_address::AccountAddress, transaction::SignedTransaction};
+use consensus_types::{
+    block::block_test_utils::random_payload, common::Payload, proof_of_store::LogicalTime,
+};
+
+fn random_txns(count: usize) -> Vec<SignedTransaction> {
+    match random_payload(count) {
+        Payload::DirectMempool(txns) => txns,
+        _ => unreachable!(),
+    }
+}
+
+fn batch_expiring_at(epoch: u64, round: u64) -> Batch {
+    Batch::new(
+        epoch,
+        AccountAddress::random(),
+        LogicalTime::new(epoch, round),
+        random_txns(3),
+    )
+}
+
+#[test]
+fn test_persist_and_get() {
+    let batch_store = BatchStore::new(1, 10, 100);
+    let batch = batch_expiring_at(1, 20);
+    let digest = batch.digest();
+
+    assert!(batch_store.get(&digest).is_none());
+    assert!(batch_store.persist(batch.clone()).unwrap());
+    // persisting the same batch again is a no-op
+    assert!(!batch_store.persist(batch.clone()).unwrap());
+    assert_eq!(batch_store.get(&digest), Some(batch));
+}
+
+#[test]
+fn test_persist_rejects_expiration_out_of_window() {
+    let batch_store = BatchStore::new(1, 10, 100);
+
+    // wrong epoch
+    assert!(batch_store.persist(batch_expiring_at(2, 20)).is_err());
+    // already expired
+    assert!(batch_store.persist(batch_expiring_at(1, 10)).is_err());
+    // too far in the future
+    assert!(batch_store.persist(batch_expiring_at(1, 111)).is_err());
+    assert!(batch_store.persist(batch_expiring_at(1, 110)).unwrap());
+}
+
+#[test]
+fn test_update_committed_time() {
+    let batch_store = BatchStore::new(1, 10, 100);
+    let early = batch_expiring_at(1, 20);
+    let late = batch_expiring_at(1, 30);
+    batch_store.persist(early.clone()).unwrap();
+    batch_store.persist(late.clone()).unwrap();
+
+    // commits from another epoch are ignored
+    assert!(batch_store
+        .update_committed_time(LogicalTime::new(2, 25))
+        .is_empty());
+    assert_eq!(batch_store.last_committed_round(), 10);
+
+    let expired = batch_store.update_committed_time(LogicalTime::new(1, 20));
+    assert_eq!(expired, vec![early.digest()]);
+    assert_eq!(batch_store.last_committed_round(), 20);
+    assert!(batch_store.get(&early.digest()).is_none());
+    assert_eq!(batch_store.get(&late.digest()), Some(late.clone()));
+
+    // stale commits do not move the committed round back
+    assert!(batch_store
+        .update_committed_time(LogicalTime::new(1, 15))
+        .is_empty());
+    assert_eq!(batch_store.last_committed_round(), 20);
+
+    let expired = batch_store.update_committed_time(LogicalTime::new(1, 40));
+    assert_eq!(expired, vec![late.digest()]);
+}
@@ -0,0 +1,94 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::quorum_store::proof_coordinator::ProofCoordinator;
+use aptos_crypto::HashValue;
+use aptos_types::validator_verifier::random_validator_verifier;
+use consensus_types::proof_of_store::{LogicalTime, SignedDigest, SignedDigestInfo};
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_proof_aggregation() {
+    let (signers, verifier) = random_validator_verifier(4, None, false);
+    let author = signers[0].author();
+    let mut proof_coordinator = ProofCoordinator::new(author, 10_000);
+    let info = SignedDigestInfo::new(
+        author,
+        HashValue::random(),
+        LogicalTime::new(1, 20),
+        10,
+        1000,
+    );
+    proof_coordinator.init_proof(info.clone(), Instant::now());
+
+    for signer in &signers[0..2] {
+        let signed_digest = SignedDigest::new(1, info.clone(), signer).unwrap();
+        assert!(proof_coordinator
+            .add_signature(signed_digest, &verifier)
+            .unwrap()
+            .is_none());
+    }
+    let signed_digest = SignedDigest::new(1, info.clone(), &signers[2]).unwrap();
+    let proof = proof_coordinator
+        .add_signature(signed_digest, &verifier)
+        .unwrap()
+        .unwrap();
+    assert_eq!(*proof.info(), info);
+    proof.verify(&verifier).unwrap();
+    assert_eq!(proof_coordinator.num_pending_proofs(), 0);
+
+    // signatures arriving after the proof is completed are ignored
+    let signed_digest = SignedDigest::new(1, info, &signers[3]).unwrap();
+    assert!(proof_coordinator
+        .add_signature(signed_digest, &verifier)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_reject_signature_for_other_author() {
+    let (signers, verifier) = random_validator_verifier(4, None, false);
+    let mut proof_coordinator = ProofCoordinator::new(signers[0].author(), 10_000);
+    let info = SignedDigestInfo::new(
+        signers[1].author(),
+        HashValue::random(),
+        LogicalTime::new(1, 20),
+        10,
+        1000,
+    );
+    let signed_digest = SignedDigest::new(1, info, &signers[0]).unwrap();
+    assert!(proof_coordinator
+        .add_signature(signed_digest, &verifier)
+        .is_err());
+}
+
+#[test]
+fn test_proof_timeout() {
+    let (signers, verifier) = random_validator_verifier(4, None, false);
+    let author = signers[0].author();
+    let mut proof_coordinator = ProofCoordinator::new(author, 100);
+    let now = Instant::now();
+    let info = SignedDigestInfo::new(
+        author,
+        HashValue::random(),
+        LogicalTime::new(1, 20),
+        10,
+        1000,
+    );
+    proof_coordinator.init_proof(info.clone(), now);
+
+    assert!(proof_coordinator
+        .expire(now + Duration::from_millis(99))
+        .is_empty());
+    assert_eq!(
+        proof_coordinator.expire(now + Duration::from_millis(100)),
+        vec![info.digest]
+    );
+    assert_eq!(proof_coordinator.num_pending_proofs(), 0);
+
+    let signed_digest = SignedDigest::new(1, info, &signers[1]).unwrap();
+    assert!(proof_coordinator
+        .add_signature(signed_digest, &verifier)
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,83 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::quorum_store::proof_manager::{ProofManager, PROOF_EXPIRATION_MARGIN_ROUNDS};
+use aptos_crypto::HashValue;
+use aptos_types::{account_address::AccountAddress, aggregate_signature::AggregateSignature};
+use consensus_types::proof_of_store::{LogicalTime, ProofOfStore, SignedDigestInfo};
+use std::collections::HashSet;
+
+fn proof_expiring_at(epoch: u64, round: u64, num_txns: u64) -> ProofOfStore {
+    ProofOfStore::new(
+        SignedDigestInfo::new(
+            AccountAddress::random(),
+            HashValue::random(),
+            LogicalTime::new(epoch, round),
+            num_txns,
+            num_txns * 100,
+        ),
+        AggregateSignature::empty(),
+    )
+}
+
+fn digests(proofs: &[ProofOfStore]) -> Vec<HashValue> {
+    proofs.iter().map(|proof| *proof.digest()).collect()
+}
+
+#[test]
+fn test_pull_proofs_in_order_within_limits() {
+    let mut proof_manager = ProofManager::new(1, 0);
+    let proofs: Vec<_> = (0..3).map(|_| proof_expiring_at(1, 100, 10)).collect();
+    for proof in &proofs {
+        proof_manager.add_proof(proof.clone());
+    }
+    // duplicates and proofs from other epochs are ignored
+    proof_manager.add_proof(proofs[0].clone());
+    proof_manager.add_proof(proof_expiring_at(2, 100, 10));
+    assert_eq!(proof_manager.num_proofs(), 3);
+
+    let pulled = proof_manager.pull_proofs(100, 10_000, &HashSet::new());
+    assert_eq!(digests(&pulled), digests(&proofs));
+
+    let pulled = proof_manager.pull_proofs(25, 10_000, &HashSet::new());
+    assert_eq!(digests(&pulled), digests(&proofs[0..2]));
+
+    let pulled = proof_manager.pull_proofs(100, 1_500, &HashSet::new());
+    assert_eq!(digests(&pulled), digests(&proofs[0..1]));
+
+    let excluded = HashSet::from([*proofs[0].digest()]);
+    let pulled = proof_manager.pull_proofs(100, 10_000, &excluded);
+    assert_eq!(digests(&pulled), digests(&proofs[1..3]));
+}
+
+#[test]
+fn test_skip_proofs_close_to_expiration() {
+    let mut proof_manager = ProofManager::new(1, 10);
+    let expiring = proof_expiring_at(1, 10 + PROOF_EXPIRATION_MARGIN_ROUNDS, 10);
+    let proposable = proof_expiring_at(1, 11 + PROOF_EXPIRATION_MARGIN_ROUNDS, 10);
+    proof_manager.add_proof(expiring);
+    proof_manager.add_proof(proposable.clone());
+
+    let pulled = proof_manager.pull_proofs(100, 10_000, &HashSet::new());
+    assert_eq!(digests(&pulled), vec![*proposable.digest()]);
+}
+
+#[test]
+fn test_handle_commit() {
+    let mut proof_manager = ProofManager::new(1, 0);
+    let committed = proof_expiring_at(1, 100, 10);
+    let expired = proof_expiring_at(1, 30, 10);
+    let remaining = proof_expiring_at(1, 100, 10);
+    proof_manager.add_proof(committed.clone());
+    proof_manager.add_proof(expired);
+    proof_manager.add_proof(remaining.clone());
+
+    proof_manager.handle_commit(LogicalTime::new(1, 30), vec![*committed.digest()]);
+    assert_eq!(proof_manager.num_proofs(), 1);
+    let pulled = proof_manager.pull_proofs(100, 10_000, &HashSet::new());
+    assert_eq!(digests(&pulled), vec![*remaining.digest()]);
+
+    // committed proofs arriving late are not added back
+    proof_manager.add_proof(committed);
+    assert_eq!(proof_manager.num_proofs(), 1);
+}
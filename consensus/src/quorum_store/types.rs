@@ -0,0 +1,164 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::ensure;
+use aptos_crypto::{hash::CryptoHash, HashValue};
+use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
+use aptos_types::{transaction::SignedTransaction, PeerId};
+use consensus_types::proof_of_store::{LogicalTime, SignedDigestInfo};
+use serde::{Deserialize, Serialize};
+use short_hex_str::AsShortHexStr;
+use std::fmt::{Display, Formatter};
+
+/// The transactions of a batch, hashed to obtain the batch digest.
+#[derive(Clone, Debug, Deserialize, Serialize, CryptoHasher, BCSCryptoHash)]
+pub struct BatchPayload {
+    txns: Vec<SignedTransaction>,
+}
+
+impl BatchPayload {
+    pub fn new(txns: Vec<SignedTransaction>) -> Self {
+        Self { txns }
+    }
+}
+
+/// A batch of transactions broadcast by its author to all validators, which store it until
+/// the expiration and sign its digest.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Batch {
+    epoch: u64,
+    author: PeerId,
+    expiration: LogicalTime,
+    digest: HashValue,
+    txns: Vec<SignedTransaction>,
+}
+
+impl Batch {
+    pub fn new(
+        epoch: u64,
+        author: PeerId,
+        expiration: LogicalTime,
+        txns: Vec<SignedTransaction>,
+    ) -> Self {
+        let payload = BatchPayload::new(txns);
+        let digest = payload.hash();
+        Self {
+            epoch,
+            author,
+            expiration,
+            digest,
+            txns: payload.txns,
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    pub fn author(&self) -> PeerId {
+        self.author
+    }
+
+    pub fn expiration(&self) -> LogicalTime {
+        self.expiration
+    }
+
+    pub fn digest(&self) -> HashValue {
+        self.digest
+    }
+
+    pub fn txns(&self) -> &[SignedTransaction] {
+        &self.txns
+    }
+
+    pub fn into_txns(self) -> Vec<SignedTransaction> {
+        self.txns
+    }
+
+    pub fn num_bytes(&self) -> u64 {
+        self.txns
+            .iter()
+            .map(|txn| txn.raw_txn_bytes_len() as u64)
+            .sum()
+    }
+
+    /// The information validators sign once they store the batch.
+    pub fn info(&self) -> SignedDigestInfo {
+        SignedDigestInfo::new(
+            self.author,
+            self.digest,
+            self.expiration,
+            self.txns.len() as u64,
+            self.num_bytes(),
+        )
+    }
+
+    /// Verifies that the digest matches the transactions.
+    pub fn verify(&self) -> anyhow::Result<()> {
+        ensure!(
+            self.epoch == self.expiration.epoch(),
+            "Batch epoch {} does not match the expiration {}",
+            self.epoch,
+            self.expiration
+        );
+        ensure!(
+            BatchPayload::new(self.txns.clone()).hash() == self.digest,
+            "Batch digest mismatch"
+        );
+        Ok(())
+    }
+}
+
+impl Display for Batch {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Batch: [author: {}, digest: {}, expiration: {}, txns: {}]",
+            self.author.short_str(),
+            self.digest,
+            self.expiration,
+            self.txns.len()
+        )
+    }
+}
+
+/// RPC request for a batch that is not available locally.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct BatchRequest {
+    epoch: u64,
+    source: PeerId,
+    digest: HashValue,
+}
+
+impl BatchRequest {
+    pub fn new(epoch: u64, source: PeerId, digest: HashValue) -> Self {
+        Self {
+            epoch,
+            source,
+            digest,
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    pub fn source(&self) -> PeerId {
+        self.source
+    }
+
+    pub fn digest(&self) -> HashValue {
+        self.digest
+    }
+}
+
+impl Display for BatchRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "BatchRequest: [source: {}, digest: {}]",
+            self.source.short_str(),
+            self.digest
+        )
+    }
+}
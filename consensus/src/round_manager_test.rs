@@ -14,7 +14,7 @@ use crate::{
     network_interface::{ConsensusMsg, ConsensusNetworkEvents, ConsensusNetworkSender},
     network_tests::{NetworkPlayground, TwinId},
     persistent_liveness_storage::RecoveryData,
-    round_manager::RoundManager,
+    round_manager::{RoundManager, UnverifiedEvent, VerifiedEvent},
     test_utils::{
         consensus_runtime, timed_block_on, MockPayloadManager, MockStateComputer, MockStorage,
         TreeInserter,
@@ -26,22 +26,26 @@ use aptos_crypto::HashValue;
 use aptos_infallible::Mutex;
 use aptos_secure_storage::Storage;
 use aptos_types::{
+    aggregate_signature::PartialSignatures,
     epoch_state::EpochState,
     ledger_info::{LedgerInfo, LedgerInfoWithSignatures},
     on_chain_config::OnChainConsensusConfig,
     transaction::SignedTransaction,
     validator_signer::ValidatorSigner,
-    validator_verifier::{generate_validator_verifier, random_validator_verifier},
+    validator_verifier::{
+        generate_validator_verifier, random_validator_verifier, ValidatorVerifier,
+    },
     waypoint::Waypoint,
 };
 use channel::{self, aptos_channel, message_queues::QueueStyle};
 use consensus_types::{
     block::{
-        block_test_utils::{certificate_for_genesis, gen_test_certificate},
+        block_test_utils::{certificate_for_genesis, gen_test_certificate, random_payload},
         Block,
     },
     block_retrieval::{BlockRetrievalRequest, BlockRetrievalStatus},
     common::{Author, Payload, Round},
+    proof_of_store::{LogicalTime, ProofOfStore, SignedDigestInfo},
     proposal_msg::ProposalMsg,
     sync_info::SyncInfo,
     timeout_2chain::{TwoChainTimeout, TwoChainTimeoutWithPartialSignatures},
@@ -352,6 +356,120 @@ fn vote_on_successful_proposal() {
     });
 }
 
+#[test]
+/// If the proposal carries certified batches of the quorum store, a vote should be sent
+fn vote_on_successful_quorum_store_proposal() {
+    let mut runtime = consensus_runtime();
+    let mut playground = NetworkPlayground::new(runtime.handle().clone());
+    let mut nodes = NodeSetup::create_nodes(&mut playground, runtime.handle().clone(), 1);
+    let node = &mut nodes[0];
+    let verifier: ValidatorVerifier = node.storage.get_validator_set().into();
+
+    let genesis_qc = certificate_for_genesis();
+    timed_block_on(&mut runtime, async {
+        // Start round 1 and clear the message queue
+        node.next_proposal().await;
+
+        let proof = proof_of_store(node.signer.author(), &[node.signer.clone()], &verifier);
+        let proposal = Block::new_proposal(
+            Payload::InQuorumStore(vec![proof]),
+            1,
+            1,
+            genesis_qc.clone(),
+            &node.signer,
+            Vec::new(),
+        )
+        .unwrap();
+        let proposal_id = proposal.id();
+        let proposal_msg = ProposalMsg::new(
+            proposal,
+            SyncInfo::new(genesis_qc.clone(), genesis_qc.clone(), None),
+        );
+        let verified_proposal = match UnverifiedEvent::ProposalMsg(Box::new(proposal_msg))
+            .verify(&verifier, true)
+            .unwrap()
+        {
+            VerifiedEvent::ProposalMsg(proposal_msg) => *proposal_msg,
+            _ => panic!("Unexpected verified event"),
+        };
+        node.round_manager
+            .process_proposal_msg(verified_proposal)
+            .await
+            .unwrap();
+        let vote_msg = node.next_vote().await;
+        assert_eq!(vote_msg.vote().author(), node.signer.author());
+        assert_eq!(vote_msg.vote().vote_data().proposed().id(), proposal_id);
+        assert_eq!(node.round_manager.consensus_state().last_voted_round(), 1);
+    });
+}
+
+#[test]
+/// Proposals are only accepted with the payload type of the configured mode, and quorum store
+/// payloads only with batches certified by a quorum of the validators.
+fn verify_quorum_store_proposal_payload() {
+    let (signers, verifier) = random_validator_verifier(4, None, false);
+    let author = signers[0].author();
+    let genesis_qc = certificate_for_genesis();
+    let verify_payload = |payload: Payload, quorum_store_enabled: bool| {
+        let proposal =
+            Block::new_proposal(payload, 1, 1, genesis_qc.clone(), &signers[0], Vec::new())
+                .unwrap();
+        let proposal_msg = ProposalMsg::new(
+            proposal,
+            SyncInfo::new(genesis_qc.clone(), genesis_qc.clone(), None),
+        );
+        UnverifiedEvent::ProposalMsg(Box::new(proposal_msg))
+            .verify(&verifier, quorum_store_enabled)
+            .is_ok()
+    };
+
+    let certified_proof = proof_of_store(author, &signers[0..3], &verifier);
+    let uncertified_proof = proof_of_store(author, &signers[0..2], &verifier);
+
+    // direct mempool payloads are only accepted without quorum store, unless they are empty
+    assert!(verify_payload(random_payload(1), false));
+    assert!(!verify_payload(random_payload(1), true));
+    assert!(verify_payload(Payload::empty(), true));
+
+    // quorum store payloads are only accepted with quorum store
+    assert!(!verify_payload(
+        Payload::InQuorumStore(vec![certified_proof.clone()]),
+        false
+    ));
+    assert!(verify_payload(
+        Payload::InQuorumStore(vec![certified_proof.clone()]),
+        true
+    ));
+    assert!(!verify_payload(
+        Payload::InQuorumStore(vec![uncertified_proof]),
+        true
+    ));
+    assert!(!verify_payload(
+        Payload::InQuorumStore(vec![certified_proof.clone(), certified_proof]),
+        true
+    ));
+}
+
+/// Builds the proof of a random batch of the author, signed by the given signers.
+fn proof_of_store(
+    author: Author,
+    signers: &[ValidatorSigner],
+    verifier: &ValidatorVerifier,
+) -> ProofOfStore {
+    let info = SignedDigestInfo::new(
+        author,
+        HashValue::random(),
+        LogicalTime::new(1, 20),
+        10,
+        1000,
+    );
+    let mut signatures = PartialSignatures::empty();
+    for signer in signers {
+        signatures.add_signature(signer.author(), signer.sign(&info).unwrap());
+    }
+    ProofOfStore::new(info, verifier.aggregate_signatures(&signatures).unwrap())
+}
+
 #[test]
 /// In back pressure mode, verify that the proposals are processed after we get out of back pressure.
 fn delay_proposal_processing_in_sync_only() {
@@ -3,7 +3,7 @@
 
 use crate::{
     error::StateSyncError,
-    state_replication::{StateComputer, StateComputerCommitCallBackType},
+    state_replication::{PayloadManager, StateComputer, StateComputerCommitCallBackType},
     test_utils::mock_storage::MockStorage,
 };
 use anyhow::{format_err, Result};
@@ -22,7 +22,8 @@ pub struct MockStateComputer {
     state_sync_client: mpsc::UnboundedSender<Vec<SignedTransaction>>,
     commit_callback: mpsc::UnboundedSender<LedgerInfoWithSignatures>,
     consensus_db: Arc<MockStorage>,
-    block_cache: Mutex<HashMap<HashValue, Payload>>,
+    block_cache: Mutex<HashMap<HashValue, Vec<SignedTransaction>>>,
+    payload_manager: Mutex<Option<Arc<dyn PayloadManager>>>,
 }
 
 impl MockStateComputer {
@@ -36,6 +37,7 @@ impl MockStateComputer {
             commit_callback,
             consensus_db,
             block_cache: Mutex::new(HashMap::new()),
+            payload_manager: Mutex::new(None),
         }
     }
 }
@@ -47,10 +49,16 @@ impl StateComputer for MockStateComputer {
         block: &Block,
         _parent_block_id: HashValue,
     ) -> Result<StateComputeResult, Error> {
-        self.block_cache.lock().insert(
-            block.id(),
-            block.payload().unwrap_or(&Payload::empty()).clone(),
-        );
+        // The payload manager of the epoch resolves the batches of quorum store payloads.
+        let payload_manager = self.payload_manager.lock().clone();
+        let txns = match payload_manager {
+            Some(payload_manager) => payload_manager.get_transactions(block).await?,
+            None => match block.payload() {
+                Some(Payload::DirectMempool(txns)) => txns.clone(),
+                _ => vec![],
+            },
+        };
+        self.block_cache.lock().insert(block.id(), txns);
         let result = StateComputeResult::new_dummy();
         Ok(result)
     }
@@ -71,9 +79,7 @@ impl StateComputer for MockStateComputer {
                 .block_cache
                 .lock()
                 .remove(&block.id())
-                .ok_or_else(|| format_err!("Cannot find block"))?
-                .into_iter()
-                .collect();
+                .ok_or_else(|| format_err!("Cannot find block"))?;
             txns.append(&mut payload);
         }
         // they may fail during shutdown
@@ -99,7 +105,9 @@ impl StateComputer for MockStateComputer {
         Ok(())
     }
 
-    fn new_epoch(&self, _: &EpochState) {}
+    fn new_epoch(&self, _: &EpochState, payload_manager: Arc<dyn PayloadManager>) {
+        *self.payload_manager.lock() = Some(payload_manager);
+    }
 }
 
 pub struct EmptyStateComputer;
@@ -127,7 +135,7 @@ impl StateComputer for EmptyStateComputer {
         Ok(())
     }
 
-    fn new_epoch(&self, _: &EpochState) {}
+    fn new_epoch(&self, _: &EpochState, _: Arc<dyn PayloadManager>) {}
 }
 
 /// Random Compute Result State Computer
@@ -178,5 +186,5 @@ impl StateComputer for RandomComputeResultStateComputer {
         Ok(())
     }
 
-    fn new_epoch(&self, _: &EpochState) {}
+    fn new_epoch(&self, _: &EpochState, _: Arc<dyn PayloadManager>) {}
 }
@@ -7,12 +7,16 @@ use crate::{
     test_utils::{consensus_runtime, timed_block_on},
     twins::twins_node::SMRNode,
 };
+use aptos_crypto::HashValue;
 use aptos_types::on_chain_config::ProposerElectionType::{
     FixedProposer, RotatingProposer, RoundProposer,
 };
-use consensus_types::{block::Block, common::Round};
+use consensus_types::{
+    block::{block_test_utils::random_payload, Block},
+    common::{Payload, Round},
+};
 use futures::StreamExt;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[test]
 /// This test checks that the first proposal has its parent and
@@ -35,6 +39,7 @@ fn basic_start_test() {
         &mut playground,
         RotatingProposer(2),
         None,
+        false,
     );
     let genesis = Block::make_genesis_block_from_ledger_info(&nodes[0].storage.get_ledger_info());
     timed_block_on(&mut runtime, async {
@@ -87,6 +92,7 @@ fn drop_config_test() {
         &mut playground,
         FixedProposer(2),
         None,
+        false,
     );
 
     // 4 honest nodes
@@ -143,6 +149,7 @@ fn twins_vote_dedup_test() {
         &mut playground,
         RotatingProposer(2),
         None,
+        false,
     );
 
     // 4 honest nodes
@@ -210,6 +217,7 @@ fn twins_proposer_test() {
         &mut playground,
         RoundProposer(HashMap::new()),
         Some(round_proposers),
+        false,
     );
 
     // 4 honest nodes
@@ -293,6 +301,7 @@ fn twins_commit_test() {
         &mut playground,
         RoundProposer(HashMap::new()),
         Some(round_proposers),
+        false,
     );
     runtime.spawn(playground.start());
 
@@ -312,3 +321,59 @@ fn twins_commit_test() {
         }
     });
 }
+
+#[test]
+/// This test checks that the transactions disseminated through
+/// the quorum store are committed.
+///
+/// Setup:
+///
+/// 4 honest nodes (n0, n1, n2, n3) with quorum store enabled, and 0 twins.
+/// Add transactions to the mempool of n1.
+///
+/// Test:
+///
+/// Run consensus until n1 commits all of the transactions, which
+/// requires n1 to batch them and the proposers to certify and
+/// propose the proofs of the batches.
+///
+/// Run the test:
+/// cargo xtest -p consensus quorum_store_commit_test -- --nocapture
+fn quorum_store_commit_test() {
+    let mut runtime = consensus_runtime();
+    let mut playground = NetworkPlayground::new(runtime.handle().clone());
+    let num_nodes = 4;
+    let num_twins = 0;
+    let mut nodes = SMRNode::start_num_nodes_with_twins(
+        num_nodes,
+        num_twins,
+        &mut playground,
+        RotatingProposer(2),
+        None,
+        true,
+    );
+
+    let txns = match random_payload(10) {
+        Payload::DirectMempool(txns) => txns,
+        _ => unreachable!(),
+    };
+    let mut pending: HashSet<HashValue> = txns
+        .iter()
+        .map(|txn| txn.clone().committed_hash())
+        .collect();
+    nodes[1].shared_mempool.add_txns(txns).unwrap();
+    runtime.spawn(playground.start());
+
+    timed_block_on(&mut runtime, async {
+        while !pending.is_empty() {
+            let committed_txns = nodes[1]
+                .state_sync
+                .next()
+                .await
+                .expect("[TwinsTest] State sync channel closed");
+            for txn in committed_txns {
+                pending.remove(&txn.committed_hash());
+            }
+        }
+    });
+}
@@ -50,8 +50,8 @@ pub struct SMRNode {
     pub storage: Arc<MockStorage>,
     pub commit_cb_receiver: mpsc::UnboundedReceiver<LedgerInfoWithSignatures>,
     _runtime: Runtime,
-    _shared_mempool: MockSharedMempool,
-    _state_sync: mpsc::UnboundedReceiver<Vec<SignedTransaction>>,
+    pub shared_mempool: MockSharedMempool,
+    pub state_sync: mpsc::UnboundedReceiver<Vec<SignedTransaction>>,
 }
 
 fn author_from_config(config: &NodeConfig) -> Author {
@@ -83,7 +83,7 @@ impl SMRNode {
         let (state_sync_client, state_sync) = mpsc::unbounded();
         let (commit_cb_sender, commit_cb_receiver) = mpsc::unbounded::<LedgerInfoWithSignatures>();
         let shared_mempool = MockSharedMempool::new();
-        let (quorum_store_to_mempool_sender, _) = mpsc::channel(1_024);
+        let quorum_store_to_mempool_sender = shared_mempool.consensus_to_mempool_sender.clone();
         let state_computer = Arc::new(MockStateComputer::new(
             state_sync_client,
             commit_cb_sender,
@@ -154,8 +154,8 @@ impl SMRNode {
             _runtime: runtime,
             commit_cb_receiver,
             storage,
-            _shared_mempool: shared_mempool,
-            _state_sync: state_sync,
+            shared_mempool,
+            state_sync,
         }
     }
 
@@ -166,6 +166,7 @@ impl SMRNode {
         playground: &mut NetworkPlayground,
         proposer_type: ProposerElectionType,
         round_proposers_idx: Option<HashMap<Round, usize>>,
+        use_quorum_store: bool,
     ) -> Vec<Self> {
         assert!(num_nodes >= num_twins);
         let ValidatorSwarm {
@@ -239,6 +240,7 @@ impl SMRNode {
             config.base.waypoint = WaypointConfig::FromConfig(waypoint);
             // Disable timeout in twins test to avoid flakiness
             config.consensus.round_initial_timeout_ms = 2_000_000;
+            config.consensus.use_quorum_store = use_quorum_store;
 
             let author = author_from_config(&config);
 
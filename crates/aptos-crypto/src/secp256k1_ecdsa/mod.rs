@@ -0,0 +1,57 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module provides an API for the ECDSA signature scheme over the secp256k1 curve, as used by
+//! Bitcoin and Ethereum, with SHA3-256 as the message digest.
+//!
+//! Note that signatures are produced over the Aptos signing message of the value (see
+//! `CryptoHash`), not over an EIP-191 Keccak-256 digest, so they can't be produced by Ethereum
+//! wallets as is.
+//!
+//! Signatures are encoded as the 64 byte concatenation of their `r` and `s` components. As any
+//! ECDSA signature `(r, s)` can be turned into another valid signature `(r, n - s)` for the same
+//! message, signing always produces, and verification only accepts, signatures with a "low" `s`
+//! (i.e. `s <= n / 2`) to prevent signature malleability.
+//!
+//! # Examples
+//!
+//! ```
+//! use aptos_crypto_derive::{CryptoHasher, BCSCryptoHash};
+//! use aptos_crypto::{
+//!     secp256k1_ecdsa::*,
+//!     traits::{Signature, SigningKey, Uniform},
+//!     test_utils::KeyPair
+//! };
+//! use rand_core::OsRng;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, CryptoHasher, BCSCryptoHash)]
+//! pub struct TestCryptoDocTest(String);
+//! let message = TestCryptoDocTest("Test message".to_string());
+//!
+//! let mut rng = OsRng;
+//! let kp = KeyPair::<Secp256k1EcdsaPrivateKey, Secp256k1EcdsaPublicKey>::generate(&mut rng);
+//!
+//! let signature = kp.private_key.sign(&message).unwrap();
+//! assert!(signature.verify(&message, &kp.public_key).is_ok());
+//! ```
+
+/// The length of the Secp256k1EcdsaPrivateKey
+pub const SECP256K1_ECDSA_PRIVATE_KEY_LENGTH: usize = 32;
+/// The length of the Secp256k1EcdsaPublicKey, in uncompressed SEC1 encoding
+pub const SECP256K1_ECDSA_PUBLIC_KEY_LENGTH: usize = 65;
+/// The length of the Secp256k1EcdsaSignature
+pub const SECP256K1_ECDSA_SIGNATURE_LENGTH: usize = 64;
+
+pub mod secp256k1_ecdsa_keys;
+pub mod secp256k1_ecdsa_sigs;
+
+#[cfg(any(test, feature = "fuzzing"))]
+pub use secp256k1_ecdsa_keys::keypair_strategy;
+
+pub use secp256k1_ecdsa_keys::{Secp256k1EcdsaPrivateKey, Secp256k1EcdsaPublicKey};
+pub use secp256k1_ecdsa_sigs::Secp256k1EcdsaSignature;
+
+pub use secp256k1_ecdsa_keys::Secp256k1EcdsaPrivateKey as PrivateKey;
+pub use secp256k1_ecdsa_keys::Secp256k1EcdsaPublicKey as PublicKey;
+pub use secp256k1_ecdsa_sigs::Secp256k1EcdsaSignature as Signature;
@@ -0,0 +1,161 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! This file implements traits for Secp256k1 ECDSA signatures.
+
+use crate::{
+    hash::CryptoHash,
+    secp256k1_ecdsa::{
+        Secp256k1EcdsaPrivateKey, Secp256k1EcdsaPublicKey, SECP256K1_ECDSA_SIGNATURE_LENGTH,
+    },
+    traits::*,
+    HashValue,
+};
+use anyhow::{anyhow, Result};
+use aptos_crypto_derive::{DeserializeKey, SerializeKey};
+use core::convert::TryFrom;
+use serde::Serialize;
+use std::fmt;
+
+/// A Secp256k1 ECDSA signature
+#[derive(DeserializeKey, Clone, SerializeKey)]
+pub struct Secp256k1EcdsaSignature(pub(crate) libsecp256k1::Signature);
+
+impl Secp256k1EcdsaSignature {
+    /// The length of the Secp256k1EcdsaSignature
+    pub const LENGTH: usize = SECP256K1_ECDSA_SIGNATURE_LENGTH;
+
+    /// Serialize a Secp256k1EcdsaSignature as the concatenation of its `r` and `s` components.
+    pub fn to_bytes(&self) -> [u8; SECP256K1_ECDSA_SIGNATURE_LENGTH] {
+        self.0.serialize()
+    }
+
+    /// Deserialize a Secp256k1EcdsaSignature without any validation checks (malleability)
+    /// apart from expected signature size and `r` and `s` components smaller than the order of
+    /// the curve.
+    pub(crate) fn from_bytes_unchecked(
+        bytes: &[u8],
+    ) -> std::result::Result<Secp256k1EcdsaSignature, CryptoMaterialError> {
+        if bytes.len() != SECP256K1_ECDSA_SIGNATURE_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        match libsecp256k1::Signature::parse_standard_slice(bytes) {
+            Ok(signature) => Ok(Secp256k1EcdsaSignature(signature)),
+            Err(_) => Err(CryptoMaterialError::DeserializationError),
+        }
+    }
+
+    /// Check for signature malleability: given a valid signature `(r, s)`, `(r, n - s)` is also a
+    /// valid signature for the same message and key. We only accept the signature with the
+    /// "low" `s`, i.e. `s <= n / 2`, where `n` is the order of the curve.
+    ///
+    /// Note: malicious signers can still produce varying signatures by choosing a different
+    /// nonce, so this method protects against malleability attacks performed by a non-signer.
+    pub fn check_s_malleability(bytes: &[u8]) -> std::result::Result<(), CryptoMaterialError> {
+        let signature = Secp256k1EcdsaSignature::from_bytes_unchecked(bytes)?;
+        if signature.0.s.is_high() {
+            return Err(CryptoMaterialError::CanonicalRepresentationError);
+        }
+        Ok(())
+    }
+
+    /// Returns the signature with a "low" `s`, which is required for the signature to verify.
+    /// This is useful for signatures produced by other implementations, e.g. by hardware wallets,
+    /// which don't necessarily normalize their signatures.
+    pub fn normalize_s(mut self) -> Self {
+        self.0.normalize_s();
+        self
+    }
+}
+
+//////////////////////
+// Signature Traits //
+//////////////////////
+
+impl Signature for Secp256k1EcdsaSignature {
+    type VerifyingKeyMaterial = Secp256k1EcdsaPublicKey;
+    type SigningKeyMaterial = Secp256k1EcdsaPrivateKey;
+
+    /// Verifies that the provided signature is valid for the provided message, rejecting
+    /// signatures with a "high" `s` (see [Secp256k1EcdsaSignature::check_s_malleability][Secp256k1EcdsaSignature::check_s_malleability]).
+    fn verify<T: CryptoHash + Serialize>(
+        &self,
+        message: &T,
+        public_key: &Secp256k1EcdsaPublicKey,
+    ) -> Result<()> {
+        Self::verify_arbitrary_msg(self, &signing_message(message)?, public_key)
+    }
+
+    /// Checks that `self` is valid for an arbitrary &[u8] `message` using `public_key`. The
+    /// message is hashed with SHA3-256 before verification.
+    fn verify_arbitrary_msg(
+        &self,
+        message: &[u8],
+        public_key: &Secp256k1EcdsaPublicKey,
+    ) -> Result<()> {
+        Secp256k1EcdsaSignature::check_s_malleability(&self.to_bytes())?;
+
+        let message = libsecp256k1::Message::parse(HashValue::sha3_256_of(message).as_ref());
+        if libsecp256k1::verify(&message, &self.0, &public_key.0) {
+            Ok(())
+        } else {
+            Err(anyhow!("Secp256k1 ECDSA signature verification failed"))
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl Length for Secp256k1EcdsaSignature {
+    fn length(&self) -> usize {
+        SECP256K1_ECDSA_SIGNATURE_LENGTH
+    }
+}
+
+impl ValidCryptoMaterial for Secp256k1EcdsaSignature {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl std::hash::Hash for Secp256k1EcdsaSignature {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let encoded_signature = self.to_bytes();
+        state.write(&encoded_signature);
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256k1EcdsaSignature {
+    type Error = CryptoMaterialError;
+
+    /// Deserialize a Secp256k1EcdsaSignature. Signatures with a "high" `s` are NOT rejected here
+    /// so that signatures produced by other implementations can be parsed and then normalized
+    /// with [Secp256k1EcdsaSignature::normalize_s][Secp256k1EcdsaSignature::normalize_s];
+    /// they are rejected during verification.
+    fn try_from(bytes: &[u8]) -> std::result::Result<Secp256k1EcdsaSignature, CryptoMaterialError> {
+        Secp256k1EcdsaSignature::from_bytes_unchecked(bytes)
+    }
+}
+
+// Those are required by the implementation of hash above
+impl PartialEq for Secp256k1EcdsaSignature {
+    fn eq(&self, other: &Secp256k1EcdsaSignature) -> bool {
+        self.to_bytes()[..] == other.to_bytes()[..]
+    }
+}
+
+impl Eq for Secp256k1EcdsaSignature {}
+
+impl fmt::Display for Secp256k1EcdsaSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.to_bytes()[..]))
+    }
+}
+
+impl fmt::Debug for Secp256k1EcdsaSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secp256k1EcdsaSignature({})", self)
+    }
+}
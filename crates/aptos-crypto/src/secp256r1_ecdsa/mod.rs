@@ -0,0 +1,57 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module provides an API for the ECDSA signature scheme over the NIST P-256 curve (a.k.a.
+//! secp256r1), with SHA2-256 as the message digest, as specified in
+//! [FIPS 186-4](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.186-4.pdf).
+//!
+//! This is the scheme implemented by secure enclaves and by WebAuthn authenticators (passkeys),
+//! where it is known as ES256.
+//!
+//! Signatures are encoded as the 64 byte concatenation of their `r` and `s` components. As any
+//! ECDSA signature `(r, s)` can be turned into another valid signature `(r, n - s)` for the same
+//! message, signing always produces, and verification only accepts, signatures with a "low" `s`
+//! (i.e. `s <= n / 2`) to prevent signature malleability.
+//!
+//! # Examples
+//!
+//! ```
+//! use aptos_crypto_derive::{CryptoHasher, BCSCryptoHash};
+//! use aptos_crypto::{
+//!     secp256r1_ecdsa::*,
+//!     traits::{Signature, SigningKey, Uniform},
+//!     test_utils::KeyPair
+//! };
+//! use rand_core::OsRng;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, CryptoHasher, BCSCryptoHash)]
+//! pub struct TestCryptoDocTest(String);
+//! let message = TestCryptoDocTest("Test message".to_string());
+//!
+//! let mut rng = OsRng;
+//! let kp = KeyPair::<Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey>::generate(&mut rng);
+//!
+//! let signature = kp.private_key.sign(&message).unwrap();
+//! assert!(signature.verify(&message, &kp.public_key).is_ok());
+//! ```
+
+/// The length of the Secp256r1EcdsaPrivateKey
+pub const SECP256R1_ECDSA_PRIVATE_KEY_LENGTH: usize = 32;
+/// The length of the Secp256r1EcdsaPublicKey, in uncompressed SEC1 encoding
+pub const SECP256R1_ECDSA_PUBLIC_KEY_LENGTH: usize = 65;
+/// The length of the Secp256r1EcdsaSignature
+pub const SECP256R1_ECDSA_SIGNATURE_LENGTH: usize = 64;
+
+pub mod secp256r1_ecdsa_keys;
+pub mod secp256r1_ecdsa_sigs;
+
+#[cfg(any(test, feature = "fuzzing"))]
+pub use secp256r1_ecdsa_keys::keypair_strategy;
+
+pub use secp256r1_ecdsa_keys::{Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey};
+pub use secp256r1_ecdsa_sigs::Secp256r1EcdsaSignature;
+
+pub use secp256r1_ecdsa_keys::Secp256r1EcdsaPrivateKey as PrivateKey;
+pub use secp256r1_ecdsa_keys::Secp256r1EcdsaPublicKey as PublicKey;
+pub use secp256r1_ecdsa_sigs::Secp256r1EcdsaSignature as Signature;
@@ -0,0 +1,163 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! This file implements traits for Secp256r1 ECDSA signatures.
+
+use crate::{
+    hash::CryptoHash,
+    secp256r1_ecdsa::{
+        Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey, SECP256R1_ECDSA_SIGNATURE_LENGTH,
+    },
+    traits::*,
+};
+use anyhow::{anyhow, Result};
+use aptos_crypto_derive::{DeserializeKey, SerializeKey};
+use core::convert::TryFrom;
+use p256::ecdsa::signature::Verifier;
+use serde::Serialize;
+use std::fmt;
+
+/// A Secp256r1 ECDSA signature
+#[derive(DeserializeKey, Clone, SerializeKey)]
+pub struct Secp256r1EcdsaSignature(pub(crate) p256::ecdsa::Signature);
+
+impl Secp256r1EcdsaSignature {
+    /// The length of the Secp256r1EcdsaSignature
+    pub const LENGTH: usize = SECP256R1_ECDSA_SIGNATURE_LENGTH;
+
+    /// Serialize a Secp256r1EcdsaSignature as the concatenation of its `r` and `s` components.
+    pub fn to_bytes(&self) -> [u8; SECP256R1_ECDSA_SIGNATURE_LENGTH] {
+        let mut bytes = [0u8; SECP256R1_ECDSA_SIGNATURE_LENGTH];
+        bytes.copy_from_slice(self.0.as_ref());
+        bytes
+    }
+
+    /// Deserialize a Secp256r1EcdsaSignature without any validation checks (malleability)
+    /// apart from expected signature size and non-zero `r` and `s` components.
+    pub(crate) fn from_bytes_unchecked(
+        bytes: &[u8],
+    ) -> std::result::Result<Secp256r1EcdsaSignature, CryptoMaterialError> {
+        if bytes.len() != SECP256R1_ECDSA_SIGNATURE_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        match p256::ecdsa::Signature::try_from(bytes) {
+            Ok(signature) => Ok(Secp256r1EcdsaSignature(signature)),
+            Err(_) => Err(CryptoMaterialError::DeserializationError),
+        }
+    }
+
+    /// Check for signature malleability: given a valid signature `(r, s)`, `(r, n - s)` is also a
+    /// valid signature for the same message and key. We only accept the signature with the
+    /// "low" `s`, i.e. `s <= n / 2`, where `n` is the order of the curve.
+    ///
+    /// Note: malicious signers can still produce varying signatures by choosing a different
+    /// nonce, so this method protects against malleability attacks performed by a non-signer.
+    pub fn check_s_malleability(bytes: &[u8]) -> std::result::Result<(), CryptoMaterialError> {
+        let signature = Secp256r1EcdsaSignature::from_bytes_unchecked(bytes)?;
+        if signature.0.normalize_s().is_some() {
+            return Err(CryptoMaterialError::CanonicalRepresentationError);
+        }
+        Ok(())
+    }
+
+    /// Returns the signature with a "low" `s`, which is required for the signature to verify.
+    /// This is useful for signatures produced by other implementations, e.g. by WebAuthn
+    /// authenticators, which don't normalize their signatures.
+    pub fn normalize_s(self) -> Self {
+        match self.0.normalize_s() {
+            Some(normalized) => Secp256r1EcdsaSignature(normalized),
+            None => self,
+        }
+    }
+}
+
+//////////////////////
+// Signature Traits //
+//////////////////////
+
+impl Signature for Secp256r1EcdsaSignature {
+    type VerifyingKeyMaterial = Secp256r1EcdsaPublicKey;
+    type SigningKeyMaterial = Secp256r1EcdsaPrivateKey;
+
+    /// Verifies that the provided signature is valid for the provided message, rejecting
+    /// signatures with a "high" `s` (see [Secp256r1EcdsaSignature::check_s_malleability][Secp256r1EcdsaSignature::check_s_malleability]).
+    fn verify<T: CryptoHash + Serialize>(
+        &self,
+        message: &T,
+        public_key: &Secp256r1EcdsaPublicKey,
+    ) -> Result<()> {
+        Self::verify_arbitrary_msg(self, &signing_message(message)?, public_key)
+    }
+
+    /// Checks that `self` is valid for an arbitrary &[u8] `message` using `public_key`. The
+    /// message is hashed with SHA2-256 before verification.
+    fn verify_arbitrary_msg(
+        &self,
+        message: &[u8],
+        public_key: &Secp256r1EcdsaPublicKey,
+    ) -> Result<()> {
+        Secp256r1EcdsaSignature::check_s_malleability(&self.to_bytes())?;
+
+        public_key
+            .0
+            .verify(message, &self.0)
+            .map_err(|e| anyhow!("{}", e))
+            .and(Ok(()))
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl Length for Secp256r1EcdsaSignature {
+    fn length(&self) -> usize {
+        SECP256R1_ECDSA_SIGNATURE_LENGTH
+    }
+}
+
+impl ValidCryptoMaterial for Secp256r1EcdsaSignature {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl std::hash::Hash for Secp256r1EcdsaSignature {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let encoded_signature = self.to_bytes();
+        state.write(&encoded_signature);
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256r1EcdsaSignature {
+    type Error = CryptoMaterialError;
+
+    /// Deserialize a Secp256r1EcdsaSignature. Signatures with a "high" `s` are NOT rejected here
+    /// so that signatures produced by other implementations can be parsed and then normalized
+    /// with [Secp256r1EcdsaSignature::normalize_s][Secp256r1EcdsaSignature::normalize_s];
+    /// they are rejected during verification.
+    fn try_from(bytes: &[u8]) -> std::result::Result<Secp256r1EcdsaSignature, CryptoMaterialError> {
+        Secp256r1EcdsaSignature::from_bytes_unchecked(bytes)
+    }
+}
+
+// Those are required by the implementation of hash above
+impl PartialEq for Secp256r1EcdsaSignature {
+    fn eq(&self, other: &Secp256r1EcdsaSignature) -> bool {
+        self.to_bytes()[..] == other.to_bytes()[..]
+    }
+}
+
+impl Eq for Secp256r1EcdsaSignature {}
+
+impl fmt::Display for Secp256r1EcdsaSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.to_bytes()[..]))
+    }
+}
+
+impl fmt::Debug for Secp256r1EcdsaSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secp256r1EcdsaSignature({})", self)
+    }
+}
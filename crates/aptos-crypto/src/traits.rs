@@ -315,6 +315,14 @@ pub(crate) mod private {
     impl Sealed for crate::multi_ed25519::MultiEd25519PublicKey {}
     impl Sealed for crate::multi_ed25519::MultiEd25519Signature {}
 
+    impl Sealed for crate::secp256k1_ecdsa::Secp256k1EcdsaPrivateKey {}
+    impl Sealed for crate::secp256k1_ecdsa::Secp256k1EcdsaPublicKey {}
+    impl Sealed for crate::secp256k1_ecdsa::Secp256k1EcdsaSignature {}
+
+    impl Sealed for crate::secp256r1_ecdsa::Secp256r1EcdsaPrivateKey {}
+    impl Sealed for crate::secp256r1_ecdsa::Secp256r1EcdsaPublicKey {}
+    impl Sealed for crate::secp256r1_ecdsa::Secp256r1EcdsaSignature {}
+
     impl Sealed for crate::bls12381::PrivateKey {}
     impl Sealed for crate::bls12381::PublicKey {}
     impl Sealed for crate::bls12381::Signature {}
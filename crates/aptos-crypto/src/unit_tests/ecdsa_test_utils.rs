@@ -0,0 +1,95 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Checks shared by the tests of the ECDSA signature schemes, generic over the scheme. Each
+//! scheme's test module only keeps the cases specific to its curve.
+
+use crate::{test_utils::TestAptosCrypto, traits::*, CryptoMaterialError};
+
+use core::{convert::TryFrom, fmt::Debug};
+use proptest::prelude::*;
+
+/// Checks that the key material round trips through its hex and byte encodings.
+pub(crate) fn check_encoding_round_trip<T>(material: &T, length: usize) -> Result<(), TestCaseError>
+where
+    T: ValidCryptoMaterial + PartialEq + Debug,
+{
+    let encoded = material.to_encoded_string().unwrap();
+    // Hex encoding of a `length`-bytes material is 2 x `length` characters, after the 0x prefix.
+    prop_assert_eq!(2 + 2 * length, encoded.len());
+    let decoded = T::from_encoded_string(&encoded);
+    prop_assert_eq!(Some(material), decoded.ok().as_ref());
+
+    let serialized: &[u8] = &(material.to_bytes());
+    prop_assert_eq!(length, serialized.len());
+    let deserialized = T::try_from(serialized);
+    prop_assert_eq!(Some(material), deserialized.ok().as_ref());
+    Ok(())
+}
+
+/// Checks that a signature of the private key has the expected length, and verifies against the
+/// public key before and after a round trip through its byte encoding.
+pub(crate) fn check_sign_and_verify<S: Signature>(
+    private_key: &S::SigningKeyMaterial,
+    public_key: &S::VerifyingKeyMaterial,
+    message: &TestAptosCrypto,
+    signature_length: usize,
+) -> Result<(), TestCaseError> {
+    let signature = private_key.sign(message).unwrap();
+    prop_assert_eq!(signature_length, signature.to_bytes().len());
+    prop_assert!(signature.verify(message, public_key).is_ok());
+
+    let serialized: &[u8] = &(signature.to_bytes());
+    let deserialized = S::try_from(serialized).unwrap();
+    prop_assert!(deserialized.verify(message, public_key).is_ok());
+    Ok(())
+}
+
+/// Checks that a signature of the private key doesn't verify against another public key.
+pub(crate) fn check_verify_fails_with_other_key<S: Signature>(
+    private_key: &S::SigningKeyMaterial,
+    other_public_key: &S::VerifyingKeyMaterial,
+    message: &TestAptosCrypto,
+) -> Result<(), TestCaseError> {
+    let signature = private_key.sign(message).unwrap();
+    prop_assert!(signature.verify(message, other_public_key).is_err());
+    Ok(())
+}
+
+/// Checks that keys and signatures of the wrong length are rejected.
+pub(crate) fn check_invalid_lengths<S: Signature>(
+    private_key_length: usize,
+    public_key_length: usize,
+    signature_length: usize,
+) {
+    assert_eq!(
+        S::SigningKeyMaterial::try_from(&vec![1u8; private_key_length - 1][..]).err(),
+        Some(CryptoMaterialError::WrongLengthError)
+    );
+    assert_eq!(
+        S::VerifyingKeyMaterial::try_from(&vec![4u8; public_key_length - 1][..]).err(),
+        Some(CryptoMaterialError::WrongLengthError)
+    );
+    assert_eq!(
+        S::try_from(&vec![1u8; signature_length + 1][..]).err(),
+        Some(CryptoMaterialError::WrongLengthError)
+    );
+}
+
+/// Checks that the zero private key and an uncompressed point outside of the curve are rejected.
+pub(crate) fn check_invalid_keys<S: Signature>(
+    private_key_length: usize,
+    public_key_length: usize,
+) {
+    assert_eq!(
+        S::SigningKeyMaterial::try_from(&vec![0u8; private_key_length][..]).err(),
+        Some(CryptoMaterialError::DeserializationError)
+    );
+    let mut not_on_curve = vec![0u8; public_key_length];
+    not_on_curve[0] = 4;
+    not_on_curve[public_key_length - 1] = 1;
+    assert_eq!(
+        S::VerifyingKeyMaterial::try_from(&not_on_curve[..]).err(),
+        Some(CryptoMaterialError::DeserializationError)
+    );
+}
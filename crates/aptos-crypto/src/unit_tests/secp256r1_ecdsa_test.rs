@@ -0,0 +1,88 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    secp256r1_ecdsa::{
+        Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey, Secp256r1EcdsaSignature,
+        SECP256R1_ECDSA_PRIVATE_KEY_LENGTH, SECP256R1_ECDSA_PUBLIC_KEY_LENGTH,
+        SECP256R1_ECDSA_SIGNATURE_LENGTH,
+    },
+    test_utils::{random_serializable_struct, uniform_keypair_strategy},
+    traits::*,
+    unit_tests::ecdsa_test_utils::*,
+    CryptoMaterialError,
+};
+
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_keys_encode_and_serde(keypair in uniform_keypair_strategy::<Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey>()) {
+        check_encoding_round_trip(&keypair.private_key, SECP256R1_ECDSA_PRIVATE_KEY_LENGTH)?;
+        check_encoding_round_trip(&keypair.public_key, SECP256R1_ECDSA_PUBLIC_KEY_LENGTH)?;
+    }
+
+    #[test]
+    fn test_sign_and_verify(
+        keypair in uniform_keypair_strategy::<Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey>(),
+        message in random_serializable_struct(),
+    ) {
+        check_sign_and_verify::<Secp256r1EcdsaSignature>(
+            &keypair.private_key,
+            &keypair.public_key,
+            &message,
+            SECP256R1_ECDSA_SIGNATURE_LENGTH,
+        )?;
+    }
+
+    #[test]
+    fn test_verify_fails_with_other_key(
+        keypair in uniform_keypair_strategy::<Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey>(),
+        other_keypair in uniform_keypair_strategy::<Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey>(),
+        message in random_serializable_struct(),
+    ) {
+        prop_assume!(keypair.public_key != other_keypair.public_key);
+        check_verify_fails_with_other_key::<Secp256r1EcdsaSignature>(
+            &keypair.private_key,
+            &other_keypair.public_key,
+            &message,
+        )?;
+    }
+
+    #[test]
+    fn test_high_s_signature_is_rejected(
+        keypair in uniform_keypair_strategy::<Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey>(),
+        message in random_serializable_struct(),
+    ) {
+        let signature = keypair.private_key.sign(&message).unwrap();
+
+        // (r, n - s) is also a valid ECDSA signature, which must be rejected.
+        let (r, s) = signature.0.split_scalars();
+        let mauled = Secp256r1EcdsaSignature(p256::ecdsa::Signature::from_scalars(r, -s).unwrap());
+        prop_assert_eq!(
+            Secp256r1EcdsaSignature::check_s_malleability(&mauled.to_bytes()),
+            Err(CryptoMaterialError::CanonicalRepresentationError)
+        );
+        prop_assert!(mauled.verify(&message, &keypair.public_key).is_err());
+
+        // Normalizing the mauled signature gives back the original signature.
+        prop_assert_eq!(mauled.normalize_s(), signature);
+    }
+}
+
+#[test]
+fn test_invalid_key_lengths() {
+    check_invalid_lengths::<Secp256r1EcdsaSignature>(
+        SECP256R1_ECDSA_PRIVATE_KEY_LENGTH,
+        SECP256R1_ECDSA_PUBLIC_KEY_LENGTH,
+        SECP256R1_ECDSA_SIGNATURE_LENGTH,
+    );
+}
+
+#[test]
+fn test_invalid_keys() {
+    check_invalid_keys::<Secp256r1EcdsaSignature>(
+        SECP256R1_ECDSA_PRIVATE_KEY_LENGTH,
+        SECP256R1_ECDSA_PUBLIC_KEY_LENGTH,
+    );
+}
@@ -157,6 +157,8 @@ pub enum RestError {
     Timeout(&'static str),
     #[error("Unknown error {0}")]
     Unknown(anyhow::Error),
+    #[error("Invalid proof {0}")]
+    InvalidProof(anyhow::Error),
     #[error("HTTP error {0}: {1}")]
     Http(StatusCode, reqwest::Error),
 }
@@ -12,12 +12,14 @@ pub mod response;
 pub use response::Response;
 pub mod state;
 pub mod types;
+pub mod verifying_client;
 
 pub use aptos_api_types::{
     self, IndexResponseBcs, MoveModuleBytecode, PendingTransaction, Transaction,
 };
 pub use state::State;
 pub use types::{deserialize_from_prefixed_hex_string, Account, Resource};
+pub use verifying_client::VerifyingClient;
 
 use crate::aptos::{AptosVersion, Balance};
 use crate::error::RestError;
@@ -26,8 +28,9 @@ use aptos_api_types::{
     deserialize_from_string,
     mime_types::{BCS, BCS_SIGNED_TRANSACTION as BCS_CONTENT_TYPE},
     AptosError, BcsBlock, Block, Bytecode, ExplainVMStatus, GasEstimation, HexEncodedBytes,
-    IndexResponse, MoveModuleId, TransactionData, TransactionOnChainData,
-    TransactionsBatchSubmissionResult, UserTransaction, VersionedEvent,
+    IndexResponse, MoveModuleId, StateValueWithProof, TransactionData, TransactionOnChainData,
+    TransactionsBatchSubmissionResult, TransactionsWithProof, UserTransaction, VersionedEvent,
+    ViewRequest,
 };
 use aptos_crypto::HashValue;
 use aptos_logger::{debug, info, sample, sample::SampleRate};
@@ -35,6 +38,8 @@ use aptos_types::{
     account_address::AccountAddress,
     account_config::{AccountResource, CoinStoreResource, NewBlockEvent, CORE_CODE_ADDRESS},
     contract_event::EventWithVersion,
+    state_proof::StateProof,
+    state_store::state_key::StateKey,
     transaction::SignedTransaction,
 };
 use futures::executor::block_on;
@@ -323,6 +328,26 @@ impl Client {
         self.json(response).await
     }
 
+    /// Simulates the transaction and includes a breakdown of the gas it was charged, see
+    /// `UserTransaction::gas_profile`.
+    pub async fn simulate_with_gas_profile(
+        &self,
+        txn: &SignedTransaction,
+    ) -> AptosResult<Response<Vec<UserTransaction>>> {
+        let txn_payload = bcs::to_bytes(txn)?;
+        let url = self.build_path("transactions/simulate?profile=true")?;
+
+        let response = self
+            .inner
+            .post(url)
+            .header(CONTENT_TYPE, BCS_CONTENT_TYPE)
+            .body(txn_payload)
+            .send()
+            .await?;
+
+        self.json(response).await
+    }
+
     pub async fn simulate_bcs(
         &self,
         txn: &SignedTransaction,
@@ -1000,6 +1025,21 @@ impl Client {
         self.json(response).await
     }
 
+    pub async fn get_account_resource_at_version_bytes(
+        &self,
+        address: AccountAddress,
+        resource_type: &str,
+        version: u64,
+    ) -> AptosResult<Response<Vec<u8>>> {
+        let url = self.build_path(&format!(
+            "accounts/{}/resource/{}?ledger_version={}",
+            address, resource_type, version
+        ))?;
+
+        let response = self.get_bcs(url).await?;
+        Ok(response.map(|inner| inner.to_vec()))
+    }
+
     pub async fn get_account_modules(
         &self,
         address: AccountAddress,
@@ -1019,6 +1059,19 @@ impl Client {
         Ok(response.and_then(|inner| bcs::from_bytes(&inner))?)
     }
 
+    pub async fn get_account_modules_at_version_bcs(
+        &self,
+        address: AccountAddress,
+        version: u64,
+    ) -> AptosResult<Response<BTreeMap<MoveModuleId, Vec<u8>>>> {
+        let url = self.build_path(&format!(
+            "accounts/{}/modules?ledger_version={}",
+            address, version
+        ))?;
+        let response = self.get_bcs(url).await?;
+        Ok(response.and_then(|inner| bcs::from_bytes(&inner))?)
+    }
+
     pub async fn get_account_module(
         &self,
         address: AccountAddress,
@@ -1037,6 +1090,19 @@ impl Client {
         self.get_bcs(url).await
     }
 
+    pub async fn get_account_module_at_version_bcs(
+        &self,
+        address: AccountAddress,
+        module_name: &str,
+        version: u64,
+    ) -> AptosResult<Response<bytes::Bytes>> {
+        let url = self.build_path(&format!(
+            "accounts/{}/module/{}?ledger_version={}",
+            address, module_name, version
+        ))?;
+        self.get_bcs(url).await
+    }
+
     pub async fn get_account_events(
         &self,
         address: AccountAddress,
@@ -1083,6 +1149,23 @@ impl Client {
         Ok(response.and_then(|inner| bcs::from_bytes(&inner))?)
     }
 
+    pub async fn get_account_events_by_creation_number_bcs(
+        &self,
+        address: AccountAddress,
+        creation_number: u64,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> AptosResult<Response<Vec<EventWithVersion>>> {
+        let url = self.build_path(&format!(
+            "accounts/{}/events/{}",
+            address.to_hex_literal(),
+            creation_number
+        ))?;
+
+        let response = self.get_bcs_with_page(url, start, limit).await?;
+        Ok(response.and_then(|inner| bcs::from_bytes(&inner))?)
+    }
+
     pub async fn get_new_block_events_bcs(
         &self,
         start: Option<u64>,
@@ -1169,6 +1252,99 @@ impl Client {
         Ok(response.and_then(|inner| bcs::from_bytes(&inner))?)
     }
 
+    /// Retrieves the BCS encoded table item identified by its BCS serialized key, which doesn't
+    /// require knowing the types of the table's keys and values.
+    pub async fn get_raw_table_item_at_version(
+        &self,
+        table_handle: AccountAddress,
+        key: &[u8],
+        version: u64,
+    ) -> AptosResult<Response<Vec<u8>>> {
+        let url = self.build_path(&format!(
+            "tables/{}/raw_item?ledger_version={}",
+            table_handle, version
+        ))?;
+        let data = json!({
+            "key": HexEncodedBytes(key.to_vec()),
+        });
+
+        let response = self.post_bcs(url, data).await?;
+        Ok(response.map(|inner| inner.to_vec()))
+    }
+
+    /// Retrieves the latest ledger info along with the epoch change ledger infos since the epoch
+    /// of `known_version`. The ledger infos aren't verified, see [`VerifyingClient`].
+    pub async fn get_state_proof_bcs(
+        &self,
+        known_version: u64,
+    ) -> AptosResult<Response<StateProof>> {
+        let url = self.build_path(&format!("state_proof?known_version={}", known_version))?;
+        let response = self.get_bcs(url).await?;
+        Ok(response.and_then(|inner| bcs::from_bytes(&inner))?)
+    }
+
+    /// Retrieves the value of `state_key` in the latest state snapshot at or before `version`,
+    /// along with the proofs of it. The proofs aren't verified, see [`VerifyingClient`].
+    pub async fn get_state_value_with_proof_bcs(
+        &self,
+        state_key: &StateKey,
+        version: Option<u64>,
+    ) -> AptosResult<Response<StateValueWithProof>> {
+        let path = match version {
+            Some(version) => format!("state_values/proof?ledger_version={}", version),
+            None => "state_values/proof".to_string(),
+        };
+        let url = self.build_path(&path)?;
+        let data = json!({
+            "state_key": HexEncodedBytes(bcs::to_bytes(state_key)?),
+        });
+
+        let response = self.post_bcs(url, data).await?;
+        Ok(response.and_then(|inner| bcs::from_bytes(&inner))?)
+    }
+
+    /// Retrieves transactions along with their events and the proofs of them. The proofs aren't
+    /// verified, see [`VerifyingClient`].
+    pub async fn get_transactions_with_proof_bcs(
+        &self,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> AptosResult<Response<TransactionsWithProof>> {
+        let url = self.build_path("transactions/proof")?;
+        let response = self.get_bcs_with_page(url, start, limit).await?;
+        Ok(response.and_then(|inner| bcs::from_bytes(&inner))?)
+    }
+
+    /// Executes a public Move view function and returns its return values as JSON
+    pub async fn view(
+        &self,
+        request: &ViewRequest,
+        version: Option<u64>,
+    ) -> AptosResult<Response<Vec<Value>>> {
+        let url = self.build_view_path(version)?;
+        let response = self.inner.post(url).json(request).send().await?;
+        self.json(response).await
+    }
+
+    /// Executes a public Move view function and returns its BCS encoded return values
+    pub async fn view_bcs(
+        &self,
+        request: &ViewRequest,
+        version: Option<u64>,
+    ) -> AptosResult<Response<Vec<Vec<u8>>>> {
+        let url = self.build_view_path(version)?;
+        let response = self.post_bcs(url, json!(request)).await?;
+        Ok(response.and_then(|inner| bcs::from_bytes(&inner))?)
+    }
+
+    fn build_view_path(&self, version: Option<u64>) -> AptosResult<Url> {
+        let path = match version {
+            Some(version) => format!("view?ledger_version={}", version),
+            None => "view".to_string(),
+        };
+        self.build_path(&path)
+    }
+
     pub async fn get_account(&self, address: AccountAddress) -> AptosResult<Response<Account>> {
         let url = self.build_path(&format!("accounts/{}", address))?;
         let response = self.inner.get(url).send().await?;
@@ -1329,7 +1505,7 @@ impl Client {
                     | RestError::Json(_)
                     | RestError::Timeout(_)
                     | RestError::Unknown(_) => true,
-                    RestError::UrlParse(_) => false,
+                    RestError::UrlParse(_) | RestError::InvalidProof(_) => false,
                 },
             };
 
@@ -0,0 +1,197 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{error::RestError, Client, Response};
+use anyhow::anyhow;
+use aptos_api_types::StateValueWithProof;
+use aptos_infallible::Mutex;
+use aptos_types::{
+    access_path::AccessPath,
+    account_address::AccountAddress,
+    epoch_change::Verifier,
+    ledger_info::LedgerInfoWithSignatures,
+    state_store::{state_key::StateKey, state_value::StateValue, table::TableHandle},
+    transaction::{TransactionListWithProof, Version},
+    trusted_state::{TrustedState, TrustedStateChange},
+    waypoint::Waypoint,
+};
+use move_core_types::language_storage::{ResourceKey, StructTag};
+use std::sync::Arc;
+
+type AptosResult<T> = Result<T, RestError>;
+
+/// A client which verifies every response against a ledger info signed by the validators, so
+/// that the node it talks to doesn't need to be trusted.
+///
+/// It starts from a trusted waypoint, e.g. the genesis waypoint of the network, and follows the
+/// validator set changes by verifying the epoch change ledger infos returned by the node. The
+/// clones of a client share its trusted state.
+#[derive(Clone, Debug)]
+pub struct VerifyingClient {
+    inner: Client,
+    trusted_state: Arc<Mutex<TrustedState>>,
+}
+
+impl VerifyingClient {
+    pub fn new(inner: Client, waypoint: Waypoint) -> Self {
+        Self::new_with_state(inner, TrustedState::from_epoch_waypoint(waypoint))
+    }
+
+    /// Starts from a previously verified state, e.g. saved by a previous run, so that the epoch
+    /// changes before it don't need to be verified again.
+    pub fn new_with_state(inner: Client, trusted_state: TrustedState) -> Self {
+        Self {
+            inner,
+            trusted_state: Arc::new(Mutex::new(trusted_state)),
+        }
+    }
+
+    /// The underlying client, whose responses aren't verified.
+    pub fn inner(&self) -> &Client {
+        &self.inner
+    }
+
+    pub fn trusted_state(&self) -> TrustedState {
+        self.trusted_state.lock().clone()
+    }
+
+    /// Ratchets the trusted state to the latest ledger info of the node, verifying the epoch
+    /// changes since the trusted version.
+    pub async fn sync(&self) -> AptosResult<TrustedState> {
+        loop {
+            let trusted_state = self.trusted_state();
+            let state_proof = self
+                .inner
+                .get_state_proof_bcs(trusted_state.version())
+                .await?
+                .into_inner();
+            match trusted_state
+                .verify_and_ratchet(&state_proof)
+                .map_err(RestError::InvalidProof)?
+            {
+                TrustedStateChange::Version { new_state }
+                | TrustedStateChange::Epoch { new_state, .. } => {
+                    self.update_trusted_state(new_state)
+                }
+                TrustedStateChange::NoChange => (),
+            }
+
+            // There were too many epoch changes to return them at once
+            if !state_proof.epoch_changes().more {
+                return Ok(self.trusted_state());
+            }
+        }
+    }
+
+    /// Retrieves the value of `state_key` in the latest state snapshot at or before `version`
+    /// (the latest version if None), along with the version of the snapshot, and verifies it.
+    /// The snapshot is rejected if it's more than `max_staleness` versions older than `version`.
+    pub async fn get_state_value_with_proof(
+        &self,
+        state_key: &StateKey,
+        version: Option<u64>,
+        max_staleness: u64,
+    ) -> AptosResult<Response<StateValueWithProof>> {
+        let response = self
+            .inner
+            .get_state_value_with_proof_bcs(state_key, version)
+            .await?;
+        let state_value_with_proof = response.inner();
+        self.verify_ledger_info(&state_value_with_proof.ledger_info_with_sigs)
+            .await?;
+        state_value_with_proof
+            .verify(state_key, version, max_staleness)
+            .map_err(RestError::InvalidProof)?;
+        Ok(response)
+    }
+
+    /// Retrieves the BCS encoded resource of an account, or None if it doesn't exist, along with
+    /// the version of the state snapshot it's read from, and verifies it.
+    pub async fn get_account_resource_bytes(
+        &self,
+        address: AccountAddress,
+        resource_type: StructTag,
+        version: Option<u64>,
+        max_staleness: u64,
+    ) -> AptosResult<Response<(Version, Option<Vec<u8>>)>> {
+        let state_key = StateKey::AccessPath(AccessPath::resource_access_path(ResourceKey::new(
+            address,
+            resource_type,
+        )));
+        Ok(self
+            .get_state_value_with_proof(&state_key, version, max_staleness)
+            .await?
+            .map(|inner| (inner.version, inner.value.map(StateValue::into_bytes))))
+    }
+
+    /// Retrieves the BCS encoded table item identified by its BCS serialized key, or None if it
+    /// doesn't exist, along with the version of the state snapshot it's read from, and verifies
+    /// it.
+    pub async fn get_raw_table_item(
+        &self,
+        table_handle: AccountAddress,
+        key: &[u8],
+        version: Option<u64>,
+        max_staleness: u64,
+    ) -> AptosResult<Response<(Version, Option<Vec<u8>>)>> {
+        let state_key = StateKey::table_item(TableHandle(table_handle), key.to_vec());
+        Ok(self
+            .get_state_value_with_proof(&state_key, version, max_staleness)
+            .await?
+            .map(|inner| (inner.version, inner.value.map(StateValue::into_bytes))))
+    }
+
+    /// Retrieves the transactions starting at `start` along with their events, and verifies
+    /// them.
+    pub async fn get_transactions(
+        &self,
+        start: u64,
+        limit: Option<u16>,
+    ) -> AptosResult<Response<TransactionListWithProof>> {
+        let response = self
+            .inner
+            .get_transactions_with_proof_bcs(Some(start), limit)
+            .await?;
+        let transactions_with_proof = response.inner();
+        self.verify_ledger_info(&transactions_with_proof.ledger_info_with_sigs)
+            .await?;
+        transactions_with_proof
+            .verify(start)
+            .map_err(RestError::InvalidProof)?;
+        Ok(response.map(|inner| inner.transaction_list_with_proof))
+    }
+
+    /// Verifies the signatures of the ledger info with the validator set of its epoch, which is
+    /// verified first if the epoch is after the trusted one.
+    async fn verify_ledger_info(
+        &self,
+        ledger_info_with_sigs: &LedgerInfoWithSignatures,
+    ) -> AptosResult<()> {
+        let epoch = ledger_info_with_sigs.ledger_info().epoch();
+        let mut trusted_state = self.trusted_state();
+        if trusted_state.epoch_change_verification_required(epoch) {
+            trusted_state = self.sync().await?;
+        }
+
+        match &trusted_state {
+            TrustedState::EpochState { epoch_state, .. } if epoch_state.epoch == epoch => {
+                epoch_state
+                    .verify(ledger_info_with_sigs)
+                    .map_err(RestError::InvalidProof)
+            }
+            _ => Err(RestError::InvalidProof(anyhow!(
+                "Ledger info of epoch {} can't be verified from trusted state {:?}",
+                epoch,
+                trusted_state
+            ))),
+        }
+    }
+
+    fn update_trusted_state(&self, new_state: TrustedState) {
+        let mut trusted_state = self.trusted_state.lock();
+        // Concurrent requests may have ratcheted it further already
+        if trusted_state.is_epoch_waypoint() || new_state.version() > trusted_state.version() {
+            *trusted_state = new_state;
+        }
+    }
+}
@@ -5,8 +5,8 @@ use crate::common::types::{ConfigSearchMode, DEFAULT_PROFILE};
 use crate::common::{
     types::{
         account_address_from_public_key, CliCommand, CliConfig, CliError, CliTypedResult,
-        EncodingOptions, PrivateKeyInputOptions, ProfileConfig, ProfileOptions, PromptOptions,
-        RngArgs,
+        DerivationPathOptions, EncodingOptions, PrivateKeyInputOptions, ProfileConfig,
+        ProfileOptions, PromptOptions, RngArgs,
     },
     utils::{fund_account, prompt_yes_with_override, read_line},
 };
@@ -46,6 +46,14 @@ pub struct InitTool {
     #[clap(long)]
     pub skip_faucet: bool,
 
+    /// Whether to derive the private key from a BIP-39 mnemonic phrase read from stdin
+    ///
+    /// The key is derived along the path given by `--account-index` or `--derivation-path`
+    #[clap(long, conflicts_with = "private_key_input")]
+    pub from_mnemonic: bool,
+
+    #[clap(flatten)]
+    pub(crate) derivation_path_options: DerivationPathOptions,
     #[clap(flatten)]
     pub rng_args: RngArgs,
     #[clap(flatten)]
@@ -133,6 +141,12 @@ impl CliCommand<()> for InitTool {
         {
             eprintln!("Using command line argument for private key");
             private_key
+        } else if self.from_mnemonic {
+            eprintln!("Enter your mnemonic phrase");
+            let input = read_line("Mnemonic phrase")?;
+            let derivation_path = self.derivation_path_options.derivation_path();
+            eprintln!("Deriving private key along {}", derivation_path);
+            self.derivation_path_options.derive_private_key(&input)?
         } else {
             eprintln!("Enter your private key as a hex literal (0x...) [Current: {} | No input: Generate new key (or keep one if present)]", profile_config.private_key.as_ref().map(|_| "Redacted").unwrap_or("None"));
             let input = read_line("Private key")?;
@@ -22,7 +22,10 @@ use aptos_keygen::KeyGen;
 use aptos_rest_client::aptos_api_types::{ExplainVMStatus, HashValue, UserTransaction};
 use aptos_rest_client::error::RestError;
 use aptos_rest_client::{Client, Transaction};
-use aptos_sdk::{transaction_builder::TransactionFactory, types::LocalAccount};
+use aptos_sdk::{
+    transaction_builder::TransactionFactory,
+    types::{aptos_derivation_path, derive_ed25519_private_key, LocalAccount},
+};
 use aptos_types::transaction::{
     authenticator::AuthenticationKey, SignedTransaction, TransactionPayload,
 };
@@ -30,6 +33,7 @@ use async_trait::async_trait;
 use clap::{ArgEnum, Parser};
 use hex::FromHexError;
 use move_core_types::account_address::AccountAddress;
+use rand::{rngs::StdRng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 #[cfg(unix)]
@@ -522,15 +526,32 @@ impl RngArgs {
 
     /// Returns a key generator with the seed if given
     pub fn key_generator(&self) -> CliTypedResult<KeyGen> {
+        if let Some(seed) = self.seed()? {
+            Ok(KeyGen::from_seed(seed))
+        } else {
+            Ok(KeyGen::from_os_rng())
+        }
+    }
+
+    /// Returns a random number generator with the seed if given, e.g. to generate a mnemonic
+    pub fn rng(&self) -> CliTypedResult<StdRng> {
+        if let Some(seed) = self.seed()? {
+            Ok(StdRng::from_seed(seed))
+        } else {
+            Ok(StdRng::from_entropy())
+        }
+    }
+
+    fn seed(&self) -> CliTypedResult<Option<[u8; 32]>> {
         if let Some(ref seed) = self.random_seed {
             // Strip 0x
             let seed = seed.strip_prefix("0x").unwrap_or(seed);
             let mut seed_slice = [0u8; 32];
 
             hex::decode_to_slice(seed, &mut seed_slice)?;
-            Ok(KeyGen::from_seed(seed_slice))
+            Ok(Some(seed_slice))
         } else {
-            Ok(KeyGen::from_os_rng())
+            Ok(None)
         }
     }
 }
@@ -817,6 +838,38 @@ pub fn account_address_from_public_key(public_key: &Ed25519PublicKey) -> Account
     AccountAddress::new(*auth_key.derived_address())
 }
 
+/// Options for deriving an ed25519 private key from a BIP-39 mnemonic phrase
+#[derive(Debug, Default, Parser)]
+pub struct DerivationPathOptions {
+    /// Index of the account to derive from the mnemonic phrase
+    ///
+    /// The key is derived along the path `m/44'/637'/{account_index}'/0'/0'` used by the Aptos
+    /// wallets.  Defaults to 0.
+    /// Mutually exclusive with `--derivation-path`
+    #[clap(long, group = "derivation_path_input")]
+    account_index: Option<u32>,
+    /// Full derivation path of the key, e.g. `m/44'/637'/0'/0'/0'`
+    ///
+    /// Every level of the path must be hardened.
+    /// Mutually exclusive with `--account-index`
+    #[clap(long, group = "derivation_path_input")]
+    derivation_path: Option<String>,
+}
+
+impl DerivationPathOptions {
+    pub fn derivation_path(&self) -> String {
+        self.derivation_path
+            .clone()
+            .unwrap_or_else(|| aptos_derivation_path(self.account_index.unwrap_or(0)))
+    }
+
+    /// Derive the private key from the mnemonic phrase
+    pub fn derive_private_key(&self, mnemonic_phrase: &str) -> CliTypedResult<Ed25519PrivateKey> {
+        derive_ed25519_private_key(mnemonic_phrase, &self.derivation_path())
+            .map_err(|err| CliError::UnableToParse("mnemonic", err.to_string()))
+    }
+}
+
 #[derive(Debug, Parser)]
 pub struct SaveFile {
     /// Output file path
@@ -1385,10 +1438,42 @@ impl TransactionOptions {
         amount_transfer: Option<u64>,
     ) -> CliTypedResult<UserTransaction> {
         let client = self.rest_client()?;
+        let signed_transaction = self
+            .simulation_transaction(&client, payload, gas_price, amount_transfer)
+            .await?;
+        let txns = client.simulate(&signed_transaction).await?.into_inner();
+        Ok(txns.first().unwrap().clone())
+    }
+
+    /// Simulates the transaction, including a breakdown of the gas it would be charged
+    pub async fn simulate_transaction_with_gas_profile(
+        &self,
+        payload: TransactionPayload,
+    ) -> CliTypedResult<UserTransaction> {
+        let client = self.rest_client()?;
+        let signed_transaction = self
+            .simulation_transaction(&client, payload, self.gas_options.gas_unit_price, None)
+            .await?;
+        let txns = client
+            .simulate_with_gas_profile(&signed_transaction)
+            .await?
+            .into_inner();
+        Ok(txns.first().unwrap().clone())
+    }
+
+    /// Builds a transaction to simulate, with the maximum gas the sender can afford and an
+    /// invalid signature
+    async fn simulation_transaction(
+        &self,
+        client: &Client,
+        payload: TransactionPayload,
+        gas_price: Option<u64>,
+        amount_transfer: Option<u64>,
+    ) -> CliTypedResult<SignedTransaction> {
         let (sender_key, sender_address) = self.get_key_and_address()?;
 
         // Get sequence number for account
-        let sequence_number = get_sequence_number(&client, sender_address).await?;
+        let sequence_number = get_sequence_number(client, sender_address).await?;
 
         // Estimate gas price if necessary
         let gas_price = if let Some(gas_price) = gas_price {
@@ -1422,7 +1507,7 @@ impl TransactionOptions {
             )
         };
 
-        let transaction_factory = TransactionFactory::new(chain_id(&client).await?)
+        let transaction_factory = TransactionFactory::new(chain_id(client).await?)
             .with_gas_unit_price(gas_price)
             .with_max_gas_amount(max_possible_gas);
 
@@ -1432,13 +1517,11 @@ impl TransactionOptions {
             .sequence_number(sequence_number)
             .build();
 
-        let signed_transaction = SignedTransaction::new(
+        Ok(SignedTransaction::new(
             unsigned_transaction,
             sender_key.public_key(),
             Ed25519Signature::try_from([0u8; 64].as_ref()).unwrap(),
-        );
-        let txns = client.simulate(&signed_transaction).await?.into_inner();
-        Ok(txns.first().unwrap().clone())
+        ))
     }
 
     pub async fn estimate_gas_price(&self) -> CliTypedResult<u64> {
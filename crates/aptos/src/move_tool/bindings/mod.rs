@@ -0,0 +1,572 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Generator of typed Rust bindings for Move modules, from their ABIs as returned by the REST API.
+//!
+//! For each module, it emits serde structs for its structs (e.g. resources and events), typed
+//! event streams for the event handles of its resources, and typed accessors for its view
+//! functions. The generated code relies on `aptos_sdk::bindings`.
+
+#[cfg(test)]
+mod tests;
+
+use anyhow::{bail, Result};
+use aptos_rest_client::aptos_api_types::{
+    Address, MoveFunction, MoveFunctionVisibility, MoveModule, MoveModuleId, MoveStruct,
+    MoveStructTag, MoveType,
+};
+use move_core_types::account_address::AccountAddress;
+use serde_generate::indent::{IndentConfig, IndentedWriter};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+};
+/// Output the bindings of the `targets` modules, along with the ones of the modules defining the
+/// structs they use, which must all be in `modules`.
+pub fn output(out: &mut dyn Write, modules: &[MoveModule], targets: &[MoveModuleId]) -> Result<()> {
+    let missing = missing_modules(modules, targets);
+    if !missing.is_empty() {
+        bail!(
+            "Modules used by the bindings are missing: {}",
+            missing
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    let targets: BTreeSet<_> = targets.iter().collect();
+    let mut modules_by_address = BTreeMap::<Address, Vec<&MoveModule>>::new();
+    for module in required_modules(modules, &targets).0 {
+        modules_by_address
+            .entry(module.address)
+            .or_default()
+            .push(module);
+    }
+
+    let mut emitter = BindingsEmitter {
+        out: IndentedWriter::new(out, IndentConfig::Space(4)),
+    };
+    emitter.output_preamble()?;
+    for (address, modules) in modules_by_address {
+        emitter.output_address_module(&address, &modules, &targets)?;
+    }
+    Ok(())
+}
+
+/// Returns the modules which are used by the `targets` modules, directly or indirectly, but which
+/// aren't in `modules`.
+pub fn missing_modules(modules: &[MoveModule], targets: &[MoveModuleId]) -> Vec<MoveModuleId> {
+    required_modules(modules, &targets.iter().collect()).1
+}
+
+/// Splits the modules needed by the bindings of the `targets` into the ones in `modules` and the
+/// missing ones.
+fn required_modules<'a>(
+    modules: &'a [MoveModule],
+    targets: &BTreeSet<&MoveModuleId>,
+) -> (Vec<&'a MoveModule>, Vec<MoveModuleId>) {
+    let modules_by_id: BTreeMap<_, _> = modules
+        .iter()
+        .map(|module| (module_id(module), module))
+        .collect();
+
+    let mut required = BTreeMap::new();
+    let mut missing = vec![];
+    let mut queue: Vec<MoveModuleId> = targets.iter().map(|id| (*id).clone()).collect();
+    while let Some(id) = queue.pop() {
+        if required.contains_key(&id) || missing.contains(&id) {
+            continue;
+        }
+        if let Some(module) = modules_by_id.get(&id) {
+            // Only the structs of the dependencies are generated
+            let view_functions = if targets.contains(&id) {
+                view_functions(module)
+            } else {
+                vec![]
+            };
+            let types = module
+                .structs
+                .iter()
+                .flat_map(|s| s.fields.iter().map(|field| &field.typ))
+                .chain(
+                    view_functions
+                        .iter()
+                        .flat_map(|function| function.params.iter().chain(&function.return_)),
+                );
+            for typ in types {
+                collect_used_modules(typ, &mut queue);
+            }
+            required.insert(id, *module);
+        } else {
+            missing.push(id);
+        }
+    }
+    (required.into_values().collect(), missing)
+}
+
+fn collect_used_modules(typ: &MoveType, modules: &mut Vec<MoveModuleId>) {
+    match typ {
+        MoveType::Vector { items } => collect_used_modules(items, modules),
+        MoveType::Reference { to, .. } => collect_used_modules(to, modules),
+        MoveType::Struct(tag) => {
+            if !is_string(tag) {
+                modules.push(MoveModuleId {
+                    address: tag.address,
+                    name: tag.module.clone(),
+                });
+            }
+            for typ in &tag.generic_type_params {
+                collect_used_modules(typ, modules);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn module_id(module: &MoveModule) -> MoveModuleId {
+    MoveModuleId {
+        address: module.address,
+        name: module.name.clone(),
+    }
+}
+
+/// `0x1::string::String` is a plain string in JSON, so it's bound to a Rust `String`
+fn is_string(tag: &MoveStructTag) -> bool {
+    tag.address == Address::from(AccountAddress::ONE)
+        && tag.module.as_str() == "string"
+        && tag.name.as_str() == "String"
+}
+
+fn is_event_handle(tag: &MoveStructTag) -> bool {
+    tag.address == Address::from(AccountAddress::ONE)
+        && tag.module.as_str() == "event"
+        && tag.name.as_str() == "EventHandle"
+}
+
+/// The public functions which can be called through the view function API, i.e. which return
+/// values and whose parameters can be given in JSON.
+fn view_functions(module: &MoveModule) -> Vec<&MoveFunction> {
+    module
+        .exposed_functions
+        .iter()
+        .filter(|function| {
+            function.visibility == MoveFunctionVisibility::Public
+                && !function.return_.is_empty()
+                && function
+                    .params
+                    .iter()
+                    .chain(&function.return_)
+                    .all(|typ| rust_type(typ).is_some())
+        })
+        .collect()
+}
+
+/// The Rust type bound to a Move type, if any, as referred to from the bindings of a Move module.
+fn rust_type(typ: &MoveType) -> Option<String> {
+    Some(match typ {
+        MoveType::Bool => "bool".to_string(),
+        MoveType::U8 => "u8".to_string(),
+        MoveType::U64 => "bindings::U64".to_string(),
+        MoveType::U128 => "bindings::U128".to_string(),
+        MoveType::Address => "bindings::Address".to_string(),
+        MoveType::Vector { items } if **items == MoveType::U8 => "bindings::Bytes".to_string(),
+        MoveType::Vector { items } => format!("std::vec::Vec<{}>", rust_type(items)?),
+        MoveType::Struct(tag) if is_string(tag) => "std::string::String".to_string(),
+        MoveType::Struct(tag) => format!(
+            "super::super::{}::{}::{}{}",
+            address_module_name(&tag.address),
+            rust_ident(tag.module.as_str()),
+            tag.name,
+            rust_type_args(&tag.generic_type_params)?
+        ),
+        MoveType::GenericTypeParam { index } => format!("T{}", index),
+        MoveType::Signer | MoveType::Reference { .. } | MoveType::Unparsable(_) => return None,
+    })
+}
+
+fn rust_type_args(types: &[MoveType]) -> Option<String> {
+    if types.is_empty() {
+        return Some(String::new());
+    }
+    Some(format!(
+        "<{}>",
+        types
+            .iter()
+            .map(rust_type)
+            .collect::<Option<Vec<_>>>()?
+            .join(", ")
+    ))
+}
+
+/// The module holding the modules published at `address`, e.g. `_0x1`
+fn address_module_name(address: &Address) -> String {
+    format!("_{}", address)
+}
+
+/// Escapes the Move identifiers which are Rust keywords
+fn rust_ident(name: &str) -> String {
+    const RUST_KEYWORDS: &[&str] = &[
+        "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern",
+        "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+        "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type", "unsafe",
+        "use", "where", "while", "abstract", "become", "box", "do", "final", "macro", "override",
+        "priv", "typeof", "unsized", "virtual", "yield",
+    ];
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// The generic type parameters `<T0, T1, ..>`, with the given bounds if any
+fn generics(count: usize, bounds: &str) -> String {
+    if count == 0 {
+        return String::new();
+    }
+    let params: Vec<_> = (0..count)
+        .map(|index| {
+            if bounds.is_empty() {
+                format!("T{}", index)
+            } else {
+                format!("T{}: {}", index, bounds)
+            }
+        })
+        .collect();
+    format!("<{}>", params.join(", "))
+}
+
+fn type_tags(count: usize) -> String {
+    let type_tags: Vec<_> = (0..count)
+        .map(|index| format!("T{}::type_tag()", index))
+        .collect();
+    format!("vec![{}]", type_tags.join(", "))
+}
+
+fn collect_type_params(typ: &MoveType, params: &mut BTreeSet<u16>) {
+    match typ {
+        MoveType::GenericTypeParam { index } => {
+            params.insert(*index);
+        }
+        MoveType::Vector { items } => collect_type_params(items, params),
+        MoveType::Struct(tag) => {
+            for typ in &tag.generic_type_params {
+                collect_type_params(typ, params);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Shared state for the bindings generator.
+struct BindingsEmitter<T> {
+    /// Writer.
+    out: IndentedWriter<T>,
+}
+
+impl<T> BindingsEmitter<T>
+where
+    T: Write,
+{
+    fn output_preamble(&mut self) -> std::io::Result<()> {
+        writeln!(
+            self.out,
+            r#"// This file was generated by `aptos move generate-bindings`. Do not modify!
+//
+// Typed bindings of Move modules, requiring the `aptos-sdk` and `serde` crates. The structs are
+// (de)serialized like the values of the REST API, in JSON as well as in BCS."#
+        )
+    }
+
+    fn output_address_module(
+        &mut self,
+        address: &Address,
+        modules: &[&MoveModule],
+        targets: &BTreeSet<&MoveModuleId>,
+    ) -> Result<()> {
+        writeln!(self.out)?;
+        writeln!(
+            self.out,
+            "#[allow(clippy::all, non_camel_case_types, non_snake_case)]"
+        )?;
+        writeln!(self.out, "pub mod {} {{", address_module_name(address))?;
+        self.out.indent();
+        for (index, module) in modules.iter().enumerate() {
+            if index > 0 {
+                writeln!(self.out)?;
+            }
+            self.output_module(module, targets.contains(&module_id(module)))?;
+        }
+        self.out.unindent();
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    fn output_module(&mut self, module: &MoveModule, is_target: bool) -> Result<()> {
+        writeln!(
+            self.out,
+            "/// Bindings of `{}::{}`",
+            module.address, module.name
+        )?;
+        writeln!(self.out, "pub mod {} {{", rust_ident(module.name.as_str()))?;
+        self.out.indent();
+        writeln!(self.out, "#[allow(unused_imports)]")?;
+        writeln!(self.out, "use aptos_sdk::bindings;")?;
+        for s in &module.structs {
+            if s.is_native || (s.name.as_str() == "String" && module.name.as_str() == "string") {
+                continue;
+            }
+            self.output_struct(module, s, is_target)?;
+        }
+        if is_target {
+            for function in view_functions(module) {
+                self.output_view_function(module, function)?;
+            }
+        }
+        self.out.unindent();
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    fn output_struct(
+        &mut self,
+        module: &MoveModule,
+        s: &MoveStruct,
+        is_target: bool,
+    ) -> Result<()> {
+        let type_param_count = s.generic_type_params.len();
+        let mut used_type_params = BTreeSet::new();
+        for field in &s.fields {
+            collect_type_params(&field.typ, &mut used_type_params);
+        }
+        let phantom_type_params: Vec<_> = (0..type_param_count as u16)
+            .filter(|index| !used_type_params.contains(index))
+            .map(|index| format!("T{},", index))
+            .collect();
+
+        writeln!(self.out)?;
+        writeln!(
+            self.out,
+            "/// `{}::{}::{}`",
+            module.address, module.name, s.name
+        )?;
+        writeln!(
+            self.out,
+            "#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]"
+        )?;
+        writeln!(
+            self.out,
+            "pub struct {}{} {{",
+            s.name,
+            generics(type_param_count, "")
+        )?;
+        self.out.indent();
+        for field in &s.fields {
+            let typ = match rust_type(&field.typ) {
+                Some(typ) => typ,
+                None => bail!(
+                    "Unsupported type {} of field {} of struct {}::{}::{}",
+                    field.typ,
+                    field.name,
+                    module.address,
+                    module.name,
+                    s.name
+                ),
+            };
+            writeln!(
+                self.out,
+                "pub {}: {},",
+                rust_ident(field.name.as_str()),
+                typ
+            )?;
+        }
+        // Type parameters which aren't used by the fields, e.g. phantom ones
+        if !phantom_type_params.is_empty() {
+            writeln!(self.out, "#[serde(skip)]")?;
+            writeln!(
+                self.out,
+                "pub _phantom: std::marker::PhantomData<({})>,",
+                phantom_type_params.join(" ")
+            )?;
+        }
+        self.out.unindent();
+        writeln!(self.out, "}}")?;
+
+        let impl_generics = generics(type_param_count, "bindings::MoveTypeTag");
+        let type_generics = generics(type_param_count, "");
+        writeln!(
+            self.out,
+            r#"
+impl{impl_generics} bindings::MoveStruct for {name}{type_generics} {{
+    fn struct_tag() -> bindings::StructTag {{
+        bindings::struct_tag("{address}", "{module}", "{name}", {type_tags})
+    }}
+}}
+
+impl{impl_generics} bindings::MoveTypeTag for {name}{type_generics} {{
+    fn type_tag() -> bindings::TypeTag {{
+        bindings::TypeTag::Struct(Box::new(<Self as bindings::MoveStruct>::struct_tag()))
+    }}
+}}"#,
+            impl_generics = impl_generics,
+            type_generics = type_generics,
+            name = s.name,
+            address = module.address,
+            module = module.name,
+            type_tags = type_tags(type_param_count),
+        )?;
+
+        let is_resource = s
+            .abilities
+            .iter()
+            .any(|ability| ability.to_string() == "key");
+        if is_target && is_resource {
+            self.output_resource_impl(s, &impl_generics, &type_generics)?;
+        }
+        Ok(())
+    }
+
+    fn output_resource_impl(
+        &mut self,
+        s: &MoveStruct,
+        impl_generics: &str,
+        type_generics: &str,
+    ) -> Result<()> {
+        writeln!(
+            self.out,
+            r#"
+impl{impl_generics} {name}{type_generics} {{
+    /// Retrieves the resource of the account at `address`, at the latest version or at `version`
+    pub async fn fetch(
+        client: &bindings::Client,
+        address: bindings::AccountAddress,
+        version: std::option::Option<u64>,
+    ) -> bindings::AptosResult<bindings::Response<Self>>
+    where
+        Self: serde::de::DeserializeOwned,
+    {{
+        bindings::get_resource(client, address, version).await
+    }}"#,
+            impl_generics = impl_generics,
+            type_generics = type_generics,
+            name = s.name,
+        )?;
+        self.out.indent();
+        for field in &s.fields {
+            let event_type = match &field.typ {
+                MoveType::Struct(tag) if is_event_handle(tag) => {
+                    match tag.generic_type_params.first().and_then(rust_type) {
+                        Some(event_type) => event_type,
+                        None => continue,
+                    }
+                }
+                _ => continue,
+            };
+            writeln!(
+                self.out,
+                r#"
+/// The events emitted through the `{field}` handle of the resource of the account at `address`
+pub fn {method}(
+    client: bindings::Client,
+    address: bindings::AccountAddress,
+) -> bindings::EventStream<{event_type}> {{
+    bindings::EventStream::new(
+        client,
+        address,
+        <Self as bindings::MoveStruct>::struct_tag(),
+        "{field}",
+    )
+}}"#,
+                field = field.name,
+                method = rust_ident(field.name.as_str()),
+                event_type = event_type,
+            )?;
+        }
+        self.out.unindent();
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    fn output_view_function(&mut self, module: &MoveModule, function: &MoveFunction) -> Result<()> {
+        // Filtered by `view_functions`
+        let params: Vec<_> = function
+            .params
+            .iter()
+            .map(|typ| rust_type(typ).unwrap())
+            .collect();
+        let returns: Vec<_> = function
+            .return_
+            .iter()
+            .map(|typ| rust_type(typ).unwrap())
+            .collect();
+        let return_values: Vec<_> = (0..returns.len())
+            .map(|index| format!("bindings::view_return_value(&values, {})?", index))
+            .collect();
+        let (return_type, return_value) = if returns.len() == 1 {
+            (returns[0].clone(), return_values[0].clone())
+        } else {
+            (
+                format!("({})", returns.join(", ")),
+                format!("({})", return_values.join(", ")),
+            )
+        };
+
+        writeln!(self.out)?;
+        writeln!(
+            self.out,
+            "/// Calls the view function `{}::{}::{}`",
+            module.address, module.name, function.name
+        )?;
+        writeln!(
+            self.out,
+            "pub async fn {}{}(",
+            rust_ident(function.name.as_str()),
+            generics(
+                function.generic_type_params.len(),
+                "bindings::MoveTypeTag + serde::Serialize + serde::de::DeserializeOwned"
+            )
+        )?;
+        self.out.indent();
+        writeln!(self.out, "client: &bindings::Client,")?;
+        for (index, param) in params.iter().enumerate() {
+            writeln!(self.out, "arg{}: {},", index, param)?;
+        }
+        writeln!(self.out, "version: std::option::Option<u64>,")?;
+        self.out.unindent();
+        writeln!(
+            self.out,
+            ") -> bindings::AptosResult<bindings::Response<{}>> {{",
+            return_type
+        )?;
+        self.out.indent();
+        writeln!(self.out, "let request = bindings::view_request(")?;
+        self.out.indent();
+        writeln!(
+            self.out,
+            "\"{}::{}::{}\",",
+            module.address, module.name, function.name
+        )?;
+        writeln!(
+            self.out,
+            "{},",
+            type_tags(function.generic_type_params.len())
+        )?;
+        let args: Vec<_> = (0..params.len())
+            .map(|index| format!("bindings::view_arg(&arg{})?", index))
+            .collect();
+        writeln!(self.out, "vec![{}],", args.join(", "))?;
+        self.out.unindent();
+        writeln!(self.out, ");")?;
+        writeln!(self.out, "client")?;
+        self.out.indent();
+        writeln!(self.out, ".view_bcs(&request, version)")?;
+        writeln!(self.out, ".await?")?;
+        writeln!(self.out, ".and_then(|values| Ok({}))", return_value)?;
+        self.out.unindent();
+        self.out.unindent();
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    common::types::{
+        AccountAddressWrapper, MovePackageDir, ProfileOptions, PromptOptions, RestOptions, SaveFile,
+    },
+    move_tool::{
+        bindings::{missing_modules, output},
+        GenerateBindings,
+    },
+    CliCommand,
+};
+use aptos_rest_client::aptos_api_types::{MoveModule, MoveModuleId};
+use aptos_types::{
+    account_address::AccountAddress,
+    account_config::CoinStoreResource,
+    event::{EventHandle, EventKey},
+};
+use serde_json::json;
+use std::{collections::BTreeMap, io::Write, path::PathBuf, process::Command, str::FromStr};
+use tempfile::tempdir;
+
+fn framework_modules() -> Vec<MoveModule> {
+    cached_packages::head_release_bundle()
+        .compiled_modules()
+        .into_iter()
+        .map(MoveModule::from)
+        .collect()
+}
+
+#[test]
+fn test_coin_bindings() {
+    let modules = framework_modules();
+    let targets = [MoveModuleId::from_str("0x1::coin").unwrap()];
+    let mut out = vec![];
+    output(&mut out, &modules, &targets).unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    // Resources and events, with their phantom type parameters
+    assert!(out.contains("pub struct CoinStore<T0> {"));
+    assert!(out.contains("pub coin: super::super::_0x1::coin::Coin<T0>,"));
+    assert!(out.contains("pub _phantom: std::marker::PhantomData<(T0,)>,"));
+    assert!(out.contains("pub struct DepositEvent {"));
+    assert!(out.contains("pub amount: bindings::U64,"));
+    assert!(
+        out.contains(r#"bindings::struct_tag("0x1", "coin", "CoinStore", vec![T0::type_tag()])"#)
+    );
+
+    // Event streams of the resources
+    assert!(out.contains("pub fn deposit_events("));
+    assert!(out.contains(") -> bindings::EventStream<super::super::_0x1::coin::DepositEvent> {"));
+
+    // View functions, but not the entry functions
+    assert!(out.contains("pub async fn balance<T0: bindings::MoveTypeTag"));
+    assert!(out.contains("arg0: bindings::Address,"));
+    assert!(out.contains(") -> bindings::AptosResult<bindings::Response<bindings::U64>> {"));
+    assert!(!out.contains("pub async fn transfer"));
+
+    // The structs used by the coin module, but only them, e.g. `0x1::string::String` is bound to
+    // a Rust `String`
+    assert!(out.contains("pub mod event {"));
+    assert!(out.contains("pub struct EventHandle<T0> {"));
+    assert!(out.contains("pub name: std::string::String,"));
+    assert!(!out.contains("pub mod string {"));
+    assert!(!out.contains("pub mod stake {"));
+}
+
+#[test]
+fn test_missing_modules() {
+    let modules: Vec<_> = framework_modules()
+        .into_iter()
+        .filter(|module| module.name.as_str() != "event")
+        .collect();
+    let targets = [MoveModuleId::from_str("0x1::coin").unwrap()];
+    assert_eq!(
+        missing_modules(&modules, &targets),
+        vec![MoveModuleId::from_str("0x1::event").unwrap()]
+    );
+    assert!(output(&mut vec![], &modules, &targets).is_err());
+}
+
+const BINDINGS_DEMO: &str = r#"
+mod bindings;
+
+use aptos_sdk::bindings::MoveStruct;
+use bindings::_0x1::{aptos_coin::AptosCoin, coin::CoinStore};
+
+fn main() {
+    assert_eq!(
+        CoinStore::<AptosCoin>::struct_tag().to_string(),
+        "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>"
+    );
+
+    let from_bcs: CoinStore<AptosCoin> = bcs::from_bytes(COIN_STORE_BCS).unwrap();
+    assert_eq!(bcs::to_bytes(&from_bcs).unwrap(), COIN_STORE_BCS);
+
+    let json: serde_json::Value = serde_json::from_str(COIN_STORE_JSON).unwrap();
+    let from_json: CoinStore<AptosCoin> = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&from_json).unwrap(), json);
+
+    assert_eq!(from_bcs, from_json);
+    println!("{}", from_json.coin.value);
+}
+"#;
+
+/// Builds the bindings of `0x1::coin` in a crate depending on `aptos-sdk`, and round-trips a
+/// `CoinStore<AptosCoin>` through them, in BCS as stored on chain and in JSON as returned by the
+/// REST API.
+#[test]
+// Ignored because building the generated crate fetches the dependencies of `aptos-sdk` from the
+// network and compiles them from scratch.
+#[ignore]
+fn test_coin_bindings_build() {
+    let targets = [
+        MoveModuleId::from_str("0x1::coin").unwrap(),
+        MoveModuleId::from_str("0x1::aptos_coin").unwrap(),
+    ];
+    let dir = tempdir().unwrap();
+    let demo_dir = dir.path().join("bindings_demo");
+    std::fs::create_dir_all(demo_dir.join("src")).unwrap();
+
+    let repo_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let mut cargo = std::fs::File::create(demo_dir.join("Cargo.toml")).unwrap();
+    write!(
+        cargo,
+        r#"[package]
+name = "bindings_demo"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aptos-sdk = {{ path = "{}" }}
+bcs = {{ git = "https://github.com/aptos-labs/bcs", rev = "2cde3e8446c460cb17b0c1d6bac7e27e964ac169" }}
+serde = {{ version = "1.0.137", features = ["derive"] }}
+serde_json = "1.0.81"
+"#,
+        repo_dir.join("sdk").display()
+    )
+    .unwrap();
+    // Use the versions of the dependencies of the workspace.
+    std::fs::copy(repo_dir.join("Cargo.lock"), demo_dir.join("Cargo.lock")).unwrap();
+
+    let mut bindings = std::fs::File::create(demo_dir.join("src/bindings.rs")).unwrap();
+    output(&mut bindings, &framework_modules(), &targets).unwrap();
+
+    let account = AccountAddress::from_hex_literal("0xa11ce").unwrap();
+    let coin_store = CoinStoreResource::new(
+        100,
+        false,
+        EventHandle::new(EventKey::new(2, account), 1),
+        EventHandle::new(EventKey::new(3, account), 0),
+    );
+    let coin_store_json = json!({
+        "coin": { "value": "100" },
+        "frozen": false,
+        "deposit_events": {
+            "counter": "1",
+            "guid": { "id": { "creation_num": "2", "addr": "0xa11ce" } },
+        },
+        "withdraw_events": {
+            "counter": "0",
+            "guid": { "id": { "creation_num": "3", "addr": "0xa11ce" } },
+        },
+    });
+    let mut demo = std::fs::File::create(demo_dir.join("src/main.rs")).unwrap();
+    write!(
+        demo,
+        "{}\nconst COIN_STORE_BCS: &[u8] = &{:?};\nconst COIN_STORE_JSON: &str = r#\"{}\"#;\n",
+        BINDINGS_DEMO,
+        bcs::to_bytes(&coin_store).unwrap(),
+        coin_store_json
+    )
+    .unwrap();
+
+    // Use a stable `target` dir to avoid downloading and recompiling crates everytime.
+    let target_dir = repo_dir.join("target");
+    let status = Command::new("cargo")
+        .current_dir(&demo_dir)
+        .arg("build")
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = Command::new(target_dir.join("debug/bindings_demo"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(std::str::from_utf8(&output.stdout).unwrap(), "100\n");
+}
+
+#[tokio::test]
+async fn test_generate_bindings_of_local_package() {
+    let dir = tempdir().unwrap();
+    let output_file = dir.path().join("bindings.rs");
+    let package_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../aptos-move/move-examples/hello_blockchain");
+    let named_addresses = BTreeMap::from([(
+        "hello_blockchain".to_string(),
+        AccountAddressWrapper::from_str("0x42").unwrap(),
+    )]);
+
+    let targets = GenerateBindings {
+        account: None,
+        package: None,
+        save_file: SaveFile {
+            output_file: output_file.clone(),
+            prompt_options: PromptOptions::yes(),
+        },
+        move_options: MovePackageDir {
+            package_dir: Some(package_dir),
+            output_dir: Some(dir.path().join("build")),
+            named_addresses,
+        },
+        rest_options: RestOptions::new(None, None),
+        profile_options: ProfileOptions::default(),
+    }
+    .execute()
+    .await
+    .unwrap();
+
+    // Only the modules of the package are targets, the framework modules they use are generated
+    // along with them
+    assert_eq!(targets, vec!["0x42::message".to_string()]);
+    let out = std::fs::read_to_string(output_file).unwrap();
+    assert!(out.contains("pub mod _0x42 {"));
+    assert!(out.contains("pub mod message {"));
+    assert!(out.contains("pub struct MessageHolder {"));
+    assert!(out.contains("pub fn message_change_events("));
+    assert!(out.contains("pub async fn get_message("));
+    assert!(out.contains("pub mod _0x1 {"));
+    assert!(out.contains("pub struct EventHandle<T0> {"));
+    assert!(!out.contains("pub mod coin {"));
+}
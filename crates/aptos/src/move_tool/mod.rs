@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod aptos_debug_natives;
+mod bindings;
 mod manifest;
 pub mod package_hooks;
 pub use package_hooks::*;
@@ -11,7 +12,7 @@ mod transactional_tests_runner;
 pub use stored_package::*;
 
 use crate::common::types::MoveManifestAccountWrapper;
-use crate::common::types::{ProfileOptions, RestOptions};
+use crate::common::types::{ProfileOptions, RestOptions, SaveFile};
 use crate::common::utils::{
     create_dir_if_not_exist, dir_default_to_current, prompt_yes_with_override, write_to_file,
 };
@@ -31,7 +32,10 @@ use crate::{
 };
 use aptos_gas::{AbstractValueSizeGasParameters, NativeGasParameters};
 use aptos_module_verifier::module_init::verify_module_init_function;
-use aptos_rest_client::aptos_api_types::MoveType;
+use aptos_rest_client::aptos_api_types::{
+    EntryFunctionId, MoveModule, MoveModuleId, MoveType, ViewRequest,
+};
+use aptos_rest_client::{Client, Transaction};
 use aptos_transactional_test_harness::run_aptos_test;
 use aptos_types::account_address::AccountAddress;
 use aptos_types::transaction::{EntryFunction, Script, TransactionArgument, TransactionPayload};
@@ -46,7 +50,7 @@ use move_cli::base::test::UnitTestResult;
 use move_command_line_common::env::MOVE_HOME;
 use std::fmt::{Display, Formatter};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     convert::TryFrom,
     path::{Path, PathBuf},
     str::FromStr,
@@ -83,6 +87,8 @@ pub enum MoveTool {
     Prove(ProvePackage),
     Document(DocumentPackage),
     TransactionalTest(TransactionalTestOpts),
+    View(ViewFunction),
+    GenerateBindings(GenerateBindings),
 }
 
 impl MoveTool {
@@ -101,6 +107,8 @@ impl MoveTool {
             MoveTool::Prove(tool) => tool.execute_serialized().await,
             MoveTool::Document(tool) => tool.execute_serialized().await,
             MoveTool::TransactionalTest(tool) => tool.execute_serialized_success().await,
+            MoveTool::View(tool) => tool.execute_serialized().await,
+            MoveTool::GenerateBindings(tool) => tool.execute_serialized().await,
         }
     }
 }
@@ -843,6 +851,14 @@ pub struct RunFunction {
     #[clap(long, multiple_values = true)]
     pub(crate) type_args: Vec<MoveType>,
 
+    /// Simulate the function instead of running it, and profile the gas it uses
+    ///
+    /// A breakdown of the gas charged per function, native function and storage operation
+    /// is written to `<MODULE>-<FUNCTION>.gas_profile.json`, and the gas charged per call
+    /// stack to `<MODULE>-<FUNCTION>.folded`, which can be rendered by flamegraph tools.
+    #[clap(long)]
+    pub(crate) profile_gas: bool,
+
     #[clap(flatten)]
     pub(crate) txn_options: TransactionOptions,
 }
@@ -868,16 +884,268 @@ impl CliCommand<TransactionSummary> for RunFunction {
             type_args.push(type_tag)
         }
 
-        self.txn_options
-            .submit_transaction(TransactionPayload::EntryFunction(EntryFunction::new(
-                self.function_id.module_id,
-                self.function_id.member_id,
-                type_args,
-                args,
-            )))
-            .await
-            .map(TransactionSummary::from)
+        let file_prefix = format!(
+            "{}-{}",
+            self.function_id.module_id.name(),
+            self.function_id.member_id
+        );
+        let payload = TransactionPayload::EntryFunction(EntryFunction::new(
+            self.function_id.module_id,
+            self.function_id.member_id,
+            type_args,
+            args,
+        ));
+
+        if !self.profile_gas {
+            return self
+                .txn_options
+                .submit_transaction(payload)
+                .await
+                .map(TransactionSummary::from);
+        }
+
+        let mut txn = self
+            .txn_options
+            .simulate_transaction_with_gas_profile(payload)
+            .await?;
+        let gas_profile = txn.gas_profile.take().ok_or_else(|| {
+            CliError::UnexpectedError("No gas profile returned by the simulation".to_string())
+        })?;
+
+        let json_path = PathBuf::from(format!("{}.gas_profile.json", file_prefix));
+        let json = serde_json::to_string_pretty(&gas_profile)
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+        write_to_file(&json_path, "Gas profile", json.as_bytes())?;
+
+        let folded_path = PathBuf::from(format!("{}.folded", file_prefix));
+        let mut folded = gas_profile.folded_stacks.join("\n");
+        folded.push('\n');
+        write_to_file(&folded_path, "Folded stacks", folded.as_bytes())?;
+
+        eprintln!(
+            "Gas profile written to {} and {}",
+            json_path.display(),
+            folded_path.display()
+        );
+        Ok(TransactionSummary::from(&Transaction::UserTransaction(
+            Box::new(txn),
+        )))
+    }
+}
+
+/// Run a view function
+///
+/// Executes a public Move function on the node without submitting a transaction,
+/// and returns the function's return values
+#[derive(Parser)]
+pub struct ViewFunction {
+    /// Function name as `<ADDRESS>::<MODULE_ID>::<FUNCTION_NAME>`
+    ///
+    /// Example: `0x1::coin::balance`
+    #[clap(long)]
+    pub(crate) function_id: MemberId,
+
+    /// Arguments combined with their type separated by spaces.
+    ///
+    /// Supported types [u8, u64, u128, bool, hex, hex_array, string, address]
+    ///
+    /// Example: `address:0x1 bool:true u8:0`
+    #[clap(long, multiple_values = true)]
+    pub(crate) args: Vec<ArgWithType>,
+
+    /// TypeTag arguments separated by spaces.
+    ///
+    /// Example: `u8 u64 u128 bool address vector`
+    #[clap(long, multiple_values = true)]
+    pub(crate) type_args: Vec<MoveType>,
+
+    /// Ledger version to execute the function at
+    ///
+    /// If not provided, it will be the latest version
+    #[clap(long)]
+    pub(crate) ledger_version: Option<u64>,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+#[async_trait]
+impl CliCommand<Vec<serde_json::Value>> for ViewFunction {
+    fn command_name(&self) -> &'static str {
+        "ViewFunction"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<serde_json::Value>> {
+        let arguments = self
+            .args
+            .iter()
+            .map(ArgWithType::to_json)
+            .collect::<CliTypedResult<Vec<_>>>()?;
+
+        let request = ViewRequest {
+            function: EntryFunctionId {
+                module: self.function_id.module_id.into(),
+                name: self.function_id.member_id.into(),
+            },
+            type_arguments: self.type_args,
+            arguments,
+        };
+
+        let client = self.rest_options.client(&self.profile_options)?;
+        Ok(client
+            .view(&request, self.ledger_version)
+            .await?
+            .into_inner())
+    }
+}
+
+/// Generate typed Rust bindings for the modules of a package
+///
+/// For every module, this generates a Rust module with a struct per Move struct, along with
+/// functions to fetch the resources of an account, to read their event streams, and to call the
+/// view functions through the REST API.  The modules defining the structs used by the package
+/// are generated as well.  The generated code depends on the `aptos-sdk` and `serde` crates.
+///
+/// The bindings are generated for the local package, or for the modules published at
+/// `--account` if given.
+#[derive(Parser)]
+pub struct GenerateBindings {
+    /// Address of the account containing the published modules
+    #[clap(long, parse(try_from_str=crate::common::types::load_account_arg))]
+    pub(crate) account: Option<AccountAddress>,
+
+    /// Name of the published package, to only generate the bindings of its modules
+    ///
+    /// Defaults to all the modules of `--account`
+    #[clap(long, requires = "account")]
+    pub(crate) package: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) save_file: SaveFile,
+    #[clap(flatten)]
+    pub(crate) move_options: MovePackageDir,
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+#[async_trait]
+impl CliCommand<Vec<String>> for GenerateBindings {
+    fn command_name(&self) -> &'static str {
+        "GenerateBindings"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<String>> {
+        self.save_file.check_file()?;
+        let (modules, targets) = if let Some(account) = self.account {
+            self.published_modules(account).await?
+        } else {
+            self.local_modules()?
+        };
+
+        let mut out = Vec::new();
+        bindings::output(&mut out, &modules, &targets)
+            .map_err(|err| CliError::UnexpectedError(format!("{:#}", err)))?;
+        self.save_file.save_to_file("Bindings", &out)?;
+        Ok(targets.iter().map(ToString::to_string).collect())
+    }
+}
+
+impl GenerateBindings {
+    /// Builds the local package, whose dependencies hold all the modules it uses
+    fn local_modules(&self) -> CliTypedResult<(Vec<MoveModule>, Vec<MoveModuleId>)> {
+        let build_options = BuildOptions {
+            install_dir: self.move_options.output_dir.clone(),
+            named_addresses: self.move_options.named_addresses(),
+            ..BuildOptions::default()
+        };
+        let pack = BuiltPackage::build(self.move_options.get_package_path()?, build_options)
+            .map_err(|e| CliError::MoveCompilationError(format!("{:#}", e)))?;
+        let targets = pack
+            .modules()
+            .map(|module| module.self_id().into())
+            .collect();
+        let modules = pack.all_modules().cloned().map(MoveModule::from).collect();
+        Ok((modules, targets))
     }
+
+    /// Fetches the modules published at `account`, along with the ones they use from other
+    /// accounts
+    async fn published_modules(
+        &self,
+        account: AccountAddress,
+    ) -> CliTypedResult<(Vec<MoveModule>, Vec<MoveModuleId>)> {
+        let client = self.rest_options.client(&self.profile_options)?;
+        let mut modules = fetch_modules(&client, account).await?;
+        let targets: Vec<MoveModuleId> = if let Some(package) = &self.package {
+            let url = self.rest_options.url(&self.profile_options)?;
+            let registry = CachedPackageRegistry::create(url, account).await?;
+            let package = registry
+                .get_package(package)
+                .await
+                .map_err(|s| CliError::CommandArgumentError(s.to_string()))?;
+            package
+                .module_names()
+                .into_iter()
+                .map(|name| {
+                    MoveModuleId::from_str(&format!("{}::{}", account.to_hex_literal(), name))
+                        .map_err(|err| CliError::UnableToParse("module", err.to_string()))
+                })
+                .collect::<CliTypedResult<_>>()?
+        } else {
+            modules
+                .iter()
+                .map(|module| MoveModuleId {
+                    address: module.address,
+                    name: module.name.clone(),
+                })
+                .collect()
+        };
+
+        let mut fetched = BTreeSet::from([account]);
+        loop {
+            let addresses: BTreeSet<_> = bindings::missing_modules(&modules, &targets)
+                .into_iter()
+                .map(|id| AccountAddress::from(id.address))
+                .filter(|address| !fetched.contains(address))
+                .collect();
+            if addresses.is_empty() {
+                break;
+            }
+            for address in addresses {
+                modules.extend(fetch_modules(&client, address).await?);
+                fetched.insert(address);
+            }
+        }
+        Ok((modules, targets))
+    }
+}
+
+async fn fetch_modules(
+    client: &Client,
+    address: AccountAddress,
+) -> CliTypedResult<Vec<MoveModule>> {
+    client
+        .get_account_modules(address)
+        .await?
+        .into_inner()
+        .into_iter()
+        .map(|bytecode| {
+            bytecode
+                .try_parse_abi()
+                .ok()
+                .and_then(|bytecode| bytecode.abi)
+                .ok_or_else(|| {
+                    CliError::UnexpectedError(format!(
+                        "Failed to parse the ABI of a module of {}",
+                        address
+                    ))
+                })
+        })
+        .collect()
 }
 
 /// Run a Move script
@@ -1048,6 +1316,55 @@ impl ArgWithType {
             arg,
         }
     }
+
+    /// Converts the argument into the JSON format accepted by the REST API
+    pub fn to_json(&self) -> CliTypedResult<serde_json::Value> {
+        match self._ty {
+            FunctionArgType::Address => {
+                let address: AccountAddress = txn_arg_parser(&self.arg, "address")?;
+                Ok(serde_json::Value::String(address.to_hex_literal()))
+            }
+            FunctionArgType::Bool => {
+                Ok(serde_json::Value::Bool(txn_arg_parser(&self.arg, "bool")?))
+            }
+            FunctionArgType::Hex => {
+                let bytes: Vec<u8> = txn_arg_parser(&self.arg, "hex")?;
+                Ok(serde_json::Value::String(format!(
+                    "0x{}",
+                    hex::encode(bytes)
+                )))
+            }
+            FunctionArgType::HexArray => {
+                let arrays: Vec<Vec<u8>> = txn_arg_parser(&self.arg, "hex_array")?;
+                Ok(serde_json::Value::Array(
+                    arrays
+                        .into_iter()
+                        .map(|bytes| serde_json::Value::String(format!("0x{}", hex::encode(bytes))))
+                        .collect(),
+                ))
+            }
+            FunctionArgType::String => Ok(serde_json::Value::String(txn_arg_parser(
+                &self.arg, "string",
+            )?)),
+            FunctionArgType::U8 => {
+                let value: u8 = txn_arg_parser(&self.arg, "u8")?;
+                Ok(serde_json::Value::from(value))
+            }
+            // u64 and u128 are represented as strings in JSON to avoid losing precision
+            FunctionArgType::U64 => {
+                let value: u64 = txn_arg_parser(&self.arg, "u64")?;
+                Ok(serde_json::Value::String(value.to_string()))
+            }
+            FunctionArgType::U128 => {
+                let value: u128 = txn_arg_parser(&self.arg, "u128")?;
+                Ok(serde_json::Value::String(value.to_string()))
+            }
+            FunctionArgType::Raw => Err(CliError::CommandArgumentError(
+                "Raw arguments can't be converted to JSON, use a typed argument instead"
+                    .to_string(),
+            )),
+        }
+    }
 }
 
 impl FromStr for ArgWithType {
@@ -0,0 +1,470 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod tests;
+
+use crate::common::types::{
+    load_account_arg, CliCommand, CliError, CliResult, CliTypedResult, EncodingOptions, GasOptions,
+    PrivateKeyInputOptions, ProfileOptions, RestOptions, SaveFile, TransactionOptions,
+    TransactionSummary,
+};
+use crate::common::utils::{
+    chain_id, get_sequence_number, prompt_yes_with_override, read_from_file,
+};
+use crate::move_tool::{ArgWithType, MemberId};
+use aptos_crypto::{
+    ed25519::{Ed25519PublicKey, Ed25519Signature},
+    multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature},
+    PrivateKey, Signature, SigningKey, ValidCryptoMaterialStringExt,
+};
+use aptos_global_constants::adjust_gas_headroom;
+use aptos_rest_client::aptos_api_types::{ExplainVMStatus, MoveType};
+use aptos_sdk::transaction_builder::TransactionFactory;
+use aptos_types::{
+    account_address::AccountAddress,
+    chain_id::ChainId,
+    transaction::{
+        authenticator::AuthenticationKey, EntryFunction, RawTransaction, SignedTransaction,
+        TransactionPayload,
+    },
+};
+use async_trait::async_trait;
+use cached_packages::aptos_stdlib;
+use clap::{Parser, Subcommand};
+use move_core_types::language_storage::TypeTag;
+use serde::{Deserialize, Serialize};
+use std::{convert::TryFrom, path::PathBuf};
+
+/// Tool for offline multisig transactions
+///
+/// A MultiEd25519 account is controlled by K-of-N Ed25519 keys.  A transaction of such an
+/// account is built into a file, signed offline by each of the K signers, and then the
+/// signatures are aggregated and the transaction submitted.
+#[derive(Subcommand)]
+pub enum MultisigTool {
+    Build(BuildTransaction),
+    CreateAccount(CreateMultisigAccount),
+    Sign(SignTransaction),
+    Submit(SubmitTransaction),
+}
+
+impl MultisigTool {
+    pub async fn execute(self) -> CliResult {
+        match self {
+            MultisigTool::Build(tool) => tool.execute_serialized().await,
+            MultisigTool::CreateAccount(tool) => tool.execute_serialized().await,
+            MultisigTool::Sign(tool) => tool.execute_serialized().await,
+            MultisigTool::Submit(tool) => tool.execute_serialized().await,
+        }
+    }
+}
+
+/// The public keys of a MultiEd25519 account
+#[derive(Debug, Parser)]
+pub struct MultisigPublicKeyOptions {
+    /// Hex encoded Ed25519 public keys of the signers, separated by spaces
+    ///
+    /// The order of the keys matters, as it determines the address of the account.
+    #[clap(long, multiple_values = true, required = true, parse(try_from_str = parse_public_key))]
+    pub(crate) public_keys: Vec<Ed25519PublicKey>,
+
+    /// Number of signatures required to authorize a transaction
+    #[clap(long)]
+    pub(crate) threshold: u8,
+}
+
+impl MultisigPublicKeyOptions {
+    pub fn public_key(&self) -> CliTypedResult<MultiEd25519PublicKey> {
+        MultiEd25519PublicKey::new(self.public_keys.clone(), self.threshold).map_err(|err| {
+            CliError::CommandArgumentError(format!(
+                "Invalid {}-of-{} public keys: {}",
+                self.threshold,
+                self.public_keys.len(),
+                err
+            ))
+        })
+    }
+}
+
+fn parse_public_key(str: &str) -> CliTypedResult<Ed25519PublicKey> {
+    Ed25519PublicKey::from_encoded_string(str)
+        .map_err(|err| CliError::UnableToParse("--public-keys", err.to_string()))
+}
+
+/// The signature of a multisig transaction by one of the signers
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PartialSignature {
+    pub public_key: Ed25519PublicKey,
+    pub signature: Ed25519Signature,
+}
+
+/// Aggregates the signatures of the signers into a signed transaction, which can be submitted
+/// if there are at least as many valid signatures as the threshold
+pub fn aggregate_signatures(
+    raw_txn: RawTransaction,
+    public_key: MultiEd25519PublicKey,
+    partial_signatures: Vec<PartialSignature>,
+) -> CliTypedResult<SignedTransaction> {
+    let mut signatures = Vec::new();
+    for partial_signature in partial_signatures {
+        let index = public_key
+            .public_keys()
+            .iter()
+            .position(|key| key == &partial_signature.public_key)
+            .ok_or_else(|| {
+                CliError::CommandArgumentError(format!(
+                    "{} isn't one of the public keys of the account",
+                    partial_signature.public_key
+                ))
+            })?;
+        partial_signature
+            .signature
+            .verify(&raw_txn, &partial_signature.public_key)
+            .map_err(|err| {
+                CliError::CommandArgumentError(format!(
+                    "Invalid signature of {}: {}",
+                    partial_signature.public_key, err
+                ))
+            })?;
+        signatures.push((partial_signature.signature, index as u8));
+    }
+
+    let threshold = *public_key.threshold() as usize;
+    if signatures.len() < threshold {
+        return Err(CliError::CommandArgumentError(format!(
+            "{} signatures are required, but only {} were given",
+            threshold,
+            signatures.len()
+        )));
+    }
+    let signature = MultiEd25519Signature::new(signatures)
+        .map_err(|err| CliError::CommandArgumentError(err.to_string()))?;
+    Ok(SignedTransaction::new_multisig(
+        raw_txn, public_key, signature,
+    ))
+}
+
+fn load_transaction(path: &PathBuf) -> CliTypedResult<RawTransaction> {
+    let bytes = read_from_file(path)?;
+    let hex_string = String::from_utf8(bytes)?;
+    let hex_string = hex_string.trim();
+    let bytes = hex::decode(hex_string.strip_prefix("0x").unwrap_or(hex_string))
+        .map_err(|err| CliError::UnableToParse("--transaction-file", err.to_string()))?;
+    bcs::from_bytes(&bytes).map_err(|err| CliError::BCS("--transaction-file", err))
+}
+
+/// The address and authentication key of a MultiEd25519 account
+#[derive(Debug, Serialize)]
+pub struct MultisigAccount {
+    pub account: AccountAddress,
+    pub authentication_key: AuthenticationKey,
+    pub public_key: String,
+    pub transaction: TransactionSummary,
+}
+
+/// Create a new MultiEd25519 account on-chain
+///
+/// The address of the account is derived from the public keys of the signers and the
+/// threshold, and the account is created by the sender of the transaction.  Coins have to be
+/// transferred to it afterwards to pay for its transactions.
+#[derive(Parser)]
+pub struct CreateMultisigAccount {
+    #[clap(flatten)]
+    pub(crate) multisig_options: MultisigPublicKeyOptions,
+    #[clap(flatten)]
+    pub(crate) txn_options: TransactionOptions,
+}
+
+#[async_trait]
+impl CliCommand<MultisigAccount> for CreateMultisigAccount {
+    fn command_name(&self) -> &'static str {
+        "CreateMultisigAccount"
+    }
+
+    async fn execute(self) -> CliTypedResult<MultisigAccount> {
+        let public_key = self.multisig_options.public_key()?;
+        let authentication_key = AuthenticationKey::multi_ed25519(&public_key);
+        let account = authentication_key.derived_address();
+        let transaction = self
+            .txn_options
+            .submit_transaction(aptos_stdlib::aptos_account_create_account(account))
+            .await?;
+        Ok(MultisigAccount {
+            account,
+            authentication_key,
+            public_key: public_key
+                .to_encoded_string()
+                .map_err(|err| CliError::UnexpectedError(err.to_string()))?,
+            transaction: TransactionSummary::from(transaction),
+        })
+    }
+}
+
+/// Build an unsigned transaction of a MultiEd25519 account, calling a Move function
+///
+/// The transaction is saved as hex encoded BCS to `--output-file`, to be signed by each of the
+/// signers with `aptos multisig sign`.  The sequence number, the chain ID and the gas are
+/// looked up on the network unless given, so that it can be built offline.
+#[derive(Parser)]
+pub struct BuildTransaction {
+    #[clap(flatten)]
+    pub(crate) multisig_options: MultisigPublicKeyOptions,
+
+    /// Address of the account
+    ///
+    /// Defaults to the address derived from the public keys, which differs if the
+    /// authentication key of the account was rotated
+    #[clap(long, parse(try_from_str = load_account_arg))]
+    pub(crate) sender_account: Option<AccountAddress>,
+
+    /// Function name as `<ADDRESS>::<MODULE_ID>::<FUNCTION_NAME>`
+    ///
+    /// Example: `0x1::aptos_account::transfer`
+    #[clap(long)]
+    pub(crate) function_id: MemberId,
+
+    /// Arguments combined with their type separated by spaces.
+    ///
+    /// Supported types [u8, u64, u128, bool, hex, string, address, raw]
+    ///
+    /// Example: `address:0x1 bool:true u8:0`
+    #[clap(long, multiple_values = true)]
+    pub(crate) args: Vec<ArgWithType>,
+
+    /// TypeTag arguments separated by spaces.
+    ///
+    /// Example: `u8 u64 u128 bool address vector signer`
+    #[clap(long, multiple_values = true)]
+    pub(crate) type_args: Vec<MoveType>,
+
+    /// Sequence number of the transaction
+    ///
+    /// Defaults to the current sequence number of the account
+    #[clap(long)]
+    pub(crate) sequence_number: Option<u64>,
+
+    /// Chain ID of the network, e.g. `testnet` or `2`
+    ///
+    /// Defaults to the chain ID of the REST endpoint
+    #[clap(long)]
+    pub(crate) chain_id: Option<ChainId>,
+
+    /// Number of seconds after which the transaction expires
+    ///
+    /// This must leave enough time to collect the signatures and submit it
+    #[clap(long, default_value_t = 3600)]
+    pub(crate) expiration_secs: u64,
+
+    #[clap(flatten)]
+    pub(crate) gas_options: GasOptions,
+    #[clap(flatten)]
+    pub(crate) save_file: SaveFile,
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+#[async_trait]
+impl CliCommand<RawTransaction> for BuildTransaction {
+    fn command_name(&self) -> &'static str {
+        "BuildMultisigTransaction"
+    }
+
+    async fn execute(self) -> CliTypedResult<RawTransaction> {
+        self.save_file.check_file()?;
+        let public_key = self.multisig_options.public_key()?;
+        let sender = self
+            .sender_account
+            .unwrap_or_else(|| AuthenticationKey::multi_ed25519(&public_key).derived_address());
+
+        let mut type_args: Vec<TypeTag> = Vec::new();
+        for type_arg in self.type_args.into_iter() {
+            let type_tag = TypeTag::try_from(type_arg)
+                .map_err(|err| CliError::UnableToParse("--type-args", err.to_string()))?;
+            type_args.push(type_tag)
+        }
+        let payload = TransactionPayload::EntryFunction(EntryFunction::new(
+            self.function_id.module_id,
+            self.function_id.member_id,
+            type_args,
+            self.args.into_iter().map(|arg| arg.arg).collect(),
+        ));
+
+        let client = self.rest_options.client(&self.profile_options)?;
+        let sequence_number = match self.sequence_number {
+            Some(sequence_number) => sequence_number,
+            None => get_sequence_number(&client, sender).await?,
+        };
+        let chain_id = match self.chain_id {
+            Some(chain_id) => chain_id,
+            None => chain_id(&client).await?,
+        };
+        let gas_unit_price = match self.gas_options.gas_unit_price {
+            Some(gas_unit_price) => gas_unit_price,
+            None => client.estimate_gas_price().await?.into_inner().gas_estimate,
+        };
+        let transaction_factory = TransactionFactory::new(chain_id)
+            .with_gas_unit_price(gas_unit_price)
+            .with_transaction_expiration_time(self.expiration_secs);
+
+        let max_gas = if let Some(max_gas) = self.gas_options.max_gas {
+            max_gas
+        } else {
+            // Simulate the transaction with as many invalid signatures as needed
+            let raw_txn = transaction_factory
+                .payload(payload.clone())
+                .sender(sender)
+                .sequence_number(sequence_number)
+                .build();
+            let invalid_signature = Ed25519Signature::try_from([0u8; 64].as_ref()).unwrap();
+            let signature = MultiEd25519Signature::new(
+                (0..*public_key.threshold())
+                    .map(|index| (invalid_signature.clone(), index))
+                    .collect(),
+            )
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+            let signed_transaction =
+                SignedTransaction::new_multisig(raw_txn, public_key, signature);
+            let simulated_txn = client
+                .simulate_bcs_with_gas_estimation(&signed_transaction, true, false)
+                .await?
+                .into_inner();
+
+            let status = simulated_txn.info.status();
+            if !status.is_success() {
+                let status = client.explain_vm_status(status);
+                return Err(CliError::SimulationError(status));
+            }
+            adjust_gas_headroom(
+                simulated_txn.info.gas_used(),
+                simulated_txn
+                    .transaction
+                    .as_signed_user_txn()
+                    .expect("Should be signed user transaction")
+                    .max_gas_amount(),
+            )
+        };
+
+        let raw_txn = transaction_factory
+            .with_max_gas_amount(max_gas)
+            .payload(payload)
+            .sender(sender)
+            .sequence_number(sequence_number)
+            .build();
+        let bytes = bcs::to_bytes(&raw_txn).map_err(|err| CliError::BCS("transaction", err))?;
+        self.save_file
+            .save_to_file("Transaction", hex::encode(bytes).as_bytes())?;
+        Ok(raw_txn)
+    }
+}
+
+/// Sign a multisig transaction built with `aptos multisig build`
+///
+/// The signature is saved to `--output-file` along with the public key of the signer, for
+/// `aptos multisig submit` to aggregate it with the others.  This doesn't need a connection
+/// to the network.
+#[derive(Parser)]
+pub struct SignTransaction {
+    /// Transaction file built with `aptos multisig build`
+    #[clap(long, parse(from_os_str))]
+    pub(crate) transaction_file: PathBuf,
+
+    #[clap(flatten)]
+    pub(crate) private_key_options: PrivateKeyInputOptions,
+    #[clap(flatten)]
+    pub(crate) encoding_options: EncodingOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+    #[clap(flatten)]
+    pub(crate) save_file: SaveFile,
+}
+
+#[async_trait]
+impl CliCommand<PartialSignature> for SignTransaction {
+    fn command_name(&self) -> &'static str {
+        "SignMultisigTransaction"
+    }
+
+    async fn execute(self) -> CliTypedResult<PartialSignature> {
+        self.save_file.check_file()?;
+        let raw_txn = load_transaction(&self.transaction_file)?;
+        let private_key = self
+            .private_key_options
+            .extract_private_key(self.encoding_options.encoding, &self.profile_options)?;
+
+        eprintln!(
+            "{}",
+            serde_json::to_string_pretty(&raw_txn)
+                .map_err(|err| CliError::UnexpectedError(err.to_string()))?
+        );
+        prompt_yes_with_override(
+            "Do you want to sign the above transaction?",
+            self.save_file.prompt_options,
+        )?;
+
+        let partial_signature = PartialSignature {
+            public_key: private_key.public_key(),
+            signature: private_key
+                .sign(&raw_txn)
+                .map_err(|err| CliError::UnexpectedError(err.to_string()))?,
+        };
+        let json = serde_json::to_string_pretty(&partial_signature)
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+        self.save_file.save_to_file("Signature", json.as_bytes())?;
+        Ok(partial_signature)
+    }
+}
+
+/// Aggregate the signatures of a multisig transaction and submit it
+///
+/// At least as many signatures as the threshold are needed, from any of the signers.
+#[derive(Parser)]
+pub struct SubmitTransaction {
+    /// Transaction file built with `aptos multisig build`
+    #[clap(long, parse(from_os_str))]
+    pub(crate) transaction_file: PathBuf,
+
+    /// Signature files saved by `aptos multisig sign`, separated by spaces
+    #[clap(long, multiple_values = true, required = true, parse(from_os_str))]
+    pub(crate) signature_files: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    pub(crate) multisig_options: MultisigPublicKeyOptions,
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+#[async_trait]
+impl CliCommand<TransactionSummary> for SubmitTransaction {
+    fn command_name(&self) -> &'static str {
+        "SubmitMultisigTransaction"
+    }
+
+    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+        let raw_txn = load_transaction(&self.transaction_file)?;
+        let partial_signatures = self
+            .signature_files
+            .iter()
+            .map(|path| {
+                serde_json::from_slice(&read_from_file(path)?)
+                    .map_err(|err| CliError::UnableToParse("--signature-files", err.to_string()))
+            })
+            .collect::<CliTypedResult<Vec<PartialSignature>>>()?;
+        let transaction = aggregate_signatures(
+            raw_txn,
+            self.multisig_options.public_key()?,
+            partial_signatures,
+        )?;
+
+        let client = self.rest_options.client(&self.profile_options)?;
+        client
+            .submit_and_wait(&transaction)
+            .await
+            .map(|response| TransactionSummary::from(response.into_inner()))
+            .map_err(|err| CliError::ApiError(err.to_string()))
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::multisig::{aggregate_signatures, PartialSignature};
+use aptos_crypto::{
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
+    multi_ed25519::MultiEd25519PublicKey,
+    PrivateKey, SigningKey,
+};
+use aptos_keygen::KeyGen;
+use aptos_types::{
+    chain_id::ChainId,
+    transaction::{authenticator::AuthenticationKey, RawTransaction},
+};
+use cached_packages::aptos_stdlib;
+use move_core_types::account_address::AccountAddress;
+
+fn keys() -> (Vec<Ed25519PrivateKey>, MultiEd25519PublicKey) {
+    let mut keygen = KeyGen::from_seed([0; 32]);
+    let private_keys: Vec<_> = (0..3)
+        .map(|_| keygen.generate_ed25519_private_key())
+        .collect();
+    let public_keys: Vec<Ed25519PublicKey> =
+        private_keys.iter().map(PrivateKey::public_key).collect();
+    let public_key = MultiEd25519PublicKey::new(public_keys, 2).unwrap();
+    (private_keys, public_key)
+}
+
+fn raw_transaction(public_key: &MultiEd25519PublicKey) -> RawTransaction {
+    RawTransaction::new(
+        AuthenticationKey::multi_ed25519(public_key).derived_address(),
+        0,
+        aptos_stdlib::aptos_account_create_account(AccountAddress::ONE),
+        1000,
+        100,
+        0,
+        ChainId::test(),
+    )
+}
+
+fn sign(private_key: &Ed25519PrivateKey, raw_txn: &RawTransaction) -> PartialSignature {
+    PartialSignature {
+        public_key: private_key.public_key(),
+        signature: private_key.sign(raw_txn).unwrap(),
+    }
+}
+
+#[test]
+fn test_aggregate_signatures() {
+    let (private_keys, public_key) = keys();
+    let raw_txn = raw_transaction(&public_key);
+
+    // The signatures don't have to be in the order of the public keys
+    let partial_signatures = vec![
+        sign(&private_keys[2], &raw_txn),
+        sign(&private_keys[0], &raw_txn),
+    ];
+    let transaction = aggregate_signatures(raw_txn, public_key, partial_signatures).unwrap();
+    assert!(transaction.check_signature().is_ok());
+}
+
+#[test]
+fn test_aggregate_signatures_below_threshold() {
+    let (private_keys, public_key) = keys();
+    let raw_txn = raw_transaction(&public_key);
+
+    let partial_signatures = vec![sign(&private_keys[1], &raw_txn)];
+    assert!(aggregate_signatures(raw_txn, public_key, partial_signatures).is_err());
+}
+
+#[test]
+fn test_aggregate_invalid_signatures() {
+    let (private_keys, public_key) = keys();
+    let raw_txn = raw_transaction(&public_key);
+    let other_txn = RawTransaction::new(
+        raw_txn.sender(),
+        1,
+        aptos_stdlib::aptos_account_create_account(AccountAddress::ONE),
+        1000,
+        100,
+        0,
+        ChainId::test(),
+    );
+
+    // Signature of another transaction
+    let partial_signatures = vec![
+        sign(&private_keys[0], &raw_txn),
+        sign(&private_keys[1], &other_txn),
+    ];
+    assert!(aggregate_signatures(raw_txn.clone(), public_key.clone(), partial_signatures).is_err());
+
+    // Signature of a key which isn't part of the account
+    let other_key = KeyGen::from_seed([1; 32]).generate_ed25519_private_key();
+    let partial_signatures = vec![sign(&private_keys[0], &raw_txn), sign(&other_key, &raw_txn)];
+    assert!(aggregate_signatures(raw_txn, public_key, partial_signatures).is_err());
+}
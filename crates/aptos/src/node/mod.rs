@@ -1481,6 +1481,7 @@ impl CliCommand<()> for BootstrapDbFromBackup {
             replay_all: false,
             ledger_history_start_version: None,
             skip_epoch_endings: false,
+            target_timestamp: None,
         };
         let global_opt = GlobalRestoreOpt {
             dry_run: false,
@@ -4,15 +4,17 @@
 use crate::{
     common::{
         types::{
-            CliError, CliTypedResult, EncodingOptions, EncodingType, KeyType, RngArgs, SaveFile,
+            CliError, CliTypedResult, DerivationPathOptions, EncodingOptions, EncodingType,
+            KeyType, RngArgs, SaveFile,
         },
-        utils::{append_file_extension, check_if_file_exists, write_to_file},
+        utils::{append_file_extension, check_if_file_exists, read_line, write_to_file},
     },
     CliCommand, CliResult,
 };
 use aptos_config::config::{Peer, PeerRole};
 use aptos_crypto::{bls12381, ed25519, x25519, PrivateKey, ValidCryptoMaterial};
 use aptos_genesis::config::HostAndPort;
+use aptos_sdk::types::generate_mnemonic_phrase;
 use aptos_types::account_address::{from_identity_public_key, AccountAddress};
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
@@ -30,6 +32,8 @@ pub const PUBLIC_KEY_EXTENSION: &str = "pub";
 #[derive(Debug, Subcommand)]
 pub enum KeyTool {
     Generate(GenerateKey),
+    GenerateMnemonic(GenerateMnemonic),
+    FromMnemonic(KeyFromMnemonic),
     ExtractPeer(ExtractPeer),
 }
 
@@ -37,6 +41,8 @@ impl KeyTool {
     pub async fn execute(self) -> CliResult {
         match self {
             KeyTool::Generate(tool) => tool.execute_serialized().await,
+            KeyTool::GenerateMnemonic(tool) => tool.execute_serialized().await,
+            KeyTool::FromMnemonic(tool) => tool.execute_serialized().await,
             KeyTool::ExtractPeer(tool) => tool.execute_serialized().await,
         }
     }
@@ -251,6 +257,73 @@ impl GenerateKey {
     }
 }
 
+/// Generates a BIP-39 mnemonic phrase
+///
+/// The phrase can be used to recover the keys of accounts with `aptos key from-mnemonic` or
+/// `aptos init --from-mnemonic`, as well as with wallets deriving keys along the path
+/// `m/44'/637'/{account_index}'/0'/0'`.  Anyone with the phrase controls the accounts, so keep
+/// it secret.
+#[derive(Debug, Parser)]
+pub struct GenerateMnemonic {
+    /// Number of words of the phrase. Must be one of [12, 15, 18, 21, 24]
+    #[clap(long, default_value_t = 12)]
+    pub(crate) word_count: usize,
+
+    #[clap(flatten)]
+    pub rng_args: RngArgs,
+}
+
+#[async_trait]
+impl CliCommand<String> for GenerateMnemonic {
+    fn command_name(&self) -> &'static str {
+        "GenerateMnemonic"
+    }
+
+    async fn execute(self) -> CliTypedResult<String> {
+        generate_mnemonic_phrase(&mut self.rng_args.rng()?, self.word_count)
+            .map_err(|err| CliError::CommandArgumentError(err.to_string()))
+    }
+}
+
+/// Derives an `ed25519` key from a BIP-39 mnemonic phrase
+///
+/// The key is derived following SLIP-0010, by default along the path `m/44'/637'/0'/0'/0'` of
+/// the first account of the phrase.  Two files will be created, `output_file` and
+/// `output_file.pub`, like with `aptos key generate`.
+#[derive(Debug, Parser)]
+pub struct KeyFromMnemonic {
+    /// Mnemonic phrase to derive the key from
+    ///
+    /// If not given, it's read from the standard input, so that it isn't saved in the shell
+    /// history
+    #[clap(long)]
+    pub(crate) mnemonic: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) derivation_path_options: DerivationPathOptions,
+    #[clap(flatten)]
+    pub(crate) save_params: SaveKey,
+}
+
+#[async_trait]
+impl CliCommand<HashMap<&'static str, PathBuf>> for KeyFromMnemonic {
+    fn command_name(&self) -> &'static str {
+        "KeyFromMnemonic"
+    }
+
+    async fn execute(self) -> CliTypedResult<HashMap<&'static str, PathBuf>> {
+        self.save_params.check_key_file()?;
+        let mnemonic = if let Some(mnemonic) = self.mnemonic {
+            mnemonic
+        } else {
+            eprintln!("Enter your mnemonic phrase");
+            read_line("Mnemonic phrase")?
+        };
+        let private_key = self.derivation_path_options.derive_private_key(&mnemonic)?;
+        self.save_params.save_key(&private_key, "ed25519")
+    }
+}
+
 #[derive(Debug, Parser)]
 pub struct SaveKey {
     #[clap(flatten)]
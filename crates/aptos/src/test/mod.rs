@@ -21,8 +21,13 @@ use crate::common::types::{
 use crate::common::utils::write_to_file;
 
 use crate::move_tool::{
-    ArgWithType, CompilePackage, DownloadPackage, FrameworkPackageArgs, IncludedArtifacts,
-    IncludedArtifactsArgs, InitPackage, MemberId, PublishPackage, RunFunction, TestPackage,
+    ArgWithType, CompilePackage, DownloadPackage, FrameworkPackageArgs, GenerateBindings,
+    IncludedArtifacts, IncludedArtifactsArgs, InitPackage, MemberId, PublishPackage, RunFunction,
+    TestPackage,
+};
+use crate::multisig::{
+    BuildTransaction, CreateMultisigAccount, MultisigAccount, MultisigPublicKeyOptions,
+    PartialSignature, SignTransaction, SubmitTransaction,
 };
 use crate::node::{
     AnalyzeMode, AnalyzeValidatorPerformance, GetStakePool, InitializeValidator, JoinValidatorSet,
@@ -48,7 +53,7 @@ use aptos_sdk::move_types::account_address::AccountAddress;
 use aptos_sdk::move_types::identifier::Identifier;
 use aptos_sdk::move_types::language_storage::ModuleId;
 use aptos_temppath::TempPath;
-use aptos_types::on_chain_config::ValidatorSet;
+use aptos_types::{on_chain_config::ValidatorSet, transaction::RawTransaction};
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -513,6 +518,8 @@ impl CliTestFramework {
             prompt_options: PromptOptions::yes(),
             encoding_options: EncodingOptions::default(),
             skip_faucet: false,
+            from_mnemonic: false,
+            derivation_path_options: Default::default(),
         }
         .execute()
         .await
@@ -852,6 +859,33 @@ impl CliTestFramework {
         .await
     }
 
+    /// Generates the bindings of the modules published by the account at `index`, or only of the
+    /// ones of `package` if given
+    pub async fn generate_bindings(
+        &self,
+        index: usize,
+        package: Option<String>,
+        output_file: PathBuf,
+    ) -> CliTypedResult<Vec<String>> {
+        GenerateBindings {
+            account: Some(self.account_id(index)),
+            package,
+            save_file: SaveFile {
+                output_file,
+                prompt_options: PromptOptions::yes(),
+            },
+            move_options: MovePackageDir {
+                package_dir: None,
+                output_dir: None,
+                named_addresses: Default::default(),
+            },
+            rest_options: self.rest_options(),
+            profile_options: Default::default(),
+        }
+        .execute()
+        .await
+    }
+
     pub async fn run_function(
         &self,
         index: usize,
@@ -886,6 +920,111 @@ impl CliTestFramework {
         .await
     }
 
+    /// Creates the MultiEd25519 account of the keys of the accounts at `signer_indices`, with the
+    /// account at `sender_index` as sender
+    pub async fn create_multisig_account(
+        &self,
+        sender_index: usize,
+        signer_indices: &[usize],
+        threshold: u8,
+    ) -> CliTypedResult<MultisigAccount> {
+        CreateMultisigAccount {
+            multisig_options: self.multisig_options(signer_indices, threshold),
+            txn_options: self.transaction_options(sender_index, None),
+        }
+        .execute()
+        .await
+    }
+
+    pub async fn build_multisig_transaction(
+        &self,
+        signer_indices: &[usize],
+        threshold: u8,
+        function_id: MemberId,
+        args: Vec<&str>,
+        output_file: PathBuf,
+    ) -> CliTypedResult<RawTransaction> {
+        let mut parsed_args = Vec::new();
+        for arg in args {
+            parsed_args.push(
+                ArgWithType::from_str(arg)
+                    .map_err(|err| CliError::UnexpectedError(err.to_string()))?,
+            )
+        }
+
+        BuildTransaction {
+            multisig_options: self.multisig_options(signer_indices, threshold),
+            sender_account: None,
+            function_id,
+            args: parsed_args,
+            type_args: Vec::new(),
+            sequence_number: None,
+            chain_id: None,
+            expiration_secs: 3600,
+            gas_options: Default::default(),
+            save_file: SaveFile {
+                output_file,
+                prompt_options: PromptOptions::yes(),
+            },
+            rest_options: self.rest_options(),
+            profile_options: Default::default(),
+        }
+        .execute()
+        .await
+    }
+
+    pub async fn sign_multisig_transaction(
+        &self,
+        index: usize,
+        transaction_file: PathBuf,
+        output_file: PathBuf,
+    ) -> CliTypedResult<PartialSignature> {
+        SignTransaction {
+            transaction_file,
+            private_key_options: PrivateKeyInputOptions::from_private_key(self.private_key(index))?,
+            encoding_options: Default::default(),
+            profile_options: Default::default(),
+            save_file: SaveFile {
+                output_file,
+                prompt_options: PromptOptions::yes(),
+            },
+        }
+        .execute()
+        .await
+    }
+
+    pub async fn submit_multisig_transaction(
+        &self,
+        signer_indices: &[usize],
+        threshold: u8,
+        transaction_file: PathBuf,
+        signature_files: Vec<PathBuf>,
+    ) -> CliTypedResult<TransactionSummary> {
+        SubmitTransaction {
+            transaction_file,
+            signature_files,
+            multisig_options: self.multisig_options(signer_indices, threshold),
+            rest_options: self.rest_options(),
+            profile_options: Default::default(),
+        }
+        .execute()
+        .await
+    }
+
+    fn multisig_options(
+        &self,
+        signer_indices: &[usize],
+        threshold: u8,
+    ) -> MultisigPublicKeyOptions {
+        MultisigPublicKeyOptions {
+            public_keys: signer_indices
+                .iter()
+                .map(|index| self.private_key(*index).public_key())
+                .collect(),
+            threshold,
+        }
+    }
+
     pub fn move_options(&self, account_strs: BTreeMap<&str, &str>) -> MovePackageDir {
         MovePackageDir {
             package_dir: Some(self.move_dir()),
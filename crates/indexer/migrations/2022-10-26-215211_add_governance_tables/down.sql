@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS proposals;
+DROP TABLE IF EXISTS proposal_votes;
+DROP TABLE IF EXISTS proposal_resolutions;
+DROP TABLE IF EXISTS staking_proxy_activities;
\ No newline at end of file
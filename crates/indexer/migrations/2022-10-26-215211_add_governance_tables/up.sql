@@ -0,0 +1,63 @@
+-- Your SQL goes here
+-- governance proposals, from the creation events of aptos_governance and of its voting forum
+CREATE TABLE proposals (
+  proposal_id BIGINT UNIQUE PRIMARY KEY NOT NULL,
+  proposer_address VARCHAR(66) NOT NULL,
+  staking_pool_address VARCHAR(66) NOT NULL,
+  -- hash of the script resolving the proposal
+  execution_hash VARCHAR(66) NOT NULL,
+  metadata JSONB NOT NULL,
+  min_vote_threshold NUMERIC NOT NULL,
+  early_resolution_vote_threshold NUMERIC,
+  expiration_timestamp TIMESTAMP NOT NULL,
+  transaction_version BIGINT NOT NULL,
+  transaction_timestamp TIMESTAMP NOT NULL,
+  inserted_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+CREATE INDEX prop_pa_index ON proposals (proposer_address);
+CREATE INDEX prop_spa_index ON proposals (staking_pool_address);
+CREATE INDEX prop_insat_index ON proposals (inserted_at);
+-- votes on governance proposals, one per stake pool voting
+CREATE TABLE proposal_votes (
+  transaction_version BIGINT NOT NULL,
+  proposal_id BIGINT NOT NULL,
+  voter_address VARCHAR(66) NOT NULL,
+  staking_pool_address VARCHAR(66) NOT NULL,
+  num_votes NUMERIC NOT NULL,
+  should_pass BOOLEAN NOT NULL,
+  transaction_timestamp TIMESTAMP NOT NULL,
+  inserted_at TIMESTAMP NOT NULL DEFAULT NOW(),
+  -- Constraints
+  PRIMARY KEY (transaction_version, proposal_id, staking_pool_address)
+);
+CREATE INDEX pv_pi_index ON proposal_votes (proposal_id);
+CREATE INDEX pv_va_index ON proposal_votes (voter_address);
+CREATE INDEX pv_spa_index ON proposal_votes (staking_pool_address);
+CREATE INDEX pv_insat_index ON proposal_votes (inserted_at);
+-- resolved governance proposals. A proposal without resolution hasn't been resolved yet
+CREATE TABLE proposal_resolutions (
+  proposal_id BIGINT UNIQUE PRIMARY KEY NOT NULL,
+  yes_votes NUMERIC NOT NULL,
+  no_votes NUMERIC NOT NULL,
+  resolved_early BOOLEAN NOT NULL,
+  transaction_version BIGINT NOT NULL,
+  transaction_timestamp TIMESTAMP NOT NULL,
+  inserted_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+CREATE INDEX pr_insat_index ON proposal_resolutions (inserted_at);
+-- successful calls to staking_proxy, delegating the operator or voter of the stake pools,
+-- staking contracts and vesting contracts of an owner
+CREATE TABLE staking_proxy_activities (
+  transaction_version BIGINT UNIQUE PRIMARY KEY NOT NULL,
+  owner_address VARCHAR(66) NOT NULL,
+  entry_function_name VARCHAR(100) NOT NULL,
+  -- the operator identifying the staking or vesting contracts, null for the owner's stake pool
+  operator_address VARCHAR(66),
+  new_operator_address VARCHAR(66),
+  new_voter_address VARCHAR(66),
+  transaction_timestamp TIMESTAMP NOT NULL,
+  inserted_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+CREATE INDEX spa_oa_index ON staking_proxy_activities (owner_address);
+CREATE INDEX spa_nva_index ON staking_proxy_activities (new_voter_address);
+CREATE INDEX spa_insat_index ON staking_proxy_activities (inserted_at);
@@ -0,0 +1,131 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_api_types::{Address, Event, MoveStructTag, MoveType, Transaction};
+use std::str::FromStr;
+
+/// Selects the events of a batch of transactions by event type and/or by the address of the
+/// module defining the event type, so that a processor only handles the events of the modules it
+/// indexes.
+#[derive(Clone, Debug, Default)]
+pub struct EventFilter {
+    event_types: Vec<MoveStructTag>,
+    module_addresses: Vec<Address>,
+}
+
+impl EventFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches the events of the given type, e.g. "0x1::coin::DepositEvent". If the type has no
+    /// generic type parameters, all the instantiations of a generic type are matched.
+    pub fn event_type(mut self, event_type: &str) -> anyhow::Result<Self> {
+        self.event_types.push(MoveStructTag::from_str(event_type)?);
+        Ok(self)
+    }
+
+    /// Matches the events whose type is defined by a module published at `address`
+    pub fn module_address(mut self, address: &str) -> anyhow::Result<Self> {
+        self.module_addresses.push(Address::from_str(address)?);
+        Ok(self)
+    }
+
+    /// Whether the event matches any of the event types or module addresses. An empty filter
+    /// matches all events.
+    pub fn matches(&self, event: &Event) -> bool {
+        if self.event_types.is_empty() && self.module_addresses.is_empty() {
+            return true;
+        }
+        let tag = match &event.typ {
+            MoveType::Struct(tag) => tag,
+            _ => return false,
+        };
+        self.module_addresses.contains(&tag.address)
+            || self.event_types.iter().any(|event_type| {
+                event_type.address == tag.address
+                    && event_type.module == tag.module
+                    && event_type.name == tag.name
+                    && (event_type.generic_type_params.is_empty()
+                        || event_type.generic_type_params == tag.generic_type_params)
+            })
+    }
+
+    /// Returns the matching events of the transactions, along with the version of the transaction
+    /// which emitted them
+    pub fn filter_events<'a>(&self, transactions: &'a [Transaction]) -> Vec<(u64, &'a Event)> {
+        let mut events = vec![];
+        for transaction in transactions {
+            let (version, txn_events) = match transaction {
+                Transaction::UserTransaction(inner) => (inner.info.version.0, &inner.events),
+                Transaction::GenesisTransaction(inner) => (inner.info.version.0, &inner.events),
+                Transaction::BlockMetadataTransaction(inner) => {
+                    (inner.info.version.0, &inner.events)
+                }
+                Transaction::PendingTransaction(_) | Transaction::StateCheckpointTransaction(_) => {
+                    continue
+                }
+            };
+            events.extend(
+                txn_events
+                    .iter()
+                    .filter(|event| self.matches(event))
+                    .map(|event| (version, event)),
+            );
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(typ: &str) -> Event {
+        serde_json::from_value(json!({
+            "guid": {
+                "account_address": "0x1",
+                "creation_number": "4",
+            },
+            "sequence_number": "0",
+            "type": typ,
+            "data": {},
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_event_filter() {
+        let deposit = event("0x1::coin::DepositEvent");
+        let custom = event("0xcafe::dapp::SwapEvent<0x1::aptos_coin::AptosCoin>");
+
+        let all = EventFilter::new();
+        assert!(all.matches(&deposit));
+        assert!(all.matches(&custom));
+
+        let by_type = EventFilter::new()
+            .event_type("0x1::coin::DepositEvent")
+            .unwrap();
+        assert!(by_type.matches(&deposit));
+        assert!(!by_type.matches(&custom));
+
+        // Without type parameters, all the instantiations match
+        let by_generic_type = EventFilter::new()
+            .event_type("0xcafe::dapp::SwapEvent")
+            .unwrap();
+        assert!(by_generic_type.matches(&custom));
+        let by_other_instantiation = EventFilter::new()
+            .event_type("0xcafe::dapp::SwapEvent<0x1::coin::CoinInfo>")
+            .unwrap();
+        assert!(!by_other_instantiation.matches(&custom));
+
+        let by_address = EventFilter::new()
+            .module_address("0x000000000000000000000000000000000000000000000000000000000000cafe")
+            .unwrap();
+        assert!(!by_address.matches(&deposit));
+        assert!(by_address.matches(&custom));
+
+        assert!(EventFilter::new().event_type("not a type").is_err());
+    }
+}
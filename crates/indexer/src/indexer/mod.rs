@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod errors;
+pub mod event_filter;
 pub mod fetcher;
 pub mod processing_result;
 pub mod tailer;
@@ -30,10 +30,15 @@ use tokio::{sync::Mutex, task::JoinHandle};
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
 
+/// Drives several `TransactionProcessor`s over the same stream of transactions from a single
+/// fetcher. Each processor tracks its own progress, and only processes the transactions from its
+/// own starting version on.
 #[derive(Clone)]
 pub struct Tailer {
     pub transaction_fetcher: Arc<Mutex<dyn TransactionFetcherTrait>>,
-    processor: Arc<dyn TransactionProcessor>,
+    processors: Vec<Arc<dyn TransactionProcessor>>,
+    /// The first version to process for each processor, in the same order as `processors`
+    processor_start_versions: Vec<u64>,
     connection_pool: PgDbPool,
 }
 
@@ -41,7 +46,7 @@ impl Tailer {
     pub fn new(
         context: Arc<ApiContext>,
         connection_pool: PgDbPool,
-        processor: Arc<dyn TransactionProcessor>,
+        processors: Vec<Arc<dyn TransactionProcessor>>,
         options: TransactionFetcherOptions,
     ) -> Result<Tailer, ParseError> {
         let resolver = Arc::new(context.move_resolver().unwrap());
@@ -49,11 +54,25 @@ impl Tailer {
 
         Ok(Self {
             transaction_fetcher: Arc::new(Mutex::new(transaction_fetcher)),
+            processor_start_versions: vec![0; processors.len()],
+            processors,
             connection_pool,
-            processor,
         })
     }
 
+    pub fn processors(&self) -> &[Arc<dyn TransactionProcessor>] {
+        &self.processors
+    }
+
+    /// Names of all the processors, for logging
+    pub fn processor_names(&self) -> String {
+        self.processors
+            .iter()
+            .map(|processor| processor.name())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
     pub fn run_migrations(&self) {
         let _ = &self
             .connection_pool
@@ -63,10 +82,20 @@ impl Tailer {
             .expect("migrations failed!");
     }
 
+    /// Runs the migrations of a processor registered outside of this crate
+    pub fn run_processor_migrations(&self, migrations: EmbeddedMigrations) {
+        let _ = &self
+            .connection_pool
+            .get()
+            .expect("Could not get connection for migrations")
+            .run_pending_migrations(migrations)
+            .expect("processor migrations failed!");
+    }
+
     /// If chain id doesn't exist, save it. Otherwise, make sure that we're indexing the same chain
     pub async fn check_or_update_chain_id(&self) -> Result<u64> {
         info!(
-            processor_name = self.processor.name(),
+            processor_name = self.processor_names(),
             "Checking if chain id is correct"
         );
         let mut conn = self.connection_pool.get()?;
@@ -84,7 +113,7 @@ impl Tailer {
             Some(chain_id) => {
                 ensure!(chain_id == new_chain_id, "Wrong chain detected! Trying to index chain {} now but existing data is for chain {}", new_chain_id, chain_id);
                 info!(
-                    processor_name = self.processor.name(),
+                    processor_name = self.processor_names(),
                     chain_id = chain_id,
                     "Chain id matches! Continue to index...",
                 );
@@ -92,7 +121,7 @@ impl Tailer {
             }
             None => {
                 info!(
-                    processor_name = self.processor.name(),
+                    processor_name = self.processor_names(),
                     chain_id = new_chain_id,
                     "Adding chain id to db, continue to index.."
                 );
@@ -118,9 +147,25 @@ impl Tailer {
         info!(version = version, "Will start fetching from version");
     }
 
+    /// Sets the first version to process for each processor. The fetcher has to start from the
+    /// smallest of them, the processors skip the transactions before their own starting version.
+    pub fn set_processor_start_versions(&mut self, start_versions: Vec<u64>) {
+        assert_eq!(
+            start_versions.len(),
+            self.processors.len(),
+            "Must provide a starting version for each processor"
+        );
+        self.processor_start_versions = start_versions;
+    }
+
+    /// Fetches the next batch of transactions and processes it with all the processors
+    /// concurrently. Returns a result for each processor which had transactions to process.
     pub async fn process_next_batch(
         &self,
-    ) -> (u64, Result<ProcessingResult, TransactionProcessingError>) {
+    ) -> (
+        u64,
+        Vec<Result<ProcessingResult, TransactionProcessingError>>,
+    ) {
         let transactions = self
             .transaction_fetcher
             .lock()
@@ -141,10 +186,24 @@ impl Tailer {
 
         let batch_start = chrono::Utc::now().naive_utc();
 
-        let results = self
-            .processor
-            .process_transactions_with_status(transactions)
-            .await;
+        let results = futures::future::join_all(
+            self.processors
+                .iter()
+                .zip(&self.processor_start_versions)
+                .filter_map(|(processor, processor_start_version)| {
+                    let transactions: Vec<_> = transactions
+                        .iter()
+                        .filter(|txn| txn.version().unwrap() >= *processor_start_version)
+                        .cloned()
+                        .collect();
+                    if transactions.is_empty() {
+                        None
+                    } else {
+                        Some(processor.process_transactions_with_status(transactions))
+                    }
+                }),
+        )
+        .await;
 
         let batch_millis = (chrono::Utc::now().naive_utc() - batch_start).num_milliseconds();
 
@@ -280,6 +339,53 @@ impl Tailer {
             .unwrap();
         res.pop().unwrap().map(|g| g.version)
     }
+
+    /// Gets the version a processor resumes from: the configured starting version if set, otherwise
+    /// the first gap in the versions it processed successfully.
+    pub fn get_processor_start_version(
+        &self,
+        processor_name: &String,
+        starting_version: Option<u64>,
+        lookback_versions: i64,
+    ) -> u64 {
+        info!(
+            processor_name = processor_name,
+            lookback_versions = lookback_versions,
+            "Fetching starting version from db..."
+        );
+        // For now this is not being used but we'd want to track it anyway
+        let starting_version_from_db_short = self
+            .get_start_version(processor_name)
+            .unwrap_or_else(|e| panic!("Failed to get starting version: {:?}", e))
+            .unwrap_or_else(|| {
+                info!(
+                    processor_name = processor_name,
+                    "No starting version from db so starting from version 0"
+                );
+                0
+            }) as u64;
+        let start_version = match starting_version {
+            None => self
+                .get_start_version_long(processor_name, lookback_versions)
+                .unwrap_or_else(|| {
+                    info!(
+                        processor_name = processor_name,
+                        "Could not fetch version from db so starting from version 0"
+                    );
+                    0
+                }) as u64,
+            Some(version) => version,
+        };
+
+        info!(
+            processor_name = processor_name,
+            final_start_version = start_version,
+            start_version_from_config = starting_version,
+            starting_version_from_db_short = starting_version_from_db_short,
+            "Setting starting version..."
+        );
+        start_version
+    }
 }
 
 pub async fn await_tasks<T: Debug>(tasks: Vec<JoinHandle<T>>) -> Vec<T> {
@@ -375,7 +481,7 @@ mod test {
         let mut tailer = Tailer::new(
             context,
             conn_pool.clone(),
-            Arc::new(pg_transaction_processor),
+            vec![Arc::new(pg_transaction_processor)],
             TransactionFetcherOptions::default(),
         )?;
         tailer.transaction_fetcher = Arc::new(Mutex::new(FakeFetcher::new(None)));
@@ -593,8 +699,7 @@ mod test {
             }
         )).unwrap();
 
-        tailer
-            .processor
+        tailer.processors()[0]
             .process_transactions_with_status(vec![genesis_txn.clone()])
             .await
             .unwrap();
@@ -681,8 +786,7 @@ mod test {
             bmt.info.epoch = Some(aptos_api_types::U64::from(1));
         }
 
-        tailer
-            .processor
+        tailer.processors()[0]
             .process_transactions_with_status(vec![block_metadata_transaction.clone()])
             .await
             .unwrap();
@@ -805,13 +909,11 @@ mod test {
         )).unwrap();
 
         // We run it twice to ensure we don't explode. Idempotency!
-        tailer
-            .processor
+        tailer.processors()[0]
             .process_transactions_with_status(vec![user_txn.clone()])
             .await
             .unwrap();
-        tailer
-            .processor
+        tailer.processors()[0]
             .process_transactions_with_status(vec![user_txn.clone()])
             .await
             .unwrap();
@@ -894,8 +996,7 @@ mod test {
         )).unwrap();
 
         let txns = vec![message_txn];
-        tailer
-            .processor
+        tailer.processors()[0]
             .process_transactions_with_status(txns)
             .await
             .unwrap();
@@ -0,0 +1,137 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::util::{parse_timestamp, standardize_address};
+use anyhow::{Context, Result};
+use aptos_api_types::{deserialize_from_string, Transaction as APITransaction};
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/**
+ * This file defines deserialized governance events as defined in our 0x1 contracts.
+ */
+
+/// Governance proposals are held by the voting forum hosted at 0x1. The `voting` module can
+/// host other forums, whose events aren't governance events.
+const GOVERNANCE_FORUM_ADDRESS: &str =
+    "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateProposalEvent {
+    pub proposer: String,
+    pub stake_pool: String,
+    #[serde(deserialize_with = "deserialize_from_string")]
+    pub proposal_id: i64,
+    pub execution_hash: String,
+    pub proposal_metadata: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VotingCreateProposalEvent {
+    #[serde(deserialize_with = "deserialize_from_string")]
+    pub proposal_id: i64,
+    pub early_resolution_vote_threshold: OptionalU128,
+    #[serde(deserialize_with = "deserialize_from_string")]
+    pub expiration_secs: u64,
+    #[serde(deserialize_with = "deserialize_from_string")]
+    pub min_vote_threshold: BigDecimal,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OptionalU128 {
+    pub vec: Vec<String>,
+}
+
+impl OptionalU128 {
+    pub fn get_value(&self) -> Result<Option<BigDecimal>> {
+        self.vec
+            .get(0)
+            .map(|value| BigDecimal::from_str(value).context("Failed to parse u128"))
+            .transpose()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VoteEvent {
+    #[serde(deserialize_with = "deserialize_from_string")]
+    pub proposal_id: i64,
+    pub voter: String,
+    pub stake_pool: String,
+    #[serde(deserialize_with = "deserialize_from_string")]
+    pub num_votes: BigDecimal,
+    pub should_pass: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResolveProposalEvent {
+    #[serde(deserialize_with = "deserialize_from_string")]
+    pub proposal_id: i64,
+    #[serde(deserialize_with = "deserialize_from_string")]
+    pub yes_votes: BigDecimal,
+    #[serde(deserialize_with = "deserialize_from_string")]
+    pub no_votes: BigDecimal,
+    pub resolved_early: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum GovernanceEvent {
+    CreateProposalEvent(CreateProposalEvent),
+    VotingCreateProposalEvent(VotingCreateProposalEvent),
+    VoteEvent(VoteEvent),
+    ResolveProposalEvent(ResolveProposalEvent),
+}
+
+impl GovernanceEvent {
+    pub fn from_event(
+        data_type: &str,
+        data: &serde_json::Value,
+        txn_version: i64,
+    ) -> Result<Option<GovernanceEvent>> {
+        match data_type {
+            "0x1::aptos_governance::CreateProposalEvent" => serde_json::from_value(data.clone())
+                .map(|inner| Some(GovernanceEvent::CreateProposalEvent(inner))),
+            "0x1::voting::CreateProposalEvent" => serde_json::from_value(data.clone())
+                .map(|inner| Some(GovernanceEvent::VotingCreateProposalEvent(inner))),
+            "0x1::aptos_governance::VoteEvent" => serde_json::from_value(data.clone())
+                .map(|inner| Some(GovernanceEvent::VoteEvent(inner))),
+            "0x1::voting::ResolveProposal" => serde_json::from_value(data.clone())
+                .map(|inner| Some(GovernanceEvent::ResolveProposalEvent(inner))),
+            _ => Ok(None),
+        }
+        .context(format!(
+            "version {} failed! failed to parse type {}, data {:?}",
+            txn_version, data_type, data
+        ))
+    }
+
+    /// Parses the governance events of a transaction. Returns the transaction version and
+    /// timestamp along with them.
+    pub fn from_transaction(
+        transaction: &APITransaction,
+    ) -> Result<(i64, chrono::NaiveDateTime, Vec<GovernanceEvent>)> {
+        let txn = match transaction {
+            APITransaction::UserTransaction(txn) => txn,
+            _ => return Ok((0, chrono::NaiveDateTime::from_timestamp(0, 0), vec![])),
+        };
+        let txn_version = txn.info.version.0 as i64;
+        let txn_timestamp = parse_timestamp(txn.timestamp.0, txn_version);
+
+        let mut governance_events = vec![];
+        for event in &txn.events {
+            let event_type = event.typ.to_string();
+            if event_type.starts_with("0x1::voting::")
+                && standardize_address(&event.guid.account_address.to_string())
+                    != GOVERNANCE_FORUM_ADDRESS
+            {
+                continue;
+            }
+            if let Some(governance_event) =
+                Self::from_event(event_type.as_str(), &event.data, txn_version)?
+            {
+                governance_events.push(governance_event);
+            }
+        }
+        Ok((txn_version, txn_timestamp, governance_events))
+    }
+}
@@ -0,0 +1,7 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+mod governance_utils;
+pub mod proposal_votes;
+pub mod proposals;
+pub mod staking_proxy_activities;
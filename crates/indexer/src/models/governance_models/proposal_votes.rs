@@ -0,0 +1,46 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+// This is required because a diesel macro makes clippy sad
+#![allow(clippy::extra_unused_lifetimes)]
+
+use super::governance_utils::GovernanceEvent;
+use crate::{schema::proposal_votes, util::standardize_address};
+use aptos_api_types::Transaction as APITransaction;
+use bigdecimal::BigDecimal;
+use field_count::FieldCount;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, FieldCount, Identifiable, Insertable, Serialize)]
+#[diesel(primary_key(transaction_version, proposal_id, staking_pool_address))]
+#[diesel(table_name = proposal_votes)]
+pub struct ProposalVote {
+    pub transaction_version: i64,
+    pub proposal_id: i64,
+    pub voter_address: String,
+    pub staking_pool_address: String,
+    pub num_votes: BigDecimal,
+    pub should_pass: bool,
+    pub transaction_timestamp: chrono::NaiveDateTime,
+}
+
+impl ProposalVote {
+    pub fn from_transaction(transaction: &APITransaction) -> anyhow::Result<Vec<Self>> {
+        let (txn_version, txn_timestamp, events) = GovernanceEvent::from_transaction(transaction)?;
+        Ok(events
+            .into_iter()
+            .filter_map(|event| match event {
+                GovernanceEvent::VoteEvent(inner) => Some(Self {
+                    transaction_version: txn_version,
+                    proposal_id: inner.proposal_id,
+                    voter_address: standardize_address(&inner.voter),
+                    staking_pool_address: standardize_address(&inner.stake_pool),
+                    num_votes: inner.num_votes,
+                    should_pass: inner.should_pass,
+                    transaction_timestamp: txn_timestamp,
+                }),
+                _ => None,
+            })
+            .collect())
+    }
+}
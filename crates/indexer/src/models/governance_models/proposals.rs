@@ -0,0 +1,108 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+// This is required because a diesel macro makes clippy sad
+#![allow(clippy::extra_unused_lifetimes)]
+
+use super::governance_utils::GovernanceEvent;
+use crate::{
+    schema::{proposal_resolutions, proposals},
+    util::{parse_timestamp_secs, standardize_address},
+};
+use aptos_api_types::Transaction as APITransaction;
+use bigdecimal::BigDecimal;
+use field_count::FieldCount;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, FieldCount, Identifiable, Insertable, Serialize)]
+#[diesel(primary_key(proposal_id))]
+#[diesel(table_name = proposals)]
+pub struct Proposal {
+    pub proposal_id: i64,
+    pub proposer_address: String,
+    pub staking_pool_address: String,
+    pub execution_hash: String,
+    pub metadata: serde_json::Value,
+    pub min_vote_threshold: BigDecimal,
+    pub early_resolution_vote_threshold: Option<BigDecimal>,
+    pub expiration_timestamp: chrono::NaiveDateTime,
+    pub transaction_version: i64,
+    pub transaction_timestamp: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize, FieldCount, Identifiable, Insertable, Serialize)]
+#[diesel(primary_key(proposal_id))]
+#[diesel(table_name = proposal_resolutions)]
+pub struct ProposalResolution {
+    pub proposal_id: i64,
+    pub yes_votes: BigDecimal,
+    pub no_votes: BigDecimal,
+    pub resolved_early: bool,
+    pub transaction_version: i64,
+    pub transaction_timestamp: chrono::NaiveDateTime,
+}
+
+impl Proposal {
+    /// A proposal is created by aptos_governance, which emits an event with the proposer, and by
+    /// its voting forum, which emits an event with the voting thresholds, in the same transaction.
+    pub fn from_transaction(
+        transaction: &APITransaction,
+    ) -> anyhow::Result<(Vec<Self>, Vec<ProposalResolution>)> {
+        let (txn_version, txn_timestamp, events) = GovernanceEvent::from_transaction(transaction)?;
+
+        let mut governance_proposals = HashMap::new();
+        let mut voting_proposals = HashMap::new();
+        let mut proposal_resolutions = vec![];
+        for event in events {
+            match event {
+                GovernanceEvent::CreateProposalEvent(inner) => {
+                    governance_proposals.insert(inner.proposal_id, inner);
+                }
+                GovernanceEvent::VotingCreateProposalEvent(inner) => {
+                    voting_proposals.insert(inner.proposal_id, inner);
+                }
+                GovernanceEvent::ResolveProposalEvent(inner) => {
+                    proposal_resolutions.push(ProposalResolution {
+                        proposal_id: inner.proposal_id,
+                        yes_votes: inner.yes_votes,
+                        no_votes: inner.no_votes,
+                        resolved_early: inner.resolved_early,
+                        transaction_version: txn_version,
+                        transaction_timestamp: txn_timestamp,
+                    });
+                }
+                GovernanceEvent::VoteEvent(_) => {}
+            }
+        }
+
+        let mut proposals = vec![];
+        for (proposal_id, governance_proposal) in governance_proposals {
+            let voting_proposal = voting_proposals.remove(&proposal_id).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "version {} failed! proposal {} wasn't created by the governance forum",
+                    txn_version,
+                    proposal_id
+                )
+            })?;
+            proposals.push(Self {
+                proposal_id,
+                proposer_address: standardize_address(&governance_proposal.proposer),
+                staking_pool_address: standardize_address(&governance_proposal.stake_pool),
+                execution_hash: governance_proposal.execution_hash,
+                metadata: governance_proposal.proposal_metadata,
+                min_vote_threshold: voting_proposal.min_vote_threshold,
+                early_resolution_vote_threshold: voting_proposal
+                    .early_resolution_vote_threshold
+                    .get_value()?,
+                expiration_timestamp: parse_timestamp_secs(
+                    voting_proposal.expiration_secs,
+                    txn_version,
+                ),
+                transaction_version: txn_version,
+                transaction_timestamp: txn_timestamp,
+            });
+        }
+        Ok((proposals, proposal_resolutions))
+    }
+}
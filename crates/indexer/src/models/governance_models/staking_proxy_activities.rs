@@ -0,0 +1,92 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+// This is required because a diesel macro makes clippy sad
+#![allow(clippy::extra_unused_lifetimes)]
+
+use crate::{
+    schema::staking_proxy_activities,
+    util::{parse_timestamp, standardize_address},
+};
+use aptos_api_types::{Transaction as APITransaction, TransactionPayload};
+use field_count::FieldCount;
+use serde::{Deserialize, Serialize};
+
+const STAKING_PROXY_MODULE: &str = "0x1::staking_proxy";
+
+#[derive(Debug, Deserialize, FieldCount, Identifiable, Insertable, Serialize)]
+#[diesel(primary_key(transaction_version))]
+#[diesel(table_name = staking_proxy_activities)]
+pub struct StakingProxyActivity {
+    pub transaction_version: i64,
+    pub owner_address: String,
+    pub entry_function_name: String,
+    pub operator_address: Option<String>,
+    pub new_operator_address: Option<String>,
+    pub new_voter_address: Option<String>,
+    pub transaction_timestamp: chrono::NaiveDateTime,
+}
+
+impl StakingProxyActivity {
+    /// The delegations are only tracked through the successful calls to the staking_proxy entry
+    /// functions, as stake pools don't emit events when their voter changes.
+    pub fn from_transaction(transaction: &APITransaction) -> anyhow::Result<Option<Self>> {
+        let txn = match transaction {
+            APITransaction::UserTransaction(txn) if txn.info.success => txn,
+            _ => return Ok(None),
+        };
+        let payload = match &txn.request.payload {
+            TransactionPayload::EntryFunctionPayload(payload)
+                if payload.function.module.to_string() == STAKING_PROXY_MODULE =>
+            {
+                payload
+            }
+            _ => return Ok(None),
+        };
+        let txn_version = txn.info.version.0 as i64;
+        let entry_function_name = payload.function.name.to_string();
+        let arguments = payload
+            .arguments
+            .iter()
+            .map(|argument| {
+                argument.as_str().map(standardize_address).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "version {} failed! staking_proxy argument {} isn't an address",
+                        txn_version,
+                        argument
+                    )
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        // (operator, new operator, new voter), from the arguments following the owner
+        let (operator_address, new_operator_address, new_voter_address) =
+            match (entry_function_name.as_str(), arguments.as_slice()) {
+                (
+                    "set_operator"
+                    | "set_vesting_contract_operator"
+                    | "set_staking_contract_operator",
+                    [old_operator, new_operator],
+                ) => (Some(old_operator.clone()), Some(new_operator.clone()), None),
+                ("set_stake_pool_operator", [new_operator]) => {
+                    (None, Some(new_operator.clone()), None)
+                }
+                (
+                    "set_voter" | "set_vesting_contract_voter" | "set_staking_contract_voter",
+                    [operator, new_voter],
+                ) => (Some(operator.clone()), None, Some(new_voter.clone())),
+                ("set_stake_pool_voter", [new_voter]) => (None, None, Some(new_voter.clone())),
+                _ => return Ok(None),
+            };
+
+        Ok(Some(Self {
+            transaction_version: txn_version,
+            owner_address: standardize_address(&txn.request.sender.to_string()),
+            entry_function_name,
+            operator_address,
+            new_operator_address,
+            new_voter_address,
+            transaction_timestamp: parse_timestamp(txn.timestamp.0, txn_version),
+        }))
+    }
+}
@@ -4,6 +4,7 @@
 pub mod block_metadata_transactions;
 pub mod coin_models;
 pub mod events;
+pub mod governance_models;
 pub mod ledger_info;
 pub mod move_modules;
 pub mod move_resources;
@@ -5,7 +5,7 @@
 use crate::{models::transactions::Transaction, schema::signatures, util::standardize_address};
 use anyhow::{Context, Result};
 use aptos_api_types::{
-    AccountSignature as APIAccountSignature, Ed25519Signature as APIEd25519Signature,
+    AccountSignature as APIAccountSignature, HexEncodedBytes,
     MultiAgentSignature as APIMultiAgentSignature,
     MultiEd25519Signature as APIMultiEd25519Signature,
     TransactionSignature as APITransactionSignature,
@@ -50,7 +50,9 @@ impl Signature {
         match s {
             APITransactionSignature::Ed25519Signature(sig) => {
                 Ok(vec![Self::parse_single_signature(
-                    sig,
+                    "ed25519_signature",
+                    &sig.public_key,
+                    &sig.signature,
                     sender,
                     transaction_version,
                     transaction_block_height,
@@ -74,6 +76,45 @@ impl Signature {
                 transaction_version,
                 transaction_block_height,
             ),
+            APITransactionSignature::Secp256r1EcdsaSignature(sig) => {
+                Ok(vec![Self::parse_single_signature(
+                    "secp256r1_ecdsa_signature",
+                    &sig.public_key,
+                    &sig.signature,
+                    sender,
+                    transaction_version,
+                    transaction_block_height,
+                    true,
+                    0,
+                    None,
+                )])
+            }
+            APITransactionSignature::WebAuthnSignature(sig) => {
+                Ok(vec![Self::parse_single_signature(
+                    "web_authn_signature",
+                    &sig.public_key,
+                    &sig.signature,
+                    sender,
+                    transaction_version,
+                    transaction_block_height,
+                    true,
+                    0,
+                    None,
+                )])
+            }
+            APITransactionSignature::Secp256k1EcdsaSignature(sig) => {
+                Ok(vec![Self::parse_single_signature(
+                    "secp256k1_ecdsa_signature",
+                    &sig.public_key,
+                    &sig.signature,
+                    sender,
+                    transaction_version,
+                    transaction_block_height,
+                    true,
+                    0,
+                    None,
+                )])
+            }
         }
     }
 
@@ -86,11 +127,21 @@ impl Signature {
             APITransactionSignature::MultiAgentSignature(_) => {
                 String::from("multi_agent_signature")
             }
+            APITransactionSignature::Secp256r1EcdsaSignature(_) => {
+                String::from("secp256r1_ecdsa_signature")
+            }
+            APITransactionSignature::WebAuthnSignature(_) => String::from("web_authn_signature"),
+            APITransactionSignature::Secp256k1EcdsaSignature(_) => {
+                String::from("secp256k1_ecdsa_signature")
+            }
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn parse_single_signature(
-        s: &APIEd25519Signature,
+        type_: &str,
+        public_key: &HexEncodedBytes,
+        signature: &HexEncodedBytes,
         sender: &String,
         transaction_version: i64,
         transaction_block_height: i64,
@@ -104,11 +155,11 @@ impl Signature {
             transaction_block_height,
             signer,
             is_sender_primary,
-            type_: String::from("ed25519_signature"),
-            public_key: s.public_key.to_string(),
+            type_: String::from(type_),
+            public_key: public_key.to_string(),
             threshold: 1,
             public_key_indices: serde_json::Value::Array(vec![]),
-            signature: s.signature.to_string(),
+            signature: signature.to_string(),
             multi_agent_index,
             multi_sig_index: 0,
         }
@@ -203,7 +254,9 @@ impl Signature {
     ) -> Vec<Self> {
         match s {
             APIAccountSignature::Ed25519Signature(sig) => vec![Self::parse_single_signature(
-                sig,
+                "ed25519_signature",
+                &sig.public_key,
+                &sig.signature,
                 sender,
                 transaction_version,
                 transaction_block_height,
@@ -220,6 +273,43 @@ impl Signature {
                 multi_agent_index,
                 override_address,
             ),
+            APIAccountSignature::Secp256r1EcdsaSignature(sig) => {
+                vec![Self::parse_single_signature(
+                    "secp256r1_ecdsa_signature",
+                    &sig.public_key,
+                    &sig.signature,
+                    sender,
+                    transaction_version,
+                    transaction_block_height,
+                    is_sender_primary,
+                    multi_agent_index,
+                    override_address,
+                )]
+            }
+            APIAccountSignature::WebAuthnSignature(sig) => vec![Self::parse_single_signature(
+                "web_authn_signature",
+                &sig.public_key,
+                &sig.signature,
+                sender,
+                transaction_version,
+                transaction_block_height,
+                is_sender_primary,
+                multi_agent_index,
+                override_address,
+            )],
+            APIAccountSignature::Secp256k1EcdsaSignature(sig) => {
+                vec![Self::parse_single_signature(
+                    "secp256k1_ecdsa_signature",
+                    &sig.public_key,
+                    &sig.signature,
+                    sender,
+                    transaction_version,
+                    transaction_block_height,
+                    is_sender_primary,
+                    multi_agent_index,
+                    override_address,
+                )]
+            }
         }
     }
 }
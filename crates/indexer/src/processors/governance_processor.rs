@@ -0,0 +1,242 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    database::{
+        clean_data_for_db, execute_with_better_error, get_chunks, PgDbPool, PgPoolConnection,
+    },
+    indexer::{
+        errors::TransactionProcessingError, processing_result::ProcessingResult,
+        transaction_processor::TransactionProcessor,
+    },
+    models::governance_models::{
+        proposal_votes::ProposalVote,
+        proposals::{Proposal, ProposalResolution},
+        staking_proxy_activities::StakingProxyActivity,
+    },
+    schema,
+};
+use aptos_api_types::Transaction as APITransaction;
+use async_trait::async_trait;
+use diesel::{result::Error, PgConnection};
+use field_count::FieldCount;
+use std::fmt::Debug;
+
+pub const NAME: &str = "governance_processor";
+pub struct GovernanceTransactionProcessor {
+    connection_pool: PgDbPool,
+}
+
+impl GovernanceTransactionProcessor {
+    pub fn new(connection_pool: PgDbPool) -> Self {
+        Self { connection_pool }
+    }
+}
+
+impl Debug for GovernanceTransactionProcessor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = &self.connection_pool.state();
+        write!(
+            f,
+            "GovernanceTransactionProcessor {{ connections: {:?}  idle_connections: {:?} }}",
+            state.connections, state.idle_connections
+        )
+    }
+}
+
+fn insert_to_db_impl(
+    conn: &mut PgConnection,
+    proposals: &[Proposal],
+    proposal_votes: &[ProposalVote],
+    proposal_resolutions: &[ProposalResolution],
+    staking_proxy_activities: &[StakingProxyActivity],
+) -> Result<(), diesel::result::Error> {
+    insert_proposals(conn, proposals)?;
+    insert_proposal_votes(conn, proposal_votes)?;
+    insert_proposal_resolutions(conn, proposal_resolutions)?;
+    insert_staking_proxy_activities(conn, staking_proxy_activities)?;
+    Ok(())
+}
+
+fn insert_to_db(
+    conn: &mut PgPoolConnection,
+    name: &'static str,
+    start_version: u64,
+    end_version: u64,
+    proposals: Vec<Proposal>,
+    proposal_votes: Vec<ProposalVote>,
+    proposal_resolutions: Vec<ProposalResolution>,
+    staking_proxy_activities: Vec<StakingProxyActivity>,
+) -> Result<(), diesel::result::Error> {
+    aptos_logger::trace!(
+        name = name,
+        start_version = start_version,
+        end_version = end_version,
+        "Inserting to db",
+    );
+    match conn
+        .build_transaction()
+        .read_write()
+        .run::<_, Error, _>(|pg_conn| {
+            insert_to_db_impl(
+                pg_conn,
+                &proposals,
+                &proposal_votes,
+                &proposal_resolutions,
+                &staking_proxy_activities,
+            )
+        }) {
+        Ok(_) => Ok(()),
+        Err(_) => conn
+            .build_transaction()
+            .read_write()
+            .run::<_, Error, _>(|pg_conn| {
+                let proposals = clean_data_for_db(proposals, true);
+
+                insert_to_db_impl(
+                    pg_conn,
+                    &proposals,
+                    &proposal_votes,
+                    &proposal_resolutions,
+                    &staking_proxy_activities,
+                )
+            }),
+    }
+}
+
+fn insert_proposals(
+    conn: &mut PgConnection,
+    item_to_insert: &[Proposal],
+) -> Result<(), diesel::result::Error> {
+    use schema::proposals::dsl::*;
+
+    let chunks = get_chunks(item_to_insert.len(), Proposal::field_count());
+    for (start_ind, end_ind) in chunks {
+        execute_with_better_error(
+            conn,
+            diesel::insert_into(schema::proposals::table)
+                .values(&item_to_insert[start_ind..end_ind])
+                .on_conflict(proposal_id)
+                .do_nothing(),
+            None,
+        )?;
+    }
+    Ok(())
+}
+
+fn insert_proposal_votes(
+    conn: &mut PgConnection,
+    item_to_insert: &[ProposalVote],
+) -> Result<(), diesel::result::Error> {
+    use schema::proposal_votes::dsl::*;
+
+    let chunks = get_chunks(item_to_insert.len(), ProposalVote::field_count());
+    for (start_ind, end_ind) in chunks {
+        execute_with_better_error(
+            conn,
+            diesel::insert_into(schema::proposal_votes::table)
+                .values(&item_to_insert[start_ind..end_ind])
+                .on_conflict((transaction_version, proposal_id, staking_pool_address))
+                .do_nothing(),
+            None,
+        )?;
+    }
+    Ok(())
+}
+
+fn insert_proposal_resolutions(
+    conn: &mut PgConnection,
+    item_to_insert: &[ProposalResolution],
+) -> Result<(), diesel::result::Error> {
+    use schema::proposal_resolutions::dsl::*;
+
+    let chunks = get_chunks(item_to_insert.len(), ProposalResolution::field_count());
+    for (start_ind, end_ind) in chunks {
+        execute_with_better_error(
+            conn,
+            diesel::insert_into(schema::proposal_resolutions::table)
+                .values(&item_to_insert[start_ind..end_ind])
+                .on_conflict(proposal_id)
+                .do_nothing(),
+            None,
+        )?;
+    }
+    Ok(())
+}
+
+fn insert_staking_proxy_activities(
+    conn: &mut PgConnection,
+    item_to_insert: &[StakingProxyActivity],
+) -> Result<(), diesel::result::Error> {
+    use schema::staking_proxy_activities::dsl::*;
+
+    let chunks = get_chunks(item_to_insert.len(), StakingProxyActivity::field_count());
+    for (start_ind, end_ind) in chunks {
+        execute_with_better_error(
+            conn,
+            diesel::insert_into(schema::staking_proxy_activities::table)
+                .values(&item_to_insert[start_ind..end_ind])
+                .on_conflict(transaction_version)
+                .do_nothing(),
+            None,
+        )?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl TransactionProcessor for GovernanceTransactionProcessor {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    async fn process_transactions(
+        &self,
+        transactions: Vec<APITransaction>,
+        start_version: u64,
+        end_version: u64,
+    ) -> Result<ProcessingResult, TransactionProcessingError> {
+        let mut all_proposals = vec![];
+        let mut all_proposal_votes = vec![];
+        let mut all_proposal_resolutions = vec![];
+        let mut all_staking_proxy_activities = vec![];
+
+        for txn in &transactions {
+            let (proposals, proposal_resolutions) = Proposal::from_transaction(txn).unwrap();
+            all_proposals.extend(proposals);
+            all_proposal_resolutions.extend(proposal_resolutions);
+            all_proposal_votes.extend(ProposalVote::from_transaction(txn).unwrap());
+            all_staking_proxy_activities
+                .extend(StakingProxyActivity::from_transaction(txn).unwrap());
+        }
+
+        let mut conn = self.get_conn();
+        let tx_result = insert_to_db(
+            &mut conn,
+            self.name(),
+            start_version,
+            end_version,
+            all_proposals,
+            all_proposal_votes,
+            all_proposal_resolutions,
+            all_staking_proxy_activities,
+        );
+        match tx_result {
+            Ok(_) => Ok(ProcessingResult::new(
+                self.name(),
+                start_version,
+                end_version,
+            )),
+            Err(err) => Err(TransactionProcessingError::TransactionCommitError((
+                anyhow::Error::from(err),
+                start_version,
+                end_version,
+                self.name(),
+            ))),
+        }
+    }
+
+    fn connection_pool(&self) -> &PgDbPool {
+        &self.connection_pool
+    }
+}
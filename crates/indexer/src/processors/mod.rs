@@ -3,29 +3,140 @@
 
 pub mod coin_processor;
 pub mod default_processor;
+pub mod governance_processor;
 pub mod stake_processor;
 pub mod token_processor;
 
-use self::coin_processor::NAME as COIN_PROCESSOR_NAME;
-use self::default_processor::NAME as DEFAULT_PROCESSOR_NAME;
-use self::stake_processor::NAME as STAKE_PROCESSOR_NAME;
-use self::token_processor::NAME as TOKEN_PROCESSOR_NAME;
+use self::coin_processor::{CoinTransactionProcessor, NAME as COIN_PROCESSOR_NAME};
+use self::default_processor::{DefaultTransactionProcessor, NAME as DEFAULT_PROCESSOR_NAME};
+use self::governance_processor::{
+    GovernanceTransactionProcessor, NAME as GOVERNANCE_PROCESSOR_NAME,
+};
+use self::stake_processor::{StakeTransactionProcessor, NAME as STAKE_PROCESSOR_NAME};
+use self::token_processor::{TokenTransactionProcessor, NAME as TOKEN_PROCESSOR_NAME};
+use crate::{database::PgDbPool, indexer::transaction_processor::TransactionProcessor};
+use anyhow::{bail, Result};
+use aptos_config::config::IndexerConfig;
+use diesel_migrations::EmbeddedMigrations;
+use once_cell::sync::Lazy;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
-pub enum Processor {
-    CoinProcessor,
-    DefaultProcessor,
-    TokenProcessor,
-    StakeProcessor,
+/// Creates a processor writing to the given connection pool
+pub type ProcessorFactory = fn(PgDbPool, &IndexerConfig) -> Arc<dyn TransactionProcessor>;
+
+/// A processor which can be run by name, through `IndexerConfig::processors`
+#[derive(Clone, Copy)]
+pub struct ProcessorRegistration {
+    pub factory: ProcessorFactory,
+    /// Migrations creating the tables the processor writes to. They're run on startup after the
+    /// built-in migrations, so their names must not collide with the built-in ones.
+    pub migrations: Option<EmbeddedMigrations>,
+}
+
+static PROCESSOR_REGISTRY: Lazy<RwLock<HashMap<String, ProcessorRegistration>>> = Lazy::new(|| {
+    let builtin_processors: [(&str, ProcessorFactory); 5] = [
+        (DEFAULT_PROCESSOR_NAME, |conn_pool, _| {
+            Arc::new(DefaultTransactionProcessor::new(conn_pool))
+        }),
+        (TOKEN_PROCESSOR_NAME, |conn_pool, config| {
+            Arc::new(TokenTransactionProcessor::new(
+                conn_pool,
+                config.ans_contract_address.clone(),
+            ))
+        }),
+        (COIN_PROCESSOR_NAME, |conn_pool, _| {
+            Arc::new(CoinTransactionProcessor::new(conn_pool))
+        }),
+        (STAKE_PROCESSOR_NAME, |conn_pool, _| {
+            Arc::new(StakeTransactionProcessor::new(conn_pool))
+        }),
+        (GOVERNANCE_PROCESSOR_NAME, |conn_pool, _| {
+            Arc::new(GovernanceTransactionProcessor::new(conn_pool))
+        }),
+    ];
+    // The tables of the built-in processors are created by the crate's own migrations
+    RwLock::new(
+        builtin_processors
+            .into_iter()
+            .map(|(name, factory)| {
+                (
+                    name.to_string(),
+                    ProcessorRegistration {
+                        factory,
+                        migrations: None,
+                    },
+                )
+            })
+            .collect(),
+    )
+});
+
+/// Registers a processor under `name`, so that it can be run alongside the built-in ones. This
+/// must be done before the indexer is bootstrapped. The processor's `name()` must be `name`.
+pub fn register_processor(
+    name: &str,
+    factory: ProcessorFactory,
+    migrations: Option<EmbeddedMigrations>,
+) -> Result<()> {
+    let mut registry = PROCESSOR_REGISTRY.write().unwrap();
+    if registry.contains_key(name) {
+        bail!("Processor {} is already registered", name);
+    }
+    registry.insert(
+        name.to_string(),
+        ProcessorRegistration {
+            factory,
+            migrations,
+        },
+    );
+    Ok(())
+}
+
+pub fn get_processor_registration(name: &str) -> Option<ProcessorRegistration> {
+    PROCESSOR_REGISTRY.read().unwrap().get(name).copied()
+}
+
+pub fn registered_processor_names() -> Vec<String> {
+    let mut names: Vec<_> = PROCESSOR_REGISTRY.read().unwrap().keys().cloned().collect();
+    names.sort();
+    names
 }
 
-impl Processor {
-    pub fn from_string(input_str: &String) -> Self {
-        match input_str.as_str() {
-            DEFAULT_PROCESSOR_NAME => Self::DefaultProcessor,
-            TOKEN_PROCESSOR_NAME => Self::TokenProcessor,
-            COIN_PROCESSOR_NAME => Self::CoinProcessor,
-            STAKE_PROCESSOR_NAME => Self::StakeProcessor,
-            _ => panic!("Processor unsupported {}", input_str),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_processor() {
+        for name in [
+            DEFAULT_PROCESSOR_NAME,
+            TOKEN_PROCESSOR_NAME,
+            COIN_PROCESSOR_NAME,
+            STAKE_PROCESSOR_NAME,
+            GOVERNANCE_PROCESSOR_NAME,
+        ] {
+            assert!(get_processor_registration(name).is_some());
         }
+        assert!(get_processor_registration("dapp_processor").is_none());
+
+        register_processor(
+            "dapp_processor",
+            |conn_pool, _| Arc::new(DefaultTransactionProcessor::new(conn_pool)),
+            None,
+        )
+        .unwrap();
+        assert!(get_processor_registration("dapp_processor").is_some());
+        assert!(registered_processor_names().contains(&"dapp_processor".to_string()));
+
+        // Names can't be registered twice
+        assert!(register_processor(
+            COIN_PROCESSOR_NAME,
+            |conn_pool, _| Arc::new(CoinTransactionProcessor::new(conn_pool)),
+            None,
+        )
+        .is_err());
     }
 }
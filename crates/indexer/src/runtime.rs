@@ -7,11 +7,7 @@ use crate::{
         fetcher::TransactionFetcherOptions, tailer::Tailer,
         transaction_processor::TransactionProcessor,
     },
-    processors::{
-        coin_processor::CoinTransactionProcessor, default_processor::DefaultTransactionProcessor,
-        stake_processor::StakeTransactionProcessor, token_processor::TokenTransactionProcessor,
-        Processor,
-    },
+    processors::{get_processor_registration, registered_processor_names},
 };
 
 use aptos_api::context::Context;
@@ -106,7 +102,7 @@ pub fn bootstrap(
 
 pub async fn run_forever(config: IndexerConfig, context: Arc<Context>) {
     // All of these options should be filled already with defaults
-    let processor_name = config.processor.clone().unwrap();
+    let processor_names = config.processors.clone().unwrap();
     let check_chain_id = config.check_chain_id.unwrap();
     let skip_migrations = config.skip_migrations.unwrap();
     let fetch_tasks = config.fetch_tasks.unwrap();
@@ -115,9 +111,10 @@ pub async fn run_forever(config: IndexerConfig, context: Arc<Context>) {
     let batch_size = config.batch_size.unwrap();
     let lookback_versions = config.gap_lookback_versions.unwrap() as i64;
 
+    let processor_name = processor_names.join(",");
     info!(processor_name = processor_name, "Starting indexer...");
 
-    let db_uri = &config.postgres_uri.unwrap();
+    let db_uri = &config.postgres_uri.clone().unwrap();
     info!(
         processor_name = processor_name,
         "Creating connection pool..."
@@ -130,67 +127,57 @@ pub async fn run_forever(config: IndexerConfig, context: Arc<Context>) {
 
     info!(processor_name = processor_name, "Instantiating tailer... ");
 
-    let processor_enum = Processor::from_string(&processor_name);
-    let processor: Arc<dyn TransactionProcessor> = match processor_enum {
-        Processor::DefaultProcessor => {
-            Arc::new(DefaultTransactionProcessor::new(conn_pool.clone()))
+    let mut processors: Vec<Arc<dyn TransactionProcessor>> = vec![];
+    let mut processor_migrations = vec![];
+    for name in &processor_names {
+        let registration = get_processor_registration(name).unwrap_or_else(|| {
+            panic!(
+                "Processor unsupported {}, registered processors: {:?}",
+                name,
+                registered_processor_names()
+            )
+        });
+        let processor = (registration.factory)(conn_pool.clone(), &config);
+        assert_eq!(
+            processor.name(),
+            name.as_str(),
+            "Processor registered as {} is named {}",
+            name,
+            processor.name()
+        );
+        if processors.iter().any(|p| p.name() == processor.name()) {
+            panic!("Processor {} is configured more than once", name);
         }
-        Processor::TokenProcessor => Arc::new(TokenTransactionProcessor::new(
-            conn_pool.clone(),
-            config.ans_contract_address,
-        )),
-        Processor::CoinProcessor => Arc::new(CoinTransactionProcessor::new(conn_pool.clone())),
-        Processor::StakeProcessor => Arc::new(StakeTransactionProcessor::new(conn_pool.clone())),
-    };
+        processors.push(processor);
+        processor_migrations.extend(registration.migrations);
+    }
 
     let options =
         TransactionFetcherOptions::new(None, None, Some(batch_size), None, fetch_tasks as usize);
 
-    let tailer = Tailer::new(context, conn_pool.clone(), processor, options)
+    let mut tailer = Tailer::new(context, conn_pool.clone(), processors, options)
         .expect("Failed to instantiate tailer");
 
     if !skip_migrations {
         info!(processor_name = processor_name, "Running migrations...");
         tailer.run_migrations();
+        for migrations in processor_migrations {
+            tailer.run_processor_migrations(migrations);
+        }
     }
 
-    info!(
-        processor_name = processor_name,
-        lookback_versions = lookback_versions,
-        "Fetching starting version from db..."
-    );
-    // For now this is not being used but we'd want to track it anyway
-    let starting_version_from_db_short = tailer
-        .get_start_version(&processor_name)
-        .unwrap_or_else(|e| panic!("Failed to get starting version: {:?}", e))
-        .unwrap_or_else(|| {
-            info!(
-                processor_name = processor_name,
-                "No starting version from db so starting from version 0"
-            );
-            0
-        }) as u64;
-    let start_version = match config.starting_version {
-        None => tailer
-            .get_start_version_long(&processor_name, lookback_versions)
-            .unwrap_or_else(|| {
-                info!(
-                    processor_name = processor_name,
-                    "Could not fetch version from db so starting from version 0"
-                );
-                0
-            }) as u64,
-        Some(version) => version,
-    };
-
-    info!(
-        processor_name = processor_name,
-        final_start_version = start_version,
-        start_version_from_config = config.starting_version,
-        starting_version_from_db_short = starting_version_from_db_short,
-        "Setting starting version..."
-    );
-    tailer.set_fetcher_version(start_version as u64).await;
+    // Each processor resumes from its own progress, the fetcher starts from the earliest one
+    let mut processor_start_versions = vec![];
+    for name in &processor_names {
+        processor_start_versions.push(tailer.get_processor_start_version(
+            name,
+            config.starting_version,
+            lookback_versions,
+        ));
+    }
+    let start_version = *processor_start_versions.iter().min().unwrap();
+    tailer.set_processor_start_versions(processor_start_versions);
+    tailer.set_fetcher_version(start_version).await;
 
     info!(processor_name = processor_name, "Starting fetcher...");
     tailer.transaction_fetcher.lock().await.start().await;
@@ -229,40 +216,50 @@ pub async fn run_forever(config: IndexerConfig, context: Arc<Context>) {
     let mut ma = MovingAverage::new(10_000);
 
     loop {
-        let (num_res, result) = receiver
+        let (num_res, results) = receiver
             .recv()
             .await
             .expect("Failed to receive batch results: got None!");
 
-        let processing_result = match result {
-            Ok(res) => res,
-            Err(tpe) => {
-                let (err, start_version, end_version, _) = tpe.inner();
-                error!(
-                    processor_name = processor_name,
-                    start_version = start_version,
-                    end_version = end_version,
-                    error =? err,
-                    "Error processing batch!"
-                );
-                panic!(
-                    "Error in '{}' while processing batch: {:?}",
-                    processor_name, err
-                );
-            }
-        };
-
-        tailer
-            .update_last_processed_version(&processor_name, processing_result.end_version)
-            .unwrap_or_else(|e| {
-                error!(
-                    processor_name = processor_name,
-                    end_version = processing_result.end_version,
-                    error = format!("{:?}", e),
-                    "Failed to update last processed version!"
-                );
-                panic!("Failed to update last processed version: {:?}", e);
-            });
+        let mut batch_versions = None;
+        for result in results {
+            let processing_result = match result {
+                Ok(res) => res,
+                Err(tpe) => {
+                    let (err, start_version, end_version, processor_name) = tpe.inner();
+                    error!(
+                        processor_name = processor_name,
+                        start_version = start_version,
+                        end_version = end_version,
+                        error =? err,
+                        "Error processing batch!"
+                    );
+                    panic!(
+                        "Error in '{}' while processing batch: {:?}",
+                        processor_name, err
+                    );
+                }
+            };
+
+            tailer
+                .update_last_processed_version(
+                    processing_result.name,
+                    processing_result.end_version,
+                )
+                .unwrap_or_else(|e| {
+                    error!(
+                        processor_name = processing_result.name,
+                        end_version = processing_result.end_version,
+                        error = format!("{:?}", e),
+                        "Failed to update last processed version!"
+                    );
+                    panic!("Failed to update last processed version: {:?}", e);
+                });
+            batch_versions = Some((
+                processing_result.start_version,
+                processing_result.end_version,
+            ));
+        }
 
         ma.tick_now(num_res);
 
@@ -273,8 +270,8 @@ pub async fn run_forever(config: IndexerConfig, context: Arc<Context>) {
                 base = new_base;
                 info!(
                     processor_name = processor_name,
-                    batch_start_version = processing_result.start_version,
-                    batch_end_version = processing_result.end_version,
+                    batch_start_version = batch_versions.map(|(start, _)| start),
+                    batch_end_version = batch_versions.map(|(_, end)| end),
                     versions_processed = versions_processed,
                     tps = (ma.avg() * 1000.0) as u64,
                     "Processed batch version"
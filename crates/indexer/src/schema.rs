@@ -289,6 +289,47 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    proposal_resolutions (proposal_id) {
+        proposal_id -> Int8,
+        yes_votes -> Numeric,
+        no_votes -> Numeric,
+        resolved_early -> Bool,
+        transaction_version -> Int8,
+        transaction_timestamp -> Timestamp,
+        inserted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    proposal_votes (transaction_version, proposal_id, staking_pool_address) {
+        transaction_version -> Int8,
+        proposal_id -> Int8,
+        voter_address -> Varchar,
+        staking_pool_address -> Varchar,
+        num_votes -> Numeric,
+        should_pass -> Bool,
+        transaction_timestamp -> Timestamp,
+        inserted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    proposals (proposal_id) {
+        proposal_id -> Int8,
+        proposer_address -> Varchar,
+        staking_pool_address -> Varchar,
+        execution_hash -> Varchar,
+        metadata -> Jsonb,
+        min_vote_threshold -> Numeric,
+        early_resolution_vote_threshold -> Nullable<Numeric>,
+        expiration_timestamp -> Timestamp,
+        transaction_version -> Int8,
+        transaction_timestamp -> Timestamp,
+        inserted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     signatures (transaction_version, multi_agent_index, multi_sig_index, is_sender_primary) {
         transaction_version -> Int8,
@@ -307,6 +348,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    staking_proxy_activities (transaction_version) {
+        transaction_version -> Int8,
+        owner_address -> Varchar,
+        entry_function_name -> Varchar,
+        operator_address -> Nullable<Varchar>,
+        new_operator_address -> Nullable<Varchar>,
+        new_voter_address -> Nullable<Varchar>,
+        transaction_timestamp -> Timestamp,
+        inserted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     table_items (transaction_version, write_set_change_index) {
         key -> Text,
@@ -486,7 +540,11 @@ diesel::allow_tables_to_appear_in_same_query!(
     move_resources,
     processor_status,
     processor_statuses,
+    proposal_resolutions,
+    proposal_votes,
+    proposals,
     signatures,
+    staking_proxy_activities,
     table_items,
     table_metadatas,
     token_activities,
@@ -503,14 +503,19 @@ pub fn convert_multi_ed25519_signature(
     }
 }
 
+/// Secp256r1 ECDSA, WebAuthn and Secp256k1 ECDSA signatures have no representation in the
+/// extractor protos yet, so `None` is returned for them.
 pub fn convert_account_signature(
     account_signature: &AccountSignature,
-) -> extractor::AccountSignature {
+) -> Option<extractor::AccountSignature> {
     let r#type = match account_signature {
         AccountSignature::Ed25519Signature(_) => extractor::account_signature::Type::Ed25519,
         AccountSignature::MultiEd25519Signature(_) => {
             extractor::account_signature::Type::MultiEd25519
         }
+        AccountSignature::Secp256r1EcdsaSignature(_)
+        | AccountSignature::WebAuthnSignature(_)
+        | AccountSignature::Secp256k1EcdsaSignature(_) => return None,
     };
     let signature = match account_signature {
         AccountSignature::Ed25519Signature(s) => {
@@ -521,11 +526,14 @@ pub fn convert_account_signature(
                 s,
             ))
         }
+        AccountSignature::Secp256r1EcdsaSignature(_)
+        | AccountSignature::WebAuthnSignature(_)
+        | AccountSignature::Secp256k1EcdsaSignature(_) => return None,
     };
-    extractor::AccountSignature {
+    Some(extractor::AccountSignature {
         r#type: r#type as i32,
         signature: Some(signature),
-    }
+    })
 }
 
 pub fn convert_transaction_signature(
@@ -539,6 +547,10 @@ pub fn convert_transaction_signature(
         TransactionSignature::Ed25519Signature(_) => extractor::signature::Type::Ed25519,
         TransactionSignature::MultiEd25519Signature(_) => extractor::signature::Type::MultiEd25519,
         TransactionSignature::MultiAgentSignature(_) => extractor::signature::Type::MultiAgent,
+        // Not representable in the extractor protos yet, see `convert_account_signature`.
+        TransactionSignature::Secp256r1EcdsaSignature(_)
+        | TransactionSignature::WebAuthnSignature(_)
+        | TransactionSignature::Secp256k1EcdsaSignature(_) => return None,
     };
 
     let signature = match signature {
@@ -550,7 +562,7 @@ pub fn convert_transaction_signature(
         }
         TransactionSignature::MultiAgentSignature(s) => {
             extractor::signature::Signature::MultiAgent(extractor::MultiAgentSignature {
-                sender: Some(convert_account_signature(&s.sender)),
+                sender: Some(convert_account_signature(&s.sender)?),
                 secondary_signer_addresses: s
                     .secondary_signer_addresses
                     .iter()
@@ -560,9 +572,12 @@ pub fn convert_transaction_signature(
                     .secondary_signers
                     .iter()
                     .map(convert_account_signature)
-                    .collect(),
+                    .collect::<Option<_>>()?,
             })
         }
+        TransactionSignature::Secp256r1EcdsaSignature(_)
+        | TransactionSignature::WebAuthnSignature(_)
+        | TransactionSignature::Secp256k1EcdsaSignature(_) => return None,
     };
 
     Some(extractor::Signature {
@@ -65,6 +65,11 @@ impl PriorityIndex {
         self.data.iter().rev()
     }
 
+    /// Iterates from the lowest to the highest priority transaction.
+    pub(crate) fn iter_lowest_first(&self) -> Iter<OrderedQueueKey> {
+        self.data.iter()
+    }
+
     pub(crate) fn size(&self) -> usize {
         self.data.len()
     }
@@ -458,6 +463,13 @@ impl ParkingLotIndex {
             .map_or(false, |(_account, txns)| txns.contains(seq_num))
     }
 
+    /// Iterates over the parked transactions, grouped by account.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = TxnPointer> + '_ {
+        self.data
+            .iter()
+            .flat_map(|(sender, txns)| txns.iter().map(move |seq_num| (*sender, *seq_num)))
+    }
+
     /// Returns a random "non-ready" transaction (with highest sequence number for that account).
     pub(crate) fn get_poppable(&self) -> Option<TxnPointer> {
         let mut rng = rand::thread_rng();
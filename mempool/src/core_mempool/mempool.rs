@@ -4,7 +4,7 @@
 //! Mempool is used to track transactions which have been submitted but not yet
 //! agreed upon.
 use crate::counters::{CONSENSUS_PULLED_LABEL, E2E_LABEL, INSERT_LABEL, LOCAL_LABEL, REMOVE_LABEL};
-use crate::shared_mempool::types::MultiBucketTimelineIndexIds;
+use crate::shared_mempool::types::{MultiBucketTimelineIndexIds, PendingTransactionInfo};
 use crate::{
     core_mempool::{
         index::TxnPointer,
@@ -109,6 +109,23 @@ impl Mempool {
         self.transactions.get_by_hash(hash)
     }
 
+    pub(crate) fn get_pending_transactions_by_sender(
+        &self,
+        sender: &AccountAddress,
+    ) -> Vec<PendingTransactionInfo> {
+        self.transactions.get_pending_transactions_by_sender(sender)
+    }
+
+    pub(crate) fn get_parked_transactions(&self, limit: usize) -> Vec<PendingTransactionInfo> {
+        self.transactions.get_parked_transactions(limit)
+    }
+
+    /// Removes a (e.g. stuck) transaction from Mempool. Following transactions of the same sender
+    /// are parked until a transaction with the evicted sequence number is added again.
+    pub(crate) fn evict_transaction(&mut self, hash: &HashValue) -> Option<SignedTransaction> {
+        self.transactions.evict_transaction(hash)
+    }
+
     /// Used to add a transaction to the Mempool.
     /// Performs basic validation: checks account's sequence number.
     pub(crate) fn add_txn(
@@ -4,12 +4,14 @@
 use crate::counters::{
     BROADCAST_BATCHED_LABEL, BROADCAST_READY_LABEL, CONSENSUS_READY_LABEL, E2E_LABEL, LOCAL_LABEL,
 };
-use crate::shared_mempool::types::MultiBucketTimelineIndexIds;
+use crate::shared_mempool::types::{
+    MultiBucketTimelineIndexIds, PendingTransactionInfo, PendingTransactionStatus,
+};
 use crate::{
     core_mempool::{
         index::{
             AccountTransactions, MultiBucketTimelineIndex, ParkingLotIndex, PriorityIndex,
-            PriorityQueueIter, TTLIndex,
+            PriorityQueueIter, TTLIndex, TxnPointer,
         },
         transaction::{MempoolTransaction, TimelineState},
     },
@@ -74,6 +76,8 @@ pub struct TransactionStore {
     capacity_bytes: usize,
     capacity_per_user: usize,
     max_batch_bytes: u64,
+    // minimum gas unit price increase, in percent, for a transaction to replace a pending one
+    replace_by_fee_min_bump_percentage: u64,
 }
 
 impl TransactionStore {
@@ -102,6 +106,7 @@ impl TransactionStore {
             capacity_bytes: config.capacity_bytes,
             capacity_per_user: config.capacity_per_user,
             max_batch_bytes: config.shared_mempool_max_batch_bytes,
+            replace_by_fee_min_bump_percentage: config.replace_by_fee_min_bump_percentage,
         }
     }
 
@@ -147,6 +152,89 @@ impl TransactionStore {
         }
     }
 
+    /// Returns all pending transactions of the sender along with their mempool status,
+    /// ordered by sequence number.
+    pub(crate) fn get_pending_transactions_by_sender(
+        &self,
+        address: &AccountAddress,
+    ) -> Vec<PendingTransactionInfo> {
+        self.transactions
+            .get(address)
+            .map_or_else(Vec::new, |txns| {
+                self.get_pending_transaction_infos(address, txns)
+            })
+    }
+
+    /// Returns at most `limit` transactions that are currently in the parking lot.
+    pub(crate) fn get_parked_transactions(&self, limit: usize) -> Vec<PendingTransactionInfo> {
+        self.parking_lot_index
+            .iter()
+            .filter_map(|(address, seq_num)| {
+                let txns = self.transactions.get(&address)?;
+                let txn = txns.get(&seq_num)?;
+                let account_sequence_number = self.get_sequence_number(&address).map_or(0, |v| *v);
+                let mut missing_sequence_number = account_sequence_number;
+                while missing_sequence_number < seq_num
+                    && txns.contains_key(&missing_sequence_number)
+                {
+                    missing_sequence_number += 1;
+                }
+                Some(Self::pending_transaction_info(
+                    txn,
+                    account_sequence_number,
+                    PendingTransactionStatus::Parked {
+                        missing_sequence_number,
+                    },
+                ))
+            })
+            .take(limit)
+            .collect()
+    }
+
+    /// Builds the pending transaction infos of a single account. A parked transaction is
+    /// waiting for the first sequence number missing between the account sequence number and
+    /// its own sequence number.
+    fn get_pending_transaction_infos(
+        &self,
+        address: &AccountAddress,
+        txns: &AccountTransactions,
+    ) -> Vec<PendingTransactionInfo> {
+        let account_sequence_number = self.get_sequence_number(address).map_or(0, |v| *v);
+        let mut next_sequence_number = account_sequence_number;
+        txns.iter()
+            .map(|(seq_num, txn)| {
+                let status = if self.parking_lot_index.contains(address, seq_num) {
+                    PendingTransactionStatus::Parked {
+                        missing_sequence_number: next_sequence_number,
+                    }
+                } else {
+                    PendingTransactionStatus::Ready
+                };
+                if *seq_num == next_sequence_number {
+                    next_sequence_number += 1;
+                }
+                Self::pending_transaction_info(txn, account_sequence_number, status)
+            })
+            .collect()
+    }
+
+    fn pending_transaction_info(
+        txn: &MempoolTransaction,
+        account_sequence_number: u64,
+        status: PendingTransactionStatus,
+    ) -> PendingTransactionInfo {
+        PendingTransactionInfo {
+            transaction: txn.txn.clone(),
+            ranking_score: txn.ranking_score,
+            insertion_timestamp_usecs: txn
+                .insertion_time
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |d| d.as_micros() as u64),
+            account_sequence_number,
+            status,
+        }
+    }
+
     /// Return (SystemTime, is the timestamp for end-to-end)
     pub(crate) fn get_insertion_time_and_bucket(
         &self,
@@ -183,51 +271,61 @@ impl TransactionStore {
         self.sequence_numbers.get(address)
     }
 
+    /// The minimum gas unit price for a transaction to replace a pending transaction with the
+    /// given gas unit price. The replacement must always pay strictly more.
+    fn min_replacement_gas_price(gas_unit_price: u64, min_bump_percentage: u64) -> u64 {
+        let bump = (gas_unit_price as u128 * min_bump_percentage as u128 + 99) / 100;
+        gas_unit_price.saturating_add(max(bump, 1) as u64)
+    }
+
     /// Insert transaction into TransactionStore. Performs validation checks and updates indexes.
     pub(crate) fn insert(&mut self, txn: MempoolTransaction) -> MempoolStatus {
         let address = txn.get_sender();
         let sequence_number = txn.sequence_info;
 
-        // If the transaction is already in Mempool, we only allow the user to
-        // increase the gas unit price to speed up a transaction, but not the max gas.
+        // If the transaction is already in Mempool, the user can replace it (e.g. to speed it up
+        // or to cancel it) with a new transaction with the same sequence number, by bumping the
+        // gas unit price by at least `replace_by_fee_min_bump_percentage`.
         //
         // Transactions with all the same inputs (but possibly signed differently) are idempotent
         // since the raw transaction is the same
+        let min_bump_percentage = self.replace_by_fee_min_bump_percentage;
         if let Some(txns) = self.transactions.get_mut(&address) {
-            if let Some(current_version) =
-                txns.get_mut(&sequence_number.transaction_sequence_number)
-            {
-                if current_version.txn.payload() != txn.txn.payload() {
-                    return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
-                        "Transaction already in mempool with a different payload".to_string(),
-                    );
-                } else if current_version.txn.expiration_timestamp_secs()
-                    != txn.txn.expiration_timestamp_secs()
+            if let Some(current_version) = txns.get(&sequence_number.transaction_sequence_number) {
+                let current_gas_price = current_version.get_gas_price();
+                if current_version.txn.payload() == txn.txn.payload()
+                    && current_version.txn.expiration_timestamp_secs()
+                        == txn.txn.expiration_timestamp_secs()
+                    && current_version.txn.max_gas_amount() == txn.txn.max_gas_amount()
+                    && current_gas_price == txn.get_gas_price()
                 {
-                    return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
-                        "Transaction already in mempool with a different expiration timestamp"
-                            .to_string(),
-                    );
-                } else if current_version.txn.max_gas_amount() != txn.txn.max_gas_amount() {
-                    return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
-                        "Transaction already in mempool with a different max gas amount"
-                            .to_string(),
-                    );
-                } else if current_version.txn.gas_unit_price() < txn.get_gas_price() {
-                    // Update txn if gas unit price is a larger value than before
-                    if let Some(txn) = txns.remove(&sequence_number.transaction_sequence_number) {
-                        self.index_remove(&txn);
-                    };
-                } else if current_version.get_gas_price() > txn.get_gas_price() {
-                    return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
-                        "Transaction already in mempool with a higher gas price".to_string(),
-                    );
-                } else {
                     // If the transaction is the same, it's an idempotent call
                     // Updating signers is not supported, the previous submission must fail
                     counters::CORE_MEMPOOL_IDEMPOTENT_TXNS.inc();
                     return MempoolStatus::new(MempoolStatusCode::Accepted);
                 }
+
+                let min_replacement_gas_price =
+                    Self::min_replacement_gas_price(current_gas_price, min_bump_percentage);
+                if txn.get_gas_price() < min_replacement_gas_price {
+                    return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
+                        format!(
+                            "Transaction already in mempool with gas unit price {}, a replacement requires a gas unit price of at least {}",
+                            current_gas_price, min_replacement_gas_price,
+                        ),
+                    );
+                }
+
+                if let Some(replaced_txn) =
+                    txns.remove(&sequence_number.transaction_sequence_number)
+                {
+                    debug!(LogSchema::new(LogEntry::ReplaceTxn).txns(TxnsLog::new_txn(
+                        address,
+                        sequence_number.transaction_sequence_number
+                    )));
+                    counters::CORE_MEMPOOL_REPLACED_TXNS.inc();
+                    self.index_remove(&replaced_txn);
+                }
             }
         }
 
@@ -313,35 +411,55 @@ impl TransactionStore {
         counters::core_mempool_index_size(counters::SIZE_BYTES_LABEL, self.size_bytes);
     }
 
-    /// Checks if Mempool is full.
-    /// If it's full, tries to free some space by evicting transactions from the ParkingLot.
-    /// We only evict on attempt to insert a transaction that would be ready for broadcast upon insertion.
+    /// Makes room in a full Mempool for a transaction that is ready upon insertion. Non-ready
+    /// transactions in the ParkingLot are evicted first, then the lowest priced ready
+    /// transactions of other accounts, as long as they are priced lower than the new
+    /// transaction. Returns whether Mempool is still full.
     fn check_is_full_after_eviction(
         &mut self,
         txn: &MempoolTransaction,
         curr_sequence_number: u64,
     ) -> bool {
         if self.is_full() && self.check_txn_ready(txn, curr_sequence_number) {
-            // try to free some space in Mempool from ParkingLot by evicting a non-ready txn
-            if let Some((address, sequence_number)) = self.parking_lot_index.get_poppable() {
-                if let Some(txn) = self
-                    .transactions
-                    .get_mut(&address)
-                    .and_then(|txns| txns.remove(&sequence_number))
-                {
-                    debug!(
-                        LogSchema::new(LogEntry::MempoolFullEvictedTxn).txns(TxnsLog::new_txn(
-                            txn.get_sender(),
-                            txn.sequence_info.transaction_sequence_number
-                        ))
-                    );
-                    self.index_remove(&txn);
+            while self.is_full() {
+                // try to free some space in Mempool from ParkingLot by evicting a non-ready txn,
+                // otherwise evict the lowest priced txn
+                let txn_to_evict = self
+                    .parking_lot_index
+                    .get_poppable()
+                    .or_else(|| self.get_lower_priced_txn(txn));
+                let (address, sequence_number) = match txn_to_evict {
+                    Some(txn_pointer) => txn_pointer,
+                    None => break,
+                };
+                match self.remove_and_park_following(&address, sequence_number) {
+                    Some(evicted_txn) => {
+                        debug!(LogSchema::new(LogEntry::MempoolFullEvictedTxn).txns(
+                            TxnsLog::new_txn(
+                                evicted_txn.get_sender(),
+                                evicted_txn.sequence_info.transaction_sequence_number
+                            )
+                        ));
+                        counters::CORE_MEMPOOL_FULL_EVICTED_TXNS.inc();
+                    }
+                    None => break,
                 }
             }
         }
         self.is_full()
     }
 
+    /// Returns the lowest priced ready transaction of another account than the sender of `txn`,
+    /// if it is priced lower than `txn`.
+    fn get_lower_priced_txn(&self, txn: &MempoolTransaction) -> Option<TxnPointer> {
+        let sender = txn.get_sender();
+        self.priority_index
+            .iter_lowest_first()
+            .take_while(|key| key.gas_ranking_score < txn.ranking_score)
+            .find(|key| key.address != sender)
+            .map(|key| (key.address, key.sequence_number.transaction_sequence_number))
+    }
+
     fn is_full(&self) -> bool {
         self.system_ttl_index.size() >= self.capacity || self.size_bytes >= self.capacity_bytes
     }
@@ -534,6 +652,41 @@ impl TransactionStore {
         }
     }
 
+    /// Evicts the transaction with the given hash, regardless of its status. As with expired
+    /// transactions, all following transactions of the account are parked since they can no
+    /// longer be executed until the evicted sequence number is filled again.
+    pub(crate) fn evict_transaction(&mut self, hash: &HashValue) -> Option<SignedTransaction> {
+        let (account, sequence_number) = *self.hash_index.get(hash)?;
+        let txn = self.remove_and_park_following(&account, sequence_number)?;
+
+        let mut txns_log = TxnsLog::new();
+        txns_log.add(account, sequence_number);
+        info!(LogSchema::new(LogEntry::EvictTxn).txns(txns_log));
+
+        Some(txn.txn)
+    }
+
+    /// Removes a transaction and parks all following transactions of the account, since they
+    /// can't be executed until the removed sequence number is filled again.
+    fn remove_and_park_following(
+        &mut self,
+        address: &AccountAddress,
+        sequence_number: u64,
+    ) -> Option<MempoolTransaction> {
+        let txns = self.transactions.get_mut(address)?;
+        for (_, t) in txns.range_mut((Bound::Excluded(sequence_number), Bound::Unbounded)) {
+            self.parking_lot_index.insert(t);
+            self.priority_index.remove(t);
+            self.timeline_index.remove(t);
+            if let TimelineState::Ready(_) = t.timeline_state {
+                t.timeline_state = TimelineState::NotReady;
+            }
+        }
+        let txn = txns.remove(&sequence_number)?;
+        self.index_remove(&txn);
+        Some(txn)
+    }
+
     /// Removes transaction from all indexes. Only call after removing from main transactions DS.
     fn index_remove(&mut self, txn: &MempoolTransaction) {
         counters::CORE_MEMPOOL_REMOVED_TXNS.inc();
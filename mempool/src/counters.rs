@@ -59,6 +59,9 @@ pub const SUCCESS_LABEL: &str = "success";
 // Bounded executor task labels
 pub const CLIENT_EVENT_LABEL: &str = "client_event";
 pub const CLIENT_EVENT_GET_TXN_LABEL: &str = "client_event_get_txn";
+pub const CLIENT_EVENT_GET_PENDING_TXNS_LABEL: &str = "client_event_get_pending_txns";
+pub const CLIENT_EVENT_GET_PARKED_TXNS_LABEL: &str = "client_event_get_parked_txns";
+pub const CLIENT_EVENT_EVICT_TXN_LABEL: &str = "client_event_evict_txn";
 pub const RECONFIG_EVENT_LABEL: &str = "reconfig";
 pub const PEER_BROADCAST_EVENT_LABEL: &str = "peer_broadcast";
 
@@ -167,6 +170,24 @@ pub static CORE_MEMPOOL_IDEMPOTENT_TXNS: Lazy<IntCounter> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Counter tracking number of txns replaced by a txn with the same sequence number and a higher gas price
+pub static CORE_MEMPOOL_REPLACED_TXNS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_core_mempool_replaced_txns_count",
+        "Number of txns replaced by a txn with a higher gas price"
+    )
+    .unwrap()
+});
+
+/// Counter tracking number of txns evicted to make room for new txns when mempool is full
+pub static CORE_MEMPOOL_FULL_EVICTED_TXNS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_core_mempool_full_evicted_txns_count",
+        "Number of txns evicted when mempool is full"
+    )
+    .unwrap()
+});
+
 pub fn core_mempool_txn_commit_latency(
     stage: &'static str,
     scope: &'static str,
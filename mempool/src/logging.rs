@@ -165,9 +165,11 @@ pub enum LogEntry {
     AddTxn,
     RemoveTxn,
     MempoolFullEvictedTxn,
+    ReplaceTxn,
     GCRemoveTxns,
     CleanCommittedTxn,
     CleanRejectedTxn,
+    EvictTxn,
     ProcessReadyTxns,
     DBError,
     UnexpectedNetworkMsg,
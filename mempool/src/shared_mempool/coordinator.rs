@@ -6,7 +6,7 @@ use crate::{
     core_mempool::{CoreMempool, TimelineState},
     counters,
     logging::{LogEntry, LogEvent, LogSchema},
-    network::{MempoolNetworkEvents, MempoolSyncMsg},
+    network::{MempoolNetworkEvents, MempoolNetworkInterface, MempoolSyncMsg},
     shared_mempool::{
         tasks,
         tasks::process_committed_transactions,
@@ -148,6 +148,60 @@ async fn handle_client_request<V>(
                 ))
                 .await;
         }
+        MempoolClientRequest::GetPendingTransactionsBySender(sender, callback) => {
+            let _timer = counters::task_spawn_latency_timer(
+                counters::CLIENT_EVENT_GET_PENDING_TXNS_LABEL,
+                counters::SPAWN_LABEL,
+            );
+            let task_start_timer = counters::task_spawn_latency_timer(
+                counters::CLIENT_EVENT_GET_PENDING_TXNS_LABEL,
+                counters::START_LABEL,
+            );
+            bounded_executor
+                .spawn(tasks::process_client_get_pending_transactions(
+                    smp.clone(),
+                    sender,
+                    callback,
+                    task_start_timer,
+                ))
+                .await;
+        }
+        MempoolClientRequest::GetParkedTransactions(limit, callback) => {
+            let _timer = counters::task_spawn_latency_timer(
+                counters::CLIENT_EVENT_GET_PARKED_TXNS_LABEL,
+                counters::SPAWN_LABEL,
+            );
+            let task_start_timer = counters::task_spawn_latency_timer(
+                counters::CLIENT_EVENT_GET_PARKED_TXNS_LABEL,
+                counters::START_LABEL,
+            );
+            bounded_executor
+                .spawn(tasks::process_client_get_parked_transactions(
+                    smp.clone(),
+                    limit,
+                    callback,
+                    task_start_timer,
+                ))
+                .await;
+        }
+        MempoolClientRequest::EvictTransaction(hash, callback) => {
+            let _timer = counters::task_spawn_latency_timer(
+                counters::CLIENT_EVENT_EVICT_TXN_LABEL,
+                counters::SPAWN_LABEL,
+            );
+            let task_start_timer = counters::task_spawn_latency_timer(
+                counters::CLIENT_EVENT_EVICT_TXN_LABEL,
+                counters::START_LABEL,
+            );
+            bounded_executor
+                .spawn(tasks::process_client_evict_transaction(
+                    smp.clone(),
+                    hash,
+                    callback,
+                    task_start_timer,
+                ))
+                .await;
+        }
     }
 }
 
@@ -349,6 +403,19 @@ pub(crate) async fn gc_coordinator(mempool: Arc<Mutex<CoreMempool>>, gc_interval
     ));
 }
 
+/// Periodically re-prioritizes the upstream peers, so that changes in the
+/// peer latencies (as observed by the peer monitor) are taken into account.
+pub(crate) async fn peer_update_job(
+    network_interface: MempoolNetworkInterface,
+    peer_update_interval_ms: u64,
+) {
+    let mut interval =
+        IntervalStream::new(interval(Duration::from_millis(peer_update_interval_ms)));
+    while let Some(_interval) = interval.next().await {
+        network_interface.update_prioritized_peers();
+    }
+}
+
 /// Periodically logs a snapshot of transactions in core mempool.
 /// In the future we may want an interactive way to directly query mempool's internal state.
 /// For now, we will rely on this periodic snapshot to observe the internal state.
@@ -40,6 +40,7 @@ use network::{
     transport::ConnectionMetadata,
     ProtocolId,
 };
+use peer_monitoring_service_client::PeerMonitorState;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::RandomState;
 use std::collections::BTreeSet;
@@ -163,6 +164,7 @@ pub(crate) struct MempoolNetworkInterface {
     role: RoleType,
     mempool_config: MempoolConfig,
     prioritized_peers_comparator: PrioritizedPeersComparator,
+    peer_monitor_state: PeerMonitorState,
 }
 
 impl MempoolNetworkInterface {
@@ -171,6 +173,7 @@ impl MempoolNetworkInterface {
         network_senders: HashMap<NetworkId, MempoolNetworkSender>,
         role: RoleType,
         mempool_config: MempoolConfig,
+        peer_monitor_state: PeerMonitorState,
     ) -> MempoolNetworkInterface {
         MempoolNetworkInterface {
             peer_metadata_storage,
@@ -180,6 +183,7 @@ impl MempoolNetworkInterface {
             role,
             mempool_config,
             prioritized_peers_comparator: PrioritizedPeersComparator::new(),
+            peer_monitor_state,
         }
     }
 
@@ -217,7 +221,8 @@ impl MempoolNetworkInterface {
         self.update_prioritized_peers();
     }
 
-    fn update_prioritized_peers(&self) {
+    /// Updates the prioritized peers, e.g., to account for peer latency changes
+    pub fn update_prioritized_peers(&self) {
         // Only do this if it's not a validator
         if self.role.is_validator() {
             return;
@@ -228,18 +233,24 @@ impl MempoolNetworkInterface {
             let peer_states = self.sync_states.read_all();
             peer_states
                 .iter()
-                .map(|(peer, state)| (*peer, state.metadata.role))
+                .map(|(peer, state)| {
+                    let ping_latency_secs = self
+                        .peer_monitor_state
+                        .get_peer_metadata(peer)
+                        .and_then(|metadata| metadata.average_ping_latency_secs);
+                    (*peer, state.metadata.role, ping_latency_secs)
+                })
                 .collect()
         };
 
-        // Order peers by network and by type
+        // Order peers by network, by type and by latency
         // Origin doesn't matter at this point, only inserted ones into peer_states are upstream
         // Validators will always have the full set
         let mut prioritized_peers = self.prioritized_peers.lock();
         let peers: Vec<_> = peers
             .iter()
             .sorted_by(|peer_a, peer_b| self.prioritized_peers_comparator.compare(peer_a, peer_b))
-            .map(|(peer, _)| *peer)
+            .map(|(peer, _, _)| *peer)
             .collect();
         let _ = std::mem::replace(&mut *prioritized_peers, peers);
     }
@@ -585,8 +596,8 @@ impl PrioritizedPeersComparator {
     /// Provides ordering for peers to send transactions to
     fn compare(
         &self,
-        peer_a: &(PeerNetworkId, PeerRole),
-        peer_b: &(PeerNetworkId, PeerRole),
+        peer_a: &(PeerNetworkId, PeerRole, Option<f64>),
+        peer_b: &(PeerNetworkId, PeerRole, Option<f64>),
     ) -> Ordering {
         let peer_network_id_a = peer_a.0;
         let peer_network_id_b = peer_b.0;
@@ -601,13 +612,17 @@ impl PrioritizedPeersComparator {
                 let role_a = peer_a.1;
                 let role_b = peer_b.1;
                 match role_a.cmp(&role_b) {
-                    // Tiebreak by hash_peer_id.
-                    Ordering::Equal => {
-                        let hash_a = self.hash_peer_id(&peer_network_id_a.peer_id());
-                        let hash_b = self.hash_peer_id(&peer_network_id_b.peer_id());
-
-                        hash_a.cmp(&hash_b)
-                    }
+                    // Then sort by ping latency (peers with unknown latencies are last)
+                    Ordering::Equal => match compare_latencies(peer_a.2, peer_b.2) {
+                        // Tiebreak by hash_peer_id.
+                        Ordering::Equal => {
+                            let hash_a = self.hash_peer_id(&peer_network_id_a.peer_id());
+                            let hash_b = self.hash_peer_id(&peer_network_id_b.peer_id());
+
+                            hash_a.cmp(&hash_b)
+                        }
+                        ordering => ordering,
+                    },
                     ordering => ordering,
                 }
             }
@@ -623,6 +638,29 @@ impl PrioritizedPeersComparator {
     }
 }
 
+/// The width of the ping latency buckets used to order peers. Peers whose
+/// latencies fall in the same bucket are considered equally fast, so that
+/// latency jitter doesn't keep reordering them.
+const PEER_LATENCY_BUCKET_SECS: f64 = 0.05;
+
+/// Orders the given ping latencies from lowest to highest bucket. Unknown
+/// latencies are ordered after all known latencies.
+fn compare_latencies(latency_a: Option<f64>, latency_b: Option<f64>) -> Ordering {
+    match (latency_a, latency_b) {
+        (Some(latency_a), Some(latency_b)) => {
+            latency_bucket(latency_a).cmp(&latency_bucket(latency_b))
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Returns the bucket of the given ping latency (see `PEER_LATENCY_BUCKET_SECS`)
+fn latency_bucket(latency_secs: f64) -> u64 {
+    (latency_secs / PEER_LATENCY_BUCKET_SECS) as u64
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -638,18 +676,22 @@ mod test {
         let val_1 = (
             PeerNetworkId::new(NetworkId::Vfn, peer_id_1),
             PeerRole::Validator,
+            None,
         );
         let val_2 = (
             PeerNetworkId::new(NetworkId::Vfn, peer_id_2),
             PeerRole::Validator,
+            None,
         );
         let vfn_1 = (
             PeerNetworkId::new(NetworkId::Public, peer_id_1),
             PeerRole::ValidatorFullNode,
+            None,
         );
         let preferred_1 = (
             PeerNetworkId::new(NetworkId::Public, peer_id_1),
             PeerRole::PreferredUpstream,
+            None,
         );
 
         // NetworkId ordering
@@ -660,6 +702,30 @@ mod test {
         assert_eq!(Ordering::Greater, comparator.compare(&vfn_1, &preferred_1));
         assert_eq!(Ordering::Less, comparator.compare(&preferred_1, &vfn_1));
 
+        // Latency ordering (unknown latencies are last)
+        let fast_val_1 = (val_1.0, val_1.1, Some(0.1));
+        let slow_val_2 = (val_2.0, val_2.1, Some(0.5));
+        assert_eq!(Ordering::Less, comparator.compare(&fast_val_1, &slow_val_2));
+        assert_eq!(
+            Ordering::Greater,
+            comparator.compare(&slow_val_2, &fast_val_1)
+        );
+        assert_eq!(Ordering::Less, comparator.compare(&slow_val_2, &val_1));
+        assert_eq!(Ordering::Greater, comparator.compare(&vfn_1, &fast_val_1));
+
+        // Latencies in the same bucket are tiebroken on peer_id, ignoring the jitter
+        let jittery_val_1 = (val_1.0, val_1.1, Some(0.11));
+        let jittery_val_2 = (val_2.0, val_2.1, Some(0.12));
+        assert_eq!(
+            comparator.compare(&val_1, &val_2),
+            comparator.compare(&jittery_val_1, &jittery_val_2)
+        );
+        let jittery_val_1 = (val_1.0, val_1.1, Some(0.13));
+        assert_eq!(
+            comparator.compare(&val_1, &val_2),
+            comparator.compare(&jittery_val_1, &jittery_val_2)
+        );
+
         // Tiebreaker on peer_id
         let hash_1 = comparator.hash_peer_id(&val_1.0.peer_id());
         let hash_2 = comparator.hash_peer_id(&val_2.0.peer_id());
@@ -5,7 +5,7 @@ use crate::{
     core_mempool::CoreMempool,
     network::{MempoolNetworkEvents, MempoolNetworkSender},
     shared_mempool::{
-        coordinator::{coordinator, gc_coordinator, snapshot_job},
+        coordinator::{coordinator, gc_coordinator, peer_update_job, snapshot_job},
         types::{MempoolEventsReceiver, SharedMempool, SharedMempoolNotification},
     },
     QuorumStoreRequest,
@@ -18,6 +18,7 @@ use event_notifications::ReconfigNotificationListener;
 use futures::channel::mpsc::{self, Receiver, UnboundedSender};
 use mempool_notifications::MempoolNotificationListener;
 use network::application::storage::PeerMetadataStorage;
+use peer_monitoring_service_client::PeerMonitorState;
 use std::{
     collections::HashMap,
     sync::{
@@ -49,6 +50,7 @@ pub(crate) fn start_shared_mempool<V>(
     validator: Arc<RwLock<V>>,
     subscribers: Vec<UnboundedSender<SharedMempoolNotification>>,
     peer_metadata_storage: Arc<PeerMetadataStorage>,
+    peer_monitor_state: PeerMonitorState,
 ) where
     V: TransactionValidation + 'static,
 {
@@ -68,8 +70,14 @@ pub(crate) fn start_shared_mempool<V>(
         subscribers,
         config.base.role,
         peer_metadata_storage,
+        peer_monitor_state,
     );
 
+    executor.spawn(peer_update_job(
+        smp.network_interface.clone(),
+        config.mempool.shared_mempool_peer_update_interval_ms,
+    ));
+
     executor.spawn(coordinator(
         smp,
         executor.clone(),
@@ -104,6 +112,7 @@ pub fn bootstrap(
     mempool_listener: MempoolNotificationListener,
     mempool_reconfig_events: ReconfigNotificationListener,
     peer_metadata_storage: Arc<PeerMetadataStorage>,
+    peer_monitor_state: PeerMonitorState,
 ) -> Runtime {
     let runtime = Builder::new_multi_thread()
         .thread_name_fn(|| {
@@ -130,6 +139,7 @@ pub fn bootstrap(
         vm_validator,
         vec![],
         peer_metadata_storage,
+        peer_monitor_state,
     );
     runtime
 }
@@ -8,8 +8,8 @@ use crate::{
     logging::{LogEntry, LogEvent, LogSchema},
     network::{BroadcastError, MempoolSyncMsg},
     shared_mempool::types::{
-        notify_subscribers, MultiBatchId, ScheduledBroadcast, SharedMempool,
-        SharedMempoolNotification, SubmissionStatusBundle,
+        notify_subscribers, MultiBatchId, PendingTransactionInfo, ScheduledBroadcast,
+        SharedMempool, SharedMempoolNotification, SubmissionStatusBundle,
     },
     thread_pool::IO_POOL,
     QuorumStoreRequest, QuorumStoreResponse, SubmissionStatus,
@@ -21,6 +21,7 @@ use aptos_infallible::{Mutex, RwLock};
 use aptos_logger::prelude::*;
 use aptos_metrics_core::HistogramTimer;
 use aptos_types::{
+    account_address::AccountAddress,
     mempool_status::{MempoolStatus, MempoolStatusCode},
     on_chain_config::OnChainConfigPayload,
     transaction::SignedTransaction,
@@ -151,6 +152,73 @@ pub(crate) async fn process_client_get_transaction<V>(
     }
 }
 
+/// Returns the pending transactions of a sender, for introspection by clients.
+pub(crate) async fn process_client_get_pending_transactions<V>(
+    smp: SharedMempool<V>,
+    sender: AccountAddress,
+    callback: oneshot::Sender<Vec<PendingTransactionInfo>>,
+    timer: HistogramTimer,
+) where
+    V: TransactionValidation,
+{
+    timer.stop_and_record();
+    let result = smp
+        .mempool
+        .lock()
+        .get_pending_transactions_by_sender(&sender);
+
+    if callback.send(result).is_err() {
+        warn!(LogSchema::event_log(
+            LogEntry::GetTransaction,
+            LogEvent::CallbackFail
+        ));
+        counters::CLIENT_CALLBACK_FAIL.inc();
+    }
+}
+
+/// Returns the transactions in the parking lot, for introspection by clients.
+pub(crate) async fn process_client_get_parked_transactions<V>(
+    smp: SharedMempool<V>,
+    limit: usize,
+    callback: oneshot::Sender<Vec<PendingTransactionInfo>>,
+    timer: HistogramTimer,
+) where
+    V: TransactionValidation,
+{
+    timer.stop_and_record();
+    let result = smp.mempool.lock().get_parked_transactions(limit);
+
+    if callback.send(result).is_err() {
+        warn!(LogSchema::event_log(
+            LogEntry::GetTransaction,
+            LogEvent::CallbackFail
+        ));
+        counters::CLIENT_CALLBACK_FAIL.inc();
+    }
+}
+
+/// Evicts a transaction from mempool on behalf of a client (e.g. an operator removing a stuck
+/// transaction).
+pub(crate) async fn process_client_evict_transaction<V>(
+    smp: SharedMempool<V>,
+    hash: HashValue,
+    callback: oneshot::Sender<Option<SignedTransaction>>,
+    timer: HistogramTimer,
+) where
+    V: TransactionValidation,
+{
+    timer.stop_and_record();
+    let result = smp.mempool.lock().evict_transaction(&hash);
+
+    if callback.send(result).is_err() {
+        warn!(LogSchema::event_log(
+            LogEntry::EvictTxn,
+            LogEvent::CallbackFail
+        ));
+        counters::CLIENT_CALLBACK_FAIL.inc();
+    }
+}
+
 /// Processes transactions from other nodes.
 pub(crate) async fn process_transaction_broadcast<V>(
     smp: SharedMempool<V>,
@@ -14,7 +14,8 @@ use aptos_config::{
 use aptos_crypto::HashValue;
 use aptos_infallible::{Mutex, RwLock};
 use aptos_types::{
-    mempool_status::MempoolStatus, transaction::SignedTransaction, vm_status::DiscardedVMStatus,
+    account_address::AccountAddress, mempool_status::MempoolStatus, transaction::SignedTransaction,
+    vm_status::DiscardedVMStatus,
 };
 use consensus_types::common::{RejectedTransactionSummary, TransactionSummary};
 use futures::{
@@ -23,6 +24,7 @@ use futures::{
     task::{Context, Poll},
 };
 use network::{application::storage::PeerMetadataStorage, transport::ConnectionMetadata};
+use peer_monitoring_service_client::PeerMonitorState;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::{
@@ -61,12 +63,14 @@ impl<V: TransactionValidation + 'static> SharedMempool<V> {
         subscribers: Vec<UnboundedSender<SharedMempoolNotification>>,
         role: RoleType,
         peer_metadata_storage: Arc<PeerMetadataStorage>,
+        peer_monitor_state: PeerMonitorState,
     ) -> Self {
         let network_interface = MempoolNetworkInterface::new(
             peer_metadata_storage,
             network_senders,
             role,
             config.clone(),
+            peer_monitor_state,
         );
         SharedMempool {
             mempool,
@@ -208,6 +212,35 @@ pub type SubmissionStatusBundle = (SignedTransaction, SubmissionStatus);
 pub enum MempoolClientRequest {
     SubmitTransaction(SignedTransaction, oneshot::Sender<Result<SubmissionStatus>>),
     GetTransactionByHash(HashValue, oneshot::Sender<Option<SignedTransaction>>),
+    /// Returns all pending transactions of the given sender, ordered by sequence number
+    GetPendingTransactionsBySender(AccountAddress, oneshot::Sender<Vec<PendingTransactionInfo>>),
+    /// Returns at most the given number of transactions in the parking lot
+    GetParkedTransactions(usize, oneshot::Sender<Vec<PendingTransactionInfo>>),
+    /// Removes the transaction with the given hash from mempool, returning it if it existed.
+    /// Following transactions of the same sender are parked.
+    EvictTransaction(HashValue, oneshot::Sender<Option<SignedTransaction>>),
+}
+
+/// Whether a pending transaction can be pulled into a block
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum PendingTransactionStatus {
+    /// All the previous transactions of the sender are committed or ready
+    Ready,
+    /// The transaction is in the parking lot, waiting for a transaction of the sender with
+    /// `missing_sequence_number` to fill the sequence number gap
+    Parked { missing_sequence_number: u64 },
+}
+
+/// A transaction sitting in mempool, along with its mempool metadata
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PendingTransactionInfo {
+    pub transaction: SignedTransaction,
+    pub ranking_score: u64,
+    /// Time at which the transaction was inserted in mempool, in microseconds since epoch
+    pub insertion_timestamp_usecs: u64,
+    /// The sequence number of the sender as known by mempool
+    pub account_sequence_number: u64,
+    pub status: PendingTransactionStatus,
 }
 
 pub type MempoolClientSender = mpsc::Sender<MempoolClientRequest>;
@@ -4,6 +4,7 @@
 use crate::tests::common::setup_mempool_with_broadcast_buckets;
 use crate::{
     core_mempool::{CoreMempool, MempoolTransaction, TimelineState},
+    shared_mempool::types::PendingTransactionStatus,
     tests::common::{add_signed_txn, add_txn, add_txns_to_mempool, setup_mempool, TestTransaction},
 };
 use aptos_config::config::NodeConfig;
@@ -144,12 +145,13 @@ fn test_update_invalid_transaction_in_mempool() {
         vec![TestTransaction::new(0, 0, 1), TestTransaction::new(1, 0, 2)],
     );
     let updated_txn = TestTransaction::make_signed_transaction_with_max_gas_amount(
-        &TestTransaction::new(0, 0, 5),
+        &TestTransaction::new(0, 0, 1),
         200,
     );
-    let _added_tnx = add_signed_txn(&mut mempool, updated_txn);
+    assert!(add_signed_txn(&mut mempool, updated_txn).is_err());
 
-    // Since both gas price and mas gas amount were updated, the ordering should not have changed.
+    // Since the max gas amount was updated without bumping the gas price, the transaction
+    // should not have been replaced and the ordering should not have changed.
     // The second transaction with gas price 2 should come first.
     assert_eq!(
         consensus.get_block(&mut mempool, 1, 1024),
@@ -160,6 +162,70 @@ fn test_update_invalid_transaction_in_mempool() {
     assert_eq!(next_tnx[0].gas_unit_price(), 1);
 }
 
+#[test]
+fn test_replace_by_fee() {
+    let mut config = NodeConfig::random();
+    config.mempool.replace_by_fee_min_bump_percentage = 10;
+    let mut pool = CoreMempool::new(&config);
+    let txns = add_txns_to_mempool(&mut pool, vec![TestTransaction::new(0, 0, 100)]);
+
+    // The gas unit price must be bumped by at least 10%.
+    let underpriced_txn = TestTransaction::make_signed_transaction_with_max_gas_amount(
+        &TestTransaction::new(0, 0, 109),
+        200,
+    );
+    assert!(add_signed_txn(&mut pool, underpriced_txn).is_err());
+    assert!(add_txn(&mut pool, TestTransaction::new(0, 0, 50)).is_err());
+
+    // A replacement with a different max gas amount is accepted with a sufficient bump.
+    let replacement_txn = TestTransaction::make_signed_transaction_with_max_gas_amount(
+        &TestTransaction::new(0, 0, 110),
+        200,
+    );
+    add_signed_txn(&mut pool, replacement_txn.clone()).unwrap();
+    assert!(pool.get_by_hash(txns[0].clone().committed_hash()).is_none());
+    assert_eq!(
+        pool.get_batch(10, 10240, HashSet::new()),
+        vec![replacement_txn]
+    );
+}
+
+#[test]
+fn test_evict_lower_priced_transactions_when_full() {
+    let mut config = NodeConfig::random();
+    config.mempool.capacity = 3;
+    let mut pool = CoreMempool::new(&config);
+    add_txns_to_mempool(
+        &mut pool,
+        vec![
+            TestTransaction::new(0, 0, 1),
+            TestTransaction::new(0, 1, 3),
+            TestTransaction::new(1, 0, 2),
+        ],
+    );
+
+    // The lowest priced transaction is evicted for a higher priced one, and the following
+    // transaction of the same account is parked.
+    add_txn(&mut pool, TestTransaction::new(2, 0, 5)).unwrap();
+    let block = pool.get_batch(10, 10240, HashSet::new());
+    let mut senders: Vec<_> = block.iter().map(|txn| txn.sender()).collect();
+    senders.sort();
+    let mut expected_senders = vec![
+        TestTransaction::get_address(1),
+        TestTransaction::get_address(2),
+    ];
+    expected_senders.sort();
+    assert_eq!(senders, expected_senders);
+    assert_eq!(pool.get_parking_lot_size(), 1);
+
+    // The parked transaction is evicted first.
+    add_txn(&mut pool, TestTransaction::new(3, 0, 1)).unwrap();
+    assert_eq!(pool.get_parking_lot_size(), 0);
+
+    // Transactions that are not priced higher than any other transaction are rejected.
+    assert!(add_txn(&mut pool, TestTransaction::new(4, 0, 1)).is_err());
+}
+
 #[test]
 fn test_commit_transaction() {
     let (mut pool, mut consensus) = setup_mempool();
@@ -696,6 +762,90 @@ fn test_get_transaction_by_hash() {
     assert!(ret.is_none());
 }
 
+#[test]
+fn test_get_pending_transactions() {
+    let mut pool = setup_mempool().0;
+    add_txns_to_mempool(
+        &mut pool,
+        vec![
+            TestTransaction::new(1, 0, 1),
+            TestTransaction::new(1, 1, 1),
+            TestTransaction::new(1, 3, 1),
+            TestTransaction::new(0, 0, 1),
+        ],
+    );
+
+    // Txn 3 is parked, waiting for txn 2.
+    let statuses = pool
+        .get_pending_transactions_by_sender(&TestTransaction::get_address(1))
+        .into_iter()
+        .map(|info| (info.transaction.sequence_number(), info.status))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        statuses,
+        vec![
+            (0, PendingTransactionStatus::Ready),
+            (1, PendingTransactionStatus::Ready),
+            (
+                3,
+                PendingTransactionStatus::Parked {
+                    missing_sequence_number: 2
+                }
+            ),
+        ]
+    );
+
+    let parked = pool.get_parked_transactions(10);
+    assert_eq!(parked.len(), 1);
+    assert_eq!(
+        parked[0].transaction.sender(),
+        TestTransaction::get_address(1)
+    );
+    assert_eq!(parked[0].transaction.sequence_number(), 3);
+    assert!(pool.get_parked_transactions(0).is_empty());
+
+    assert!(pool
+        .get_pending_transactions_by_sender(&TestTransaction::get_address(2))
+        .is_empty());
+}
+
+#[test]
+fn test_evict_transaction() {
+    let mut pool = setup_mempool().0;
+    let txns = add_txns_to_mempool(
+        &mut pool,
+        vec![
+            TestTransaction::new(1, 0, 1),
+            TestTransaction::new(1, 1, 1),
+            TestTransaction::new(1, 2, 1),
+        ],
+    );
+    let hash = txns[1].clone().committed_hash();
+
+    assert_eq!(pool.evict_transaction(&hash), Some(txns[1].clone()));
+    assert!(pool.get_by_hash(hash).is_none());
+    assert!(pool.evict_transaction(&hash).is_none());
+
+    // Txn 2 is parked since txn 1 is missing.
+    let block = pool.get_batch(10, 1024, HashSet::new());
+    assert_eq!(block, vec![txns[0].clone()]);
+    let parked = pool.get_parked_transactions(10);
+    assert_eq!(parked.len(), 1);
+    assert_eq!(parked[0].transaction, txns[2]);
+    assert_eq!(
+        parked[0].status,
+        PendingTransactionStatus::Parked {
+            missing_sequence_number: 1
+        }
+    );
+
+    // Resubmitting txn 1 makes txn 2 ready again.
+    add_signed_txn(&mut pool, txns[1].clone()).unwrap();
+    assert!(pool.get_parked_transactions(10).is_empty());
+    let (timeline, _) = pool.read_timeline(&vec![0].into(), 10);
+    assert_eq!(timeline.len(), 3);
+}
+
 #[test]
 fn test_get_transaction_by_hash_after_the_txn_is_updated() {
     let mut pool = setup_mempool().0;
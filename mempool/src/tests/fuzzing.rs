@@ -9,6 +9,7 @@ use aptos_config::{config::NodeConfig, network_id::NetworkId};
 use aptos_infallible::{Mutex, RwLock};
 use aptos_types::transaction::SignedTransaction;
 use network::application::storage::PeerMetadataStorage;
+use peer_monitoring_service_client::PeerMonitorState;
 use proptest::{
     arbitrary::any,
     prelude::*,
@@ -45,6 +46,7 @@ pub fn test_mempool_process_incoming_transactions_impl(
         vec![],
         config.base.role,
         PeerMetadataStorage::new(&[NetworkId::Validator]),
+        PeerMonitorState::new(),
     );
 
     let _ = tasks::process_incoming_transactions(&smp, txns, timeline_state);
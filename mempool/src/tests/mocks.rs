@@ -26,6 +26,7 @@ use network::{
     peer_manager::{conn_notifs_channel, ConnectionRequestSender, PeerManagerRequestSender},
     protocols::network::{NewNetworkEvents, NewNetworkSender},
 };
+use peer_monitoring_service_client::PeerMonitorState;
 use std::{collections::HashSet, sync::Arc};
 use storage_interface::{mock::MockDbReaderWriter, DbReaderWriter};
 use tokio::runtime::{Builder, Handle, Runtime};
@@ -136,6 +137,7 @@ impl MockSharedMempool {
             Arc::new(RwLock::new(validator)),
             vec![],
             peer_metadata_storage,
+            PeerMonitorState::new(),
         );
 
         (ac_client, mempool, quorum_store_sender, mempool_notifier)
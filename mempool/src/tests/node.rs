@@ -36,6 +36,7 @@ use network::{
     transport::ConnectionMetadata,
     ProtocolId,
 };
+use peer_monitoring_service_client::PeerMonitorState;
 use rand::rngs::StdRng;
 use std::{
     collections::{HashMap, HashSet},
@@ -597,6 +598,7 @@ fn start_node_mempool(
         Arc::new(RwLock::new(MockVMValidator)),
         vec![sender],
         peer_metadata_storage,
+        PeerMonitorState::new(),
     );
 
     (mempool, runtime, subscriber)
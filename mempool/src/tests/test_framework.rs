@@ -37,6 +37,7 @@ use network::{
     },
     ProtocolId,
 };
+use peer_monitoring_service_client::PeerMonitorState;
 use std::{
     collections::{HashMap, HashSet},
     sync::Arc,
@@ -507,6 +508,7 @@ fn setup_mempool(
         vm_validator,
         vec![sender],
         peer_metadata_storage,
+        PeerMonitorState::new(),
     );
 
     (
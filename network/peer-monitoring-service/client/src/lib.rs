@@ -24,6 +24,15 @@ use peer_monitoring_service_types::{
 use std::{sync::Arc, time::Duration};
 use thiserror::Error;
 
+mod metrics;
+pub mod peer_monitor;
+pub mod peer_states;
+#[cfg(test)]
+mod tests;
+
+pub use peer_monitor::PeerMonitor;
+pub use peer_states::{PeerMonitorState, PeerMonitoringMetadata};
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Aptos network rpc error: {0}")]
@@ -31,6 +40,9 @@ pub enum Error {
 
     #[error("Error from remote monitoring service: {0}")]
     PeerMonitoringServiceError(#[from] PeerMonitoringServiceError),
+
+    #[error("Unexpected response received: {0}")]
+    UnexpectedResponse(String),
 }
 
 /// The interface for sending peer monitoring service requests and querying
@@ -0,0 +1,42 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_config::network_id::PeerNetworkId;
+use aptos_metrics_core::{
+    register_histogram_vec, register_int_counter_vec, HistogramVec, IntCounterVec,
+};
+use once_cell::sync::Lazy;
+
+/// Counter for the peer monitoring request errors (client-side)
+pub static PEER_MONITORING_REQUEST_ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_peer_monitoring_service_client_request_errors",
+        "Counters related to the peer monitoring client request errors",
+        &["request_type", "network"]
+    )
+    .unwrap()
+});
+
+/// Histogram for the ping latencies observed by the peer monitor
+pub static PEER_PING_LATENCIES: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "aptos_peer_monitoring_service_client_ping_latencies",
+        "Histogram of the ping latencies (secs) observed by the peer monitor",
+        &["network"]
+    )
+    .unwrap()
+});
+
+/// Increments the given request counter with the provided values
+pub fn increment_request_counter(counter: &IntCounterVec, label: &str, peer: &PeerNetworkId) {
+    let network = peer.network_id();
+    counter.with_label_values(&[label, network.as_str()]).inc();
+}
+
+/// Observes the ping latency (secs) for the given peer
+pub fn observe_ping_latency(peer: &PeerNetworkId, latency_secs: f64) {
+    let network = peer.network_id();
+    PEER_PING_LATENCIES
+        .with_label_values(&[network.as_str()])
+        .observe(latency_secs);
+}
@@ -0,0 +1,186 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{metrics, peer_states::PeerMonitorState, Error, PeerMonitoringServiceClient};
+use aptos_config::{config::PeerMonitoringServiceConfig, network_id::PeerNetworkId};
+use aptos_logger::prelude::*;
+use aptos_time_service::{TimeService, TimeServiceTrait};
+use futures::StreamExt;
+use network::{application::interface::NetworkInterface, ProtocolId};
+use peer_monitoring_service_types::{PeerMonitoringServiceRequest, PeerMonitoringServiceResponse};
+use std::{collections::HashSet, time::Duration};
+use tokio::runtime::Handle;
+
+// Useful constants for the peer monitor
+const PEER_MONITOR_LOG_FREQ_SECS: u64 = 10;
+
+/// The peer monitor periodically polls all connected peers (that support
+/// the peer monitoring service) for their latency and depth from the
+/// validators, and stores the results in the shared `PeerMonitorState`.
+pub struct PeerMonitor {
+    config: PeerMonitoringServiceConfig,
+    network_client: PeerMonitoringServiceClient,
+    peer_monitor_state: PeerMonitorState,
+    runtime: Option<Handle>, // An optional runtime on which to spawn the request tasks
+    time_service: TimeService,
+}
+
+impl PeerMonitor {
+    pub fn new(
+        config: PeerMonitoringServiceConfig,
+        network_client: PeerMonitoringServiceClient,
+        peer_monitor_state: PeerMonitorState,
+        runtime: Option<Handle>,
+        time_service: TimeService,
+    ) -> Self {
+        Self {
+            config,
+            network_client,
+            peer_monitor_state,
+            runtime,
+            time_service,
+        }
+    }
+
+    /// Runs the peer monitor that continuously polls the connected peers
+    pub async fn start(self) {
+        info!("Starting the peer monitor!");
+        let ticker = self
+            .time_service
+            .interval(Duration::from_millis(self.config.peer_monitor_interval_ms));
+        futures::pin_mut!(ticker);
+
+        loop {
+            // Wait for the next round before polling
+            ticker.next().await;
+
+            // Remove the states of any disconnected peers
+            let connected_peers = self.get_connected_peers();
+            self.peer_monitor_state.garbage_collect(&connected_peers);
+
+            // Send the requests that are due to each peer
+            for peer in connected_peers {
+                self.send_request_if_due(
+                    peer,
+                    PeerMonitoringServiceRequest::Ping,
+                    self.config.latency_ping_interval_ms,
+                );
+                self.send_request_if_due(
+                    peer,
+                    PeerMonitoringServiceRequest::GetDepthFromValidators,
+                    self.config.depth_from_validators_interval_ms,
+                );
+            }
+        }
+    }
+
+    /// Returns all connected peers that support the peer monitoring service
+    fn get_connected_peers(&self) -> HashSet<PeerNetworkId> {
+        let peer_metadata_storage = self.network_client.peer_metadata_storage();
+        peer_metadata_storage
+            .networks()
+            .flat_map(|network_id| self.network_client.connected_peers(network_id))
+            .filter(|(_, peer_info)| {
+                peer_info.supports_protocol(ProtocolId::PeerMonitoringServiceRpc)
+            })
+            .map(|(peer, _)| peer)
+            .collect()
+    }
+
+    /// Spawns a task to send the request to the peer if the request is due
+    fn send_request_if_due(
+        &self,
+        peer: PeerNetworkId,
+        request: PeerMonitoringServiceRequest,
+        request_interval_ms: u64,
+    ) {
+        let now = self.time_service.now();
+        if !self.peer_monitor_state.is_request_due(
+            &peer,
+            &request,
+            now,
+            Duration::from_millis(request_interval_ms),
+        ) {
+            return;
+        }
+
+        // Mark the request as started. We do this here to prevent
+        // the next monitoring round from sending the same request.
+        self.peer_monitor_state
+            .request_started(peer, request.clone(), now);
+
+        // Create the request task
+        let config = self.config.clone();
+        let network_client = self.network_client.clone();
+        let peer_monitor_state = self.peer_monitor_state.clone();
+        let time_service = self.time_service.clone();
+        let request_task = async move {
+            let request_timeout = Duration::from_millis(config.request_timeout_ms);
+            let start_time = time_service.now();
+            let result = network_client
+                .send_request(peer, request.clone(), request_timeout)
+                .await;
+            let latency = time_service.now().duration_since(start_time);
+
+            // Handle the response
+            let result = result.and_then(|response| {
+                handle_response(&config, &peer_monitor_state, &peer, response, latency)
+            });
+            let num_consecutive_failures =
+                peer_monitor_state.request_completed(&peer, &request, result.is_ok());
+            if let Err(error) = result {
+                sample!(
+                    SampleRate::Duration(Duration::from_secs(PEER_MONITOR_LOG_FREQ_SECS)),
+                    warn!(
+                        "Peer monitoring request {:?} to peer {:?} failed! Error: {:?}. Consecutive failures: {}",
+                        request, peer, error, num_consecutive_failures
+                    );
+                );
+                metrics::increment_request_counter(
+                    &metrics::PEER_MONITORING_REQUEST_ERRORS,
+                    request.get_label(),
+                    &peer,
+                );
+            }
+        };
+
+        // Spawn the request task
+        if let Some(runtime) = &self.runtime {
+            runtime.spawn(request_task);
+        } else {
+            tokio::spawn(request_task);
+        }
+    }
+}
+
+/// Processes the response from the peer and updates the peer monitor state
+fn handle_response(
+    config: &PeerMonitoringServiceConfig,
+    peer_monitor_state: &PeerMonitorState,
+    peer: &PeerNetworkId,
+    response: PeerMonitoringServiceResponse,
+    latency: Duration,
+) -> Result<(), Error> {
+    match response {
+        PeerMonitoringServiceResponse::Ping(response) => {
+            debug!(
+                "Received ping response from peer {:?} (server time: {:?})",
+                peer, response.server_timestamp_usecs
+            );
+            let latency_secs = latency.as_secs_f64();
+            peer_monitor_state.record_ping_latency(
+                peer,
+                latency_secs,
+                config.max_num_latency_pings_to_retain,
+            );
+            metrics::observe_ping_latency(peer, latency_secs);
+        }
+        PeerMonitoringServiceResponse::DepthFromValidators(response) => {
+            peer_monitor_state.record_depth_from_validators(peer, response.depth_from_validators);
+        }
+        response => {
+            return Err(Error::UnexpectedResponse(response.get_label().into()));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,187 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_config::network_id::PeerNetworkId;
+use aptos_infallible::RwLock;
+use peer_monitoring_service_types::PeerMonitoringServiceRequest;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// The monitoring metadata of a peer, as observed by the peer monitor.
+/// This is exposed to other components (e.g., state sync and mempool)
+/// to help with peer selection.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PeerMonitoringMetadata {
+    pub average_ping_latency_secs: Option<f64>, // The average latency of the recent pings
+    pub depth_from_validators: Option<u64>,     // The depth of the peer from the validators
+}
+
+/// Tracks the requests of a single type sent to a peer
+#[derive(Clone, Debug, Default)]
+struct RequestTracker {
+    in_flight: bool,                    // If a request is currently in-flight
+    last_request_time: Option<Instant>, // The time at which the last request was sent
+    num_consecutive_failures: u64,      // The number of consecutive request failures
+}
+
+impl RequestTracker {
+    /// Returns true iff a new request should be sent to the peer
+    fn is_request_due(&self, now: Instant, request_interval: Duration) -> bool {
+        if self.in_flight {
+            return false;
+        }
+        match self.last_request_time {
+            Some(last_request_time) => now.duration_since(last_request_time) >= request_interval,
+            None => true,
+        }
+    }
+}
+
+/// The monitoring state of a single peer
+#[derive(Clone, Debug, Default)]
+struct PeerState {
+    depth_from_validators: Option<u64>,
+    recent_ping_latencies_secs: VecDeque<f64>,
+    request_trackers: HashMap<PeerMonitoringServiceRequest, RequestTracker>,
+}
+
+impl PeerState {
+    fn get_metadata(&self) -> PeerMonitoringMetadata {
+        let num_pings = self.recent_ping_latencies_secs.len();
+        let average_ping_latency_secs = if num_pings > 0 {
+            Some(self.recent_ping_latencies_secs.iter().sum::<f64>() / num_pings as f64)
+        } else {
+            None
+        };
+        PeerMonitoringMetadata {
+            average_ping_latency_secs,
+            depth_from_validators: self.depth_from_validators,
+        }
+    }
+}
+
+/// The shared monitoring state of all peers. The state is updated by
+/// the peer monitor and can be cloned and read by any component.
+#[derive(Clone, Debug, Default)]
+pub struct PeerMonitorState {
+    peer_states: Arc<RwLock<HashMap<PeerNetworkId, PeerState>>>,
+}
+
+impl PeerMonitorState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the monitoring metadata for the given peer (if any)
+    pub fn get_peer_metadata(&self, peer: &PeerNetworkId) -> Option<PeerMonitoringMetadata> {
+        self.peer_states
+            .read()
+            .get(peer)
+            .map(PeerState::get_metadata)
+    }
+
+    /// Returns the monitoring metadata for all monitored peers
+    pub fn get_all_peer_metadata(&self) -> HashMap<PeerNetworkId, PeerMonitoringMetadata> {
+        self.peer_states
+            .read()
+            .iter()
+            .map(|(peer, peer_state)| (*peer, peer_state.get_metadata()))
+            .collect()
+    }
+
+    /// Returns true iff the given request should be sent to the peer
+    pub(crate) fn is_request_due(
+        &self,
+        peer: &PeerNetworkId,
+        request: &PeerMonitoringServiceRequest,
+        now: Instant,
+        request_interval: Duration,
+    ) -> bool {
+        self.peer_states
+            .read()
+            .get(peer)
+            .and_then(|peer_state| peer_state.request_trackers.get(request))
+            .map_or(true, |tracker| {
+                tracker.is_request_due(now, request_interval)
+            })
+    }
+
+    /// Marks the given request to the peer as started
+    pub(crate) fn request_started(
+        &self,
+        peer: PeerNetworkId,
+        request: PeerMonitoringServiceRequest,
+        now: Instant,
+    ) {
+        let mut peer_states = self.peer_states.write();
+        let tracker = peer_states
+            .entry(peer)
+            .or_default()
+            .request_trackers
+            .entry(request)
+            .or_default();
+        tracker.in_flight = true;
+        tracker.last_request_time = Some(now);
+    }
+
+    /// Marks the given request to the peer as completed
+    pub(crate) fn request_completed(
+        &self,
+        peer: &PeerNetworkId,
+        request: &PeerMonitoringServiceRequest,
+        success: bool,
+    ) -> u64 {
+        let mut peer_states = self.peer_states.write();
+        let tracker = match peer_states
+            .get_mut(peer)
+            .and_then(|peer_state| peer_state.request_trackers.get_mut(request))
+        {
+            Some(tracker) => tracker,
+            None => return 0, // The peer has been garbage collected
+        };
+        tracker.in_flight = false;
+        if success {
+            tracker.num_consecutive_failures = 0;
+        } else {
+            tracker.num_consecutive_failures += 1;
+        }
+        tracker.num_consecutive_failures
+    }
+
+    /// Records a new ping latency for the peer, retaining only the most recent pings
+    pub(crate) fn record_ping_latency(
+        &self,
+        peer: &PeerNetworkId,
+        latency_secs: f64,
+        max_num_latency_pings_to_retain: u64,
+    ) {
+        if let Some(peer_state) = self.peer_states.write().get_mut(peer) {
+            let recent_pings = &mut peer_state.recent_ping_latencies_secs;
+            recent_pings.push_back(latency_secs);
+            while recent_pings.len() as u64 > max_num_latency_pings_to_retain {
+                recent_pings.pop_front();
+            }
+        }
+    }
+
+    /// Records the depth from the validators reported by the peer
+    pub(crate) fn record_depth_from_validators(
+        &self,
+        peer: &PeerNetworkId,
+        depth_from_validators: Option<u64>,
+    ) {
+        if let Some(peer_state) = self.peer_states.write().get_mut(peer) {
+            peer_state.depth_from_validators = depth_from_validators;
+        }
+    }
+
+    /// Removes the states of all peers that are no longer connected
+    pub(crate) fn garbage_collect(&self, connected_peers: &HashSet<PeerNetworkId>) {
+        self.peer_states
+            .write()
+            .retain(|peer, _| connected_peers.contains(peer));
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::peer_states::{PeerMonitorState, PeerMonitoringMetadata};
+use aptos_config::network_id::{NetworkId, PeerNetworkId};
+use aptos_types::PeerId;
+use peer_monitoring_service_types::PeerMonitoringServiceRequest;
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+#[test]
+fn test_request_scheduling() {
+    // Create the peer monitor state
+    let peer_monitor_state = PeerMonitorState::new();
+    let peer = PeerNetworkId::new(NetworkId::Public, PeerId::random());
+    let request = PeerMonitoringServiceRequest::Ping;
+    let request_interval = Duration::from_secs(10);
+
+    // Verify the first request is always due
+    let now = Instant::now();
+    assert!(peer_monitor_state.is_request_due(&peer, &request, now, request_interval));
+
+    // Start the request and verify it's no longer due (while in-flight)
+    peer_monitor_state.request_started(peer, request.clone(), now);
+    let later = now + request_interval;
+    assert!(!peer_monitor_state.is_request_due(&peer, &request, later, request_interval));
+
+    // Verify other request types are still due
+    let depth_request = PeerMonitoringServiceRequest::GetDepthFromValidators;
+    assert!(peer_monitor_state.is_request_due(&peer, &depth_request, now, request_interval));
+
+    // Complete the request and verify it's due only after the interval
+    assert_eq!(
+        peer_monitor_state.request_completed(&peer, &request, false),
+        1
+    );
+    assert!(!peer_monitor_state.is_request_due(
+        &peer,
+        &request,
+        now + Duration::from_secs(9),
+        request_interval
+    ));
+    assert!(peer_monitor_state.is_request_due(&peer, &request, later, request_interval));
+
+    // Verify the failure count is reset on success
+    peer_monitor_state.request_started(peer, request.clone(), later);
+    assert_eq!(
+        peer_monitor_state.request_completed(&peer, &request, false),
+        2
+    );
+    peer_monitor_state.request_started(peer, request.clone(), later);
+    assert_eq!(
+        peer_monitor_state.request_completed(&peer, &request, true),
+        0
+    );
+}
+
+#[test]
+fn test_peer_metadata() {
+    // Create the peer monitor state
+    let peer_monitor_state = PeerMonitorState::new();
+    let peer = PeerNetworkId::new(NetworkId::Public, PeerId::random());
+
+    // Verify there's no metadata for the peer
+    assert_eq!(peer_monitor_state.get_peer_metadata(&peer), None);
+
+    // Start a request and verify the metadata is empty
+    peer_monitor_state.request_started(peer, PeerMonitoringServiceRequest::Ping, Instant::now());
+    assert_eq!(
+        peer_monitor_state.get_peer_metadata(&peer),
+        Some(PeerMonitoringMetadata::default())
+    );
+
+    // Record several pings and verify only the most recent ones are averaged
+    let max_num_latency_pings_to_retain = 3;
+    for latency_secs in [10.0, 1.0, 2.0, 3.0] {
+        peer_monitor_state.record_ping_latency(
+            &peer,
+            latency_secs,
+            max_num_latency_pings_to_retain,
+        );
+    }
+    peer_monitor_state.record_depth_from_validators(&peer, Some(2));
+    let expected_metadata = PeerMonitoringMetadata {
+        average_ping_latency_secs: Some(2.0),
+        depth_from_validators: Some(2),
+    };
+    assert_eq!(
+        peer_monitor_state.get_peer_metadata(&peer),
+        Some(expected_metadata.clone())
+    );
+    assert_eq!(
+        peer_monitor_state.get_all_peer_metadata().get(&peer),
+        Some(&expected_metadata)
+    );
+}
+
+#[test]
+fn test_garbage_collection() {
+    // Create the peer monitor state with several peers
+    let peer_monitor_state = PeerMonitorState::new();
+    let peers: Vec<_> = (0..3)
+        .map(|_| PeerNetworkId::new(NetworkId::Vfn, PeerId::random()))
+        .collect();
+    for peer in &peers {
+        peer_monitor_state.request_started(
+            *peer,
+            PeerMonitoringServiceRequest::Ping,
+            Instant::now(),
+        );
+    }
+
+    // Disconnect one of the peers and verify its state is removed
+    let connected_peers: HashSet<_> = peers[1..].iter().cloned().collect();
+    peer_monitor_state.garbage_collect(&connected_peers);
+    assert!(peer_monitor_state.get_peer_metadata(&peers[0]).is_none());
+    for peer in &peers[1..] {
+        assert!(peer_monitor_state.get_peer_metadata(peer).is_some());
+    }
+}
@@ -9,16 +9,21 @@ use crate::{
     network::PeerMonitoringServiceNetworkEvents,
 };
 use ::network::{application::storage::PeerMetadataStorage, ProtocolId};
-use aptos_config::config::PeerMonitoringServiceConfig;
+use aptos_config::config::{PeerMonitoringServiceConfig, PeerRole};
 use aptos_logger::prelude::*;
+use aptos_time_service::{TimeService, TimeServiceTrait};
 use bounded_executor::BoundedExecutor;
 use futures::stream::StreamExt;
 use peer_monitoring_service_types::{
-    ConnectedPeersResponse, PeerMonitoringServiceError, PeerMonitoringServiceRequest,
-    PeerMonitoringServiceResponse, Result, ServerProtocolVersionResponse,
+    ConnectedPeersResponse, DepthFromValidatorsResponse, KnownPeersResponse,
+    PeerMonitoringServiceError, PeerMonitoringServiceRequest, PeerMonitoringServiceResponse,
+    PingResponse, Result, ServerProtocolVersionResponse, ValidatorsAndVFNsResponse,
 };
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 use thiserror::Error;
 use tokio::runtime::Handle;
 
@@ -55,6 +60,7 @@ pub struct PeerMonitoringServiceServer {
     bounded_executor: BoundedExecutor,
     network_requests: PeerMonitoringServiceNetworkEvents,
     peer_metadata: Arc<PeerMetadataStorage>,
+    time_service: TimeService,
 }
 
 impl PeerMonitoringServiceServer {
@@ -63,6 +69,7 @@ impl PeerMonitoringServiceServer {
         executor: Handle,
         network_requests: PeerMonitoringServiceNetworkEvents,
         peer_metadata: Arc<PeerMetadataStorage>,
+        time_service: TimeService,
     ) -> Self {
         let bounded_executor =
             BoundedExecutor::new(config.max_concurrent_requests as usize, executor);
@@ -71,6 +78,7 @@ impl PeerMonitoringServiceServer {
             bounded_executor,
             network_requests,
             peer_metadata,
+            time_service,
         }
     }
 
@@ -90,9 +98,11 @@ impl PeerMonitoringServiceServer {
             // All handler methods are currently CPU-bound so we want
             // to spawn on the blocking thread pool.
             let peer_metadata = self.peer_metadata.clone();
+            let time_service = self.time_service.clone();
             self.bounded_executor
                 .spawn_blocking(move || {
-                    let response = Handler::new(peer_metadata).call(protocol, request);
+                    let response =
+                        Handler::new(peer_metadata, time_service).call(protocol, request);
                     log_monitoring_service_response(&response);
                     response_sender.send(response);
                 })
@@ -107,11 +117,15 @@ impl PeerMonitoringServiceServer {
 #[derive(Clone)]
 pub struct Handler {
     peer_metadata: Arc<PeerMetadataStorage>,
+    time_service: TimeService,
 }
 
 impl Handler {
-    pub fn new(peer_metadata: Arc<PeerMetadataStorage>) -> Self {
-        Self { peer_metadata }
+    pub fn new(peer_metadata: Arc<PeerMetadataStorage>, time_service: TimeService) -> Self {
+        Self {
+            peer_metadata,
+            time_service,
+        }
     }
 
     pub fn call(
@@ -198,11 +212,45 @@ impl Handler {
     }
 
     fn get_depth_from_validators(&self) -> Result<PeerMonitoringServiceResponse, Error> {
-        unimplemented!();
+        // Validators are the only nodes running the validator network
+        let depth_from_validators = if self
+            .peer_metadata
+            .networks()
+            .any(|network_id| network_id.is_validator_network())
+        {
+            Some(0)
+        } else {
+            // Otherwise, derive the depth from the roles of the connected peers
+            self.peer_metadata
+                .networks()
+                .flat_map(|network| self.peer_metadata.read_all(network).into_values())
+                .filter(|peer_info| peer_info.is_connected())
+                .filter_map(|peer_info| match peer_info.active_connection.role {
+                    PeerRole::Validator => Some(1),
+                    PeerRole::ValidatorFullNode => Some(2),
+                    _ => None, // The depth of the other peers is unknown
+                })
+                .min()
+        };
+
+        Ok(PeerMonitoringServiceResponse::DepthFromValidators(
+            DepthFromValidatorsResponse {
+                depth_from_validators,
+            },
+        ))
     }
 
     fn get_known_peers(&self) -> Result<PeerMonitoringServiceResponse, Error> {
-        unimplemented!();
+        // Fetch all known peers (regardless of their connection state)
+        let mut known_peers = HashSet::new();
+        for network in self.peer_metadata.networks() {
+            known_peers.extend(self.peer_metadata.keys(network));
+        }
+
+        // Return the known peers
+        Ok(PeerMonitoringServiceResponse::KnownPeers(
+            KnownPeersResponse { known_peers },
+        ))
     }
 
     fn get_server_protocol_version(&self) -> Result<PeerMonitoringServiceResponse, Error> {
@@ -214,11 +262,37 @@ impl Handler {
     }
 
     fn get_validators_and_vfns(&self) -> Result<PeerMonitoringServiceResponse, Error> {
-        unimplemented!();
+        // Fetch all connected validators and VFNs
+        let mut validators = HashSet::new();
+        let mut vfns = HashSet::new();
+        for network in self.peer_metadata.networks() {
+            for (peer_network_id, peer_info) in self.peer_metadata.read_all(network) {
+                if !peer_info.is_connected() {
+                    continue;
+                }
+                match peer_info.active_connection.role {
+                    PeerRole::Validator => {
+                        validators.insert(peer_network_id);
+                    }
+                    PeerRole::ValidatorFullNode => {
+                        vfns.insert(peer_network_id);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Return the validators and VFNs
+        Ok(PeerMonitoringServiceResponse::ValidatorsAndVFNs(
+            ValidatorsAndVFNsResponse { validators, vfns },
+        ))
     }
 
     fn handle_ping(&self) -> Result<PeerMonitoringServiceResponse, Error> {
-        unimplemented!();
+        let server_timestamp_usecs = self.time_service.now_unix_time().as_micros() as u64;
+        Ok(PeerMonitoringServiceResponse::Ping(PingResponse {
+            server_timestamp_usecs,
+        }))
     }
 }
 
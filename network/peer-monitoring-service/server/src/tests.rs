@@ -11,6 +11,7 @@ use aptos_config::{
     network_id::{NetworkId, PeerNetworkId},
 };
 use aptos_logger::Level;
+use aptos_time_service::{MockTimeService, TimeService, TimeServiceTrait};
 use aptos_types::{network_address::NetworkAddress, PeerId};
 use channel::aptos_channel;
 use futures::channel::oneshot;
@@ -29,19 +30,22 @@ use network::{
     transport::{ConnectionId, ConnectionMetadata},
 };
 use peer_monitoring_service_types::{
-    ConnectedPeersResponse, PeerMonitoringServiceError, PeerMonitoringServiceMessage,
-    PeerMonitoringServiceRequest, PeerMonitoringServiceResponse, ServerProtocolVersionResponse,
+    ConnectedPeersResponse, DepthFromValidatorsResponse, KnownPeersResponse,
+    PeerMonitoringServiceError, PeerMonitoringServiceMessage, PeerMonitoringServiceRequest,
+    PeerMonitoringServiceResponse, PingResponse, ServerProtocolVersionResponse,
+    ValidatorsAndVFNsResponse,
 };
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    collections::{hash_map::Entry, HashMap, HashSet},
     str::FromStr,
     sync::Arc,
+    time::Duration,
 };
 
 #[tokio::test]
 async fn test_get_server_protocol_version() {
     // Create the peer monitoring client and server
-    let (mut mock_client, service, _) = MockClient::new();
+    let (mut mock_client, service, _, _) = MockClient::new();
     tokio::spawn(service.start());
 
     // Process a request to fetch the protocol version
@@ -59,7 +63,7 @@ async fn test_get_server_protocol_version() {
 #[tokio::test]
 async fn test_get_connected_peers() {
     // Create the peer monitoring client and server
-    let (mut mock_client, service, peer_metadata_storage) = MockClient::new();
+    let (mut mock_client, service, peer_metadata_storage, _) = MockClient::new();
     tokio::spawn(service.start());
 
     // Process a request to fetch the connected peers
@@ -73,19 +77,11 @@ async fn test_get_connected_peers() {
     assert_eq!(response, expected_response);
 
     // Update the connected peers with a new peer
-    let peer_id = PeerId::random();
-    let peer_network_id = PeerNetworkId::new(NetworkId::Validator, peer_id);
-    let connection_metadata = ConnectionMetadata::new(
-        peer_id,
-        ConnectionId::default(),
-        NetworkAddress::from_str("/ip4/127.0.0.1/tcp/8081").unwrap(),
-        ConnectionOrigin::Inbound,
-        MessagingProtocolVersion::V1,
-        ProtocolIdSet::empty(),
+    let peer_network_id = insert_connected_peer(
+        &peer_metadata_storage,
+        NetworkId::Validator,
         PeerRole::Unknown,
     );
-    let peer_info = PeerInfo::new(connection_metadata);
-    peer_metadata_storage.insert(peer_network_id, peer_info);
 
     // Process a request to fetch the connected peers
     let request = PeerMonitoringServiceRequest::GetConnectedPeers;
@@ -102,15 +98,7 @@ async fn test_get_connected_peers() {
     assert_eq!(response, expected_response);
 
     // Disconnect the peer
-    peer_metadata_storage
-        .write(peer_network_id, |entry| match entry {
-            Entry::Vacant(..) => Err(PeerError::NotFound),
-            Entry::Occupied(inner) => {
-                inner.get_mut().status = PeerState::Disconnected;
-                Ok(())
-            }
-        })
-        .unwrap();
+    disconnect_peer(&peer_metadata_storage, peer_network_id);
 
     // Process a request to fetch the connected peers
     let request = PeerMonitoringServiceRequest::GetConnectedPeers;
@@ -123,6 +111,130 @@ async fn test_get_connected_peers() {
     assert_eq!(response, expected_response);
 }
 
+#[tokio::test]
+async fn test_get_depth_from_validators() {
+    // Create the peer monitoring client and server for a validator
+    let (mut mock_client, service, _, _) = MockClient::new();
+    tokio::spawn(service.start());
+
+    // Verify that validators are at depth 0
+    let request = PeerMonitoringServiceRequest::GetDepthFromValidators;
+    let response = mock_client.send_request(request).await.unwrap();
+    assert_eq!(response, create_depth_response(Some(0)));
+
+    // Create the peer monitoring client and server for a fullnode
+    let (mut mock_client, service, peer_metadata_storage, _) =
+        MockClient::new_with_networks(&[NetworkId::Vfn, NetworkId::Public]);
+    tokio::spawn(service.start());
+
+    // Verify the depth is unknown without any connected upstream peers
+    let request = PeerMonitoringServiceRequest::GetDepthFromValidators;
+    let response = mock_client.send_request(request).await.unwrap();
+    assert_eq!(response, create_depth_response(None));
+
+    // Connect to a VFN and verify the depth is updated
+    insert_connected_peer(
+        &peer_metadata_storage,
+        NetworkId::Public,
+        PeerRole::ValidatorFullNode,
+    );
+    let request = PeerMonitoringServiceRequest::GetDepthFromValidators;
+    let response = mock_client.send_request(request).await.unwrap();
+    assert_eq!(response, create_depth_response(Some(2)));
+
+    // Connect to a validator and verify the depth is updated
+    let validator =
+        insert_connected_peer(&peer_metadata_storage, NetworkId::Vfn, PeerRole::Validator);
+    let request = PeerMonitoringServiceRequest::GetDepthFromValidators;
+    let response = mock_client.send_request(request).await.unwrap();
+    assert_eq!(response, create_depth_response(Some(1)));
+
+    // Disconnect the validator and verify the depth is updated
+    disconnect_peer(&peer_metadata_storage, validator);
+    let request = PeerMonitoringServiceRequest::GetDepthFromValidators;
+    let response = mock_client.send_request(request).await.unwrap();
+    assert_eq!(response, create_depth_response(Some(2)));
+}
+
+#[tokio::test]
+async fn test_get_known_peers() {
+    // Create the peer monitoring client and server
+    let (mut mock_client, service, peer_metadata_storage, _) = MockClient::new();
+    tokio::spawn(service.start());
+
+    // Insert several peers and disconnect one of them
+    let mut known_peers = HashSet::new();
+    for _ in 0..3 {
+        known_peers.insert(insert_connected_peer(
+            &peer_metadata_storage,
+            NetworkId::Validator,
+            PeerRole::Validator,
+        ));
+    }
+    disconnect_peer(&peer_metadata_storage, *known_peers.iter().next().unwrap());
+
+    // Process a request to fetch the known peers
+    let request = PeerMonitoringServiceRequest::GetKnownPeers;
+    let response = mock_client.send_request(request).await.unwrap();
+
+    // Verify the response contains all peers (including the disconnected one)
+    let expected_response =
+        PeerMonitoringServiceResponse::KnownPeers(KnownPeersResponse { known_peers });
+    assert_eq!(response, expected_response);
+}
+
+#[tokio::test]
+async fn test_get_validators_and_vfns() {
+    // Create the peer monitoring client and server
+    let (mut mock_client, service, peer_metadata_storage, _) =
+        MockClient::new_with_networks(&[NetworkId::Vfn, NetworkId::Public]);
+    tokio::spawn(service.start());
+
+    // Insert a validator, a VFN and a regular peer
+    let validator =
+        insert_connected_peer(&peer_metadata_storage, NetworkId::Vfn, PeerRole::Validator);
+    let vfn = insert_connected_peer(
+        &peer_metadata_storage,
+        NetworkId::Public,
+        PeerRole::ValidatorFullNode,
+    );
+    insert_connected_peer(&peer_metadata_storage, NetworkId::Public, PeerRole::Unknown);
+
+    // Process a request to fetch the validators and VFNs
+    let request = PeerMonitoringServiceRequest::GetValidatorsAndVFNs;
+    let response = mock_client.send_request(request).await.unwrap();
+
+    // Verify the response is correct
+    let expected_response =
+        PeerMonitoringServiceResponse::ValidatorsAndVFNs(ValidatorsAndVFNsResponse {
+            validators: HashSet::from([validator]),
+            vfns: HashSet::from([vfn]),
+        });
+    assert_eq!(response, expected_response);
+}
+
+#[tokio::test]
+async fn test_ping() {
+    // Create the peer monitoring client and server
+    let (mut mock_client, service, _, time_service) = MockClient::new();
+    tokio::spawn(service.start());
+
+    for _ in 0..3 {
+        // Process a ping request
+        let request = PeerMonitoringServiceRequest::Ping;
+        let response = mock_client.send_request(request).await.unwrap();
+
+        // Verify the response contains the current server time
+        let expected_response = PeerMonitoringServiceResponse::Ping(PingResponse {
+            server_timestamp_usecs: time_service.now_unix_time().as_micros() as u64,
+        });
+        assert_eq!(response, expected_response);
+
+        // Elapse some time
+        time_service.advance(Duration::from_secs(1));
+    }
+}
+
 /// A wrapper around the inbound network interface/channel for easily sending
 /// mock client requests to a [`PeerMonitoringServiceServer`].
 struct MockClient {
@@ -130,7 +242,23 @@ struct MockClient {
 }
 
 impl MockClient {
-    fn new() -> (Self, PeerMonitoringServiceServer, Arc<PeerMetadataStorage>) {
+    fn new() -> (
+        Self,
+        PeerMonitoringServiceServer,
+        Arc<PeerMetadataStorage>,
+        MockTimeService,
+    ) {
+        Self::new_with_networks(&[NetworkId::Validator])
+    }
+
+    fn new_with_networks(
+        network_ids: &[NetworkId],
+    ) -> (
+        Self,
+        PeerMonitoringServiceServer,
+        Arc<PeerMetadataStorage>,
+        MockTimeService,
+    ) {
         initialize_logger();
 
         // Create the peer monitoring service event stream
@@ -149,13 +277,15 @@ impl MockClient {
         );
 
         // Create the peer monitoring server
-        let peer_metadata_storage = PeerMetadataStorage::new(&[NetworkId::Validator]);
+        let peer_metadata_storage = PeerMetadataStorage::new(network_ids);
         let executor = tokio::runtime::Handle::current();
+        let time_service = TimeService::mock();
         let peer_monitoring_server = PeerMonitoringServiceServer::new(
             peer_monitoring_service_config,
             executor,
             network_request_stream,
             peer_metadata_storage.clone(),
+            time_service.clone(),
         );
 
         // Create the mock client
@@ -164,7 +294,12 @@ impl MockClient {
         };
 
         // Return the client and server
-        (mock_client, peer_monitoring_server, peer_metadata_storage)
+        (
+            mock_client,
+            peer_monitoring_server,
+            peer_metadata_storage,
+            time_service.into_mock(),
+        )
     }
 
     async fn send_request(
@@ -203,6 +338,51 @@ impl MockClient {
     }
 }
 
+/// Creates a depth from validators response
+fn create_depth_response(depth_from_validators: Option<u64>) -> PeerMonitoringServiceResponse {
+    PeerMonitoringServiceResponse::DepthFromValidators(DepthFromValidatorsResponse {
+        depth_from_validators,
+    })
+}
+
+/// Inserts a new connected peer with the given role into the peer metadata storage
+fn insert_connected_peer(
+    peer_metadata_storage: &Arc<PeerMetadataStorage>,
+    network_id: NetworkId,
+    peer_role: PeerRole,
+) -> PeerNetworkId {
+    let peer_id = PeerId::random();
+    let peer_network_id = PeerNetworkId::new(network_id, peer_id);
+    let connection_metadata = ConnectionMetadata::new(
+        peer_id,
+        ConnectionId::default(),
+        NetworkAddress::from_str("/ip4/127.0.0.1/tcp/8081").unwrap(),
+        ConnectionOrigin::Inbound,
+        MessagingProtocolVersion::V1,
+        ProtocolIdSet::empty(),
+        peer_role,
+    );
+    let peer_info = PeerInfo::new(connection_metadata);
+    peer_metadata_storage.insert(peer_network_id, peer_info);
+    peer_network_id
+}
+
+/// Marks the given peer as disconnected in the peer metadata storage
+fn disconnect_peer(
+    peer_metadata_storage: &Arc<PeerMetadataStorage>,
+    peer_network_id: PeerNetworkId,
+) {
+    peer_metadata_storage
+        .write(peer_network_id, |entry| match entry {
+            Entry::Vacant(..) => Err(PeerError::NotFound),
+            Entry::Occupied(inner) => {
+                inner.get_mut().status = PeerState::Disconnected;
+                Ok(())
+            }
+        })
+        .unwrap();
+}
+
 /// Initializes the Aptos logger for tests
 pub fn initialize_logger() {
     aptos_logger::Logger::builder()
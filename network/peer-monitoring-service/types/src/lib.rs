@@ -6,7 +6,10 @@
 use aptos_config::network_id::PeerNetworkId;
 use network::application::types::PeerInfo;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, convert::TryFrom};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+};
 use thiserror::Error;
 
 pub type Result<T, E = PeerMonitoringServiceError> = ::std::result::Result<T, E>;
@@ -90,19 +93,19 @@ pub struct ConnectedPeersResponse {
 /// A response for the depth from validators request
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct DepthFromValidatorsResponse {
-    pub todo: bool,
+    pub depth_from_validators: Option<u64>, // The min depth from the validators (if known)
 }
 
 /// A response for the known peers request
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct KnownPeersResponse {
-    pub todo: bool,
+    pub known_peers: HashSet<PeerNetworkId>, // All peers known to the node (connected or not)
 }
 
 /// A response for the ping request
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct PingResponse {
-    pub todo: bool,
+    pub server_timestamp_usecs: u64, // The unix timestamp (usecs) at which the server handled the ping
 }
 
 /// A response for the server protocol version request
@@ -114,7 +117,8 @@ pub struct ServerProtocolVersionResponse {
 /// A response for the current validators and VFNs
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ValidatorsAndVFNsResponse {
-    pub todo: bool,
+    pub validators: HashSet<PeerNetworkId>, // The connected peers with the validator role
+    pub vfns: HashSet<PeerNetworkId>,       // The connected peers with the VFN role
 }
 
 #[derive(Clone, Debug, Error)]
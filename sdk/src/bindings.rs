@@ -0,0 +1,423 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Support for the typed bindings of Move packages generated by `aptos move generate-bindings`.
+//!
+//! The Rust types of the bindings are (de)serialized like the values returned by the REST API,
+//! both in JSON with `serde_json` and in BCS with `bcs`. For that, the Move types whose JSON
+//! encoding differs from their BCS encoding are represented with the wrappers of this module,
+//! e.g. [`U64`] is a string in JSON.
+
+use crate::{
+    move_types::identifier::Identifier,
+    rest_client::aptos_api_types::{EntryFunctionId, MoveType, ViewRequest},
+};
+pub use crate::{
+    move_types::{
+        account_address::AccountAddress,
+        language_storage::{StructTag, TypeTag},
+    },
+    rest_client::{error::RestError, Client, Response},
+};
+use anyhow::anyhow;
+use serde::{
+    de::{DeserializeOwned, Error as _},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+pub type AptosResult<T> = Result<T, RestError>;
+
+/// A Rust type standing for a Move type, e.g. as the type argument of a generic struct
+pub trait MoveTypeTag {
+    fn type_tag() -> TypeTag;
+}
+
+/// A Rust type standing for a Move struct, e.g. a resource or an event
+pub trait MoveStruct: MoveTypeTag {
+    fn struct_tag() -> StructTag;
+}
+
+macro_rules! string_encoded_integer {
+    ($name:ident, $int:ty, $type_tag:expr, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub $int);
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(&self.0.to_string())
+                } else {
+                    self.0.serialize(serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    let s = <String>::deserialize(deserializer)?;
+                    s.parse().map($name).map_err(D::Error::custom)
+                } else {
+                    <$int>::deserialize(deserializer).map($name)
+                }
+            }
+        }
+
+        impl From<$int> for $name {
+            fn from(value: $int) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for $int {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl MoveTypeTag for $name {
+            fn type_tag() -> TypeTag {
+                $type_tag
+            }
+        }
+    };
+}
+
+string_encoded_integer!(
+    U64,
+    u64,
+    TypeTag::U64,
+    "A Move `u64`, encoded as a string in JSON, as it may not fit in a JavaScript number"
+);
+string_encoded_integer!(
+    U128,
+    u128,
+    TypeTag::U128,
+    "A Move `u128`, encoded as a string in JSON, as it may not fit in a JavaScript number"
+);
+
+/// A Move `address`, encoded as a `0x` prefixed hex string in JSON
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Address(pub AccountAddress);
+
+impl Serialize for Address {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.0.to_hex_literal())
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = <String>::deserialize(deserializer)?;
+            AccountAddress::from_hex_literal(&s)
+                .map(Address)
+                .map_err(D::Error::custom)
+        } else {
+            AccountAddress::deserialize(deserializer).map(Address)
+        }
+    }
+}
+
+impl From<AccountAddress> for Address {
+    fn from(address: AccountAddress) -> Self {
+        Self(address)
+    }
+}
+
+impl From<Address> for AccountAddress {
+    fn from(address: Address) -> Self {
+        address.0
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.to_hex_literal())
+    }
+}
+
+impl MoveTypeTag for Address {
+    fn type_tag() -> TypeTag {
+        TypeTag::Address
+    }
+}
+
+/// A Move `vector<u8>`, encoded as a `0x` prefixed hex string in JSON
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes(pub Vec<u8>);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&format!("0x{}", hex::encode(&self.0)))
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = <String>::deserialize(deserializer)?;
+            hex::decode(s.strip_prefix("0x").unwrap_or(&s))
+                .map(Bytes)
+                .map_err(D::Error::custom)
+        } else {
+            Vec::<u8>::deserialize(deserializer).map(Bytes)
+        }
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(bytes: Bytes) -> Self {
+        bytes.0
+    }
+}
+
+impl MoveTypeTag for Bytes {
+    fn type_tag() -> TypeTag {
+        TypeTag::Vector(Box::new(TypeTag::U8))
+    }
+}
+
+impl MoveTypeTag for bool {
+    fn type_tag() -> TypeTag {
+        TypeTag::Bool
+    }
+}
+
+impl MoveTypeTag for u8 {
+    fn type_tag() -> TypeTag {
+        TypeTag::U8
+    }
+}
+
+/// `0x1::string::String`, which is a string in JSON as well as in BCS
+impl MoveTypeTag for String {
+    fn type_tag() -> TypeTag {
+        TypeTag::Struct(Box::new(StructTag {
+            address: AccountAddress::ONE,
+            module: Identifier::new("string").unwrap(),
+            name: Identifier::new("String").unwrap(),
+            type_params: vec![],
+        }))
+    }
+}
+
+impl<T: MoveTypeTag> MoveTypeTag for Vec<T> {
+    fn type_tag() -> TypeTag {
+        TypeTag::Vector(Box::new(T::type_tag()))
+    }
+}
+
+/// Builds the tag of a struct of the bindings, from the names of its address, module and struct
+pub fn struct_tag(address: &str, module: &str, name: &str, type_params: Vec<TypeTag>) -> StructTag {
+    StructTag {
+        address: AccountAddress::from_hex_literal(address).expect("Invalid struct address"),
+        module: Identifier::new(module).expect("Invalid struct module name"),
+        name: Identifier::new(name).expect("Invalid struct name"),
+        type_params,
+    }
+}
+
+/// Retrieves the resource of type `T` of the account at `address`, at the latest version or at
+/// `version`
+pub async fn get_resource<T: MoveStruct + DeserializeOwned>(
+    client: &Client,
+    address: AccountAddress,
+    version: Option<u64>,
+) -> AptosResult<Response<T>> {
+    let resource_type = T::struct_tag().to_string();
+    match version {
+        Some(version) => {
+            client
+                .get_account_resource_at_version_bcs(address, &resource_type, version)
+                .await
+        }
+        None => {
+            client
+                .get_account_resource_bcs(address, &resource_type)
+                .await
+        }
+    }
+}
+
+/// An event decoded into its type in the bindings
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypedEvent<T> {
+    /// Version of the transaction which emitted the event
+    pub version: u64,
+    pub sequence_number: u64,
+    pub data: T,
+}
+
+/// Retrieves the events of an event handle of a resource in order, batch after batch
+#[derive(Clone, Debug)]
+pub struct EventStream<T> {
+    client: Client,
+    address: AccountAddress,
+    struct_tag: StructTag,
+    field_name: &'static str,
+    next_sequence_number: u64,
+    phantom: PhantomData<T>,
+}
+
+impl<T> EventStream<T> {
+    /// Starts from the first event of the handle in the `field_name` field of the resource of type
+    /// `struct_tag` of the account at `address`
+    pub fn new(
+        client: Client,
+        address: AccountAddress,
+        struct_tag: StructTag,
+        field_name: &'static str,
+    ) -> Self {
+        Self {
+            client,
+            address,
+            struct_tag,
+            field_name,
+            next_sequence_number: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Starts from the event with the given sequence number instead
+    pub fn starting_at(mut self, sequence_number: u64) -> Self {
+        self.next_sequence_number = sequence_number;
+        self
+    }
+
+    pub fn next_sequence_number(&self) -> u64 {
+        self.next_sequence_number
+    }
+}
+
+impl<T: DeserializeOwned> EventStream<T> {
+    /// Retrieves the next events, at most `limit` of them.  It's empty if there's no event after
+    /// the previous batch yet, in which case it can be polled again later.
+    pub async fn next_batch(&mut self, limit: Option<u16>) -> AptosResult<Vec<TypedEvent<T>>> {
+        let events = self
+            .client
+            .get_account_events_bcs(
+                self.address,
+                &self.struct_tag.to_string(),
+                self.field_name,
+                Some(self.next_sequence_number),
+                limit,
+            )
+            .await?
+            .into_inner()
+            .into_iter()
+            .map(|event| {
+                Ok(TypedEvent {
+                    version: event.transaction_version,
+                    sequence_number: event.event.sequence_number(),
+                    data: bcs::from_bytes(event.event.event_data())?,
+                })
+            })
+            .collect::<AptosResult<Vec<_>>>()?;
+        if let Some(event) = events.last() {
+            self.next_sequence_number = event.sequence_number + 1;
+        }
+        Ok(events)
+    }
+}
+
+/// Builds the request calling a view function of the bindings, e.g. `0x1::coin::balance`
+pub fn view_request(
+    function: &str,
+    type_args: Vec<TypeTag>,
+    args: Vec<serde_json::Value>,
+) -> ViewRequest {
+    ViewRequest {
+        function: EntryFunctionId::from_str(function).expect("Invalid view function"),
+        type_arguments: type_args.into_iter().map(MoveType::from).collect(),
+        arguments: args,
+    }
+}
+
+/// Converts an argument of a view function into the JSON the REST API expects
+pub fn view_arg<T: Serialize>(arg: &T) -> AptosResult<serde_json::Value> {
+    Ok(serde_json::to_value(arg)?)
+}
+
+/// Decodes the return value at `index` of the BCS encoded return values of a view function
+pub fn view_return_value<T: DeserializeOwned>(values: &[Vec<u8>], index: usize) -> AptosResult<T> {
+    let value = values
+        .get(index)
+        .ok_or_else(|| RestError::Unknown(anyhow!("Missing return value {}", index)))?;
+    Ok(bcs::from_bytes(value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Resource {
+        amount: U64,
+        total: U128,
+        owner: Address,
+        data: Bytes,
+        name: String,
+        values: Vec<U64>,
+    }
+
+    #[test]
+    fn test_json_and_bcs_encodings() {
+        let resource = Resource {
+            amount: U64(u64::MAX),
+            total: U128(1),
+            owner: Address(AccountAddress::ONE),
+            data: Bytes(vec![0xca, 0xfe]),
+            name: "name".to_string(),
+            values: vec![U64(1), U64(2)],
+        };
+
+        // Same as the REST API
+        let value = json!({
+            "amount": "18446744073709551615",
+            "total": "1",
+            "owner": "0x1",
+            "data": "0xcafe",
+            "name": "name",
+            "values": ["1", "2"],
+        });
+        assert_eq!(serde_json::to_value(&resource).unwrap(), value);
+        assert_eq!(serde_json::from_value::<Resource>(value).unwrap(), resource);
+
+        // Same as the Move values
+        let bytes = bcs::to_bytes(&(
+            u64::MAX,
+            1u128,
+            AccountAddress::ONE,
+            vec![0xcau8, 0xfe],
+            "name",
+            vec![1u64, 2],
+        ))
+        .unwrap();
+        assert_eq!(bcs::to_bytes(&resource).unwrap(), bytes);
+        assert_eq!(bcs::from_bytes::<Resource>(&bytes).unwrap(), resource);
+    }
+}
@@ -21,6 +21,8 @@
 
 pub use bcs;
 
+pub mod bindings;
+
 pub mod coin_client;
 
 pub mod crypto {
@@ -4,6 +4,8 @@
 use crate::{
     crypto::{
         ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
+        secp256k1_ecdsa::{Secp256k1EcdsaPrivateKey, Secp256k1EcdsaPublicKey},
+        secp256r1_ecdsa::{Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey},
         traits::Uniform,
     },
     transaction_builder::TransactionBuilder,
@@ -13,8 +15,60 @@ use crate::{
     },
 };
 
+use anyhow::{anyhow, bail};
 use aptos_types::event::EventKey;
 pub use aptos_types::*;
+use bip39::{Language, Mnemonic, Seed};
+use ed25519_dalek_bip32::{DerivationPath, ExtendedSecretKey};
+use std::str::FromStr;
+
+/// The coin type of Aptos in the BIP-44 derivation paths, as registered in SLIP-0044.
+pub const APTOS_COIN_TYPE: u32 = 637;
+
+/// Returns the derivation path of the `account_index`-th account of a mnemonic phrase, i.e.
+/// `m/44'/637'/{account_index}'/0'/0'`, as used by the Aptos wallets.
+pub fn aptos_derivation_path(account_index: u32) -> String {
+    format!("m/44'/{}'/{}'/0'/0'", APTOS_COIN_TYPE, account_index)
+}
+
+/// Generates a random BIP-39 mnemonic phrase of `word_count` English words, which must be one of
+/// 12, 15, 18, 21 or 24.
+pub fn generate_mnemonic_phrase<R>(rng: &mut R, word_count: usize) -> anyhow::Result<String>
+where
+    R: ::rand_core::RngCore + ::rand_core::CryptoRng,
+{
+    if ![12, 15, 18, 21, 24].contains(&word_count) {
+        bail!(
+            "Invalid mnemonic word count {}, must be one of 12, 15, 18, 21 or 24",
+            word_count
+        );
+    }
+    // Each word encodes 11 bits, of which 1 in every 33 is a checksum bit
+    let mut entropy = vec![0u8; word_count * 4 / 3];
+    rng.fill_bytes(&mut entropy);
+    let mnemonic = Mnemonic::from_entropy(&entropy, Language::English)?;
+    Ok(mnemonic.into_phrase())
+}
+
+/// Derives the Ed25519 private key of a BIP-39 mnemonic phrase along `derivation_path`, following
+/// SLIP-0010. Only hardened derivation is defined for Ed25519, so every level of the path must be
+/// hardened.
+pub fn derive_ed25519_private_key(
+    mnemonic_phrase: &str,
+    derivation_path: &str,
+) -> anyhow::Result<Ed25519PrivateKey> {
+    let mnemonic = Mnemonic::from_phrase(mnemonic_phrase.trim(), Language::English)?;
+    let derivation_path = DerivationPath::from_str(derivation_path)
+        .map_err(|err| anyhow!("Invalid derivation path {}: {}", derivation_path, err))?;
+    // The mnemonic isn't protected by a passphrase
+    let seed = Seed::new(&mnemonic, "");
+    let extended_key = ExtendedSecretKey::from_seed(seed.as_bytes())
+        .and_then(|key| key.derive(&derivation_path))
+        .map_err(|err| anyhow!("Failed to derive key: {}", err))?;
+    Ok(Ed25519PrivateKey::try_from(
+        extended_key.secret_key.as_bytes().as_ref(),
+    )?)
+}
 
 /// LocalAccount represents an account on the Aptos blockchain. Internally it
 /// holds the private / public key pair and the address of the account. You can
@@ -55,6 +109,20 @@ impl LocalAccount {
         Self::new(address, key, 0)
     }
 
+    /// Recover an account from a BIP-39 mnemonic phrase, deriving its key along
+    /// `derivation_path`, e.g. [`aptos_derivation_path`]. Note: This assumes the
+    /// authentication key of the account was never rotated.
+    pub fn from_mnemonic(
+        mnemonic_phrase: &str,
+        derivation_path: &str,
+        sequence_number: u64,
+    ) -> anyhow::Result<Self> {
+        let key = AccountKey::from_mnemonic(mnemonic_phrase, derivation_path)?;
+        let address = key.authentication_key().derived_address();
+
+        Ok(Self::new(address, key, sequence_number))
+    }
+
     pub fn sign_transaction(&self, txn: RawTransaction) -> SignedTransaction {
         txn.sign(self.private_key(), self.public_key().clone())
             .expect("Signing a txn can't fail")
@@ -154,6 +222,13 @@ impl AccountKey {
         Self::from_private_key(private_key)
     }
 
+    /// Derives the key of a BIP-39 mnemonic phrase along `derivation_path`, see
+    /// [`derive_ed25519_private_key`].
+    pub fn from_mnemonic(mnemonic_phrase: &str, derivation_path: &str) -> anyhow::Result<Self> {
+        let private_key = derive_ed25519_private_key(mnemonic_phrase, derivation_path)?;
+        Ok(Self::from_private_key(private_key))
+    }
+
     pub fn from_private_key(private_key: Ed25519PrivateKey) -> Self {
         let public_key = Ed25519PublicKey::from(&private_key);
         let authentication_key = AuthenticationKey::ed25519(&public_key);
@@ -183,3 +258,156 @@ impl From<Ed25519PrivateKey> for AccountKey {
         Self::from_private_key(private_key)
     }
 }
+
+/// A Secp256r1 ECDSA key controlling an account, e.g. a key exported from a secure enclave.
+///
+/// Note that keys held by WebAuthn authenticators (passkeys) can't be exported: transactions for
+/// those accounts are signed by the authenticator, using
+/// [`WebAuthnSignature::challenge`](crate::types::transaction::webauthn::WebAuthnSignature::challenge)
+/// as the challenge, and submitted with [`SignedTransaction::new_webauthn`].
+#[derive(Debug)]
+pub struct Secp256r1EcdsaAccountKey {
+    private_key: Secp256r1EcdsaPrivateKey,
+    public_key: Secp256r1EcdsaPublicKey,
+    authentication_key: AuthenticationKey,
+}
+
+impl Secp256r1EcdsaAccountKey {
+    pub fn generate<R>(rng: &mut R) -> Self
+    where
+        R: ::rand_core::RngCore + ::rand_core::CryptoRng,
+    {
+        let private_key = Secp256r1EcdsaPrivateKey::generate(rng);
+        Self::from_private_key(private_key)
+    }
+
+    pub fn from_private_key(private_key: Secp256r1EcdsaPrivateKey) -> Self {
+        let public_key = Secp256r1EcdsaPublicKey::from(&private_key);
+        let authentication_key = AuthenticationKey::secp256r1_ecdsa(&public_key);
+
+        Self {
+            private_key,
+            public_key,
+            authentication_key,
+        }
+    }
+
+    pub fn private_key(&self) -> &Secp256r1EcdsaPrivateKey {
+        &self.private_key
+    }
+
+    pub fn public_key(&self) -> &Secp256r1EcdsaPublicKey {
+        &self.public_key
+    }
+
+    pub fn authentication_key(&self) -> AuthenticationKey {
+        self.authentication_key
+    }
+
+    pub fn sign_transaction(&self, txn: RawTransaction) -> SignedTransaction {
+        txn.sign_secp256r1_ecdsa(self.private_key(), self.public_key().clone())
+            .expect("Signing a txn can't fail")
+            .into_inner()
+    }
+}
+
+impl From<Secp256r1EcdsaPrivateKey> for Secp256r1EcdsaAccountKey {
+    fn from(private_key: Secp256r1EcdsaPrivateKey) -> Self {
+        Self::from_private_key(private_key)
+    }
+}
+
+/// A Secp256k1 ECDSA key controlling an account, e.g. a key shared with an Ethereum wallet.
+#[derive(Debug)]
+pub struct Secp256k1EcdsaAccountKey {
+    private_key: Secp256k1EcdsaPrivateKey,
+    public_key: Secp256k1EcdsaPublicKey,
+    authentication_key: AuthenticationKey,
+}
+
+impl Secp256k1EcdsaAccountKey {
+    pub fn generate<R>(rng: &mut R) -> Self
+    where
+        R: ::rand_core::RngCore + ::rand_core::CryptoRng,
+    {
+        let private_key = Secp256k1EcdsaPrivateKey::generate(rng);
+        Self::from_private_key(private_key)
+    }
+
+    pub fn from_private_key(private_key: Secp256k1EcdsaPrivateKey) -> Self {
+        let public_key = Secp256k1EcdsaPublicKey::from(&private_key);
+        let authentication_key = AuthenticationKey::secp256k1_ecdsa(&public_key);
+
+        Self {
+            private_key,
+            public_key,
+            authentication_key,
+        }
+    }
+
+    pub fn private_key(&self) -> &Secp256k1EcdsaPrivateKey {
+        &self.private_key
+    }
+
+    pub fn public_key(&self) -> &Secp256k1EcdsaPublicKey {
+        &self.public_key
+    }
+
+    pub fn authentication_key(&self) -> AuthenticationKey {
+        self.authentication_key
+    }
+
+    pub fn sign_transaction(&self, txn: RawTransaction) -> SignedTransaction {
+        txn.sign_secp256k1_ecdsa(self.private_key(), self.public_key().clone())
+            .expect("Signing a txn can't fail")
+            .into_inner()
+    }
+}
+
+impl From<Secp256k1EcdsaPrivateKey> for Secp256k1EcdsaAccountKey {
+    fn from(private_key: Secp256k1EcdsaPrivateKey) -> Self {
+        Self::from_private_key(private_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_recover_account_from_mnemonic() {
+        // Same vector as the TypeScript SDK
+        let mnemonic_phrase =
+            "shoot island position soft burden budget tooth cruel issue economy destroy above";
+        let account =
+            LocalAccount::from_mnemonic(mnemonic_phrase, &aptos_derivation_path(0), 0).unwrap();
+        assert_eq!(
+            account.address(),
+            AccountAddress::from_hex_literal(
+                "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30"
+            )
+            .unwrap()
+        );
+
+        // Other accounts of the same mnemonic have other keys
+        let other_account =
+            LocalAccount::from_mnemonic(mnemonic_phrase, &aptos_derivation_path(1), 0).unwrap();
+        assert_ne!(account.address(), other_account.address());
+
+        // Ed25519 keys can only be derived along hardened paths
+        assert!(AccountKey::from_mnemonic(mnemonic_phrase, "m/44'/637'/0'/0/0").is_err());
+        assert!(AccountKey::from_mnemonic("not a mnemonic", &aptos_derivation_path(0)).is_err());
+    }
+
+    #[test]
+    fn test_generate_mnemonic_phrase() {
+        let mut rng = StdRng::from_seed([0u8; 32]);
+        for word_count in [12, 15, 18, 21, 24] {
+            let mnemonic_phrase = generate_mnemonic_phrase(&mut rng, word_count).unwrap();
+            assert_eq!(mnemonic_phrase.split_whitespace().count(), word_count);
+            AccountKey::from_mnemonic(&mnemonic_phrase, &aptos_derivation_path(0)).unwrap();
+        }
+        assert!(generate_mnemonic_phrase(&mut rng, 13).is_err());
+    }
+}
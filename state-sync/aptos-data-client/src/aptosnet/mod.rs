@@ -8,6 +8,7 @@ use crate::{
             increment_request_counter, set_gauge, start_request_timer, DataType, PRIORITIZED_PEER,
             REGULAR_PEER,
         },
+        peer_selection::select_peer,
         state::{ErrorType, PeerStates},
     },
     AptosDataClient, Error, GlobalDataSummary, Response, ResponseCallback, ResponseContext,
@@ -33,6 +34,7 @@ use network::{
     application::interface::NetworkInterface,
     protocols::{rpc::error::RpcError, wire::handshake::v1::ProtocolId},
 };
+use peer_monitoring_service_client::PeerMonitorState;
 use rand::seq::SliceRandom;
 use std::{convert::TryFrom, fmt, sync::Arc, time::Duration};
 use storage_service_client::StorageServiceClient;
@@ -47,6 +49,7 @@ use tokio::{runtime::Handle, task::JoinHandle};
 
 mod logging;
 mod metrics;
+mod peer_selection;
 mod state;
 #[cfg(test)]
 mod tests;
@@ -92,6 +95,8 @@ pub struct AptosNetDataClient {
     global_summary_cache: Arc<RwLock<GlobalDataSummary>>,
     /// Used for generating the next request/response id.
     response_id_generator: Arc<U64IdGenerator>,
+    /// Used for measuring the latencies of requests.
+    time_service: TimeService,
 }
 
 impl AptosNetDataClient {
@@ -101,6 +106,7 @@ impl AptosNetDataClient {
         storage_service_config: StorageServiceConfig,
         time_service: TimeService,
         network_client: StorageServiceClient,
+        peer_monitor_state: PeerMonitorState,
         runtime: Option<Handle>,
     ) -> (Self, DataSummaryPoller) {
         let client = Self {
@@ -110,9 +116,11 @@ impl AptosNetDataClient {
                 base_config,
                 storage_service_config,
                 network_client.get_peer_metadata_storage(),
+                peer_monitor_state,
             ))),
             global_summary_cache: Arc::new(RwLock::new(GlobalDataSummary::empty())),
             response_id_generator: Arc::new(U64IdGenerator::new()),
+            time_service: time_service.clone(),
         };
         let poller = DataSummaryPoller::new(
             client.clone(),
@@ -160,15 +168,19 @@ impl AptosNetDataClient {
             self.identify_serviceable(regular_peers, request)
         };
 
-        // Randomly select a peer to handle the request
-        serviceable_peers
-            .choose(&mut rand::thread_rng())
-            .copied()
-            .ok_or_else(|| {
-                Error::DataIsUnavailable(
-                    format!("No connected peers are advertising that they can serve this data! Request: {:?}",request),
-                )
-            })
+        // Select a peer to handle the request (according to the selection policy)
+        select_peer(
+            self.data_client_config.peer_selection_policy,
+            &serviceable_peers,
+            &self.peer_states.read(),
+            request,
+        )
+        .ok_or_else(|| {
+            Error::DataIsUnavailable(format!(
+                "No connected peers are advertising that they can serve this data! Request: {:?}",
+                request
+            ))
+        })
     }
 
     /// Identifies the peers in the given set of prospective peers
@@ -373,6 +385,7 @@ impl AptosNetDataClient {
 
         increment_request_counter(&metrics::SENT_REQUESTS, &request.get_label(), peer);
 
+        let request_start_time = self.time_service.now();
         let result = self
             .network_client
             .send_request(
@@ -402,6 +415,15 @@ impl AptosNetDataClient {
                 // feels simpler for the consumer.
                 self.peer_states.write().update_score_success(peer);
 
+                // Update the observed quality of service of the peer (for peer selection)
+                let latency = self.time_service.now().duration_since(request_start_time);
+                self.peer_states.write().update_request_stats_success(
+                    peer,
+                    &request,
+                    latency,
+                    get_response_size_bytes(&response),
+                );
+
                 // Package up all of the context needed to fully report an error
                 // with this RPC.
                 let response_callback = AptosNetResponseCallback {
@@ -457,12 +479,12 @@ impl AptosNetDataClient {
         &self,
         _id: ResponseId,
         peer: PeerNetworkId,
-        _request: &StorageServiceRequest,
+        request: &StorageServiceRequest,
         error_type: ErrorType,
     ) {
-        self.peer_states
-            .write()
-            .update_score_error(peer, error_type);
+        let mut peer_states = self.peer_states.write();
+        peer_states.update_score_error(peer, error_type);
+        peer_states.update_request_stats_error(peer, request);
     }
 }
 
@@ -697,6 +719,18 @@ impl DataSummaryPoller {
     }
 }
 
+/// Returns the size (in bytes) of the given response, as sent over the wire
+fn get_response_size_bytes(response: &StorageServiceResponse) -> u64 {
+    match response {
+        StorageServiceResponse::CompressedResponse(_, compressed_data) => {
+            compressed_data.len() as u64
+        }
+        StorageServiceResponse::RawResponse(data_response) => {
+            bcs::serialized_size(data_response).unwrap_or_default() as u64
+        }
+    }
+}
+
 /// Logs the given poller error based on the logging frequency
 fn log_poller_error(error: Error) {
     sample!(
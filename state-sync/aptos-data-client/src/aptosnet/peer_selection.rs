@@ -0,0 +1,83 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::aptosnet::state::{PeerStates, RequestStats};
+use aptos_config::{config::PeerSelectionPolicy, network_id::PeerNetworkId};
+use rand::seq::SliceRandom;
+use storage_service_types::requests::StorageServiceRequest;
+
+/// Reference values used to normalize the observed quality of service of
+/// peers. A peer performing exactly at the reference is given a factor of 0.5
+/// (this is also the factor given to peers that we haven't measured yet).
+const REFERENCE_LATENCY_SECS: f64 = 0.5;
+const REFERENCE_THROUGHPUT_BYTES_PER_SEC: f64 = 1_000_000.0; // 1 MB/s
+/// The depth assumed for peers whose distance from the validators is unknown.
+const UNKNOWN_DEPTH_FROM_VALIDATORS: u64 = 3;
+/// The minimum weight of a peer, so that every serviceable peer is still
+/// occasionally selected (e.g., to notice that a slow peer has recovered).
+const MIN_PEER_WEIGHT: f64 = 0.001;
+
+/// Selects a peer (from the given serviceable peers) to handle the request,
+/// according to the specified peer selection policy.
+pub(crate) fn select_peer(
+    peer_selection_policy: PeerSelectionPolicy,
+    serviceable_peers: &[PeerNetworkId],
+    peer_states: &PeerStates,
+    request: &StorageServiceRequest,
+) -> Option<PeerNetworkId> {
+    match peer_selection_policy {
+        PeerSelectionPolicy::Random => serviceable_peers.choose(&mut rand::thread_rng()).copied(),
+        PeerSelectionPolicy::Weighted => serviceable_peers
+            .choose_weighted(&mut rand::thread_rng(), |peer| {
+                calculate_peer_weight(
+                    peer_states.get_request_stats(peer, request),
+                    peer_states.get_ping_latency_secs(peer),
+                    peer_states.get_depth_from_validators(peer),
+                )
+            })
+            .ok()
+            .copied(),
+    }
+}
+
+/// Calculates the selection weight of a peer using the observed request stats
+/// (latency, throughput and error rate) and the peer's distance from the
+/// validators. Faster, more reliable peers that are closer to the validators
+/// are given higher weights. If no requests have completed yet, the ping
+/// latency measured by the peer monitor is used instead.
+pub(crate) fn calculate_peer_weight(
+    request_stats: Option<&RequestStats>,
+    ping_latency_secs: Option<f64>,
+    depth_from_validators: Option<u64>,
+) -> f64 {
+    let (average_latency_secs, average_throughput, error_rate) = match request_stats {
+        Some(request_stats) => (
+            request_stats.average_latency_secs,
+            request_stats.average_throughput_bytes_per_sec,
+            request_stats.error_rate,
+        ),
+        None => (None, None, 0.0),
+    };
+
+    // Lower latencies result in a higher factor
+    let latency_secs = average_latency_secs
+        .or(ping_latency_secs)
+        .unwrap_or(REFERENCE_LATENCY_SECS);
+    let latency_factor = REFERENCE_LATENCY_SECS / (REFERENCE_LATENCY_SECS + latency_secs);
+
+    // Higher throughput results in a higher factor
+    let throughput = average_throughput.unwrap_or(REFERENCE_THROUGHPUT_BYTES_PER_SEC);
+    let throughput_factor = throughput / (throughput + REFERENCE_THROUGHPUT_BYTES_PER_SEC);
+
+    // Frequent errors result in a lower factor
+    let reliability_factor = 1.0 - error_rate;
+
+    // Peers further from the validators result in a lower factor
+    let depth = depth_from_validators.unwrap_or(UNKNOWN_DEPTH_FROM_VALIDATORS);
+    let depth_factor = 1.0 / (1.0 + depth as f64);
+
+    f64::max(
+        latency_factor * throughput_factor * reliability_factor * depth_factor,
+        MIN_PEER_WEIGHT,
+    )
+}
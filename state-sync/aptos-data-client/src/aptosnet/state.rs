@@ -6,17 +6,19 @@ use crate::{
     AdvertisedData, GlobalDataSummary, OptimalChunkSizes, ResponseError,
 };
 use aptos_config::{
-    config::{BaseConfig, StorageServiceConfig},
+    config::{BaseConfig, PeerRole, StorageServiceConfig},
     network_id::{NetworkId, PeerNetworkId},
 };
 use aptos_logger::prelude::*;
 use itertools::Itertools;
 use netcore::transport::ConnectionOrigin;
 use network::application::storage::PeerMetadataStorage;
+use peer_monitoring_service_client::PeerMonitorState;
 use std::{
     cmp::min,
     collections::{HashMap, HashSet},
     sync::Arc,
+    time::Duration,
 };
 use storage_service_types::requests::StorageServiceRequest;
 use storage_service_types::responses::StorageServerSummary;
@@ -33,6 +35,8 @@ const NOT_USEFUL_MULTIPLIER: f64 = 0.95;
 const MALICIOUS_MULTIPLIER: f64 = 0.8;
 /// Ignore a peer when their score dips below this threshold.
 const IGNORE_PEER_THRESHOLD: f64 = 25.0;
+/// The weight given to each new observation in the moving averages of the request stats.
+const REQUEST_STATS_SMOOTHING_FACTOR: f64 = 0.2;
 
 pub(crate) enum ErrorType {
     /// A response or error that's not actively malicious but also doesn't help
@@ -54,6 +58,48 @@ impl From<ResponseError> for ErrorType {
     }
 }
 
+/// The observed quality of service of a peer for a single type of request.
+/// All values are exponentially weighted moving averages, so that recent
+/// observations matter more than old ones.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct RequestStats {
+    /// The average time taken by the peer to respond (if any responses were seen)
+    pub average_latency_secs: Option<f64>,
+    /// The average rate at which the peer sends response data (if known)
+    pub average_throughput_bytes_per_sec: Option<f64>,
+    /// The fraction of recent requests that failed (between 0 and 1)
+    pub error_rate: f64,
+}
+
+impl RequestStats {
+    /// Updates the stats according to a successful response
+    pub fn record_success(&mut self, latency: Duration, response_bytes: u64) {
+        let latency_secs = latency.as_secs_f64();
+        self.average_latency_secs = Some(moving_average(self.average_latency_secs, latency_secs));
+        if latency_secs > 0.0 {
+            let throughput = response_bytes as f64 / latency_secs;
+            self.average_throughput_bytes_per_sec = Some(moving_average(
+                self.average_throughput_bytes_per_sec,
+                throughput,
+            ));
+        }
+        self.error_rate = moving_average(Some(self.error_rate), 0.0);
+    }
+
+    /// Updates the stats according to a failed request
+    pub fn record_error(&mut self) {
+        self.error_rate = moving_average(Some(self.error_rate), 1.0);
+    }
+}
+
+/// Returns the new moving average after observing the given value
+fn moving_average(average: Option<f64>, value: f64) -> f64 {
+    match average {
+        Some(average) => average + REQUEST_STATS_SMOOTHING_FACTOR * (value - average),
+        None => value,
+    }
+}
+
 #[derive(Debug)]
 struct PeerState {
     /// The latest observed advertised data for this peer, or `None` if we
@@ -61,6 +107,8 @@ struct PeerState {
     storage_summary: Option<StorageServerSummary>,
     /// For now, a simplified port of the original state-sync v1 scoring system.
     score: f64,
+    /// The observed quality of service of the peer, per request type.
+    request_stats: HashMap<&'static str, RequestStats>,
 }
 
 impl Default for PeerState {
@@ -68,6 +116,7 @@ impl Default for PeerState {
         Self {
             storage_summary: None,
             score: STARTING_SCORE,
+            request_stats: HashMap::new(),
         }
     }
 }
@@ -113,6 +162,7 @@ pub(crate) struct PeerStates {
     in_flight_priority_polls: HashSet<PeerNetworkId>, // The priority peers with in-flight polls
     in_flight_regular_polls: HashSet<PeerNetworkId>,  // The regular peers with in-flight polls
     peer_metadata_storage: Arc<PeerMetadataStorage>,
+    peer_monitor_state: PeerMonitorState, // The peer metadata observed by the peer monitor
 }
 
 impl PeerStates {
@@ -120,6 +170,7 @@ impl PeerStates {
         base_config: BaseConfig,
         storage_service_config: StorageServiceConfig,
         peer_metadata_storage: Arc<PeerMetadataStorage>,
+        peer_monitor_state: PeerMonitorState,
     ) -> Self {
         Self {
             base_config,
@@ -128,6 +179,7 @@ impl PeerStates {
             in_flight_priority_polls: HashSet::new(),
             in_flight_regular_polls: HashSet::new(),
             peer_metadata_storage,
+            peer_monitor_state,
         }
     }
 
@@ -190,6 +242,81 @@ impl PeerStates {
         }
     }
 
+    /// Updates the request stats of the peer according to a successful response
+    pub fn update_request_stats_success(
+        &mut self,
+        peer: PeerNetworkId,
+        request: &StorageServiceRequest,
+        latency: Duration,
+        response_bytes: u64,
+    ) {
+        self.peer_to_state
+            .entry(peer)
+            .or_default()
+            .request_stats
+            .entry(request.data_request.get_label())
+            .or_default()
+            .record_success(latency, response_bytes);
+    }
+
+    /// Updates the request stats of the peer according to a failed request
+    pub fn update_request_stats_error(
+        &mut self,
+        peer: PeerNetworkId,
+        request: &StorageServiceRequest,
+    ) {
+        self.peer_to_state
+            .entry(peer)
+            .or_default()
+            .request_stats
+            .entry(request.data_request.get_label())
+            .or_default()
+            .record_error();
+    }
+
+    /// Returns the request stats of the peer for the given request type (if any)
+    pub fn get_request_stats(
+        &self,
+        peer: &PeerNetworkId,
+        request: &StorageServiceRequest,
+    ) -> Option<&RequestStats> {
+        self.peer_to_state.get(peer).and_then(|peer_state| {
+            peer_state
+                .request_stats
+                .get(request.data_request.get_label())
+        })
+    }
+
+    /// Returns the distance of the peer from the validators, if it can be
+    /// inferred from the network and the role of the connection. For example,
+    /// validators have a depth of 0 and VFNs have a depth of 1. Otherwise,
+    /// the depth reported to the peer monitor is used (if any).
+    pub fn get_depth_from_validators(&self, peer: &PeerNetworkId) -> Option<u64> {
+        if peer.network_id().is_validator_network() {
+            return Some(0);
+        }
+        let inferred_depth = self
+            .peer_metadata_storage
+            .read(*peer)
+            .and_then(|peer_info| match peer_info.active_connection.role {
+                PeerRole::Validator => Some(0),
+                PeerRole::ValidatorFullNode => Some(1),
+                _ => None,
+            });
+        inferred_depth.or_else(|| {
+            self.peer_monitor_state
+                .get_peer_metadata(peer)
+                .and_then(|metadata| metadata.depth_from_validators)
+        })
+    }
+
+    /// Returns the average ping latency of the peer, as observed by the peer monitor
+    pub fn get_ping_latency_secs(&self, peer: &PeerNetworkId) -> Option<f64> {
+        self.peer_monitor_state
+            .get_peer_metadata(peer)
+            .and_then(|metadata| metadata.average_ping_latency_secs)
+    }
+
     /// Returns the number of in-flight priority polls
     pub fn num_in_flight_priority_polls(&self) -> u64 {
         self.in_flight_priority_polls.len() as u64
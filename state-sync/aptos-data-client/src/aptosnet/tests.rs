@@ -2,9 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::{AptosDataClient, AptosNetDataClient, DataSummaryPoller, Error};
-use crate::aptosnet::{poll_peer, state::calculate_optimal_chunk_sizes};
+use crate::aptosnet::{
+    peer_selection::calculate_peer_weight,
+    poll_peer,
+    state::{calculate_optimal_chunk_sizes, RequestStats},
+};
 use aptos_config::{
-    config::{AptosDataClientConfig, BaseConfig, RoleType, StorageServiceConfig},
+    config::{
+        AptosDataClientConfig, BaseConfig, PeerSelectionPolicy, RoleType, StorageServiceConfig,
+    },
     network_id::{NetworkId, PeerNetworkId},
 };
 use aptos_crypto::HashValue;
@@ -19,7 +25,7 @@ use aptos_types::{
 use channel::{aptos_channel, message_queues::QueueStyle};
 use claims::{assert_err, assert_matches, assert_none};
 use futures::StreamExt;
-use maplit::hashmap;
+use maplit::{hashmap, hashset};
 use netcore::transport::ConnectionOrigin;
 use network::{
     application::{interface::MultiNetworkSender, storage::PeerMetadataStorage, types::PeerState},
@@ -27,7 +33,12 @@ use network::{
     protocols::{network::NewNetworkSender, wire::handshake::v1::ProtocolId},
     transport::ConnectionMetadata,
 };
-use std::{collections::hash_map::Entry, sync::Arc, time::Duration};
+use peer_monitoring_service_client::PeerMonitorState;
+use std::{
+    collections::{hash_map::Entry, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 use storage_service_client::{StorageServiceClient, StorageServiceNetworkSender};
 use storage_service_server::network::{NetworkRequest, ResponseSender};
 use storage_service_types::{
@@ -36,8 +47,8 @@ use storage_service_types::{
         StorageServiceRequest, TransactionOutputsWithProofRequest, TransactionsWithProofRequest,
     },
     responses::{
-        CompleteDataRange, DataResponse, DataSummary, ProtocolMetadata, StorageServerSummary,
-        StorageServiceResponse, OPTIMISTIC_FETCH_VERSION_DELTA,
+        CompleteDataRange, DataResponse, DataSummary, ProtocolMetadata, ServerProtocolVersion,
+        StorageServerSummary, StorageServiceResponse, OPTIMISTIC_FETCH_VERSION_DELTA,
     },
     StorageServiceError, StorageServiceMessage,
 };
@@ -106,6 +117,7 @@ impl MockNetwork {
             StorageServiceConfig::default(),
             mock_time.clone(),
             network_client,
+            PeerMonitorState::new(),
             None,
         );
 
@@ -1409,6 +1421,186 @@ async fn optimal_chunk_size_calculations() {
     assert_eq!(400, optimal_chunk_sizes.transaction_output_chunk_size);
 }
 
+#[tokio::test]
+async fn request_stats_are_updated() {
+    ::aptos_logger::Logger::init_for_testing();
+    let (mut mock_network, _, client, _) = MockNetwork::new(None, None, None);
+
+    let good_peer = mock_network.add_peer(true);
+    let bad_peer = mock_network.add_peer(true);
+
+    // Spawn a handler for both peers
+    tokio::spawn(async move {
+        while let Some((peer, _, _, response_sender)) = mock_network.next_request().await {
+            if peer == good_peer.peer_id() {
+                let data_response = DataResponse::ServerProtocolVersion(ServerProtocolVersion {
+                    protocol_version: 1,
+                });
+                response_sender.send(Ok(StorageServiceResponse::new(data_response, true).unwrap()));
+            } else if peer == bad_peer.peer_id() {
+                response_sender.send(Err(StorageServiceError::InternalError("".to_string())));
+            }
+        }
+    });
+
+    // Send a request to each peer
+    let storage_request = StorageServiceRequest::new(DataRequest::GetServerProtocolVersion, true);
+    client
+        .send_request_to_peer(good_peer, storage_request.clone())
+        .await
+        .unwrap();
+    assert_err!(
+        client
+            .send_request_to_peer(bad_peer, storage_request.clone())
+            .await
+    );
+
+    // Verify the request stats of the good peer
+    let peer_states = client.peer_states.read();
+    let good_peer_stats = peer_states
+        .get_request_stats(&good_peer, &storage_request)
+        .unwrap();
+    assert!(good_peer_stats.average_latency_secs.is_some());
+    assert_eq!(good_peer_stats.error_rate, 0.0);
+
+    // Verify the request stats of the bad peer
+    let bad_peer_stats = peer_states
+        .get_request_stats(&bad_peer, &storage_request)
+        .unwrap();
+    assert_none!(bad_peer_stats.average_latency_secs);
+    assert!(bad_peer_stats.error_rate > 0.0);
+
+    // Verify the stats are tracked per request type
+    let summary_request = StorageServiceRequest::new(DataRequest::GetStorageServerSummary, true);
+    assert_none!(peer_states.get_request_stats(&good_peer, &summary_request));
+}
+
+#[tokio::test]
+async fn weighted_peer_selection() {
+    ::aptos_logger::Logger::init_for_testing();
+    let (mut mock_network, _, client, _) = MockNetwork::new(None, None, None);
+
+    // Add two priority peers
+    let fast_peer = mock_network.add_peer(true);
+    let slow_peer = mock_network.add_peer(true);
+
+    // Record fast responses for the fast peer, and slow responses and errors for the slow peer
+    let storage_request = StorageServiceRequest::new(DataRequest::GetStorageServerSummary, true);
+    for _ in 0..10 {
+        let mut peer_states = client.peer_states.write();
+        peer_states.update_request_stats_success(
+            fast_peer,
+            &storage_request,
+            Duration::from_millis(50),
+            10_000_000,
+        );
+        peer_states.update_request_stats_success(
+            slow_peer,
+            &storage_request,
+            Duration::from_secs(5),
+            10_000,
+        );
+        peer_states.update_request_stats_error(slow_peer, &storage_request);
+    }
+
+    // Verify the fast peer is selected for (almost) all requests
+    let num_fast_peer_selections = (0..100)
+        .filter(|_| client.choose_peer_for_request(&storage_request) == Ok(fast_peer))
+        .count();
+    assert!(num_fast_peer_selections >= 90);
+
+    // Verify the slow peer is still selected when the fast peer disconnects
+    mock_network.disconnect_peer(fast_peer);
+    assert_eq!(
+        client.choose_peer_for_request(&storage_request),
+        Ok(slow_peer)
+    );
+}
+
+#[tokio::test]
+async fn random_peer_selection() {
+    ::aptos_logger::Logger::init_for_testing();
+
+    // Use the random peer selection policy
+    let data_client_config = AptosDataClientConfig {
+        peer_selection_policy: PeerSelectionPolicy::Random,
+        ..Default::default()
+    };
+    let (mut mock_network, _, client, _) = MockNetwork::new(None, Some(data_client_config), None);
+
+    // Add two priority peers
+    let fast_peer = mock_network.add_peer(true);
+    let slow_peer = mock_network.add_peer(true);
+
+    // Record errors for the slow peer
+    let storage_request = StorageServiceRequest::new(DataRequest::GetStorageServerSummary, true);
+    for _ in 0..10 {
+        client
+            .peer_states
+            .write()
+            .update_request_stats_error(slow_peer, &storage_request);
+    }
+
+    // Verify both peers are selected (the request stats are ignored)
+    let selected_peers: HashSet<_> = (0..100)
+        .map(|_| client.choose_peer_for_request(&storage_request).unwrap())
+        .collect();
+    assert_eq!(selected_peers, hashset![fast_peer, slow_peer]);
+}
+
+#[test]
+fn peer_weight_calculations() {
+    // Create the request stats for a fast and a slow peer
+    let fast_peer_stats = RequestStats {
+        average_latency_secs: Some(0.1),
+        average_throughput_bytes_per_sec: Some(10_000_000.0),
+        error_rate: 0.0,
+    };
+    let slow_peer_stats = RequestStats {
+        average_latency_secs: Some(2.0),
+        average_throughput_bytes_per_sec: Some(100_000.0),
+        error_rate: 0.0,
+    };
+    let unreliable_peer_stats = RequestStats {
+        error_rate: 0.5,
+        ..fast_peer_stats.clone()
+    };
+
+    // Verify faster peers are given higher weights (and unknown peers are in between)
+    let fast_peer_weight = calculate_peer_weight(Some(&fast_peer_stats), None, Some(0));
+    let slow_peer_weight = calculate_peer_weight(Some(&slow_peer_stats), None, Some(0));
+    let unknown_peer_weight = calculate_peer_weight(None, None, Some(0));
+    assert!(fast_peer_weight > unknown_peer_weight);
+    assert!(unknown_peer_weight > slow_peer_weight);
+
+    // Verify unreliable peers are given lower weights
+    let unreliable_peer_weight = calculate_peer_weight(Some(&unreliable_peer_stats), None, Some(0));
+    assert!(fast_peer_weight > unreliable_peer_weight);
+
+    // Verify the ping latencies are used for peers without request stats
+    let fast_ping_weight = calculate_peer_weight(None, Some(0.1), Some(0));
+    let slow_ping_weight = calculate_peer_weight(None, Some(2.0), Some(0));
+    assert!(fast_ping_weight > unknown_peer_weight);
+    assert!(unknown_peer_weight > slow_ping_weight);
+    assert_eq!(
+        calculate_peer_weight(Some(&fast_peer_stats), Some(2.0), Some(0)),
+        fast_peer_weight
+    );
+
+    // Verify peers closer to the validators are given higher weights
+    let vfn_weight = calculate_peer_weight(Some(&fast_peer_stats), None, Some(1));
+    let pfn_weight = calculate_peer_weight(Some(&fast_peer_stats), None, None);
+    assert!(fast_peer_weight > vfn_weight);
+    assert!(vfn_weight > pfn_weight);
+
+    // Verify the weights are always positive
+    let failing_peer_stats = RequestStats {
+        error_rate: 1.0,
+        ..slow_peer_stats
+    };
+    assert!(calculate_peer_weight(Some(&failing_peer_stats), None, None) > 0.0);
+}
+
 /// A helper method that fetches peers to poll depending on the peer priority
 fn fetch_peer_to_poll(
     client: AptosNetDataClient,
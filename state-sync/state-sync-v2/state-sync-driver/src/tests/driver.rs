@@ -32,6 +32,7 @@ use executor_test_helpers::bootstrap_genesis;
 use futures::{FutureExt, StreamExt};
 use mempool_notifications::MempoolNotificationListener;
 use network::application::{interface::MultiNetworkSender, storage::PeerMetadataStorage};
+use peer_monitoring_service_client::PeerMonitorState;
 use std::{collections::HashMap, sync::Arc};
 use storage_interface::DbReaderWriter;
 use storage_service_client::StorageServiceClient;
@@ -272,6 +273,7 @@ async fn create_driver_for_tests(
         node_config.state_sync.storage_service,
         TimeService::mock(),
         network_client,
+        PeerMonitorState::new(),
         None,
     );
 
@@ -25,6 +25,7 @@ use executor_test_helpers::bootstrap_genesis;
 use futures::{FutureExt, StreamExt};
 use mempool_notifications::new_mempool_notifier_listener_pair;
 use network::application::{interface::MultiNetworkSender, storage::PeerMetadataStorage};
+use peer_monitoring_service_client::PeerMonitorState;
 use std::{collections::HashMap, sync::Arc};
 use storage_interface::DbReaderWriter;
 use storage_service_client::StorageServiceClient;
@@ -76,6 +77,7 @@ fn test_new_initialized_configs() {
         node_config.state_sync.storage_service,
         TimeService::mock(),
         network_client,
+        PeerMonitorState::new(),
         None,
     );
 
@@ -128,7 +128,7 @@ impl TransactionBackupController {
         format!("transaction_{}-", self.start_version)
     }
 
-    fn manifest_name() -> &'static ShellSafeName {
+    pub(crate) fn manifest_name() -> &'static ShellSafeName {
         static NAME: Lazy<ShellSafeName> =
             Lazy::new(|| ShellSafeName::from_str("transaction.manifest").unwrap());
         &NAME
@@ -14,7 +14,10 @@ use backup_cli::{
         state_snapshot::backup::{StateSnapshotBackupController, StateSnapshotBackupOpt},
         transaction::backup::{TransactionBackupController, TransactionBackupOpt},
     },
-    coordinators::backup::{BackupCoordinator, BackupCoordinatorOpt},
+    coordinators::{
+        backup::{BackupCoordinator, BackupCoordinatorOpt},
+        maintenance::{BackupMaintenanceCoordinator, BackupMaintenanceOpt},
+    },
     metadata::{cache, cache::MetadataCacheOpt},
     storage::StorageOpt,
     utils::{
@@ -33,6 +36,11 @@ enum Command {
         about = "Long running process backing up the chain continuously."
     )]
     Coordinator(CoordinatorCommand),
+    #[clap(
+        about = "Maintain the existing backups: detect gaps, merge small transaction backups and \
+        delete state snapshots out of the retention window."
+    )]
+    Maintenance(MaintenanceOpt),
 }
 
 #[derive(Parser)]
@@ -133,6 +141,18 @@ struct CoordinatorRunOpt {
     storage: StorageOpt,
 }
 
+#[derive(Parser)]
+struct MaintenanceOpt {
+    #[clap(flatten)]
+    maintenance: BackupMaintenanceOpt,
+
+    #[clap(flatten)]
+    concurrent_downloads: ConcurrentDownloadsOpt,
+
+    #[clap(subcommand)]
+    storage: StorageOpt,
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     main_impl().await.map_err(|e| {
@@ -218,6 +238,15 @@ async fn main_impl() -> Result<()> {
                 .await?;
             }
         },
+        Command::Maintenance(opt) => {
+            BackupMaintenanceCoordinator::new(
+                opt.maintenance,
+                opt.storage.init_storage().await?,
+                opt.concurrent_downloads.get(),
+            )
+            .run()
+            .await?;
+        }
     }
     Ok(())
 }
@@ -0,0 +1,701 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    backup_types::{
+        state_snapshot::manifest::StateSnapshotBackup,
+        transaction::{backup::TransactionBackupController, manifest::TransactionBackup},
+    },
+    metadata,
+    metadata::{
+        cache::MetadataCacheOpt, view::MetadataView, Metadata, StateSnapshotBackupMeta,
+        TransactionBackupMeta,
+    },
+    metrics::maintenance::{
+        MAINTENANCE_COORDINATOR_FAIL_TS, MAINTENANCE_COORDINATOR_START_TS,
+        MAINTENANCE_COORDINATOR_SUCC_TS, MAINTENANCE_DELETED_STATE_SNAPSHOTS,
+        MAINTENANCE_EPOCH_ENDING_GAPS, MAINTENANCE_MERGED_TRANSACTION_BACKUPS,
+        MAINTENANCE_TRANSACTION_GAPS,
+    },
+    storage::{BackupStorage, BackupStorageExt, FileHandle},
+    utils::unix_timestamp_sec,
+};
+use anyhow::{ensure, Result};
+use aptos_logger::prelude::*;
+use aptos_types::transaction::Version;
+use clap::Parser;
+use itertools::Itertools;
+use std::{
+    cmp::{max, Reverse},
+    mem,
+    sync::Arc,
+};
+use tokio::io::AsyncWriteExt;
+
+#[derive(Parser)]
+pub struct BackupMaintenanceOpt {
+    #[clap(flatten)]
+    pub metadata_cache_opt: MetadataCacheOpt,
+    #[clap(
+        long,
+        help = "Merge adjacent transaction backups into bigger ones, each covering up to this many \
+        versions. The chunks of the merged backups are reused as is, only the manifests and the \
+        metadata are rewritten. [Defaults to not merging]"
+    )]
+    pub transaction_merge_target_versions: Option<u64>,
+    #[clap(
+        long,
+        help = "Delete state snapshots taken more than this many epochs before the latest state \
+        snapshot. Notice that a deleted snapshot can no longer be used to restore the DB to the \
+        versions before the next snapshot. [Defaults to keeping all state snapshots]"
+    )]
+    pub state_snapshot_retention_epochs: Option<u64>,
+    #[clap(
+        long,
+        help = "Only report the gaps and what would be merged and deleted, without modifying the \
+        backup storage."
+    )]
+    pub dry_run: bool,
+}
+
+/// Maintains the existing backups in the storage: reports ranges missing from the backups, merges
+/// small transaction backups into bigger ones and deletes state snapshots outside the retention
+/// window.
+pub struct BackupMaintenanceCoordinator {
+    storage: Arc<dyn BackupStorage>,
+    metadata_cache_opt: MetadataCacheOpt,
+    transaction_merge_target_versions: Option<u64>,
+    state_snapshot_retention_epochs: Option<u64>,
+    dry_run: bool,
+    concurrent_downloads: usize,
+}
+
+impl BackupMaintenanceCoordinator {
+    pub fn new(
+        opt: BackupMaintenanceOpt,
+        storage: Arc<dyn BackupStorage>,
+        concurrent_downloads: usize,
+    ) -> Self {
+        Self {
+            storage,
+            metadata_cache_opt: opt.metadata_cache_opt,
+            transaction_merge_target_versions: opt.transaction_merge_target_versions,
+            state_snapshot_retention_epochs: opt.state_snapshot_retention_epochs,
+            dry_run: opt.dry_run,
+            concurrent_downloads,
+        }
+    }
+
+    pub async fn run(self) -> Result<()> {
+        info!("Backup maintenance coordinator started.");
+        MAINTENANCE_COORDINATOR_START_TS.set(unix_timestamp_sec());
+
+        let ret = self.run_impl().await;
+
+        if let Err(e) = &ret {
+            error!(
+                error = ?e,
+                "Backup maintenance coordinator failed."
+            );
+            MAINTENANCE_COORDINATOR_FAIL_TS.set(unix_timestamp_sec());
+        } else {
+            info!("Backup maintenance coordinator exiting with success.");
+            MAINTENANCE_COORDINATOR_SUCC_TS.set(unix_timestamp_sec());
+        }
+
+        ret
+    }
+
+    async fn run_impl(self) -> Result<()> {
+        let metadata_by_file = metadata::cache::sync_and_load_by_file(
+            &self.metadata_cache_opt,
+            Arc::clone(&self.storage),
+            self.concurrent_downloads,
+        )
+        .await?;
+        let metadata_view: MetadataView = metadata_by_file
+            .iter()
+            .flat_map(|(_file_handle, metadata_vec)| metadata_vec.iter().cloned())
+            .collect::<Vec<_>>()
+            .into();
+
+        Self::report_gaps(&metadata_view);
+
+        if let Some(target_versions) = self.transaction_merge_target_versions {
+            self.merge_transaction_backups(&metadata_by_file, target_versions)
+                .await?;
+        }
+        if let Some(retention_epochs) = self.state_snapshot_retention_epochs {
+            self.expire_state_snapshots(&metadata_by_file, retention_epochs)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    fn report_gaps(metadata_view: &MetadataView) {
+        let transaction_gaps = metadata_view.transaction_backup_gaps();
+        for (first_version, last_version) in &transaction_gaps {
+            warn!(
+                first_version = first_version,
+                last_version = last_version,
+                "Versions missing from the transaction backups."
+            );
+        }
+        MAINTENANCE_TRANSACTION_GAPS.set(transaction_gaps.len() as i64);
+
+        let epoch_ending_gaps = metadata_view.epoch_ending_backup_gaps();
+        for (first_epoch, last_epoch) in &epoch_ending_gaps {
+            warn!(
+                first_epoch = first_epoch,
+                last_epoch = last_epoch,
+                "Epochs missing from the epoch ending backups."
+            );
+        }
+        MAINTENANCE_EPOCH_ENDING_GAPS.set(epoch_ending_gaps.len() as i64);
+
+        info!(
+            transaction_gaps = transaction_gaps.len(),
+            epoch_ending_gaps = epoch_ending_gaps.len(),
+            "Gap detection finished."
+        );
+    }
+
+    async fn merge_transaction_backups(
+        &self,
+        metadata_by_file: &[(FileHandle, Vec<Metadata>)],
+        target_versions: u64,
+    ) -> Result<()> {
+        let backups = metadata_by_file
+            .iter()
+            .flat_map(|(_file_handle, metadata_vec)| metadata_vec)
+            .filter_map(|metadata| match metadata {
+                Metadata::TransactionBackup(backup) => Some(backup.clone()),
+                _ => None,
+            })
+            .collect();
+        let (runs, covered) = plan_transaction_merges(backups, target_versions);
+
+        let mut obsolete = covered;
+        let mut num_merged = 0;
+        for run in runs {
+            let (first_version, last_version) = (
+                run[0].first_version,
+                run.last().expect("Not empty.").last_version,
+            );
+            info!(
+                first_version = first_version,
+                last_version = last_version,
+                num_backups = run.len(),
+                dry_run = self.dry_run,
+                "Merging transaction backups."
+            );
+            if !self.dry_run {
+                self.write_merged_transaction_backup(&run).await?;
+            }
+            num_merged += run.len();
+            obsolete.extend(run);
+        }
+        MAINTENANCE_MERGED_TRANSACTION_BACKUPS.set(num_merged as i64);
+
+        // Only now that the merged backups are in place, delete the ones they replace. Chunks are
+        // referenced by the merged manifests, so only the metadata and the manifests are deleted.
+        for (file_handle, metadata_vec) in metadata_by_file {
+            let backups = match all_transaction_backups_in(metadata_vec, &obsolete) {
+                Some(backups) => backups,
+                None => continue,
+            };
+            info!(
+                metadata_file = file_handle,
+                dry_run = self.dry_run,
+                "Deleting obsolete transaction backup."
+            );
+            if !self.dry_run {
+                // The metadata goes first so that the backup is never seen without its manifest.
+                self.storage.delete_file(file_handle).await?;
+                for backup in backups {
+                    self.storage.delete_file(&backup.manifest).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn write_merged_transaction_backup(
+        &self,
+        run: &[TransactionBackupMeta],
+    ) -> Result<FileHandle> {
+        let mut chunks = Vec::new();
+        for backup in run {
+            let manifest: TransactionBackup = self.storage.load_json_file(&backup.manifest).await?;
+            manifest.verify()?;
+            ensure!(
+                manifest.first_version == backup.first_version
+                    && manifest.last_version == backup.last_version,
+                "Manifest {} has range [{}, {}], metadata says [{}, {}].",
+                backup.manifest,
+                manifest.first_version,
+                manifest.last_version,
+                backup.first_version,
+                backup.last_version,
+            );
+            chunks.extend(manifest.chunks);
+        }
+        let manifest = TransactionBackup {
+            first_version: run[0].first_version,
+            last_version: run.last().expect("Not empty.").last_version,
+            chunks,
+        };
+        manifest.verify()?;
+
+        let backup_handle = self
+            .storage
+            .create_backup_with_random_suffix(&format!("transaction_{}-", manifest.first_version))
+            .await?;
+        let (manifest_handle, mut manifest_file) = self
+            .storage
+            .create_for_write(&backup_handle, TransactionBackupController::manifest_name())
+            .await?;
+        manifest_file
+            .write_all(&serde_json::to_vec(&manifest)?)
+            .await?;
+        manifest_file.shutdown().await?;
+
+        let metadata = Metadata::new_transaction_backup(
+            manifest.first_version,
+            manifest.last_version,
+            manifest_handle.clone(),
+        );
+        self.storage
+            .save_metadata_line(&metadata.name(), &metadata.to_text_line()?)
+            .await?;
+
+        Ok(manifest_handle)
+    }
+
+    async fn expire_state_snapshots(
+        &self,
+        metadata_by_file: &[(FileHandle, Vec<Metadata>)],
+        retention_epochs: u64,
+    ) -> Result<()> {
+        let snapshots = metadata_by_file
+            .iter()
+            .flat_map(|(_file_handle, metadata_vec)| metadata_vec)
+            .filter_map(|metadata| match metadata {
+                Metadata::StateSnapshotBackup(backup) => Some(backup.clone()),
+                _ => None,
+            })
+            .collect();
+        let expired = plan_state_snapshot_expiration(snapshots, retention_epochs);
+
+        let mut num_deleted = 0;
+        for (file_handle, metadata_vec) in metadata_by_file {
+            let snapshots = match all_state_snapshots_in(metadata_vec, &expired) {
+                Some(snapshots) => snapshots,
+                None => continue,
+            };
+            for snapshot in &snapshots {
+                info!(
+                    epoch = snapshot.epoch,
+                    version = snapshot.version,
+                    dry_run = self.dry_run,
+                    "Deleting expired state snapshot."
+                );
+            }
+            if !self.dry_run {
+                self.delete_state_snapshots(file_handle, &snapshots).await?;
+            }
+            num_deleted += snapshots.len();
+        }
+        MAINTENANCE_DELETED_STATE_SNAPSHOTS.set(num_deleted as i64);
+
+        Ok(())
+    }
+
+    async fn delete_state_snapshots(
+        &self,
+        metadata_file: &FileHandle,
+        snapshots: &[&StateSnapshotBackupMeta],
+    ) -> Result<()> {
+        let mut manifests = Vec::new();
+        for snapshot in snapshots {
+            let manifest: StateSnapshotBackup =
+                self.storage.load_json_file(&snapshot.manifest).await?;
+            manifests.push((&snapshot.manifest, manifest));
+        }
+
+        // The metadata goes first so that a partially deleted snapshot is never selected.
+        self.storage.delete_file(metadata_file).await?;
+        for (manifest_handle, manifest) in manifests {
+            for chunk in manifest.chunks {
+                self.storage.delete_file(&chunk.blobs).await?;
+                self.storage.delete_file(&chunk.proof).await?;
+            }
+            self.storage.delete_file(&manifest.proof).await?;
+            self.storage.delete_file(manifest_handle).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Plans the merges of transaction backups: backups are grouped into runs of adjacent backups, each
+/// run covering no more than `target_versions` versions. Returns the runs of more than one backup,
+/// along with the backups fully covered by other backups (e.g. left over by an interrupted merge),
+/// which are obsolete.
+fn plan_transaction_merges(
+    backups: Vec<TransactionBackupMeta>,
+    target_versions: u64,
+) -> (Vec<Vec<TransactionBackupMeta>>, Vec<TransactionBackupMeta>) {
+    let mut runs = Vec::new();
+    let mut covered = Vec::new();
+    let mut current_run: Vec<TransactionBackupMeta> = Vec::new();
+    let mut next_ver: Version = 0;
+
+    for backup in backups
+        .into_iter()
+        .sorted_by_key(|b| (b.first_version, Reverse(b.last_version)))
+    {
+        if backup.last_version < next_ver {
+            covered.push(backup);
+            continue;
+        }
+
+        let run_first_version = current_run.first().map(|b| b.first_version);
+        let new_next_ver = max(next_ver, backup.last_version + 1);
+        match run_first_version {
+            Some(run_first_version)
+                if backup.first_version == next_ver
+                    && backup.last_version - run_first_version < target_versions =>
+            {
+                current_run.push(backup)
+            }
+            // Gaps and partial overlaps (which are never merged) end the current run as well.
+            _ => runs.push(mem::replace(&mut current_run, vec![backup])),
+        }
+        next_ver = new_next_ver;
+    }
+    runs.push(current_run);
+    runs.retain(|run| run.len() > 1);
+
+    (runs, covered)
+}
+
+/// Returns the state snapshots taken more than `retention_epochs` epochs before the latest one.
+fn plan_state_snapshot_expiration(
+    snapshots: Vec<StateSnapshotBackupMeta>,
+    retention_epochs: u64,
+) -> Vec<StateSnapshotBackupMeta> {
+    let latest_epoch = match snapshots.iter().map(|s| s.epoch).max() {
+        Some(epoch) => epoch,
+        None => return Vec::new(),
+    };
+    snapshots
+        .into_iter()
+        .filter(|s| s.epoch.saturating_add(retention_epochs) < latest_epoch)
+        .collect()
+}
+
+/// Returns the transaction backups in a metadata file iff all the entries in it are among the
+/// given ones, in which case the file can be deleted as a whole.
+fn all_transaction_backups_in<'a>(
+    metadata_vec: &'a [Metadata],
+    backups: &[TransactionBackupMeta],
+) -> Option<Vec<&'a TransactionBackupMeta>> {
+    if metadata_vec.is_empty() {
+        return None;
+    }
+    metadata_vec
+        .iter()
+        .map(|metadata| match metadata {
+            Metadata::TransactionBackup(backup) if backups.contains(backup) => Some(backup),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the state snapshots in a metadata file iff all the entries in it are among the given
+/// ones, in which case the file can be deleted as a whole.
+fn all_state_snapshots_in<'a>(
+    metadata_vec: &'a [Metadata],
+    snapshots: &[StateSnapshotBackupMeta],
+) -> Option<Vec<&'a StateSnapshotBackupMeta>> {
+    if metadata_vec.is_empty() {
+        return None;
+    }
+    metadata_vec
+        .iter()
+        .map(|metadata| match metadata {
+            Metadata::StateSnapshotBackup(snapshot) if snapshots.contains(snapshot) => {
+                Some(snapshot)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        backup_types::{
+            state_snapshot::manifest::{StateSnapshotBackup, StateSnapshotChunk},
+            transaction::manifest::{TransactionBackup, TransactionChunk},
+        },
+        coordinators::maintenance::{
+            plan_state_snapshot_expiration, plan_transaction_merges, BackupMaintenanceCoordinator,
+            BackupMaintenanceOpt,
+        },
+        metadata,
+        metadata::{
+            cache::MetadataCacheOpt, Metadata, StateSnapshotBackupMeta, TransactionBackupMeta,
+        },
+        storage::{local_fs::LocalFs, BackupStorage, BackupStorageExt, FileHandle},
+    };
+    use aptos_crypto::HashValue;
+    use aptos_temppath::TempPath;
+    use std::sync::Arc;
+    use tokio::io::AsyncWriteExt;
+
+    fn txn(first_version: u64, last_version: u64) -> TransactionBackupMeta {
+        TransactionBackupMeta {
+            first_version,
+            last_version,
+            manifest: format!("{}-{}", first_version, last_version),
+        }
+    }
+
+    fn snapshot(epoch: u64) -> StateSnapshotBackupMeta {
+        StateSnapshotBackupMeta {
+            epoch,
+            version: epoch * 100,
+            manifest: epoch.to_string(),
+        }
+    }
+
+    fn ranges(backups: &[TransactionBackupMeta]) -> Vec<(u64, u64)> {
+        backups
+            .iter()
+            .map(|b| (b.first_version, b.last_version))
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_transaction_merges() {
+        let backups = vec![
+            txn(0, 9),
+            txn(10, 19),
+            txn(20, 29),
+            txn(30, 39),
+            txn(40, 79),
+            txn(80, 89),
+            // gap
+            txn(100, 109),
+            txn(110, 119),
+            txn(110, 114), // covered
+        ];
+        let (runs, covered) = plan_transaction_merges(backups, 30);
+        assert_eq!(
+            runs.iter().map(|run| ranges(run)).collect::<Vec<_>>(),
+            vec![
+                vec![(0, 9), (10, 19), (20, 29)],
+                vec![(100, 109), (110, 119)],
+            ]
+        );
+        assert_eq!(ranges(&covered), vec![(110, 114)]);
+
+        let (runs, covered) = plan_transaction_merges(vec![txn(0, 9), txn(5, 14)], 100);
+        assert!(runs.is_empty());
+        assert!(covered.is_empty());
+    }
+
+    #[test]
+    fn test_plan_state_snapshot_expiration() {
+        let snapshots = vec![snapshot(1), snapshot(5), snapshot(9), snapshot(10)];
+        let epochs = |retention_epochs| {
+            plan_state_snapshot_expiration(snapshots.clone(), retention_epochs)
+                .into_iter()
+                .map(|s| s.epoch)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(epochs(0), vec![1, 5, 9]);
+        assert_eq!(epochs(4), vec![1, 5]);
+        assert_eq!(epochs(5), vec![1]);
+        assert_eq!(epochs(9), Vec::<u64>::new());
+        assert!(plan_state_snapshot_expiration(Vec::new(), 0).is_empty());
+    }
+
+    async fn write_file(
+        storage: &Arc<dyn BackupStorage>,
+        backup_handle: &str,
+        name: &str,
+        content: &[u8],
+    ) -> FileHandle {
+        let (file_handle, mut file) = storage
+            .create_for_write(backup_handle, &name.parse().unwrap())
+            .await
+            .unwrap();
+        file.write_all(content).await.unwrap();
+        file.shutdown().await.unwrap();
+        file_handle
+    }
+
+    async fn save_metadata(storage: &Arc<dyn BackupStorage>, metadata: Metadata) {
+        storage
+            .save_metadata_line(&metadata.name(), &metadata.to_text_line().unwrap())
+            .await
+            .unwrap();
+    }
+
+    async fn write_transaction_backup(
+        storage: &Arc<dyn BackupStorage>,
+        first_version: u64,
+        last_version: u64,
+    ) -> FileHandle {
+        let backup_handle = storage
+            .create_backup_with_random_suffix(&format!("transaction_{}-", first_version))
+            .await
+            .unwrap();
+        let chunk = TransactionChunk {
+            first_version,
+            last_version,
+            transactions: write_file(storage, &backup_handle, "txns.chunk", b"txns").await,
+            proof: write_file(storage, &backup_handle, "txns.proof", b"proof").await,
+        };
+        let manifest = TransactionBackup {
+            first_version,
+            last_version,
+            chunks: vec![chunk],
+        };
+        let manifest_handle = write_file(
+            storage,
+            &backup_handle,
+            "transaction.manifest",
+            &serde_json::to_vec(&manifest).unwrap(),
+        )
+        .await;
+        save_metadata(
+            storage,
+            Metadata::new_transaction_backup(first_version, last_version, manifest_handle.clone()),
+        )
+        .await;
+        manifest_handle
+    }
+
+    async fn write_state_snapshot(storage: &Arc<dyn BackupStorage>, epoch: u64) -> Vec<FileHandle> {
+        let version = epoch * 100;
+        let backup_handle = storage
+            .create_backup_with_random_suffix(&format!("state_ver_{}", version))
+            .await
+            .unwrap();
+        let chunk = StateSnapshotChunk {
+            first_idx: 0,
+            last_idx: 0,
+            first_key: HashValue::zero(),
+            last_key: HashValue::zero(),
+            blobs: write_file(storage, &backup_handle, "0-.chunk", b"blobs").await,
+            proof: write_file(storage, &backup_handle, "0-.proof", b"proof").await,
+        };
+        let manifest = StateSnapshotBackup {
+            version,
+            epoch,
+            root_hash: HashValue::zero(),
+            proof: write_file(storage, &backup_handle, "state.proof", b"proof").await,
+            chunks: vec![chunk],
+        };
+        let manifest_handle = write_file(
+            storage,
+            &backup_handle,
+            "state.manifest",
+            &serde_json::to_vec(&manifest).unwrap(),
+        )
+        .await;
+        save_metadata(
+            storage,
+            Metadata::new_state_snapshot_backup(epoch, version, manifest_handle.clone()),
+        )
+        .await;
+
+        let chunk = &manifest.chunks[0];
+        vec![
+            chunk.blobs.clone(),
+            chunk.proof.clone(),
+            manifest.proof.clone(),
+            manifest_handle,
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_maintenance() {
+        let backup_dir = TempPath::new();
+        backup_dir.create_as_dir().unwrap();
+        let cache_dir = TempPath::new();
+        let storage: Arc<dyn BackupStorage> =
+            Arc::new(LocalFs::new(backup_dir.path().to_path_buf()));
+
+        let mut txn_manifests = Vec::new();
+        for (first_version, last_version) in [(0, 9), (10, 19), (20, 29), (30, 39)] {
+            txn_manifests
+                .push(write_transaction_backup(&storage, first_version, last_version).await);
+        }
+        let mut snapshot_files = Vec::new();
+        for epoch in [1, 2, 3] {
+            snapshot_files.push(write_state_snapshot(&storage, epoch).await);
+        }
+
+        let run = |dry_run| {
+            BackupMaintenanceCoordinator::new(
+                BackupMaintenanceOpt {
+                    metadata_cache_opt: MetadataCacheOpt::new(Some(cache_dir.path())),
+                    transaction_merge_target_versions: Some(20),
+                    state_snapshot_retention_epochs: Some(1),
+                    dry_run,
+                },
+                Arc::clone(&storage),
+                4,
+            )
+            .run()
+        };
+        let metadata_cache_opt = MetadataCacheOpt::new(Some(cache_dir.path()));
+        let load_view =
+            || metadata::cache::sync_and_load(&metadata_cache_opt, Arc::clone(&storage), 4);
+
+        // Nothing changes in a dry run.
+        run(true).await.unwrap();
+        let view = load_view().await.unwrap();
+        assert_eq!(
+            ranges(&view.select_transaction_backups(0, u64::MAX).unwrap()),
+            vec![(0, 9), (10, 19), (20, 29), (30, 39)],
+        );
+        assert!(view.expect_state_snapshot(100).is_ok());
+
+        run(false).await.unwrap();
+        let view = load_view().await.unwrap();
+        let backups = view.select_transaction_backups(0, u64::MAX).unwrap();
+        assert_eq!(ranges(&backups), vec![(0, 19), (20, 39)]);
+        // The merged manifests reference the original chunks, which are kept.
+        for backup in backups {
+            let manifest: TransactionBackup =
+                storage.load_json_file(&backup.manifest).await.unwrap();
+            manifest.verify().unwrap();
+            for chunk in manifest.chunks {
+                storage.read_all(&chunk.transactions).await.unwrap();
+                storage.read_all(&chunk.proof).await.unwrap();
+            }
+        }
+        for manifest in &txn_manifests {
+            assert!(storage.read_all(manifest).await.is_err());
+        }
+
+        // Only the snapshot of epoch 1 is out of the retention window.
+        assert!(view.expect_state_snapshot(100).is_err());
+        assert!(view.expect_state_snapshot(200).is_ok());
+        assert!(view.expect_state_snapshot(300).is_ok());
+        for file in &snapshot_files[0] {
+            assert!(storage.read_all(file).await.is_err());
+        }
+        for file in snapshot_files[1].iter().chain(&snapshot_files[2]) {
+            storage.read_all(file).await.unwrap();
+        }
+    }
+}
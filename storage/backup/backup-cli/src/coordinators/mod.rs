@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod backup;
+pub mod maintenance;
 pub mod replay_verify;
 pub mod restore;
 pub mod verify;
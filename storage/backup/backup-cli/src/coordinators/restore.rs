@@ -3,23 +3,31 @@
 
 use crate::{
     backup_types::{
-        epoch_ending::restore::EpochHistoryRestoreController,
+        epoch_ending::restore::{EpochHistory, EpochHistoryRestoreController},
         state_snapshot::restore::{StateSnapshotRestoreController, StateSnapshotRestoreOpt},
-        transaction::restore::TransactionRestoreBatchController,
+        transaction::{
+            manifest::{TransactionBackup, TransactionChunk},
+            restore::{LoadedChunk, TransactionRestoreBatchController},
+        },
     },
     metadata,
-    metadata::{cache::MetadataCacheOpt, TransactionBackupMeta},
+    metadata::{cache::MetadataCacheOpt, view::MetadataView, TransactionBackupMeta},
     metrics::restore::{
         COORDINATOR_FAIL_TS, COORDINATOR_START_TS, COORDINATOR_SUCC_TS, COORDINATOR_TARGET_VERSION,
     },
-    storage::BackupStorage,
-    utils::{unix_timestamp_sec, GlobalRestoreOptions},
+    storage::{BackupStorage, BackupStorageExt},
+    utils::{stream::StreamX, unix_timestamp_sec, GlobalRestoreOptions},
 };
-use anyhow::{anyhow, bail, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 use aptos_logger::prelude::*;
-use aptos_types::transaction::Version;
+use aptos_types::{
+    ledger_info::LedgerInfoWithSignatures,
+    proof::TransactionAccumulatorRangeProof,
+    transaction::{Transaction, Version},
+};
 use clap::Parser;
-use std::sync::Arc;
+use futures::{stream, TryStreamExt};
+use std::{slice, sync::Arc};
 
 #[derive(Parser)]
 pub struct RestoreCoordinatorOpt {
@@ -39,6 +47,13 @@ pub struct RestoreCoordinatorOpt {
     pub ledger_history_start_version: Option<Version>,
     #[clap(long, help = "Skip restoring epoch ending info, used for debugging.")]
     pub skip_epoch_endings: bool,
+    #[clap(
+        long,
+        help = "Restore the DB to the state right before the first block with a timestamp (in \
+        microseconds since the Unix epoch) greater than this. The target version is resolved from \
+        the transaction backups. Can't be used together with --target-version."
+    )]
+    pub target_timestamp: Option<u64>,
 }
 
 pub struct RestoreCoordinator {
@@ -48,6 +63,7 @@ pub struct RestoreCoordinator {
     replay_all: bool,
     ledger_history_start_version: Option<Version>,
     skip_epoch_endings: bool,
+    target_timestamp: Option<u64>,
 }
 
 impl RestoreCoordinator {
@@ -63,6 +79,7 @@ impl RestoreCoordinator {
             replay_all: opt.replay_all,
             ledger_history_start_version: opt.ledger_history_start_version,
             skip_epoch_endings: opt.skip_epoch_endings,
+            target_timestamp: opt.target_timestamp,
         }
     }
 
@@ -90,10 +107,12 @@ impl RestoreCoordinator {
         // N.b.
         // The coordinator now focuses on doing one procedure, ignoring the combination of options
         // supported before:
-        //   1. a most recent state snapshot
-        //   2. a only transaction and its output, at the state snapshot version
-        //   3. the epoch history from 0 up until the latest closed epoch preceding the state
-        //      snapshot version.
+        //   1. a state snapshot, the most recent one at or before the target version
+        //   2. transactions and their outputs from the state snapshot version, replayed up to the
+        //      target version (or, without a target, to the end of the transaction backup
+        //      containing the state snapshot version)
+        //   3. the epoch history from 0 up until the latest closed epoch preceding the target
+        //      version.
         // And it does so in a resume-able way.
 
         if self.replay_all {
@@ -102,6 +121,12 @@ impl RestoreCoordinator {
         if self.ledger_history_start_version.is_some() {
             bail!("--ledger-history-start-version not supported in this version.");
         }
+        if self.target_timestamp.is_some() {
+            ensure!(
+                self.target_version() == Version::MAX,
+                "--target-timestamp can't be used together with --target-version.",
+            );
+        }
 
         let metadata_view = metadata::cache::sync_and_load(
             &self.metadata_cache_opt,
@@ -125,29 +150,76 @@ impl RestoreCoordinator {
             return Ok(());
         }
 
+        let requested_target_version = match self.target_timestamp {
+            Some(timestamp_usecs) => {
+                let version = self
+                    .resolve_target_timestamp(&metadata_view, timestamp_usecs)
+                    .await?;
+                info!(
+                    timestamp_usecs = timestamp_usecs,
+                    version = version,
+                    "Target timestamp resolved."
+                );
+                version
+            }
+            None => self.target_version(),
+        };
+        let target_version = self.get_actual_target_version(
+            &metadata_view.select_transaction_backups(0, requested_target_version)?,
+            requested_target_version,
+        )?;
+
         let state_snapshot_backup =
             if let Some(version) = self.global_opt.run_mode.get_in_progress_state_snapshot()? {
                 info!(
                     version = version,
                     "Found in progress state snapshot restore",
                 );
-                metadata_view.expect_state_snapshot(version)?
+                let backup = metadata_view.expect_state_snapshot(version)?;
+                ensure!(
+                    backup.version <= target_version,
+                    "In progress state snapshot restore at version {} is newer than the target \
+                    version {}.",
+                    backup.version,
+                    target_version,
+                );
+                backup
             } else {
-                let max_txn_ver = metadata_view
-                    .max_transaction_version()?
-                    .ok_or_else(|| anyhow!("No transaction backup found."))?;
                 metadata_view
-                    .select_state_snapshot(std::cmp::min(self.target_version(), max_txn_ver))?
+                    .select_state_snapshot(target_version)?
                     .ok_or_else(|| anyhow!("No usable state snapshot."))?
             };
         let version = state_snapshot_backup.version;
-        let epoch_ending_backups = metadata_view.select_epoch_ending_backups(version)?;
-        let transaction_backup = metadata_view
-            .select_transaction_backups(version, version)?
-            .pop()
-            .unwrap();
-        COORDINATOR_TARGET_VERSION.set(version as i64);
-        info!(version = version, "Restore target decided.");
+
+        let transaction_backups =
+            metadata_view.select_transaction_backups(version, target_version)?;
+        // Without an explicit target, only the transaction backup containing the state snapshot
+        // version is replayed.
+        let target_version =
+            if self.target_timestamp.is_none() && self.target_version() == Version::MAX {
+                transaction_backups
+                    .first()
+                    .ok_or_else(|| anyhow!("No transaction backup found."))?
+                    .last_version
+            } else {
+                target_version
+            };
+        let transaction_backups = transaction_backups
+            .into_iter()
+            .take_while(|b| b.first_version <= target_version)
+            .collect::<Vec<_>>();
+        let epoch_ending_backups = metadata_view.select_epoch_ending_backups(target_version)?;
+        COORDINATOR_TARGET_VERSION.set(target_version as i64);
+        info!(
+            state_snapshot_version = version,
+            target_version = target_version,
+            "Restore target decided."
+        );
+
+        let global_opt = GlobalRestoreOptions {
+            target_version,
+            ..self.global_opt.clone()
+        };
 
         let epoch_history = if !self.skip_epoch_endings {
             Some(Arc::new(
@@ -156,7 +228,7 @@ impl RestoreCoordinator {
                         .into_iter()
                         .map(|backup| backup.manifest)
                         .collect(),
-                    self.global_opt.clone(),
+                    global_opt.clone(),
                     self.storage.clone(),
                 )
                 .run()
@@ -171,25 +243,29 @@ impl RestoreCoordinator {
                 manifest_handle: state_snapshot_backup.manifest,
                 version,
             },
-            self.global_opt.clone(),
+            global_opt.clone(),
             Arc::clone(&self.storage),
             epoch_history.clone(),
         )
         .run()
         .await?;
 
-        let txn_manifests = vec![transaction_backup.manifest];
+        let txn_manifests = transaction_backups
+            .iter()
+            .map(|b| b.manifest.clone())
+            .collect();
         TransactionRestoreBatchController::new(
-            self.global_opt,
-            self.storage,
+            global_opt,
+            Arc::clone(&self.storage),
             txn_manifests,
             Some(version + 1),
-            epoch_history,
+            epoch_history.clone(),
         )
         .run()
         .await?;
 
-        Ok(())
+        self.verify_restored_db(&transaction_backups, target_version, epoch_history)
+            .await
     }
 }
 
@@ -198,14 +274,14 @@ impl RestoreCoordinator {
         self.global_opt.target_version
     }
 
-    #[allow(dead_code)]
     fn get_actual_target_version(
         &self,
         transaction_backups: &[TransactionBackupMeta],
+        target_version: Version,
     ) -> Result<Version> {
         if let Some(b) = transaction_backups.last() {
-            if b.last_version > self.target_version() {
-                Ok(self.target_version())
+            if b.last_version >= target_version {
+                Ok(target_version)
             } else {
                 warn!(
                     "Can't find transaction backup containing the target version, \
@@ -217,4 +293,313 @@ impl RestoreCoordinator {
             bail!("No transaction backup found.")
         }
     }
+
+    /// Loads the chunks of all the transaction backups, in version order.
+    async fn load_transaction_chunks(
+        &self,
+        transaction_backups: &[TransactionBackupMeta],
+    ) -> Result<Vec<TransactionChunk>> {
+        let con = self.global_opt.concurrent_downloads;
+        let storage = &self.storage;
+        let manifests = stream::iter(transaction_backups.iter().map(|backup| async move {
+            let manifest: TransactionBackup = storage.load_json_file(&backup.manifest).await?;
+            manifest.verify()?;
+            Result::<_>::Ok(manifest)
+        }))
+        .buffered_x(con * 2, con)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        Ok(manifests
+            .into_iter()
+            .flat_map(|manifest| manifest.chunks)
+            .collect())
+    }
+
+    /// Finds the last version before the first block with a timestamp greater than
+    /// `timestamp_usecs`.
+    ///
+    /// The ledger info proving a transaction chunk is no older than any of the transactions in it,
+    /// so the first chunk that can contain such a block is found by a binary search on the ledger
+    /// info timestamps, and only the transactions from that chunk on need to be scanned.
+    async fn resolve_target_timestamp(
+        &self,
+        metadata_view: &MetadataView,
+        timestamp_usecs: u64,
+    ) -> Result<Version> {
+        let transaction_backups = metadata_view.select_transaction_backups(0, Version::MAX)?;
+        let chunks = self.load_transaction_chunks(&transaction_backups).await?;
+        let last_version = chunks
+            .last()
+            .ok_or_else(|| anyhow!("No transaction backup found."))?
+            .last_version;
+
+        let (mut low, mut high) = (0, chunks.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (_range_proof, ledger_info) = self
+                .storage
+                .load_bcs_file::<(TransactionAccumulatorRangeProof, LedgerInfoWithSignatures)>(
+                    &chunks[mid].proof,
+                )
+                .await?;
+            if ledger_info.ledger_info().timestamp_usecs() <= timestamp_usecs {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        for chunk in chunks.into_iter().skip(low) {
+            let loaded_chunk = LoadedChunk::load(chunk, &self.storage, None).await?;
+            let first_version = loaded_chunk.manifest.first_version;
+            for (version, txn) in (first_version..).zip(loaded_chunk.txns.iter()) {
+                if let Transaction::BlockMetadata(block_metadata) = txn {
+                    if block_metadata.timestamp_usecs() > timestamp_usecs {
+                        ensure!(
+                            version > 0,
+                            "No transaction before timestamp {}.",
+                            timestamp_usecs,
+                        );
+                        return Ok(version - 1);
+                    }
+                }
+            }
+        }
+
+        warn!(
+            timestamp_usecs = timestamp_usecs,
+            "The target timestamp is newer than all transaction backups, \
+            will restore as much as possible"
+        );
+        Ok(last_version)
+    }
+
+    /// Checks that the DB ends exactly at the target version, with the transaction info proven by
+    /// the backup (which carries the state checkpoint root hash and commits to the outputs of the
+    /// transaction).
+    async fn verify_restored_db(
+        &self,
+        transaction_backups: &[TransactionBackupMeta],
+        target_version: Version,
+        epoch_history: Option<Arc<EpochHistory>>,
+    ) -> Result<()> {
+        let (db_version, db_txn_info) =
+            match self.global_opt.run_mode.get_latest_transaction_info()? {
+                Some(latest) => latest,
+                // Nothing is written to the DB when verifying.
+                None => return Ok(()),
+            };
+        ensure!(
+            db_version == target_version,
+            "Restored DB ends at version {}, expected {}.",
+            db_version,
+            target_version,
+        );
+
+        let last_backup = transaction_backups
+            .last()
+            .ok_or_else(|| anyhow!("No transaction backup found."))?;
+        let chunk = self
+            .load_transaction_chunks(slice::from_ref(last_backup))
+            .await?
+            .into_iter()
+            .find(|chunk| {
+                chunk.first_version <= target_version && target_version <= chunk.last_version
+            })
+            .ok_or_else(|| anyhow!("No transaction chunk found at version {}.", target_version))?;
+        let first_version = chunk.first_version;
+        let loaded_chunk = LoadedChunk::load(chunk, &self.storage, epoch_history.as_ref()).await?;
+        let expected_txn_info = &loaded_chunk.txn_infos[(target_version - first_version) as usize];
+        ensure!(
+            &db_txn_info == expected_txn_info,
+            "Transaction info at version {} in the restored DB doesn't match the backup. \
+            In DB: {:?}, in backup: {:?}",
+            target_version,
+            db_txn_info,
+            expected_txn_info,
+        );
+
+        info!(
+            version = target_version,
+            ledger_info_version = loaded_chunk.ledger_info.ledger_info().version(),
+            state_checkpoint_hash = ?db_txn_info.state_checkpoint_hash(),
+            "Restored DB verified against the backup."
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        backup_types::{
+            epoch_ending::backup::{EpochEndingBackupController, EpochEndingBackupOpt},
+            state_snapshot::backup::{StateSnapshotBackupController, StateSnapshotBackupOpt},
+            transaction::backup::{TransactionBackupController, TransactionBackupOpt},
+        },
+        coordinators::restore::{RestoreCoordinator, RestoreCoordinatorOpt},
+        metadata::cache::MetadataCacheOpt,
+        storage::{local_fs::LocalFs, BackupStorage},
+        utils::{
+            backup_service_client::BackupServiceClient, test_utils::start_local_backup_service,
+            ConcurrentDownloadsOpt, GlobalBackupOpt, GlobalRestoreOpt, ReplayConcurrencyLevelOpt,
+            RocksdbOpt, TrustedWaypointOpt,
+        },
+    };
+    use aptos_temppath::TempPath;
+    use aptos_types::transaction::{Transaction, Version};
+    use aptosdb::AptosDB;
+    use executor_test_helpers::integration_test_impl::test_execution_with_storage_impl;
+    use std::{convert::TryInto, sync::Arc};
+    use storage_interface::DbReader;
+    use tokio::{runtime::Runtime, time::Duration};
+
+    fn restore(
+        rt: &Runtime,
+        store: &Arc<dyn BackupStorage>,
+        target_version: Option<Version>,
+        target_timestamp: Option<u64>,
+    ) -> TempPath {
+        let tgt_db_dir = TempPath::new();
+        tgt_db_dir.create_as_dir().unwrap();
+        let metadata_cache_dir = TempPath::new();
+
+        let global_opt = GlobalRestoreOpt {
+            dry_run: false,
+            db_dir: Some(tgt_db_dir.path().to_path_buf()),
+            target_version,
+            trusted_waypoints: TrustedWaypointOpt::default(),
+            rocksdb_opt: RocksdbOpt::default(),
+            concurrent_downloads: ConcurrentDownloadsOpt::default(),
+            replay_concurrency_level: ReplayConcurrencyLevelOpt::default(),
+        }
+        .try_into()
+        .unwrap();
+        let opt = RestoreCoordinatorOpt {
+            metadata_cache_opt: MetadataCacheOpt::new(Some(metadata_cache_dir.path())),
+            replay_all: false,
+            ledger_history_start_version: None,
+            skip_epoch_endings: false,
+            target_timestamp,
+        };
+        rt.block_on(RestoreCoordinator::new(opt, global_opt, Arc::clone(store)).run())
+            .unwrap();
+
+        tgt_db_dir
+    }
+
+    #[test]
+    fn test_point_in_time_restore() {
+        let db = test_execution_with_storage_impl();
+        let latest_ver = db.get_latest_version().unwrap();
+        let latest_epoch = db.get_latest_epoch_state().unwrap().epoch;
+        let epoch_ending_lis = db
+            .get_epoch_ending_ledger_infos(0, latest_epoch)
+            .unwrap()
+            .ledger_info_with_sigs;
+        let state_snapshot_li = epoch_ending_lis[epoch_ending_lis.len() / 2].ledger_info();
+        let state_snapshot_ver = state_snapshot_li.version();
+
+        let backup_dir = TempPath::new();
+        backup_dir.create_as_dir().unwrap();
+        let store: Arc<dyn BackupStorage> = Arc::new(LocalFs::new(backup_dir.path().to_path_buf()));
+        let (rt, port) = start_local_backup_service(Arc::clone(&db));
+        let client = Arc::new(BackupServiceClient::new(format!(
+            "http://localhost:{}",
+            port
+        )));
+        let global_backup_opt = GlobalBackupOpt {
+            max_chunk_size: 2048,
+        };
+
+        // Backup everything, with a single state snapshot in the middle.
+        rt.block_on(
+            EpochEndingBackupController::new(
+                EpochEndingBackupOpt {
+                    start_epoch: 0,
+                    end_epoch: latest_epoch,
+                },
+                global_backup_opt.clone(),
+                Arc::clone(&client),
+                Arc::clone(&store),
+            )
+            .run(),
+        )
+        .unwrap();
+        rt.block_on(
+            StateSnapshotBackupController::new(
+                StateSnapshotBackupOpt {
+                    epoch: state_snapshot_li.epoch(),
+                },
+                global_backup_opt.clone(),
+                Arc::clone(&client),
+                Arc::clone(&store),
+            )
+            .run(),
+        )
+        .unwrap();
+        rt.block_on(
+            TransactionBackupController::new(
+                TransactionBackupOpt {
+                    start_version: 0,
+                    num_transactions: latest_ver as usize + 1,
+                },
+                global_backup_opt,
+                Arc::clone(&client),
+                Arc::clone(&store),
+            )
+            .run(),
+        )
+        .unwrap();
+
+        let check_restored_db = |tgt_db_dir: &TempPath, target_ver: Version| {
+            let tgt_db = AptosDB::new_readonly_for_test(tgt_db_dir);
+            assert_eq!(
+                tgt_db
+                    .get_latest_transaction_info_option()
+                    .unwrap()
+                    .map(|(version, _txn_info)| version),
+                Some(target_ver),
+            );
+            let num_replayed = target_ver - state_snapshot_ver + 1;
+            assert_eq!(
+                db.get_transactions(state_snapshot_ver, num_replayed, target_ver, true)
+                    .unwrap(),
+                tgt_db
+                    .get_transactions(state_snapshot_ver, num_replayed, target_ver, true)
+                    .unwrap(),
+            );
+        };
+
+        // Restore to a version.
+        let target_ver = (state_snapshot_ver + latest_ver + 1) / 2;
+        let tgt_db_dir = restore(&rt, &store, Some(target_ver), None);
+        check_restored_db(&tgt_db_dir, target_ver);
+
+        // Restore to a timestamp, right before the last block after the state snapshot.
+        let (block_ver, block_timestamp) = db
+            .get_transactions(
+                state_snapshot_ver,
+                latest_ver - state_snapshot_ver + 1,
+                latest_ver,
+                false, /* fetch_events */
+            )
+            .unwrap()
+            .transactions
+            .iter()
+            .zip(state_snapshot_ver..)
+            .filter_map(|(txn, version)| match txn {
+                Transaction::BlockMetadata(block_metadata) if version > state_snapshot_ver => {
+                    Some((version, block_metadata.timestamp_usecs()))
+                }
+                _ => None,
+            })
+            .last()
+            .expect("Blocks are committed after the state snapshot.");
+        let tgt_db_dir = restore(&rt, &store, None, Some(block_timestamp - 1));
+        check_restored_db(&tgt_db_dir, block_ver - 1);
+
+        rt.shutdown_timeout(Duration::from_secs(1));
+    }
 }
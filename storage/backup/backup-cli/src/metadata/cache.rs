@@ -16,7 +16,7 @@ use futures::stream::poll_fn;
 use once_cell::sync::Lazy;
 use std::{
     collections::{HashMap, HashSet},
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::Arc,
     time::Instant,
 };
@@ -50,6 +50,12 @@ impl MetadataCacheOpt {
     // in cache we save things other than the cached files.
     const SUB_DIR: &'static str = "cache";
 
+    pub fn new(dir: Option<impl AsRef<Path>>) -> Self {
+        Self {
+            dir: dir.map(|dir| dir.as_ref().to_path_buf()),
+        }
+    }
+
     fn cache_dir(&self) -> PathBuf {
         self.dir
             .clone()
@@ -72,6 +78,21 @@ pub async fn sync_and_load(
     storage: Arc<dyn BackupStorage>,
     concurrent_downloads: usize,
 ) -> Result<MetadataView> {
+    Ok(sync_and_load_by_file(opt, storage, concurrent_downloads)
+        .await?
+        .into_iter()
+        .flat_map(|(_file_handle, metadata_vec)| metadata_vec)
+        .collect::<Vec<_>>()
+        .into())
+}
+
+/// Same as `sync_and_load()`, but returns the metadata entries grouped by the remote metadata
+/// file they are loaded from, so that the files can be individually managed (e.g. deleted).
+pub(crate) async fn sync_and_load_by_file(
+    opt: &MetadataCacheOpt,
+    storage: Arc<dyn BackupStorage>,
+    concurrent_downloads: usize,
+) -> Result<Vec<(FileHandle, Vec<Metadata>)>> {
     let timer = Instant::now();
     let cache_dir = opt.cache_dir();
     create_dir_all(&cache_dir).await.err_notes(&cache_dir)?; // create if not present already
@@ -175,26 +196,25 @@ pub async fn sync_and_load(
 
     info!("Loading all metadata files to memory.");
     // Load metadata from synced cache files.
-    let mut metadata_vec = Vec::new();
+    let mut metadata_by_file = Vec::new();
     for h in new_remote_hashes.into_iter().chain(up_to_date_local_hashes) {
         let cached_file = cache_dir.join(h);
-        metadata_vec.extend(
-            OpenOptions::new()
-                .read(true)
-                .open(&cached_file)
-                .await
-                .err_notes(&cached_file)?
-                .load_metadata_lines()
-                .await
-                .err_notes(&cached_file)?
-                .into_iter(),
-        )
+        let metadata_vec = OpenOptions::new()
+            .read(true)
+            .open(&cached_file)
+            .await
+            .err_notes(&cached_file)?
+            .load_metadata_lines()
+            .await
+            .err_notes(&cached_file)?;
+        let file_handle = remote_file_handle_by_hash.get(h).expect("In map.");
+        metadata_by_file.push((file_handle.clone(), metadata_vec));
     }
     info!(
         total_time = timer.elapsed().as_secs(),
         "Metadata cache loaded.",
     );
-    Ok(metadata_vec.into())
+    Ok(metadata_by_file)
 }
 
 trait FileHandleHash {
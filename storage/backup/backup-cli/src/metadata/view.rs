@@ -7,7 +7,11 @@ use crate::metadata::{
 use anyhow::{anyhow, ensure, Result};
 use aptos_types::transaction::Version;
 use itertools::Itertools;
-use std::{fmt, str::FromStr};
+use std::{
+    cmp::{max, Reverse},
+    fmt,
+    str::FromStr,
+};
 
 pub struct MetadataView {
     epoch_ending_backups: Vec<EpochEndingBackupMeta>,
@@ -70,10 +74,20 @@ impl MetadataView {
         // range (which is always true when we backup from a single backup coordinator)
         let mut next_ver = 0;
         let mut res = Vec::new();
-        for backup in self.transaction_backups.iter().sorted() {
+        // Among backups starting at the same version, the longest comes first, so that backups
+        // fully covered by another one (e.g. left over from a merge by the maintenance) are
+        // skipped.
+        for backup in self
+            .transaction_backups
+            .iter()
+            .sorted_by_key(|b| (b.first_version, Reverse(b.last_version)))
+        {
             if backup.first_version > target_version {
                 break;
             }
+            if backup.last_version < next_ver {
+                continue;
+            }
             ensure!(
                 backup.first_version == next_ver,
                 "Transaction backup ranges not continuous, expecting version {}, got {}.",
@@ -126,6 +140,40 @@ impl MetadataView {
 
         Ok(res)
     }
+
+    /// Returns the version ranges (inclusive on both sides) not covered by any transaction
+    /// backup, up to the latest version backed up.
+    pub fn transaction_backup_gaps(&self) -> Vec<(Version, Version)> {
+        find_gaps(
+            self.transaction_backups
+                .iter()
+                .map(|b| (b.first_version, b.last_version)),
+        )
+    }
+
+    /// Returns the epoch ranges (inclusive on both sides) not covered by any epoch ending backup,
+    /// up to the latest epoch backed up.
+    pub fn epoch_ending_backup_gaps(&self) -> Vec<(u64, u64)> {
+        find_gaps(
+            self.epoch_ending_backups
+                .iter()
+                .map(|b| (b.first_epoch, b.last_epoch)),
+        )
+    }
+}
+
+/// Given (possibly overlapping) inclusive ranges, returns the ranges between 0 and the end of the
+/// last range that are covered by none of them.
+fn find_gaps(ranges: impl Iterator<Item = (u64, u64)>) -> Vec<(u64, u64)> {
+    let mut gaps = Vec::new();
+    let mut next = 0;
+    for (first, last) in ranges.sorted() {
+        if first > next {
+            gaps.push((next, first - 1));
+        }
+        next = max(next, last + 1);
+    }
+    gaps
 }
 
 impl From<Vec<Metadata>> for MetadataView {
@@ -204,3 +252,77 @@ impl FromStr for BackupStorageState {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::{view::MetadataView, Metadata};
+
+    fn txn(first_version: u64, last_version: u64) -> Metadata {
+        Metadata::new_transaction_backup(
+            first_version,
+            last_version,
+            format!(
+                "transaction_{}-{}/transaction.manifest",
+                first_version, last_version
+            ),
+        )
+    }
+
+    fn epoch_ending(first_epoch: u64, last_epoch: u64) -> Metadata {
+        Metadata::new_epoch_ending_backup(
+            first_epoch,
+            last_epoch,
+            first_epoch * 10,
+            last_epoch * 10 + 9,
+            format!(
+                "epoch_ending_{}-{}/epoch_ending.manifest",
+                first_epoch, last_epoch
+            ),
+        )
+    }
+
+    #[test]
+    fn test_select_transaction_backups_skips_covered() {
+        let view: MetadataView = vec![
+            txn(0, 9),
+            txn(10, 19),
+            txn(20, 29),
+            txn(10, 29),
+            txn(30, 39),
+        ]
+        .into();
+
+        let ranges = |start, target| {
+            view.select_transaction_backups(start, target)
+                .unwrap()
+                .into_iter()
+                .map(|b| (b.first_version, b.last_version))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ranges(0, 100), vec![(0, 9), (10, 29), (30, 39)]);
+        assert_eq!(ranges(15, 25), vec![(10, 29)]);
+
+        let view: MetadataView = vec![txn(0, 9), txn(5, 19)].into();
+        assert!(view.select_transaction_backups(0, 100).is_err());
+    }
+
+    #[test]
+    fn test_backup_gaps() {
+        let view: MetadataView = vec![
+            txn(0, 9),
+            txn(20, 29),
+            txn(25, 39),
+            txn(50, 59),
+            epoch_ending(1, 2),
+            epoch_ending(3, 3),
+            epoch_ending(6, 9),
+        ]
+        .into();
+        assert_eq!(view.transaction_backup_gaps(), vec![(10, 19), (40, 49)]);
+        assert_eq!(view.epoch_ending_backup_gaps(), vec![(0, 0), (4, 5)]);
+
+        let view: MetadataView = Vec::new().into();
+        assert!(view.transaction_backup_gaps().is_empty());
+        assert!(view.epoch_ending_backup_gaps().is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_push_metrics::{register_int_gauge, IntGauge};
+use once_cell::sync::Lazy;
+
+pub static MAINTENANCE_TRANSACTION_GAPS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_db_backup_maintenance_transaction_gaps",
+        "Number of version ranges not covered by any transaction backup."
+    )
+    .unwrap()
+});
+
+pub static MAINTENANCE_EPOCH_ENDING_GAPS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_db_backup_maintenance_epoch_ending_gaps",
+        "Number of epoch ranges not covered by any epoch ending backup."
+    )
+    .unwrap()
+});
+
+pub static MAINTENANCE_MERGED_TRANSACTION_BACKUPS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_db_backup_maintenance_merged_transaction_backups",
+        "Number of transaction backups merged into bigger ones in the last maintenance run."
+    )
+    .unwrap()
+});
+
+pub static MAINTENANCE_DELETED_STATE_SNAPSHOTS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_db_backup_maintenance_deleted_state_snapshots",
+        "Number of state snapshots deleted in the last maintenance run."
+    )
+    .unwrap()
+});
+
+pub static MAINTENANCE_COORDINATOR_START_TS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_db_backup_maintenance_coordinator_start_timestamp_s",
+        "Timestamp when the maintenance coordinator starts."
+    )
+    .unwrap()
+});
+
+pub static MAINTENANCE_COORDINATOR_SUCC_TS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_db_backup_maintenance_coordinator_succeed_timestamp_s",
+        "Timestamp when the maintenance coordinator succeeds."
+    )
+    .unwrap()
+});
+
+pub static MAINTENANCE_COORDINATOR_FAIL_TS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_db_backup_maintenance_coordinator_fail_timestamp_s",
+        "Timestamp when the maintenance coordinator fails."
+    )
+    .unwrap()
+});
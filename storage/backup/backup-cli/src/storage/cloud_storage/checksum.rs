@@ -0,0 +1,154 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::{future::BoxFuture, ready, FutureExt};
+use object_store::{path::Path, ObjectStore};
+use sha2::{Digest, Sha256};
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// Hex encoded SHA-256 digest of the hashed content, which is what is stored in checksum objects.
+pub(super) fn encode_checksum(hasher: Sha256) -> String {
+    hex::encode(hasher.finalize())
+}
+
+/// Wraps the writer of an object, hashing the content on the fly. The checksum object is
+/// uploaded once the object itself is completely written, on `shutdown()`.
+pub(super) struct ChecksumWriter {
+    inner: Box<dyn AsyncWrite + Send + Unpin>,
+    hasher: Option<Sha256>,
+    store: Arc<dyn ObjectStore>,
+    checksum_path: Path,
+    checksum_upload: Option<BoxFuture<'static, io::Result<()>>>,
+}
+
+impl ChecksumWriter {
+    pub fn new(
+        inner: Box<dyn AsyncWrite + Send + Unpin>,
+        store: Arc<dyn ObjectStore>,
+        checksum_path: Path,
+    ) -> Self {
+        Self {
+            inner,
+            hasher: Some(Sha256::new()),
+            store,
+            checksum_path,
+            checksum_upload: None,
+        }
+    }
+}
+
+impl AsyncWrite for ChecksumWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let hasher = match this.hasher.as_mut() {
+            Some(hasher) => hasher,
+            None => {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    "Write after shutdown.",
+                )))
+            }
+        };
+        let num_bytes = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+        hasher.update(&buf[..num_bytes]);
+        Poll::Ready(Ok(num_bytes))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.checksum_upload.is_none() {
+            // Finish the (multipart) upload of the object before uploading its checksum.
+            ready!(Pin::new(&mut this.inner).poll_shutdown(cx))?;
+            let hasher = this
+                .hasher
+                .take()
+                .expect("Hasher must exist before shutdown.");
+            let checksum = encode_checksum(hasher);
+            let store = this.store.clone();
+            let checksum_path = this.checksum_path.clone();
+            this.checksum_upload = Some(
+                async move {
+                    store
+                        .put(&checksum_path, checksum.into())
+                        .await
+                        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+                }
+                .boxed(),
+            );
+        }
+        this.checksum_upload
+            .as_mut()
+            .expect("Checksum upload must exist.")
+            .as_mut()
+            .poll(cx)
+    }
+}
+
+/// Wraps the reader of an object, hashing the content on the fly and failing the read once
+/// the end of the object is reached if the content doesn't match the expected checksum.
+pub(super) struct ChecksumVerifyingReader<R> {
+    inner: R,
+    hasher: Option<Sha256>,
+    expected_checksum: String,
+    object_path: Path,
+}
+
+impl<R> ChecksumVerifyingReader<R> {
+    pub fn new(inner: R, expected_checksum: String, object_path: Path) -> Self {
+        Self {
+            inner,
+            hasher: Some(Sha256::new()),
+            expected_checksum,
+            object_path,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ChecksumVerifyingReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let filled_before = buf.filled().len();
+        ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+        let new_bytes = &buf.filled()[filled_before..];
+
+        if !new_bytes.is_empty() {
+            if let Some(hasher) = this.hasher.as_mut() {
+                hasher.update(new_bytes);
+            }
+        } else if buf.remaining() > 0 {
+            // EOF, verify the checksum (only once, in case the reader is polled again).
+            if let Some(hasher) = this.hasher.take() {
+                let checksum = encode_checksum(hasher);
+                if checksum != this.expected_checksum {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "Checksum mismatch for {}, expected: {}, actual: {}",
+                            this.object_path, this.expected_checksum, checksum,
+                        ),
+                    )));
+                }
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
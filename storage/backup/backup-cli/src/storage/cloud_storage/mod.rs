@@ -0,0 +1,314 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+mod checksum;
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    storage::{
+        cloud_storage::checksum::{ChecksumVerifyingReader, ChecksumWriter},
+        BackupHandle, BackupHandleRef, BackupStorage, FileHandle, FileHandleRef, ShellSafeName,
+        TextLine,
+    },
+    utils::error_notes::ErrorNotes,
+};
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use clap::Parser;
+use futures::{StreamExt, TryStreamExt};
+use object_store::{
+    aws::AmazonS3Builder, azure::MicrosoftAzureBuilder, gcp::GoogleCloudStorageBuilder, path::Path,
+    ObjectStore, RetryConfig,
+};
+use std::{io, path::PathBuf, sync::Arc, time::Duration};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::StreamReader;
+
+#[derive(Clone, Parser)]
+pub struct CloudStorageOpt {
+    #[clap(
+        long,
+        help = "Path prefix (folder) in the bucket or container under which backups are stored. \
+        [Defaults to the root of the bucket or container]"
+    )]
+    pub prefix: Option<String>,
+    #[clap(
+        long,
+        default_value = "10",
+        help = "Maximum number of retries of a failed request to the remote storage."
+    )]
+    pub max_retries: usize,
+    #[clap(
+        long,
+        default_value = "300",
+        help = "Maximum time in seconds spent retrying a failed request to the remote storage."
+    )]
+    pub retry_timeout_secs: u64,
+}
+
+impl CloudStorageOpt {
+    fn retry_config(&self) -> RetryConfig {
+        RetryConfig {
+            max_retries: self.max_retries,
+            retry_timeout: Duration::from_secs(self.retry_timeout_secs),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Parser)]
+pub struct S3Opt {
+    #[clap(long, help = "S3 bucket to hold backups.")]
+    pub bucket: String,
+    #[clap(long, help = "Region of the S3 bucket.")]
+    pub region: String,
+    #[clap(
+        long,
+        help = "Endpoint of an S3 compatible storage (e.g. MinIO). [Defaults to AWS S3]"
+    )]
+    pub endpoint: Option<String>,
+    #[clap(
+        long,
+        help = "AWS access key ID. [Defaults to the instance or web identity credentials]"
+    )]
+    pub access_key_id: Option<String>,
+    #[clap(
+        long,
+        help = "AWS secret access key, required if the access key ID is set."
+    )]
+    pub secret_access_key: Option<String>,
+    #[clap(
+        long,
+        help = "Allow plain HTTP endpoints, e.g. a local S3 compatible storage."
+    )]
+    pub allow_http: bool,
+    #[clap(flatten)]
+    pub cloud_storage: CloudStorageOpt,
+}
+
+#[derive(Parser)]
+pub struct GcsOpt {
+    #[clap(long, help = "GCS bucket to hold backups.")]
+    pub bucket: String,
+    #[clap(
+        long,
+        parse(from_os_str),
+        help = "Path to the JSON file of the service account used to access the bucket."
+    )]
+    pub service_account_path: PathBuf,
+    #[clap(flatten)]
+    pub cloud_storage: CloudStorageOpt,
+}
+
+#[derive(Parser)]
+pub struct AzureOpt {
+    #[clap(long, help = "Azure storage account name.")]
+    pub account: String,
+    #[clap(long, help = "Azure storage account access key.")]
+    pub access_key: String,
+    #[clap(long, help = "Azure Blob container to hold backups.")]
+    pub container: String,
+    #[clap(
+        long,
+        help = "Use the local storage emulator (Azurite) instead of Azure."
+    )]
+    pub use_emulator: bool,
+    #[clap(flatten)]
+    pub cloud_storage: CloudStorageOpt,
+}
+
+/// A storage backend that stores everything in a remote object store (S3 compatible, GCS or
+/// Azure Blob). Objects are uploaded with multipart uploads and failed requests are retried.
+///
+/// Every object is accompanied by a checksum object under `checksums/`, holding the SHA-256 of its
+/// content, which is verified when the object is read.
+pub struct CloudStorage {
+    store: Arc<dyn ObjectStore>,
+    /// The path under which everything is stored.
+    prefix: Path,
+}
+
+impl CloudStorage {
+    const CHECKSUM_DIR: &'static str = "checksums";
+    const METADATA_DIR: &'static str = "metadata";
+
+    pub fn new(store: Arc<dyn ObjectStore>, prefix: Option<String>) -> Self {
+        Self {
+            store,
+            prefix: prefix.map_or_else(Path::default, |prefix| Path::from(prefix.as_str())),
+        }
+    }
+
+    pub fn new_with_s3_opt(opt: S3Opt) -> Result<Self> {
+        let mut builder = AmazonS3Builder::new()
+            .with_bucket_name(&opt.bucket)
+            .with_region(&opt.region)
+            .with_allow_http(opt.allow_http)
+            .with_retry(opt.cloud_storage.retry_config());
+        if let Some(endpoint) = &opt.endpoint {
+            builder = builder.with_endpoint(endpoint);
+        }
+        match (&opt.access_key_id, &opt.secret_access_key) {
+            (Some(access_key_id), Some(secret_access_key)) => {
+                builder = builder
+                    .with_access_key_id(access_key_id)
+                    .with_secret_access_key(secret_access_key);
+            }
+            (None, None) => (),
+            _ => bail!("Access key ID and secret access key must be set together."),
+        }
+        Ok(Self::new(
+            Arc::new(builder.build()?),
+            opt.cloud_storage.prefix,
+        ))
+    }
+
+    pub fn new_with_gcs_opt(opt: GcsOpt) -> Result<Self> {
+        let service_account_path = opt
+            .service_account_path
+            .to_str()
+            .ok_or_else(|| anyhow!("Invalid service account path."))?;
+        let store = GoogleCloudStorageBuilder::new()
+            .with_bucket_name(&opt.bucket)
+            .with_service_account_path(service_account_path)
+            .with_retry(opt.cloud_storage.retry_config())
+            .build()?;
+        Ok(Self::new(Arc::new(store), opt.cloud_storage.prefix))
+    }
+
+    pub fn new_with_azure_opt(opt: AzureOpt) -> Result<Self> {
+        let store = MicrosoftAzureBuilder::new()
+            .with_account(&opt.account)
+            .with_access_key(&opt.access_key)
+            .with_container_name(&opt.container)
+            .with_use_emulator(opt.use_emulator)
+            .with_retry(opt.cloud_storage.retry_config())
+            .build()?;
+        Ok(Self::new(Arc::new(store), opt.cloud_storage.prefix))
+    }
+
+    /// The path of the object holding the content of a file.
+    fn object_path(&self, file_handle: &FileHandleRef) -> Path {
+        file_handle
+            .split('/')
+            .fold(self.prefix.clone(), |path, part| path.child(part))
+    }
+
+    /// The path of the object holding the checksum of a file.
+    fn checksum_path(&self, file_handle: &FileHandleRef) -> Path {
+        file_handle
+            .split('/')
+            .fold(self.prefix.child(Self::CHECKSUM_DIR), |path, part| {
+                path.child(part)
+            })
+    }
+
+    async fn create_file(
+        &self,
+        file_handle: &FileHandleRef,
+    ) -> Result<Box<dyn AsyncWrite + Send + Unpin>> {
+        let path = self.object_path(file_handle);
+        if self.store.head(&path).await.is_ok() {
+            bail!("File already exists: {}", path);
+        }
+        let (_multipart_id, writer) = self.store.put_multipart(&path).await.err_notes(&path)?;
+        Ok(Box::new(ChecksumWriter::new(
+            writer,
+            self.store.clone(),
+            self.checksum_path(file_handle),
+        )))
+    }
+}
+
+#[async_trait]
+impl BackupStorage for CloudStorage {
+    async fn create_backup(&self, name: &ShellSafeName) -> Result<BackupHandle> {
+        // There are no folders to create in an object store.
+        Ok(name.to_string())
+    }
+
+    async fn create_for_write(
+        &self,
+        backup_handle: &BackupHandleRef,
+        name: &ShellSafeName,
+    ) -> Result<(FileHandle, Box<dyn AsyncWrite + Send + Unpin>)> {
+        let file_handle = format!("{}/{}", backup_handle, name.as_str());
+        let file = self.create_file(&file_handle).await?;
+        Ok((file_handle, file))
+    }
+
+    async fn open_for_read(
+        &self,
+        file_handle: &FileHandleRef,
+    ) -> Result<Box<dyn AsyncRead + Send + Unpin>> {
+        let checksum_path = self.checksum_path(file_handle);
+        let expected_checksum = self
+            .store
+            .get(&checksum_path)
+            .await
+            .err_notes(&checksum_path)?
+            .bytes()
+            .await
+            .err_notes(&checksum_path)?;
+        let expected_checksum = String::from_utf8(expected_checksum.to_vec())?;
+
+        let path = self.object_path(file_handle);
+        let stream = self
+            .store
+            .get(&path)
+            .await
+            .err_notes(&path)?
+            .into_stream()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e));
+        Ok(Box::new(ChecksumVerifyingReader::new(
+            StreamReader::new(stream),
+            expected_checksum,
+            path,
+        )))
+    }
+
+    async fn save_metadata_line(&self, name: &ShellSafeName, content: &TextLine) -> Result<()> {
+        let file_handle = format!("{}/{}", Self::METADATA_DIR, name.as_str());
+        let mut file = self.create_file(&file_handle).await?;
+        file.write_all(content.as_ref().as_bytes())
+            .await
+            .err_notes(&file_handle)?;
+        file.shutdown().await.err_notes(&file_handle)?;
+
+        Ok(())
+    }
+
+    async fn list_metadata_files(&self) -> Result<Vec<FileHandle>> {
+        let dir = self.prefix.child(Self::METADATA_DIR);
+        self.store
+            .list(Some(&dir))
+            .await
+            .err_notes(&dir)?
+            .map(|object_meta| -> Result<FileHandle> {
+                let object_meta = object_meta?;
+                let file_name = object_meta
+                    .location
+                    .filename()
+                    .ok_or_else(|| anyhow!("Unexpected object: {}", object_meta.location))?;
+                Ok(format!("{}/{}", Self::METADATA_DIR, file_name))
+            })
+            .collect::<Vec<Result<_>>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    async fn delete_file(&self, file_handle: &FileHandleRef) -> Result<()> {
+        // Delete the object before its checksum, so that it can't be read unverified.
+        let path = self.object_path(file_handle);
+        self.store.delete(&path).await.err_notes(&path)?;
+        let checksum_path = self.checksum_path(file_handle);
+        self.store
+            .delete(&checksum_path)
+            .await
+            .err_notes(&checksum_path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::storage::test_util::{
+    arb_backups, arb_metadata_files, test_delete_file_impl, test_save_and_list_metadata_files_impl,
+    test_write_and_read_impl,
+};
+use object_store::memory::InMemory;
+use proptest::prelude::*;
+use std::str::FromStr;
+use tokio::{io::AsyncReadExt, runtime::Runtime};
+
+fn new_in_memory_storage(prefix: Option<String>) -> (Arc<InMemory>, CloudStorage) {
+    let store = Arc::new(InMemory::new());
+    (store.clone(), CloudStorage::new(store, prefix))
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+
+    #[test]
+    fn test_write_and_read(
+        backups in arb_backups(),
+        prefix in proptest::option::of("[a-z]{1,10}"),
+    ) {
+        let (_, storage) = new_in_memory_storage(prefix);
+
+        let rt = Runtime::new().unwrap();
+        rt.block_on(test_write_and_read_impl(Box::new(storage), backups));
+    }
+
+    #[test]
+    fn test_save_list_metadata_files(
+        input in arb_metadata_files(),
+    ) {
+        let (_, storage) = new_in_memory_storage(None);
+
+        let rt = Runtime::new().unwrap();
+        rt.block_on(test_save_and_list_metadata_files_impl(Box::new(storage), input));
+    }
+}
+
+#[tokio::test]
+async fn test_delete_file() {
+    let (_, storage) = new_in_memory_storage(None);
+    test_delete_file_impl(Box::new(storage)).await;
+}
+
+#[tokio::test]
+async fn test_checksum_verification() {
+    let (store, storage) = new_in_memory_storage(Some("backups".to_string()));
+    let backup_handle = storage
+        .create_backup(&ShellSafeName::from_str("backup").unwrap())
+        .await
+        .unwrap();
+    let (file_handle, mut file) = storage
+        .create_for_write(&backup_handle, &ShellSafeName::from_str("file").unwrap())
+        .await
+        .unwrap();
+    file.write_all(b"content").await.unwrap();
+    file.shutdown().await.unwrap();
+
+    // The file can't be overwritten.
+    assert!(storage
+        .create_for_write(&backup_handle, &ShellSafeName::from_str("file").unwrap())
+        .await
+        .is_err());
+
+    // Corrupt the content, reading it fails.
+    store
+        .put(&storage.object_path(&file_handle), "corrupted".into())
+        .await
+        .unwrap();
+    let mut buf = Vec::new();
+    let mut file = storage.open_for_read(&file_handle).await.unwrap();
+    let err = file.read_to_end(&mut buf).await.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // Missing checksum, reading it fails.
+    store
+        .delete(&storage.checksum_path(&file_handle))
+        .await
+        .unwrap();
+    assert!(storage.open_for_read(&file_handle).await.is_err());
+}
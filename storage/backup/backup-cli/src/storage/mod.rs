@@ -1,6 +1,7 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod cloud_storage;
 pub mod command_adapter;
 pub mod local_fs;
 
@@ -10,6 +11,7 @@ mod test_util;
 mod tests;
 
 use crate::storage::{
+    cloud_storage::{AzureOpt, CloudStorage, GcsOpt, S3Opt},
     command_adapter::{CommandAdapter, CommandAdapterOpt},
     local_fs::{LocalFs, LocalFsOpt},
 };
@@ -167,6 +169,10 @@ pub trait BackupStorage: Send + Sync {
     ///   2. But the cache does expect the content stays the same for a file handle, so when
     /// reorganising metadata files, give them new unique names.
     async fn list_metadata_files(&self) -> Result<Vec<FileHandle>>;
+    /// Delete a file, which is either created by `create_for_write()` or listed by
+    /// `list_metadata_files()`. Used by the backup maintenance to remove backups that are merged
+    /// into bigger ones or fall out of the retention window.
+    async fn delete_file(&self, file_handle: &FileHandleRef) -> Result<()>;
 }
 
 #[derive(Parser)]
@@ -180,6 +186,21 @@ pub enum StorageOpt {
     https://github.com/aptos-labs/aptos-core/tree/main/storage/backup/backup-cli/src/storage/command_adapter/sample_configs/"
     )]
     CommandAdapter(CommandAdapterOpt),
+    #[clap(
+        about = "Select the S3 backup storage type, which stores backups in an AWS S3 bucket or an \
+    S3 compatible storage (e.g. MinIO), with multipart uploads, retries and checksum verification."
+    )]
+    S3(S3Opt),
+    #[clap(
+        about = "Select the GCS backup storage type, which stores backups in a Google Cloud Storage \
+    bucket, with multipart uploads, retries and checksum verification."
+    )]
+    Gcs(GcsOpt),
+    #[clap(
+        about = "Select the Azure backup storage type, which stores backups in an Azure Blob Storage \
+    container, with multipart uploads, retries and checksum verification."
+    )]
+    Azure(AzureOpt),
 }
 
 impl StorageOpt {
@@ -187,6 +208,9 @@ impl StorageOpt {
         Ok(match self {
             StorageOpt::LocalFs(opt) => Arc::new(LocalFs::new_with_opt(opt)),
             StorageOpt::CommandAdapter(opt) => Arc::new(CommandAdapter::new_with_opt(opt).await?),
+            StorageOpt::S3(opt) => Arc::new(CloudStorage::new_with_s3_opt(opt)?),
+            StorageOpt::Gcs(opt) => Arc::new(CloudStorage::new_with_gcs_opt(opt)?),
+            StorageOpt::Azure(opt) => Arc::new(CloudStorage::new_with_azure_opt(opt)?),
         })
     }
 }
@@ -47,6 +47,41 @@ pub async fn test_write_and_read_impl(
     }
 }
 
+pub async fn test_delete_file_impl(store: Box<dyn BackupStorage>) {
+    let backup_handle = store
+        .create_backup(&"backup".parse().unwrap())
+        .await
+        .unwrap();
+    let (file_handle, mut file) = store
+        .create_for_write(&backup_handle, &"file".parse().unwrap())
+        .await
+        .unwrap();
+    file.write_all(b"content").await.unwrap();
+    file.shutdown().await.unwrap();
+    store
+        .save_metadata_line(&"meta".parse().unwrap(), &TextLine::new("line").unwrap())
+        .await
+        .unwrap();
+    let metadata_files = store.list_metadata_files().await.unwrap();
+    assert_eq!(metadata_files.len(), 1);
+
+    store.delete_file(&file_handle).await.unwrap();
+    store.delete_file(&metadata_files[0]).await.unwrap();
+
+    let read_result = async {
+        let mut buf = Vec::new();
+        store
+            .open_for_read(&file_handle)
+            .await?
+            .read_to_end(&mut buf)
+            .await?;
+        Result::<_>::Ok(buf)
+    }
+    .await;
+    assert!(read_result.is_err());
+    assert!(store.list_metadata_files().await.unwrap().is_empty());
+}
+
 pub fn arb_backups(
 ) -> impl Strategy<Value = HashMap<ShellSafeName, HashMap<ShellSafeName, Vec<u8>>>> {
     hash_map(
@@ -22,7 +22,7 @@ use aptos_logger::info;
 use aptos_types::state_store::state_storage_usage::StateStorageUsage;
 use aptos_types::{
     state_store::{state_key::StateKey, state_value::StateValue},
-    transaction::Version,
+    transaction::{TransactionInfo, Version},
     waypoint::Waypoint,
 };
 use aptosdb::state_restore::StateSnapshotProgress;
@@ -39,6 +39,7 @@ use std::{
     path::{Path, PathBuf},
     sync::Arc,
 };
+use storage_interface::DbReader;
 use tokio::fs::metadata;
 
 #[derive(Clone, Parser)]
@@ -231,6 +232,15 @@ impl RestoreRunMode {
             RestoreRunMode::Verify => Ok(None),
         }
     }
+
+    pub fn get_latest_transaction_info(&self) -> Result<Option<(Version, TransactionInfo)>> {
+        match self {
+            RestoreRunMode::Restore { restore_handler } => {
+                restore_handler.aptosdb.get_latest_transaction_info_option()
+            }
+            RestoreRunMode::Verify => Ok(None),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -5,6 +5,8 @@ use aptos_crypto::{
     ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
     hash::{CryptoHasher as _, TestOnlyHasher},
     multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature},
+    secp256k1_ecdsa::{Secp256k1EcdsaPrivateKey, Secp256k1EcdsaPublicKey},
+    secp256r1_ecdsa::{Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey},
     traits::{SigningKey, Uniform},
 };
 use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
@@ -45,11 +47,23 @@ fn trace_crypto_values(tracer: &mut Tracer, samples: &mut Samples) -> Result<()>
     let public_key: Ed25519PublicKey = (&private_key).into();
     let signature = private_key.sign(&message).unwrap();
 
+    let secp256r1_ecdsa_private_key = Secp256r1EcdsaPrivateKey::generate(&mut rng);
+    let secp256r1_ecdsa_public_key: Secp256r1EcdsaPublicKey = (&secp256r1_ecdsa_private_key).into();
+    let secp256r1_ecdsa_signature = secp256r1_ecdsa_private_key.sign(&message).unwrap();
+
+    let secp256k1_ecdsa_private_key = Secp256k1EcdsaPrivateKey::generate(&mut rng);
+    let secp256k1_ecdsa_public_key: Secp256k1EcdsaPublicKey = (&secp256k1_ecdsa_private_key).into();
+    let secp256k1_ecdsa_signature = secp256k1_ecdsa_private_key.sign(&message).unwrap();
+
     tracer.trace_value(samples, &hashed_message)?;
     tracer.trace_value(samples, &public_key)?;
     tracer.trace_value::<MultiEd25519PublicKey>(samples, &public_key.into())?;
     tracer.trace_value(samples, &signature)?;
     tracer.trace_value::<MultiEd25519Signature>(samples, &signature.into())?;
+    tracer.trace_value(samples, &secp256r1_ecdsa_public_key)?;
+    tracer.trace_value(samples, &secp256r1_ecdsa_signature)?;
+    tracer.trace_value(samples, &secp256k1_ecdsa_public_key)?;
+    tracer.trace_value(samples, &secp256k1_ecdsa_signature)?;
     Ok(())
 }
 
@@ -4,4 +4,5 @@
 mod account;
 #[cfg(feature = "cli-framework-test-move")]
 mod r#move;
+mod multisig;
 mod validator;
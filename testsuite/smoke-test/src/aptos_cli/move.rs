@@ -135,6 +135,21 @@ async fn test_move_publish_flow() {
         .await
         .is_ok());
 
+    // Generate the bindings of the published package, which include the framework modules it uses
+    let bindings_file = cli.move_dir().join("bindings.rs");
+    match cli
+        .generate_bindings(0, Some(PACKAGE_NAME.to_owned()), bindings_file.clone())
+        .await
+    {
+        Ok(modules) => assert_eq!(modules, vec![format!("{}::message", account)]),
+        Err(err) => panic!("Should not have failed to generate bindings {:?}", err),
+    }
+    let bindings = std::fs::read_to_string(bindings_file).unwrap();
+    assert!(bindings.contains(&format!("pub mod _{} {{", account)));
+    assert!(bindings.contains("pub struct MessageHolder {"));
+    assert!(bindings.contains("pub async fn get_message("));
+    assert!(bindings.contains("pub struct EventHandle<T0> {"));
+
     // Now download the package. It will be stored in a directory PACKAGE_NAME inside move_dir.
     let _ = match cli
         .download_package(0, PACKAGE_NAME.to_owned(), cli.move_dir())
@@ -0,0 +1,80 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::smoke_test_environment::SwarmBuilder;
+use aptos::move_tool::MemberId;
+use aptos_crypto::PrivateKey;
+use aptos_temppath::TempPath;
+use std::str::FromStr;
+
+#[tokio::test]
+async fn test_multisig_flow() {
+    let (_swarm, cli, _faucet) = SwarmBuilder::new_local(1)
+        .with_aptos()
+        .build_with_cli(3)
+        .await;
+    let dir = TempPath::new();
+    dir.create_as_dir().unwrap();
+
+    // A 2-of-3 account of the keys of the CLI accounts, created and funded by the first one
+    let signers = [0, 1, 2];
+    let multisig = cli.create_multisig_account(0, &signers, 2).await.unwrap();
+    assert_eq!(multisig.transaction.success, Some(true));
+    let transfer = MemberId::from_str("0x1::aptos_account::transfer").unwrap();
+    cli.run_function(
+        0,
+        None,
+        transfer.clone(),
+        vec![&format!("address:{}", multisig.account), "u64:200000"],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    // Transfer coins from the multisig account to the second account
+    let transaction_file = dir.path().join("transaction.txt");
+    let raw_txn = cli
+        .build_multisig_transaction(
+            &signers,
+            2,
+            transfer,
+            vec![&format!("address:{}", cli.account_id(1)), "u64:1000"],
+            transaction_file.clone(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(raw_txn.sender(), multisig.account);
+    assert_eq!(raw_txn.sequence_number(), 0);
+
+    // Signed offline by the first and the last signers
+    let mut signature_files = Vec::new();
+    for index in [0, 2] {
+        let signature_file = dir.path().join(format!("signature_{}.json", index));
+        let signature = cli
+            .sign_multisig_transaction(index, transaction_file.clone(), signature_file.clone())
+            .await
+            .unwrap();
+        assert_eq!(signature.public_key, cli.private_key(index).public_key());
+        signature_files.push(signature_file);
+    }
+
+    // A single signature is below the threshold
+    cli.submit_multisig_transaction(
+        &signers,
+        2,
+        transaction_file.clone(),
+        signature_files[..1].to_vec(),
+    )
+    .await
+    .expect_err("One signature should not be enough to submit");
+
+    let receiver_balance = cli.account_balance_now(1).await.unwrap();
+    let summary = cli
+        .submit_multisig_transaction(&signers, 2, transaction_file, signature_files)
+        .await
+        .unwrap();
+    assert_eq!(summary.success, Some(true));
+    assert_eq!(summary.sender, Some(multisig.account));
+    cli.assert_account_balance_now(1, receiver_balance + 1000)
+        .await;
+}
@@ -3,7 +3,8 @@
 
 use aptos_indexer::{
     database::{new_db_pool, PgDbPool, PgPoolConnection},
-    models::transactions::TransactionQuery,
+    models::{processor_status::ProcessorStatusV2Query, transactions::TransactionQuery},
+    processors::{coin_processor, default_processor},
 };
 use aptos_sdk::types::LocalAccount;
 use cached_packages::aptos_stdlib::aptos_token_stdlib;
@@ -105,8 +106,11 @@ async fn test_old_indexer() {
 
             config.indexer.enabled = true;
             config.indexer.postgres_uri = Some(get_database_url());
-            config.indexer.processor =
-                Some(aptos_indexer::processors::default_processor::NAME.to_string());
+            // Both processors are driven by the same indexer runtime
+            config.indexer.processors = Some(vec![
+                default_processor::NAME.to_string(),
+                coin_processor::NAME.to_string(),
+            ]);
         }))
         .build()
         .await;
@@ -175,4 +179,15 @@ async fn test_old_indexer() {
     assert_eq!(events2.len(), 2);
     assert_eq!(events2.first().unwrap().type_, "0x1::coin::WithdrawEvent");
     assert_eq!(events2.get(1).unwrap().type_, "0x1::coin::DepositEvent");
+
+    // Each processor tracks its own progress
+    for processor_name in [default_processor::NAME, coin_processor::NAME] {
+        let status = ProcessorStatusV2Query::get_by_processor(
+            &processor_name.to_string(),
+            &mut conn_pool.get().unwrap(),
+        )
+        .unwrap()
+        .unwrap();
+        assert!(status.last_success_version >= tx2.version);
+    }
 }
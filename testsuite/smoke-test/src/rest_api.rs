@@ -1,15 +1,18 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
-use aptos_crypto::ed25519::Ed25519Signature;
+use aptos_crypto::{ed25519::Ed25519Signature, HashValue};
 use aptos_gas::{AptosGasParameters, FromOnChainGasSchedule};
 use aptos_rest_client::aptos_api_types::{MoveModuleId, TransactionData};
+use aptos_rest_client::VerifyingClient;
 use aptos_sdk::move_types::language_storage::StructTag;
+use aptos_sdk::move_types::move_resource::MoveStructType;
 use aptos_types::account_address::AccountAddress;
 use aptos_types::account_config::{AccountResource, CORE_CODE_ADDRESS};
 use aptos_types::on_chain_config::GasScheduleV2;
 use aptos_types::transaction::authenticator::AuthenticationKey;
 use aptos_types::transaction::{SignedTransaction, Transaction};
+use aptos_types::waypoint::Waypoint;
 use cached_packages::aptos_stdlib;
 use forge::Swarm;
 use std::convert::TryFrom;
@@ -64,6 +67,70 @@ async fn test_basic_client() {
     info.client().get_transactions(None, None).await.unwrap();
 }
 
+#[tokio::test]
+async fn test_verifying_client() {
+    let mut swarm = new_local_swarm_with_aptos(1).await;
+    let genesis_waypoint = swarm
+        .validators()
+        .next()
+        .unwrap()
+        .config()
+        .base
+        .waypoint
+        .genesis_waypoint();
+    let mut info = swarm.aptos_public_info();
+    let account = info.create_and_fund_user_account(10_000_000).await.unwrap();
+
+    let client = VerifyingClient::new(info.client().clone(), genesis_waypoint);
+    let trusted_state = client.sync().await.unwrap();
+    assert!(!trusted_state.is_epoch_waypoint());
+    assert!(trusted_state.version() > 0);
+
+    // Resources, including missing ones, are verified against the validator signatures
+    let version = trusted_state.version();
+    let (snapshot_version, account_resource) = client
+        .get_account_resource_bytes(
+            account.address(),
+            AccountResource::struct_tag(),
+            Some(version),
+            version,
+        )
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(snapshot_version <= version);
+    let account_resource: AccountResource = bcs::from_bytes(&account_resource.unwrap()).unwrap();
+    assert_eq!(account_resource.sequence_number(), 0);
+    assert!(client
+        .get_account_resource_bytes(
+            AccountAddress::random(),
+            AccountResource::struct_tag(),
+            None,
+            u64::MAX,
+        )
+        .await
+        .unwrap()
+        .into_inner()
+        .1
+        .is_none());
+
+    // So are transactions and their events
+    let transactions = client
+        .get_transactions(0, Some(2))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(transactions.transactions.len(), 2);
+    assert_eq!(transactions.events.unwrap().len(), 2);
+
+    // Nothing can be verified from a waypoint of another chain
+    let other_waypoint =
+        Waypoint::from_str(&format!("0:{}", HashValue::random().to_hex())).unwrap();
+    let other_client = VerifyingClient::new(info.client().clone(), other_waypoint);
+    assert!(other_client.sync().await.is_err());
+    assert!(other_client.get_transactions(0, Some(2)).await.is_err());
+}
+
 // Test needs to be fixed to estimate over a longer period of time / probably needs an adjustable window
 // to test
 #[ignore]
@@ -375,6 +442,38 @@ async fn test_bcs() {
         aptos_crypto::HashValue::from(json_txn.info.hash),
         bcs_txn.info.transaction_hash()
     );
+    assert!(json_txn.gas_profile.is_none());
+
+    // Gas profiling shouldn't change the outcome of the simulation, and its breakdown should add
+    // up to the gas used
+    let profiled_txns = client
+        .simulate_with_gas_profile(&signed_txn)
+        .await
+        .unwrap()
+        .into_inner();
+    let profiled_txn = profiled_txns.first().unwrap();
+    assert_eq!(profiled_txn.info.hash, json_txn.info.hash);
+    assert_eq!(profiled_txn.info.gas_used, json_txn.info.gas_used);
+
+    let gas_profile = profiled_txn.gas_profile.as_ref().unwrap();
+    let total_gas = u64::from(gas_profile.intrinsic)
+        + u64::from(gas_profile.storage_reads.gas)
+        + gas_profile
+            .functions
+            .iter()
+            .chain(&gas_profile.natives)
+            .chain(&gas_profile.storage_writes)
+            .map(|entry| u64::from(entry.gas))
+            .sum::<u64>();
+    let scaling_factor = u64::from(gas_profile.gas_unit_scaling_factor);
+    assert_eq!(
+        (total_gas + scaling_factor - 1) / scaling_factor,
+        u64::from(json_txn.info.gas_used)
+    );
+    assert!(gas_profile
+        .functions
+        .iter()
+        .any(|entry| entry.name == "0x1::coin::transfer"));
 
     // Actually submit the transaction, and ensure it submits and succeeds
     // TODO: check failure case?
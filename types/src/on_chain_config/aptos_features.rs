@@ -10,6 +10,8 @@ use serde::{Deserialize, Serialize};
 pub enum FeatureFlag {
     CODE_DEPENDENCY_CHECK = 1,
     TREAT_FRIEND_AS_PRIVATE = 2,
+    SECP256R1_ECDSA_AUTHENTICATOR = 3,
+    SECP256K1_ECDSA_AUTHENTICATOR = 4,
 }
 
 /// Representation of features on chain as a bitset.
@@ -3,13 +3,15 @@
 
 use crate::{
     account_address::AccountAddress,
-    transaction::{RawTransaction, RawTransactionWithData},
+    transaction::{webauthn::WebAuthnSignature, RawTransaction, RawTransactionWithData},
 };
 use anyhow::{ensure, Error, Result};
 use aptos_crypto::{
     ed25519::{Ed25519PublicKey, Ed25519Signature},
     hash::CryptoHash,
     multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature},
+    secp256k1_ecdsa::{Secp256k1EcdsaPublicKey, Secp256k1EcdsaSignature},
+    secp256r1_ecdsa::{Secp256r1EcdsaPublicKey, Secp256r1EcdsaSignature},
     traits::Signature,
     CryptoMaterialError, HashValue, ValidCryptoMaterial, ValidCryptoMaterialStringExt,
 };
@@ -57,6 +59,21 @@ pub enum TransactionAuthenticator {
         secondary_signer_addresses: Vec<AccountAddress>,
         secondary_signers: Vec<AccountAuthenticator>,
     },
+    /// Single Secp256r1 ECDSA signature
+    Secp256r1Ecdsa {
+        public_key: Secp256r1EcdsaPublicKey,
+        signature: Secp256r1EcdsaSignature,
+    },
+    /// WebAuthn assertion signed with a Secp256r1 ECDSA key
+    WebAuthn {
+        public_key: Secp256r1EcdsaPublicKey,
+        signature: WebAuthnSignature,
+    },
+    /// Single Secp256k1 ECDSA signature
+    Secp256k1Ecdsa {
+        public_key: Secp256k1EcdsaPublicKey,
+        signature: Secp256k1EcdsaSignature,
+    },
 }
 
 impl TransactionAuthenticator {
@@ -79,6 +96,36 @@ impl TransactionAuthenticator {
         }
     }
 
+    /// Create a single-signature secp256r1 ecdsa authenticator
+    pub fn secp256r1_ecdsa(
+        public_key: Secp256r1EcdsaPublicKey,
+        signature: Secp256r1EcdsaSignature,
+    ) -> Self {
+        Self::Secp256r1Ecdsa {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Create a WebAuthn authenticator
+    pub fn webauthn(public_key: Secp256r1EcdsaPublicKey, signature: WebAuthnSignature) -> Self {
+        Self::WebAuthn {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Create a single-signature secp256k1 ecdsa authenticator
+    pub fn secp256k1_ecdsa(
+        public_key: Secp256k1EcdsaPublicKey,
+        signature: Secp256k1EcdsaSignature,
+    ) -> Self {
+        Self::Secp256k1Ecdsa {
+            public_key,
+            signature,
+        }
+    }
+
     /// Create a multi-agent authenticator
     pub fn multi_agent(
         sender: AccountAuthenticator,
@@ -127,6 +174,18 @@ impl TransactionAuthenticator {
                 }
                 Ok(())
             }
+            Self::Secp256r1Ecdsa {
+                public_key,
+                signature,
+            } => signature.verify(raw_txn, public_key),
+            Self::WebAuthn {
+                public_key,
+                signature,
+            } => signature.verify(raw_txn, public_key),
+            Self::Secp256k1Ecdsa {
+                public_key,
+                signature,
+            } => signature.verify(raw_txn, public_key),
         }
     }
 
@@ -144,6 +203,18 @@ impl TransactionAuthenticator {
                 signature,
             } => AccountAuthenticator::multi_ed25519(public_key.clone(), signature.clone()),
             Self::MultiAgent { sender, .. } => sender.clone(),
+            Self::Secp256r1Ecdsa {
+                public_key,
+                signature,
+            } => AccountAuthenticator::secp256r1_ecdsa(public_key.clone(), signature.clone()),
+            Self::WebAuthn {
+                public_key,
+                signature,
+            } => AccountAuthenticator::webauthn(public_key.clone(), signature.clone()),
+            Self::Secp256k1Ecdsa {
+                public_key,
+                signature,
+            } => AccountAuthenticator::secp256k1_ecdsa(public_key.clone(), signature.clone()),
         }
     }
 
@@ -153,7 +224,10 @@ impl TransactionAuthenticator {
             | Self::MultiEd25519 {
                 public_key: _,
                 signature: _,
-            } => vec![],
+            }
+            | Self::Secp256r1Ecdsa { .. }
+            | Self::WebAuthn { .. }
+            | Self::Secp256k1Ecdsa { .. } => vec![],
             Self::MultiAgent {
                 sender: _,
                 secondary_signer_addresses,
@@ -168,7 +242,10 @@ impl TransactionAuthenticator {
             | Self::MultiEd25519 {
                 public_key: _,
                 signature: _,
-            } => vec![],
+            }
+            | Self::Secp256r1Ecdsa { .. }
+            | Self::WebAuthn { .. }
+            | Self::Secp256k1Ecdsa { .. } => vec![],
             Self::MultiAgent {
                 sender: _,
                 secondary_signer_addresses: _,
@@ -176,6 +253,13 @@ impl TransactionAuthenticator {
             } => secondary_signers.to_vec(),
         }
     }
+
+    /// Return the authenticators of all signers, starting with the sender
+    pub fn all_signers(&self) -> Vec<AccountAuthenticator> {
+        let mut signers = vec![self.sender()];
+        signers.extend(self.secondary_signers());
+        signers
+    }
 }
 
 impl fmt::Display for TransactionAuthenticator {
@@ -224,6 +308,36 @@ impl fmt::Display for TransactionAuthenticator {
                     sender, sec_addrs, sec_signers,
                 )
             }
+            Self::Secp256r1Ecdsa {
+                public_key: _,
+                signature: _,
+            } => {
+                write!(
+                    f,
+                    "TransactionAuthenticator[scheme: Secp256r1Ecdsa, sender: {}]",
+                    self.sender()
+                )
+            }
+            Self::WebAuthn {
+                public_key: _,
+                signature: _,
+            } => {
+                write!(
+                    f,
+                    "TransactionAuthenticator[scheme: WebAuthn, sender: {}]",
+                    self.sender()
+                )
+            }
+            Self::Secp256k1Ecdsa {
+                public_key: _,
+                signature: _,
+            } => {
+                write!(
+                    f,
+                    "TransactionAuthenticator[scheme: Secp256k1Ecdsa, sender: {}]",
+                    self.sender()
+                )
+            }
         }
     }
 }
@@ -241,6 +355,10 @@ impl fmt::Display for TransactionAuthenticator {
 pub enum Scheme {
     Ed25519 = 0,
     MultiEd25519 = 1,
+    /// Used by both plain Secp256r1 ECDSA signatures and WebAuthn assertions, so that the same
+    /// Secp256r1 key controls the same account regardless of how it signs.
+    Secp256r1Ecdsa = 2,
+    Secp256k1Ecdsa = 3,
     // ... add more schemes here
     /// Scheme identifier used when hashing an account's address together with a seed to derive the
     /// address (not the authentication key) of a resource account. This is an abuse of the notion
@@ -256,6 +374,8 @@ impl fmt::Display for Scheme {
         let display = match self {
             Scheme::Ed25519 => "Ed25519",
             Scheme::MultiEd25519 => "MultiEd25519",
+            Scheme::Secp256r1Ecdsa => "Secp256r1Ecdsa",
+            Scheme::Secp256k1Ecdsa => "Secp256k1Ecdsa",
             Scheme::DeriveResourceAccountAddress => "DeriveResourceAccountAddress",
         };
         write!(f, "Scheme::{}", display)
@@ -274,6 +394,21 @@ pub enum AccountAuthenticator {
         public_key: MultiEd25519PublicKey,
         signature: MultiEd25519Signature,
     },
+    /// Single Secp256r1 ECDSA signature
+    Secp256r1Ecdsa {
+        public_key: Secp256r1EcdsaPublicKey,
+        signature: Secp256r1EcdsaSignature,
+    },
+    /// WebAuthn assertion signed with a Secp256r1 ECDSA key
+    WebAuthn {
+        public_key: Secp256r1EcdsaPublicKey,
+        signature: WebAuthnSignature,
+    },
+    /// Single Secp256k1 ECDSA signature
+    Secp256k1Ecdsa {
+        public_key: Secp256k1EcdsaPublicKey,
+        signature: Secp256k1EcdsaSignature,
+    },
     // ... add more schemes here
 }
 
@@ -283,6 +418,8 @@ impl AccountAuthenticator {
         match self {
             Self::Ed25519 { .. } => Scheme::Ed25519,
             Self::MultiEd25519 { .. } => Scheme::MultiEd25519,
+            Self::Secp256r1Ecdsa { .. } | Self::WebAuthn { .. } => Scheme::Secp256r1Ecdsa,
+            Self::Secp256k1Ecdsa { .. } => Scheme::Secp256k1Ecdsa,
         }
     }
 
@@ -305,6 +442,36 @@ impl AccountAuthenticator {
         }
     }
 
+    /// Create a single-signature secp256r1 ecdsa authenticator
+    pub fn secp256r1_ecdsa(
+        public_key: Secp256r1EcdsaPublicKey,
+        signature: Secp256r1EcdsaSignature,
+    ) -> Self {
+        Self::Secp256r1Ecdsa {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Create a WebAuthn authenticator
+    pub fn webauthn(public_key: Secp256r1EcdsaPublicKey, signature: WebAuthnSignature) -> Self {
+        Self::WebAuthn {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Create a single-signature secp256k1 ecdsa authenticator
+    pub fn secp256k1_ecdsa(
+        public_key: Secp256k1EcdsaPublicKey,
+        signature: Secp256k1EcdsaSignature,
+    ) -> Self {
+        Self::Secp256k1Ecdsa {
+            public_key,
+            signature,
+        }
+    }
+
     /// Return Ok if the authenticator's public key matches its signature, Err otherwise
     pub fn verify<T: Serialize + CryptoHash>(&self, message: &T) -> Result<()> {
         match self {
@@ -316,6 +483,18 @@ impl AccountAuthenticator {
                 public_key,
                 signature,
             } => signature.verify(message, public_key),
+            Self::Secp256r1Ecdsa {
+                public_key,
+                signature,
+            } => signature.verify(message, public_key),
+            Self::WebAuthn {
+                public_key,
+                signature,
+            } => signature.verify(message, public_key),
+            Self::Secp256k1Ecdsa {
+                public_key,
+                signature,
+            } => signature.verify(message, public_key),
         }
     }
 
@@ -324,6 +503,10 @@ impl AccountAuthenticator {
         match self {
             Self::Ed25519 { public_key, .. } => public_key.to_bytes().to_vec(),
             Self::MultiEd25519 { public_key, .. } => public_key.to_bytes().to_vec(),
+            Self::Secp256r1Ecdsa { public_key, .. } | Self::WebAuthn { public_key, .. } => {
+                public_key.to_bytes().to_vec()
+            }
+            Self::Secp256k1Ecdsa { public_key, .. } => public_key.to_bytes().to_vec(),
         }
     }
 
@@ -332,6 +515,9 @@ impl AccountAuthenticator {
         match self {
             Self::Ed25519 { signature, .. } => signature.to_bytes().to_vec(),
             Self::MultiEd25519 { signature, .. } => signature.to_bytes().to_vec(),
+            Self::Secp256r1Ecdsa { signature, .. } => signature.to_bytes().to_vec(),
+            Self::WebAuthn { signature, .. } => signature.signature().to_bytes().to_vec(),
+            Self::Secp256k1Ecdsa { signature, .. } => signature.to_bytes().to_vec(),
         }
     }
 
@@ -350,6 +536,8 @@ impl AccountAuthenticator {
         match self {
             Self::Ed25519 { .. } => 1,
             Self::MultiEd25519 { signature, .. } => signature.signatures().len(),
+            Self::Secp256r1Ecdsa { .. } | Self::WebAuthn { .. } => 1,
+            Self::Secp256k1Ecdsa { .. } => 1,
         }
     }
 }
@@ -402,6 +590,16 @@ impl AuthenticationKey {
         Self::from_preimage(&AuthenticationKeyPreimage::multi_ed25519(public_key))
     }
 
+    /// Create an authentication key from a Secp256r1 ECDSA public key
+    pub fn secp256r1_ecdsa(public_key: &Secp256r1EcdsaPublicKey) -> Self {
+        Self::from_preimage(&AuthenticationKeyPreimage::secp256r1_ecdsa(public_key))
+    }
+
+    /// Create an authentication key from a Secp256k1 ECDSA public key
+    pub fn secp256k1_ecdsa(public_key: &Secp256k1EcdsaPublicKey) -> Self {
+        Self::from_preimage(&AuthenticationKeyPreimage::secp256k1_ecdsa(public_key))
+    }
+
     /// Return an address derived from the last `AccountAddress::LENGTH` bytes of this
     /// authentication key.
     pub fn derived_address(&self) -> AccountAddress {
@@ -457,6 +655,16 @@ impl AuthenticationKeyPreimage {
         Self::new(public_key.to_bytes(), Scheme::MultiEd25519)
     }
 
+    /// Construct a preimage from a Secp256r1 ECDSA public key
+    pub fn secp256r1_ecdsa(public_key: &Secp256r1EcdsaPublicKey) -> AuthenticationKeyPreimage {
+        Self::new(public_key.to_bytes().to_vec(), Scheme::Secp256r1Ecdsa)
+    }
+
+    /// Construct a preimage from a Secp256k1 ECDSA public key
+    pub fn secp256k1_ecdsa(public_key: &Secp256k1EcdsaPublicKey) -> AuthenticationKeyPreimage {
+        Self::new(public_key.to_bytes().to_vec(), Scheme::Secp256k1Ecdsa)
+    }
+
     /// Construct a vector from this authentication key
     pub fn into_vec(self) -> Vec<u8> {
         self.0
@@ -10,7 +10,10 @@ use crate::{
     proof::{
         accumulator::InMemoryAccumulator, TransactionInfoListWithProof, TransactionInfoWithProof,
     },
-    transaction::authenticator::{AccountAuthenticator, TransactionAuthenticator},
+    transaction::{
+        authenticator::{AccountAuthenticator, TransactionAuthenticator},
+        webauthn::WebAuthnSignature,
+    },
     vm_status::{DiscardedVMStatus, KeptVMStatus, StatusCode, StatusType, VMStatus},
     write_set::WriteSet,
 };
@@ -19,6 +22,8 @@ use aptos_crypto::{
     ed25519::*,
     hash::{CryptoHash, EventAccumulatorHasher},
     multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature},
+    secp256k1_ecdsa::{Secp256k1EcdsaPrivateKey, Secp256k1EcdsaPublicKey, Secp256k1EcdsaSignature},
+    secp256r1_ecdsa::{Secp256r1EcdsaPrivateKey, Secp256r1EcdsaPublicKey, Secp256r1EcdsaSignature},
     traits::{signing_message, SigningKey},
     CryptoMaterialError, HashValue,
 };
@@ -39,6 +44,7 @@ mod change_set;
 mod module;
 mod script;
 mod transaction_argument;
+pub mod webauthn;
 
 pub use change_set::ChangeSet;
 pub use module::{Module, ModuleBundle};
@@ -222,6 +228,32 @@ impl RawTransaction {
         )))
     }
 
+    /// Signs the given `RawTransaction` with a Secp256r1 ECDSA key. Note that this consumes the
+    /// `RawTransaction` and turns it into a `SignatureCheckedTransaction`.
+    pub fn sign_secp256r1_ecdsa(
+        self,
+        private_key: &Secp256r1EcdsaPrivateKey,
+        public_key: Secp256r1EcdsaPublicKey,
+    ) -> Result<SignatureCheckedTransaction> {
+        let signature = private_key.sign(&self)?;
+        Ok(SignatureCheckedTransaction(
+            SignedTransaction::new_secp256r1_ecdsa(self, public_key, signature),
+        ))
+    }
+
+    /// Signs the given `RawTransaction` with a Secp256k1 ECDSA key. Note that this consumes the
+    /// `RawTransaction` and turns it into a `SignatureCheckedTransaction`.
+    pub fn sign_secp256k1_ecdsa(
+        self,
+        private_key: &Secp256k1EcdsaPrivateKey,
+        public_key: Secp256k1EcdsaPublicKey,
+    ) -> Result<SignatureCheckedTransaction> {
+        let signature = private_key.sign(&self)?;
+        Ok(SignatureCheckedTransaction(
+            SignedTransaction::new_secp256k1_ecdsa(self, public_key, signature),
+        ))
+    }
+
     /// Signs the given multi-agent `RawTransaction`, which is a transaction with secondary
     /// signers in addition to a sender. The private keys of the sender and the
     /// secondary signers are used to sign the transaction.
@@ -494,6 +526,45 @@ impl SignedTransaction {
         }
     }
 
+    pub fn new_secp256r1_ecdsa(
+        raw_txn: RawTransaction,
+        public_key: Secp256r1EcdsaPublicKey,
+        signature: Secp256r1EcdsaSignature,
+    ) -> SignedTransaction {
+        let authenticator = TransactionAuthenticator::secp256r1_ecdsa(public_key, signature);
+        SignedTransaction {
+            raw_txn,
+            authenticator,
+            size: OnceCell::new(),
+        }
+    }
+
+    pub fn new_webauthn(
+        raw_txn: RawTransaction,
+        public_key: Secp256r1EcdsaPublicKey,
+        signature: WebAuthnSignature,
+    ) -> SignedTransaction {
+        let authenticator = TransactionAuthenticator::webauthn(public_key, signature);
+        SignedTransaction {
+            raw_txn,
+            authenticator,
+            size: OnceCell::new(),
+        }
+    }
+
+    pub fn new_secp256k1_ecdsa(
+        raw_txn: RawTransaction,
+        public_key: Secp256k1EcdsaPublicKey,
+        signature: Secp256k1EcdsaSignature,
+    ) -> SignedTransaction {
+        let authenticator = TransactionAuthenticator::secp256k1_ecdsa(public_key, signature);
+        SignedTransaction {
+            raw_txn,
+            authenticator,
+            size: OnceCell::new(),
+        }
+    }
+
     pub fn new_multi_agent(
         raw_txn: RawTransaction,
         sender: AccountAuthenticator,
@@ -0,0 +1,190 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! Support for [WebAuthn](https://www.w3.org/TR/webauthn-2/) assertions, which allow transactions
+//! to be signed by passkeys and other platform authenticators holding a Secp256r1 ECDSA key.
+//!
+//! An authenticator does not sign the transaction directly. Instead, the SHA3-256 hash of the
+//! transaction's signing message is used as the WebAuthn challenge, and the authenticator signs
+//! `authenticator_data || sha256(client_data_json)`, where `client_data_json` embeds the
+//! base64url-encoded challenge.
+
+use anyhow::{ensure, Result};
+use aptos_crypto::{
+    hash::CryptoHash,
+    secp256r1_ecdsa::{Secp256r1EcdsaPublicKey, Secp256r1EcdsaSignature},
+    traits::{signing_message, Signature},
+    HashValue,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// The `type` of the client data for an assertion, see
+/// https://www.w3.org/TR/webauthn-2/#dom-collectedclientdata-type
+const WEBAUTHN_GET_TYPE: &str = "webauthn.get";
+
+/// The authenticator data starts with the 32 bytes SHA2-256 hash of the relying party id,
+/// followed by a one byte flags field and a 4 bytes signature counter.
+const AUTHENTICATOR_DATA_MIN_LENGTH: usize = 37;
+const AUTHENTICATOR_DATA_FLAGS_INDEX: usize = 32;
+/// The "user present" bit of the authenticator data flags.
+const AUTHENTICATOR_DATA_FLAG_UP: u8 = 0x01;
+
+/// The subset of the client data collected by the WebAuthn client that is checked during
+/// verification, see https://www.w3.org/TR/webauthn-2/#dictionary-client-data
+#[derive(Deserialize)]
+struct CollectedClientData {
+    #[serde(rename = "type")]
+    ty: String,
+    challenge: String,
+}
+
+/// A WebAuthn assertion over a transaction, produced by an authenticator holding a Secp256r1
+/// ECDSA key.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct WebAuthnSignature {
+    /// The Secp256r1 ECDSA signature over `authenticator_data || sha256(client_data_json)`
+    signature: Secp256r1EcdsaSignature,
+    /// The raw authenticator data returned by the authenticator
+    #[serde(with = "serde_bytes")]
+    authenticator_data: Vec<u8>,
+    /// The raw JSON serialized client data, as returned by the WebAuthn client
+    #[serde(with = "serde_bytes")]
+    client_data_json: Vec<u8>,
+}
+
+impl WebAuthnSignature {
+    pub fn new(
+        signature: Secp256r1EcdsaSignature,
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+    ) -> Self {
+        Self {
+            signature,
+            authenticator_data,
+            client_data_json,
+        }
+    }
+
+    pub fn signature(&self) -> &Secp256r1EcdsaSignature {
+        &self.signature
+    }
+
+    pub fn authenticator_data(&self) -> &[u8] {
+        &self.authenticator_data
+    }
+
+    pub fn client_data_json(&self) -> &[u8] {
+        &self.client_data_json
+    }
+
+    /// Return the challenge that must be passed to the authenticator to sign `message`
+    pub fn challenge<T: CryptoHash + Serialize>(message: &T) -> Result<HashValue> {
+        Ok(HashValue::sha3_256_of(&signing_message(message)?))
+    }
+
+    /// Return the bytes signed by the authenticator for the given authenticator and client data
+    pub fn signed_bytes(authenticator_data: &[u8], client_data_json: &[u8]) -> Vec<u8> {
+        let mut bytes = authenticator_data.to_vec();
+        bytes.extend_from_slice(&Sha256::digest(client_data_json));
+        bytes
+    }
+
+    /// Return Ok if this assertion is valid for `message` and `public_key`, Err otherwise
+    pub fn verify<T: CryptoHash + Serialize>(
+        &self,
+        message: &T,
+        public_key: &Secp256r1EcdsaPublicKey,
+    ) -> Result<()> {
+        let client_data: CollectedClientData = serde_json::from_slice(&self.client_data_json)?;
+        ensure!(
+            client_data.ty == WEBAUTHN_GET_TYPE,
+            "Unexpected WebAuthn client data type: {}",
+            client_data.ty
+        );
+        let expected_challenge =
+            base64::encode_config(Self::challenge(message)?.as_ref(), base64::URL_SAFE_NO_PAD);
+        ensure!(
+            client_data.challenge == expected_challenge,
+            "WebAuthn challenge does not match the transaction"
+        );
+        ensure!(
+            self.authenticator_data.len() >= AUTHENTICATOR_DATA_MIN_LENGTH,
+            "WebAuthn authenticator data is too short: {} bytes",
+            self.authenticator_data.len()
+        );
+        ensure!(
+            self.authenticator_data[AUTHENTICATOR_DATA_FLAGS_INDEX] & AUTHENTICATOR_DATA_FLAG_UP
+                != 0,
+            "WebAuthn authenticator data does not have the user present flag set"
+        );
+
+        self.signature.verify_arbitrary_msg(
+            &Self::signed_bytes(&self.authenticator_data, &self.client_data_json),
+            public_key,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{account_address::AccountAddress, chain_id::ChainId, transaction::RawTransaction};
+    use aptos_crypto::{
+        secp256r1_ecdsa::Secp256r1EcdsaPrivateKey, PrivateKey, SigningKey, Uniform,
+    };
+
+    fn raw_txn() -> RawTransaction {
+        RawTransaction::new_script(
+            AccountAddress::random(),
+            0,
+            crate::transaction::Script::new(vec![], vec![], vec![]),
+            0,
+            0,
+            0,
+            ChainId::test(),
+        )
+    }
+
+    fn assertion(
+        private_key: &Secp256r1EcdsaPrivateKey,
+        ty: &str,
+        challenge: HashValue,
+        flags: u8,
+    ) -> WebAuthnSignature {
+        let mut authenticator_data = vec![0u8; AUTHENTICATOR_DATA_MIN_LENGTH];
+        authenticator_data[AUTHENTICATOR_DATA_FLAGS_INDEX] = flags;
+        let client_data_json = format!(
+            r#"{{"type":"{}","challenge":"{}","origin":"https://aptoslabs.com"}}"#,
+            ty,
+            base64::encode_config(challenge.as_ref(), base64::URL_SAFE_NO_PAD)
+        )
+        .into_bytes();
+        let signature = private_key.sign_arbitrary_message(&WebAuthnSignature::signed_bytes(
+            &authenticator_data,
+            &client_data_json,
+        ));
+        WebAuthnSignature::new(signature, authenticator_data, client_data_json)
+    }
+
+    #[test]
+    fn test_verify_webauthn_assertion() {
+        let private_key = Secp256r1EcdsaPrivateKey::generate_for_testing();
+        let public_key = private_key.public_key();
+        let txn = raw_txn();
+        let challenge = WebAuthnSignature::challenge(&txn).unwrap();
+
+        let valid = assertion(&private_key, WEBAUTHN_GET_TYPE, challenge, 0x05);
+        assert!(valid.verify(&txn, &public_key).is_ok());
+        assert!(valid.verify(&raw_txn(), &public_key).is_err());
+
+        let wrong_type = assertion(&private_key, "webauthn.create", challenge, 0x05);
+        assert!(wrong_type.verify(&txn, &public_key).is_err());
+
+        let wrong_challenge = assertion(&private_key, WEBAUTHN_GET_TYPE, HashValue::zero(), 0x05);
+        assert!(wrong_challenge.verify(&txn, &public_key).is_err());
+
+        let user_not_present = assertion(&private_key, WEBAUTHN_GET_TYPE, challenge, 0x04);
+        assert!(user_not_present.verify(&txn, &public_key).is_err());
+    }
+}